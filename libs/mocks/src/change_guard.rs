@@ -29,6 +29,10 @@ pub mod pallet {
 		) {
 			register_call!(move |(a, b)| f(a, b));
 		}
+
+		pub fn mock_discard(f: impl Fn(T::PoolId, T::ChangeId) -> DispatchResult + 'static) {
+			register_call!(move |(a, b)| f(a, b));
+		}
 	}
 
 	impl<T: Config> ChangeGuard for Pallet<T> {
@@ -43,6 +47,10 @@ pub mod pallet {
 		fn released(a: T::PoolId, b: T::ChangeId) -> Result<T::Change, DispatchError> {
 			execute_call!((a, b))
 		}
+
+		fn discard(a: T::PoolId, b: T::ChangeId) -> DispatchResult {
+			execute_call!((a, b))
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -26,6 +26,10 @@ pub mod pallet {
 			register_call!(move |(a, b)| f(a, b));
 		}
 
+		pub fn mock_is_frozen(f: impl Fn(&T::DataId, &T::CollectionId) -> bool + 'static) {
+			register_call!(move |(a, b)| f(a, b));
+		}
+
 		pub fn mock_collection(
 			f: impl Fn(&T::CollectionId) -> Result<T::Collection, DispatchError> + 'static,
 		) {
@@ -53,6 +57,10 @@ pub mod pallet {
 			execute_call!((a, b))
 		}
 
+		fn is_frozen(a: &T::DataId, b: &T::CollectionId) -> bool {
+			execute_call!((a, b))
+		}
+
 		fn collection(a: &T::CollectionId) -> Result<T::Collection, DispatchError> {
 			execute_call!(a)
 		}
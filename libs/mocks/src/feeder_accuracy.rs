@@ -0,0 +1,31 @@
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use cfg_traits::FeederAccuracy;
+	use frame_support::pallet_prelude::*;
+	use mock_builder::{execute_call, register_call};
+	use sp_runtime::Perbill;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Feeder;
+		type Key;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	type CallIds<T: Config> = StorageMap<_, _, String, mock_builder::CallId>;
+
+	impl<T: Config> Pallet<T> {
+		pub fn mock_deviation(f: impl Fn(&T::Feeder, &T::Key) -> Option<Perbill> + 'static) {
+			register_call!(move |(a, b)| f(a, b));
+		}
+	}
+
+	impl<T: Config> FeederAccuracy<T::Feeder, T::Key> for Pallet<T> {
+		fn deviation(a: &T::Feeder, b: &T::Key) -> Option<Perbill> {
+			execute_call!((a, b))
+		}
+	}
+}
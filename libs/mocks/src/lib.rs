@@ -4,12 +4,14 @@ pub mod converter;
 pub mod currency_conversion;
 pub mod data;
 pub mod ethereum_transactor;
+pub mod feeder_accuracy;
 pub mod fees;
 pub mod foreign_investment;
 pub mod foreign_investment_hooks;
 pub mod investment;
 pub mod liquidity_pools;
 pub mod liquidity_pools_gateway;
+pub mod member_updater;
 pub mod pay_fee;
 pub mod permissions;
 pub mod pools;
@@ -26,10 +28,12 @@ pub mod write_off_policy;
 pub use change_guard::pallet as pallet_mock_change_guard;
 pub use currency_conversion::pallet as pallet_mock_currency_conversion;
 pub use data::pallet as pallet_mock_data;
+pub use feeder_accuracy::pallet as pallet_mock_feeder_accuracy;
 pub use fees::pallet as pallet_mock_fees;
 pub use investment::pallet as pallet_mock_investment;
 pub use liquidity_pools::pallet as pallet_mock_liquidity_pools;
 pub use liquidity_pools_gateway::pallet as pallet_mock_liquidity_pools_gateway;
+pub use member_updater::pallet as pallet_mock_member_updater;
 pub use pay_fee::pallet as pallet_mock_pay_fee;
 pub use permissions::pallet as pallet_mock_permissions;
 pub use pools::pallet as pallet_mock_pools;
@@ -1,9 +1,12 @@
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
-	use cfg_traits::liquidity_pools::{MessageProcessor, OutboundMessageHandler};
+	use cfg_traits::liquidity_pools::{
+		GasPriceUpdater, MessageFeeQuoter, MessageProcessor, OutboundMessageHandler,
+	};
 	use frame_support::pallet_prelude::*;
 	use mock_builder::{execute_call, register_call, CallHandler};
 	use orml_traits::GetByKey;
+	use sp_core::U256;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -28,7 +31,7 @@ pub mod pallet {
 			register_call!(f);
 		}
 
-		pub fn mock_get(f: impl Fn(&T::Destination) -> Option<[u8; 20]> + 'static) {
+		pub fn mock_get(f: impl Fn(&T::Destination) -> Option<[u8; 32]> + 'static) {
 			register_call!(f);
 		}
 
@@ -37,6 +40,24 @@ pub mod pallet {
 		) {
 			register_call!(move |(sender, destination, msg)| f(sender, destination, msg));
 		}
+
+		pub fn mock_is_packed(
+			f: impl Fn(&T::AccountId, &T::Destination, &T::Message) -> bool + 'static,
+		) {
+			register_call!(move |(sender, destination, msg)| f(sender, destination, msg));
+		}
+
+		pub fn mock_quote(
+			f: impl Fn(&T::Destination, &T::Message) -> Result<U256, DispatchError> + 'static,
+		) {
+			register_call!(move |(destination, msg)| f(destination, msg));
+		}
+
+		pub fn mock_update_gas_price(
+			f: impl Fn(&T::Destination, U256) -> DispatchResult + 'static,
+		) -> CallHandler {
+			register_call!(move |(destination, price)| f(destination, price))
+		}
 	}
 
 	impl<T: Config> MessageProcessor for Pallet<T> {
@@ -51,8 +72,8 @@ pub mod pallet {
 		}
 	}
 
-	impl<T: Config> GetByKey<T::Destination, Option<[u8; 20]>> for Pallet<T> {
-		fn get(a: &T::Destination) -> Option<[u8; 20]> {
+	impl<T: Config> GetByKey<T::Destination, Option<[u8; 32]>> for Pallet<T> {
+		fn get(a: &T::Destination) -> Option<[u8; 32]> {
 			execute_call!(a)
 		}
 	}
@@ -69,5 +90,35 @@ pub mod pallet {
 		) -> DispatchResult {
 			execute_call!((sender, destination, msg))
 		}
+
+		fn is_packed(
+			sender: &Self::Sender,
+			destination: &Self::Destination,
+			msg: &Self::Message,
+		) -> bool {
+			execute_call!((sender, destination, msg))
+		}
+	}
+
+	impl<T: Config> MessageFeeQuoter for Pallet<T> {
+		type Balance = U256;
+		type Destination = T::Destination;
+		type Message = T::Message;
+
+		fn quote(
+			destination: &Self::Destination,
+			msg: &Self::Message,
+		) -> Result<Self::Balance, DispatchError> {
+			execute_call!((destination, msg))
+		}
+	}
+
+	impl<T: Config> GasPriceUpdater for Pallet<T> {
+		type Destination = T::Destination;
+		type Price = U256;
+
+		fn update_gas_price(destination: &Self::Destination, price: U256) -> DispatchResult {
+			execute_call!((destination, price))
+		}
 	}
 }
@@ -0,0 +1,44 @@
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use cfg_traits::{liquidity_pools::MemberUpdater, Seconds};
+	use frame_support::pallet_prelude::*;
+	use mock_builder::{execute_call, register_call, CallHandler};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type PoolId;
+		type TrancheId;
+		type Member;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	type CallIds<T: Config> = StorageMap<_, _, String, mock_builder::CallId>;
+
+	impl<T: Config> Pallet<T> {
+		pub fn mock_update_member(
+			f: impl Fn(T::AccountId, T::PoolId, T::TrancheId, T::Member, Seconds) -> DispatchResult
+				+ 'static,
+		) -> CallHandler {
+			register_call!(move |(a, b, c, d, e)| f(a, b, c, d, e))
+		}
+	}
+
+	impl<T: Config> MemberUpdater<T::AccountId> for Pallet<T> {
+		type Member = T::Member;
+		type PoolId = T::PoolId;
+		type TrancheId = T::TrancheId;
+
+		fn update_member(
+			a: T::AccountId,
+			b: T::PoolId,
+			c: T::TrancheId,
+			d: T::Member,
+			e: Seconds,
+		) -> DispatchResult {
+			execute_call!((a, b, c, d, e))
+		}
+	}
+}
@@ -1,8 +1,11 @@
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
-	use cfg_traits::liquidity_pools::{MessageReceiver, MessageSender};
+	use cfg_traits::liquidity_pools::{
+		GasPriceUpdater, MessageFeeQuoter, MessageReceiver, MessageSender,
+	};
 	use frame_support::pallet_prelude::*;
 	use mock_builder::{execute_call_instance, register_call_instance, CallHandler};
+	use sp_core::U256;
 
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config {
@@ -29,6 +32,18 @@ pub mod pallet {
 		) -> CallHandler {
 			register_call_instance!(move |(a, b, c)| f(a, b, c))
 		}
+
+		pub fn mock_quote(
+			f: impl Fn(&T::Middleware, &T::Message) -> Result<U256, DispatchError> + 'static,
+		) {
+			register_call_instance!(move |(a, b)| f(a, b));
+		}
+
+		pub fn mock_update_gas_price(
+			f: impl Fn(&T::Middleware, U256) -> DispatchResult + 'static,
+		) -> CallHandler {
+			register_call_instance!(move |(a, b)| f(a, b))
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> MessageReceiver for Pallet<T, I> {
@@ -50,4 +65,23 @@ pub mod pallet {
 			execute_call_instance!((a, b, c))
 		}
 	}
+
+	impl<T: Config<I>, I: 'static> MessageFeeQuoter for Pallet<T, I> {
+		type Balance = U256;
+		type Destination = T::Middleware;
+		type Message = T::Message;
+
+		fn quote(a: &Self::Destination, b: &Self::Message) -> Result<U256, DispatchError> {
+			execute_call_instance!((a, b))
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> GasPriceUpdater for Pallet<T, I> {
+		type Destination = T::Middleware;
+		type Price = U256;
+
+		fn update_gas_price(a: &Self::Destination, b: U256) -> DispatchResult {
+			execute_call_instance!((a, b))
+		}
+	}
 }
@@ -1,6 +1,6 @@
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
-	use cfg_traits::swaps::{OrderInfo, OrderRatio, TokenSwaps};
+	use cfg_traits::swaps::{OrderInfo, OrderOptions, OrderRatio, TokenSwaps};
 	use frame_support::pallet_prelude::*;
 	use mock_builder::{execute_call, register_call};
 
@@ -27,10 +27,11 @@ pub mod pallet {
 					T::CurrencyId,
 					T::BalanceOut,
 					OrderRatio<T::Ratio>,
+					OrderOptions<T::BalanceOut>,
 				) -> Result<T::OrderId, DispatchError>
 				+ 'static,
 		) {
-			register_call!(move |(a, b, c, d, e)| f(a, b, c, d, e));
+			register_call!(move |(a, b, c, d, e, g)| f(a, b, c, d, e, g));
 		}
 
 		pub fn mock_update_order(
@@ -98,8 +99,9 @@ pub mod pallet {
 			c: Self::CurrencyId,
 			d: Self::BalanceOut,
 			e: OrderRatio<Self::Ratio>,
+			g: OrderOptions<Self::BalanceOut>,
 		) -> Result<Self::OrderId, DispatchError> {
-			execute_call!((a, b, c, d, e))
+			execute_call!((a, b, c, d, e, g))
 		}
 
 		fn update_order(
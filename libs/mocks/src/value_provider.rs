@@ -23,6 +23,12 @@ pub mod pallet {
 		) {
 			register_call!(move |(a, b)| f(a, b));
 		}
+
+		pub fn mock_history(
+			f: impl Fn(&T::Source, &T::Key) -> Result<Vec<T::Value>, DispatchError> + 'static,
+		) {
+			register_call!(move |(a, b)| f(a, b));
+		}
 	}
 
 	impl<T: Config> ValueProvider<T::Source, T::Key> for Pallet<T> {
@@ -31,5 +37,9 @@ pub mod pallet {
 		fn get(a: &T::Source, b: &T::Key) -> Result<Option<Self::Value>, DispatchError> {
 			execute_call!((a, b))
 		}
+
+		fn history(a: &T::Source, b: &T::Key) -> Result<Vec<Self::Value>, DispatchError> {
+			execute_call!((a, b))
+		}
 	}
 }
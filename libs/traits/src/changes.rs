@@ -1,4 +1,4 @@
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
 
 /// Trait for get feedback before apply certain changes.
 /// It can be used when you need to ask to a third party or external module if
@@ -32,4 +32,8 @@ pub trait ChangeGuard {
 		pool_id: Self::PoolId,
 		change_id: Self::ChangeId,
 	) -> Result<Self::Change, DispatchError>;
+
+	/// Remove a noted `change_id` so it can no longer be released.
+	/// An error will be returned if the change not exists.
+	fn discard(pool_id: Self::PoolId, change_id: Self::ChangeId) -> DispatchResult;
 }
@@ -25,6 +25,12 @@ pub trait DataRegistry<DataId, CollectionId> {
 	/// Return the last data value for a data id in a collection
 	fn get(data_id: &DataId, collection_id: &CollectionId) -> Result<Self::Data, DispatchError>;
 
+	/// Return whether a data id in a collection is frozen, meaning its data
+	/// is no longer updated until the freeze is lifted
+	fn is_frozen(_data_id: &DataId, _collection_id: &CollectionId) -> bool {
+		false
+	}
+
 	/// Retrives a collection of data with all data associated to a collection
 	/// id
 	fn collection(collection_id: &CollectionId) -> Result<Self::Collection, DispatchError>;
@@ -27,7 +27,7 @@ use frame_support::{
 use impl_trait_for_tuples::impl_for_tuples;
 use orml_traits::asset_registry;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
-use sp_runtime::{traits::Member, DispatchError};
+use sp_runtime::{traits::Member, DispatchError, Perbill};
 use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
 pub mod changes;
@@ -287,16 +287,35 @@ impl<T> PreConditions<T> for Never {
 /// Trait to determine whether a sending account and currency have a
 /// restriction, and if so is there an allowance for the receiver location.
 pub trait TransferAllowance<AccountId> {
+	type Balance;
 	type CurrencyId;
 	type Location: Member + Debug + Eq + PartialEq + TypeInfo + Encode + Decode + MaxEncodedLen;
-	/// Determines whether the `send` account is allowed to make a transfer to
-	/// the `receive` location with `currency` type currency. Returns result
-	/// wrapped bool for whether allowance is allowed.
+	/// Determines whether the `send` account is allowed to make a transfer of
+	/// `amount` to the `receive` location with `currency` type currency.
+	/// Returns result wrapped bool for whether allowance is allowed.
+	///
+	/// NOTE: An allowed amount is accounted against the limits of the
+	/// allowance, so this should only be called for transfers taking place.
 	fn allowance(
 		send: AccountId,
 		receive: Self::Location,
 		currency: Self::CurrencyId,
+		amount: Self::Balance,
 	) -> Result<Option<Self::Location>, DispatchError>;
+
+	/// Whether an error returned by [`TransferAllowance::allowance`] is due to
+	/// a missing allowance for the receiver location. Any other error, like
+	/// exceeding the limits of an allowance, must reject the transfer even if
+	/// it is allowed otherwise.
+	fn is_missing_allowance(error: &DispatchError) -> bool;
+
+	/// Ensures that a transfer of `amount` with `currency` type currency from
+	/// the `send` account does not require approval, or has been approved.
+	fn ensure_approved(
+		send: AccountId,
+		currency: Self::CurrencyId,
+		amount: Self::Balance,
+	) -> DispatchResult;
 }
 
 /// Trait to retrieve information about currencies.
@@ -404,6 +423,12 @@ pub trait ValueProvider<Source, Key> {
 
 	fn get(source: &Source, id: &Key) -> Result<Option<Self::Value>, DispatchError>;
 
+	/// Returns the historical values of a key, sorted from the oldest to the
+	/// newest. By default, only the last value is returned.
+	fn history(source: &Source, id: &Key) -> Result<Vec<Self::Value>, DispatchError> {
+		Ok(Self::get(source, id)?.into_iter().collect())
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn set(_source: &Source, _key: &Key, _value: Self::Value) {}
 }
@@ -418,6 +443,22 @@ impl<Source, Key, Value> ValueProvider<Source, Key> for NoProvider<Value> {
 	}
 }
 
+/// Rates the values fed by the feeders of a value provider against a
+/// reference value of their keys
+pub trait FeederAccuracy<Feeder, Key> {
+	/// Returns the deviation of the last value fed by a feeder for a key from
+	/// the reference value of the key. Returns `None` if the key is not
+	/// registered in a collection fed by the feeder, or has no reference
+	/// value yet.
+	fn deviation(feeder: &Feeder, key: &Key) -> Option<Perbill>;
+}
+
+impl<Feeder, Key> FeederAccuracy<Feeder, Key> for () {
+	fn deviation(_: &Feeder, _: &Key) -> Option<Perbill> {
+		None
+	}
+}
+
 /// Checks whether an asset is the local representation of another one
 pub trait HasLocalAssetRepresentation<AssetRegistry> {
 	fn is_local_representation_of(&self, variant_currency: &Self) -> Result<bool, DispatchError>;
@@ -15,6 +15,8 @@ use frame_support::{dispatch::DispatchResult, weights::Weight};
 use sp_runtime::{app_crypto::sp_core::H160, DispatchError};
 use sp_std::vec::Vec;
 
+use crate::Seconds;
+
 /// Type that represents the hash of an LP message.
 pub type MessageHash = [u8; 32];
 
@@ -117,6 +119,39 @@ pub trait MessageSender {
 	) -> DispatchResult;
 }
 
+/// The behavior of an entity that can quote the cost of sending messages
+pub trait MessageFeeQuoter {
+	/// The destination where this message is sent
+	type Destination;
+
+	/// The type of the message
+	type Message;
+
+	/// The type used to express the cost, denominated in native currency
+	type Balance;
+
+	/// Returns the cost of sending a message to destination, both for
+	/// submitting it on this chain and for executing it on the destination
+	fn quote(
+		destination: &Self::Destination,
+		message: &Self::Message,
+	) -> Result<Self::Balance, DispatchError>;
+}
+
+/// The behavior of an entity that keeps track of the gas price of the
+/// destinations it sends messages to
+pub trait GasPriceUpdater {
+	/// The destination the gas price refers to
+	type Destination;
+
+	/// The type used to express the gas price
+	type Price;
+
+	/// Updates the price of the gas executed on destination, used to pay for
+	/// the execution of the messages sent there
+	fn update_gas_price(destination: &Self::Destination, price: Self::Price) -> DispatchResult;
+}
+
 /// The behavior of an entity that can receive messages
 pub trait MessageReceiver {
 	/// The middleware by where this message is received
@@ -173,6 +208,16 @@ pub trait OutboundMessageHandler {
 		destination: Self::Destination,
 		msg: Self::Message,
 	) -> DispatchResult;
+
+	/// Whether handling the message now packs it into a batch which is
+	/// already pending to be sent, so sending it adds no cost.
+	fn is_packed(
+		_sender: &Self::Sender,
+		_destination: &Self::Destination,
+		_msg: &Self::Message,
+	) -> bool {
+		false
+	}
 }
 
 /// The trait required for handling inbound LP messages.
@@ -185,4 +230,34 @@ pub trait InboundMessageHandler {
 
 	/// Handle an inbound message.
 	fn handle(sender: Self::Sender, msg: Self::Message) -> DispatchResult;
+
+	/// Weight that handling the message can take on top of the defensive
+	/// weight charged for every message, i.e. for dispatching calls carried
+	/// by the message.
+	fn extra_weight(_msg: &Self::Message) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The trait required for announcing investors to the domain they invest
+/// from.
+pub trait MemberUpdater<AccountId> {
+	/// The pool identifier.
+	type PoolId;
+
+	/// The tranche identifier.
+	type TrancheId;
+
+	/// The address of the member in its domain.
+	type Member;
+
+	/// Inform the domain of the member about its validity as investor of the
+	/// tranche. The sender pays for the message.
+	fn update_member(
+		sender: AccountId,
+		pool_id: Self::PoolId,
+		tranche_id: Self::TrancheId,
+		member: Self::Member,
+		valid_until: Seconds,
+	) -> DispatchResult;
 }
@@ -3,6 +3,8 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::fmt::Debug;
 
+use crate::Seconds;
+
 /// Determines an order price
 #[derive(Clone, Copy, Debug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
 pub enum OrderRatio<Ratio> {
@@ -10,6 +12,21 @@ pub enum OrderRatio<Ratio> {
 	Custom(Ratio),
 }
 
+/// Determines how an order can be filled and for how long
+#[derive(Clone, Copy, Debug, Default, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+pub struct OrderOptions<Balance> {
+	/// Time after which the order expires and its funds are returned.
+	/// Without it, the order lives until it is filled or cancelled.
+	pub expires_at: Option<Seconds>,
+
+	/// The order must be completely filled when placed, or it is not placed.
+	pub fill_or_kill: bool,
+
+	/// Minimum outgoing amount of each partial fill, replacing the default
+	/// one.
+	pub min_fill: Option<Balance>,
+}
+
 /// A simple representation of a currency swap.
 #[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub struct Swap<Amount, Currency> {
@@ -30,6 +47,8 @@ pub struct OrderInfo<Balance, Currency, Ratio> {
 	pub swap: Swap<Balance, Currency>,
 	/// The ratio at which the swap should happen
 	pub ratio: OrderRatio<Ratio>,
+	/// How the swap can be filled and for how long
+	pub options: OrderOptions<Balance>,
 }
 
 pub trait TokenSwaps<Account> {
@@ -40,13 +59,14 @@ pub trait TokenSwaps<Account> {
 	type OrderId;
 
 	/// Swap tokens selling `amount_out` of `currency_out` and buying
-	/// `currency_in` given an order ratio.
+	/// `currency_in` given an order ratio and options.
 	fn place_order(
 		account: Account,
 		currency_in: Self::CurrencyId,
 		currency_out: Self::CurrencyId,
 		amount_out: Self::BalanceOut,
 		ratio: OrderRatio<Self::Ratio>,
+		options: OrderOptions<Self::BalanceOut>,
 	) -> Result<Self::OrderId, DispatchError>;
 
 	/// Update an existing active order.
@@ -13,7 +13,7 @@
 use frame_support::pallet_prelude::RuntimeDebug;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_core::{crypto::AccountId32, H160};
+use sp_core::{blake2_256, crypto::AccountId32, H160};
 use sp_runtime::{traits::AccountIdConversion, TypeId};
 
 use crate::EVMChainId;
@@ -47,6 +47,13 @@ pub fn eth_address_to_account(chain_id: u64, address: H160) -> AccountId32 {
 	AccountId32::new(bytes)
 }
 
+/// By hashing the address together with the chain information, given that
+/// the address already uses all the bytes of an account
+pub fn raw32_address_to_account(chain_id: u64, address: [u8; 32]) -> AccountId32 {
+	let tag = b"RAW32";
+	AccountId32::new((tag, chain_id, address).using_encoded(blake2_256))
+}
+
 /// A Domain is a chain or network we can send a message to.
 #[derive(Encode, Decode, Clone, Copy, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
 pub enum Domain {
@@ -54,6 +61,9 @@ pub enum Domain {
 	Centrifuge,
 	/// An EVM domain, identified by its EVM Chain Id
 	Evm(EVMChainId),
+	/// A non-EVM domain using 32 bytes addresses, identified by a chain id
+	/// assigned by Centrifuge
+	Other(u64),
 }
 
 impl TypeId for Domain {
@@ -67,8 +77,8 @@ impl Domain {
 
 	pub fn get_evm_chain_id(&self) -> Option<EVMChainId> {
 		match self {
-			Domain::Centrifuge => None,
 			Domain::Evm(id) => Some(*id),
+			Domain::Centrifuge | Domain::Other(_) => None,
 		}
 	}
 }
@@ -79,6 +89,8 @@ pub enum DomainAddress {
 	Centrifuge(AccountId32),
 	/// An EVM chain address
 	Evm(EVMChainId, H160),
+	/// A 32 bytes address from a non-EVM chain
+	Raw32(u64, [u8; 32]),
 }
 
 impl TypeId for DomainAddress {
@@ -90,6 +102,7 @@ impl From<DomainAddress> for Domain {
 		match x {
 			DomainAddress::Centrifuge(_) => Domain::Centrifuge,
 			DomainAddress::Evm(chain_id, _) => Domain::Evm(chain_id),
+			DomainAddress::Raw32(chain_id, _) => Domain::Other(chain_id),
 		}
 	}
 }
@@ -101,6 +114,7 @@ impl DomainAddress {
 			Domain::Evm(chain_id) => {
 				DomainAddress::Evm(chain_id, account_to_eth_address(address.into()))
 			}
+			Domain::Other(chain_id) => DomainAddress::Raw32(chain_id, address),
 		}
 	}
 
@@ -115,20 +129,59 @@ impl DomainAddress {
 		match self.clone() {
 			Self::Centrifuge(x) => x,
 			Self::Evm(chain_id, x) => eth_address_to_account(chain_id, x),
+			Self::Raw32(chain_id, x) => raw32_address_to_account(chain_id, x),
 		}
 	}
 
 	/// Returns the current address as an ethrerum address,
 	/// clamping the inner address if needed.
-	pub fn h160(&self) -> H160 {
+	///
+	/// NOTE: `Raw32` addresses have no ethereum representation.
+	pub fn h160(&self) -> Option<H160> {
 		match self.clone() {
-			Self::Centrifuge(x) => account_to_eth_address(x),
-			Self::Evm(_, x) => x,
+			Self::Centrifuge(x) => Some(account_to_eth_address(x)),
+			Self::Evm(_, x) => Some(x),
+			Self::Raw32(..) => None,
 		}
 	}
 
 	/// Returns the current address as plain bytes
+	///
+	/// NOTE: `Raw32` addresses are returned as they are, given that the
+	/// derived account can not be translated back to the address.
 	pub fn bytes(&self) -> [u8; MAX_ADDRESS_SIZE] {
-		self.account().into()
+		match self {
+			Self::Raw32(_, x) => *x,
+			_ => self.account().into(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const CHAIN_ID: u64 = 7;
+	const ADDRESS: [u8; 32] = [42; 32];
+
+	#[test]
+	fn raw32_domain_address_roundtrip() {
+		let address = DomainAddress::new(Domain::Other(CHAIN_ID), ADDRESS);
+
+		assert_eq!(address, DomainAddress::Raw32(CHAIN_ID, ADDRESS));
+		assert_eq!(address.domain(), Domain::Other(CHAIN_ID));
+		assert_eq!(address.bytes(), ADDRESS);
+		assert_eq!(address.h160(), None);
+	}
+
+	#[test]
+	fn raw32_account_depends_on_chain() {
+		let account = DomainAddress::Raw32(CHAIN_ID, ADDRESS).account();
+
+		assert_ne!(account, AccountId32::new(ADDRESS));
+		assert_ne!(
+			account,
+			DomainAddress::Raw32(CHAIN_ID + 1, ADDRESS).account()
+		);
 	}
 }
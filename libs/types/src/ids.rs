@@ -33,7 +33,9 @@ pub const BLOCK_REWARDS_PALLET_ID: PalletId = PalletId(*b"cfg/blrw");
 pub const LIQUIDITY_REWARDS_PALLET_ID: PalletId = PalletId(*b"cfg/lqrw");
 pub const POOL_FEES_PALLET_ID: PalletId = PalletId(*b"cfg/plfs");
 pub const TOKEN_MUX_PALLET_ID: PalletId = PalletId(*b"cfg/tmux");
+pub const STABLE_SWAP_PALLET_ID: PalletId = PalletId(*b"cfg/stsw");
 pub const CFG_LOCK_ID: PalletId = PalletId(*b"cfg/lock");
+pub const ORACLE_FEED_REWARDS_PALLET_ID: PalletId = PalletId(*b"cfg/orfr");
 
 // Other ids
 pub const CHAIN_BRIDGE_HASH_ID: [u8; 13] = *b"cent_nft_hash";
@@ -18,9 +18,9 @@ use sp_runtime::{
 	traits::{EnsureAddAssign, Zero},
 	DispatchResult,
 };
-use sp_std::cmp::PartialEq;
+use sp_std::{cmp::PartialEq, vec::Vec};
 
-use crate::orders::Order;
+use crate::orders::{FulfillmentWithPrice, Order};
 
 /// A representation of a investment identifier that can be converted to an
 /// account address
@@ -163,6 +163,46 @@ pub struct InvestmentPortfolio<Balance, CurrencyId> {
 	pub claimable_currency: Balance,
 }
 
+/// The cost basis of the tranche tokens an account obtained by collecting
+/// invest orders.
+#[derive(Encode, Decode, Default, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CostBasis<Balance> {
+	/// The amount of tranche tokens obtained by collecting invest orders which
+	/// were not yet redeemed
+	pub tranche_tokens: Balance,
+	/// The amount of pool currency paid for these tranche tokens
+	pub cost: Balance,
+	/// The amount of pool currency paid for already redeemed tranche tokens
+	pub realized_cost: Balance,
+	/// The amount of pool currency received for already redeemed tranche
+	/// tokens
+	pub realized_proceeds: Balance,
+}
+
+/// The position of an account in an investment, consisting of the cost
+/// basis, its current value and the orders not yet collected.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct InvestmentPosition<Balance, BalanceRatio> {
+	/// The cost basis of the tranche tokens of the position
+	pub cost_basis: CostBasis<Balance>,
+	/// The average price paid per tranche token
+	pub average_price: Option<BalanceRatio>,
+	/// The current price of a tranche token
+	pub price: Option<BalanceRatio>,
+	/// The current value of the tranche tokens in pool currency
+	pub value: Option<Balance>,
+	/// The current invest order
+	pub invest_order: Option<Order<Balance, OrderId>>,
+	/// The current redeem order
+	pub redeem_order: Option<Order<Balance, OrderId>>,
+	/// The cleared fulfillments of the invest order which are not yet
+	/// collected
+	pub cleared_invest_orders: Vec<(OrderId, FulfillmentWithPrice<BalanceRatio>)>,
+	/// The cleared fulfillments of the redeem order which are not yet
+	/// collected
+	pub cleared_redeem_orders: Vec<(OrderId, FulfillmentWithPrice<BalanceRatio>)>,
+}
+
 impl<Balance: Default, CurrencyId> InvestmentPortfolio<Balance, CurrencyId> {
 	pub fn new(pool_currency_id: CurrencyId) -> Self {
 		Self {
@@ -27,7 +27,8 @@ pub enum RestrictedTransferLocation {
 	Local(AccountId),
 	/// XCM Location sending destinations.
 	Xcm(Box<VersionedLocation>),
-	/// DomainAddress sending location from a liquidity pools' instance
+	/// DomainAddress sending location from a liquidity pools' instance,
+	/// either an EVM or a non-EVM (`Raw32`) address
 	Address(DomainAddress),
 }
 
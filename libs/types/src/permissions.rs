@@ -20,13 +20,14 @@ use serde::{Deserialize, Serialize};
 use sp_std::{
 	cmp::{Ord, PartialEq, PartialOrd},
 	marker::PhantomData,
+	vec::Vec,
 };
 
 /// PoolRole can hold any type of role specific functions a user can do on a
 /// given pool.
 // NOTE: In order to not carry around the TrancheId type all the time, we give it a
 // default.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PoolRole<TrancheId = [u8; 16]> {
 	PoolAdmin,
@@ -38,9 +39,10 @@ pub enum PoolRole<TrancheId = [u8; 16]> {
 	TrancheInvestor(TrancheId, Seconds),
 	PODReadAccess,
 	FrozenTrancheInvestor(TrancheId),
+	RecoveryAdmin,
 }
 
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PermissionedCurrencyRole {
 	/// This role can hold & transfer tokens
@@ -54,7 +56,7 @@ pub enum PermissionedCurrencyRole {
 /// The Role enum is used by the permissions pallet,
 /// to specify which role an account has within a
 /// specific scope.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Role<TrancheId = [u8; 16]> {
 	/// Roles that apply to a specific pool.
@@ -93,6 +95,7 @@ bitflags::bitflags! {
 		const INVESTOR_ADMIN = 0b00010000;
 		const RISK_ADMIN = 0b00100000;
 		const POD_READ_ACCESS = 0b01000000;
+		const RECOVERY_ADMIN = 0b10000000;
 	}
 
 	/// The current admin roles we support
@@ -225,6 +228,7 @@ where
 					self.pool_admin.contains(PoolAdminRoles::POD_READ_ACCESS)
 				}
 				PoolRole::FrozenTrancheInvestor(id) => self.tranche_investor.contains_frozen(id),
+				PoolRole::RecoveryAdmin => self.pool_admin.contains(PoolAdminRoles::RECOVERY_ADMIN),
 			},
 			Role::PermissionedCurrencyRole(permissioned_currency_role) => {
 				match permissioned_currency_role {
@@ -267,6 +271,9 @@ where
 					Ok(self.pool_admin.remove(PoolAdminRoles::POD_READ_ACCESS))
 				}
 				PoolRole::FrozenTrancheInvestor(id) => self.tranche_investor.unfreeze(id),
+				PoolRole::RecoveryAdmin => {
+					Ok(self.pool_admin.remove(PoolAdminRoles::RECOVERY_ADMIN))
+				}
 			},
 			Role::PermissionedCurrencyRole(permissioned_currency_role) => {
 				match permissioned_currency_role {
@@ -302,6 +309,9 @@ where
 					Ok(self.pool_admin.insert(PoolAdminRoles::POD_READ_ACCESS))
 				}
 				PoolRole::FrozenTrancheInvestor(id) => self.tranche_investor.freeze(id),
+				PoolRole::RecoveryAdmin => {
+					Ok(self.pool_admin.insert(PoolAdminRoles::RECOVERY_ADMIN))
+				}
 			},
 			Role::PermissionedCurrencyRole(permissioned_currency_role) => {
 				match permissioned_currency_role {
@@ -344,6 +354,73 @@ where
 	}
 }
 
+impl<Now, MinDelay, TrancheId, MaxTranches> PermissionRoles<Now, MinDelay, TrancheId, MaxTranches>
+where
+	Now: TimeAsSecs,
+	MinDelay: Get<Seconds>,
+	TrancheId: PartialEq + PartialOrd + Copy,
+	MaxTranches: Get<u32>,
+{
+	/// All roles which are currently held. Tranche investor and holder roles
+	/// carry the time until which they are valid.
+	pub fn roles(&self) -> Vec<Role<TrancheId>> {
+		let mut roles = Vec::new();
+
+		for (flag, role) in [
+			(PoolAdminRoles::POOL_ADMIN, PoolRole::PoolAdmin),
+			(PoolAdminRoles::BORROWER, PoolRole::Borrower),
+			(PoolAdminRoles::PRICING_ADMIN, PoolRole::PricingAdmin),
+			(PoolAdminRoles::LIQUIDITY_ADMIN, PoolRole::LiquidityAdmin),
+			(PoolAdminRoles::INVESTOR_ADMIN, PoolRole::InvestorAdmin),
+			(PoolAdminRoles::RISK_ADMIN, PoolRole::LoanAdmin),
+			(PoolAdminRoles::POD_READ_ACCESS, PoolRole::PODReadAccess),
+			(PoolAdminRoles::RECOVERY_ADMIN, PoolRole::RecoveryAdmin),
+		] {
+			if self.pool_admin.contains(flag) {
+				roles.push(Role::PoolRole(role));
+			}
+		}
+
+		let now = <Now as TimeAsSecs>::now();
+		for info in self.tranche_investor.info.iter() {
+			if info.permissioned_till >= now {
+				roles.push(Role::PoolRole(PoolRole::TrancheInvestor(
+					info.tranche_id,
+					info.permissioned_till,
+				)));
+			}
+			if info.is_frozen {
+				roles.push(Role::PoolRole(PoolRole::FrozenTrancheInvestor(
+					info.tranche_id,
+				)));
+			}
+		}
+
+		for (flag, role) in [
+			(
+				CurrencyAdminRoles::PERMISSIONED_ASSET_MANAGER,
+				PermissionedCurrencyRole::Manager,
+			),
+			(
+				CurrencyAdminRoles::PERMISSIONED_ASSET_ISSUER,
+				PermissionedCurrencyRole::Issuer,
+			),
+		] {
+			if self.currency_admin.contains(flag) {
+				roles.push(Role::PermissionedCurrencyRole(role));
+			}
+		}
+
+		if let Some(info) = self.permissioned_asset_holder.get_info() {
+			roles.push(Role::PermissionedCurrencyRole(
+				PermissionedCurrencyRole::Holder(info.permissioned_till),
+			));
+		}
+
+		roles
+	}
+}
+
 impl<Now, MinDelay> PermissionedCurrencyHolders<Now, MinDelay>
 where
 	Now: TimeAsSecs,
@@ -617,6 +694,7 @@ mod tests {
 					Role::PoolRole(PoolRole::InvestorAdmin),
 					Role::PoolRole(PoolRole::LoanAdmin),
 					Role::PoolRole(PoolRole::PODReadAccess),
+					Role::PoolRole(PoolRole::RecoveryAdmin),
 				] {
 					assert_ok!(roles.add(role));
 					assert!(roles.exists(role));
@@ -634,6 +712,7 @@ mod tests {
 					Role::PoolRole(PoolRole::InvestorAdmin),
 					Role::PoolRole(PoolRole::LoanAdmin),
 					Role::PoolRole(PoolRole::PODReadAccess),
+					Role::PoolRole(PoolRole::RecoveryAdmin),
 				] {
 					assert_ok!(roles.add(role));
 					assert_ok!(roles.rm(role));
@@ -17,7 +17,7 @@
 
 use cfg_traits::{
 	ethereum::EthereumTransactor,
-	liquidity_pools::{MessageReceiver, MessageSender},
+	liquidity_pools::{GasPriceUpdater, MessageFeeQuoter, MessageReceiver, MessageSender},
 };
 use cfg_types::{
 	domain_address::{Domain, DomainAddress},
@@ -50,11 +50,13 @@ mod tests;
 const MAX_AXELAR_EVM_CHAIN_SIZE: u32 = 16;
 
 const MAX_SOURCE_CHAIN_BYTES: u32 = 128;
-// Ensure we allow enough to support a hex encoded address with the `0x` prefix.
-const MAX_SOURCE_ADDRESS_BYTES: u32 = 42;
+// Ensure we allow enough to support a hex encoded 32 bytes address with the
+// `0x` prefix.
+const MAX_SOURCE_ADDRESS_BYTES: u32 = 66;
 const MAX_TOKEN_SYMBOL_BYTES: u32 = 32;
 const MAX_PAYLOAD_BYTES: u32 = 1024;
 const EVM_ADDRESS_LEN: usize = 20;
+const RAW32_ADDRESS_LEN: usize = 32;
 
 pub type ChainName = BoundedVec<u8, ConstU32<MAX_AXELAR_EVM_CHAIN_SIZE>>;
 
@@ -62,6 +64,8 @@ pub type ChainName = BoundedVec<u8, ConstU32<MAX_AXELAR_EVM_CHAIN_SIZE>>;
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum AxelarId {
 	Evm(EVMChainId),
+	/// A non-EVM chain, identified by the chain id assigned by Centrifuge
+	Other(u64),
 }
 
 /// Configuration for outbound messages though axelar
@@ -70,6 +74,9 @@ pub struct AxelarConfig {
 	/// Address of application contract
 	/// This is the address wrapped in the axelar message
 	/// In both, inbound and outbound messages
+	///
+	/// NOTE: Unused by non-EVM domains, which configure their own application
+	/// address.
 	pub app_contract_address: H160,
 
 	/// Address used to receive message from axelar.
@@ -87,6 +94,25 @@ pub struct AxelarConfig {
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum DomainConfig {
 	Evm(EvmConfig),
+	Other(OtherConfig),
+}
+
+impl DomainConfig {
+	/// The identification of the configured domain
+	pub fn axelar_id(&self) -> AxelarId {
+		match self {
+			Self::Evm(config) => AxelarId::Evm(config.chain_id),
+			Self::Other(config) => AxelarId::Other(config.chain_id),
+		}
+	}
+
+	/// The configured values used when executing the EVM call.
+	pub fn outbound_fee_values(&self) -> &FeeValues {
+		match self {
+			Self::Evm(config) => &config.outbound_fee_values,
+			Self::Other(config) => &config.outbound_fee_values,
+		}
+	}
 }
 
 /// Data for validating and executing the internal EVM call.
@@ -99,6 +125,22 @@ pub struct EvmConfig {
 	pub outbound_fee_values: FeeValues,
 }
 
+/// Data for validating and sending messages to a non-EVM chain, whose
+/// application uses 32 bytes addresses.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OtherConfig {
+	/// Associated chain id, as assigned by Centrifuge
+	pub chain_id: u64,
+
+	/// Address of the application in the non-EVM chain.
+	/// This is the address wrapped in the axelar message
+	/// In both, inbound and outbound messages
+	pub app_address: [u8; RAW32_ADDRESS_LEN],
+
+	/// The values used when executing the EVM call.
+	pub outbound_fee_values: FeeValues,
+}
+
 /// The FeeValues holds all information related to the transaction costs.
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct FeeValues {
@@ -112,6 +154,21 @@ pub struct FeeValues {
 	pub gas_limit: U256,
 }
 
+/// Bounds within which the gas prices reported by a destination chain are
+/// accepted.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct GasPriceBounds {
+	/// The lowest gas price accepted.
+	pub min: U256,
+
+	/// The highest gas price accepted.
+	pub max: U256,
+
+	/// The gas the execution of a message is estimated to take on the
+	/// destination chain.
+	pub destination_gas: U256,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -150,6 +207,24 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ChainNameById<T: Config> = StorageMap<_, Twox64Concat, AxelarId, ChainName>;
 
+	/// Gas prices reported by the destination chains, within the bounds set
+	/// for them, denominated in native currency per unit of gas executed on
+	/// the destination chain.
+	///
+	/// NOTE: When present, the value of the configured outbound fee values is
+	/// replaced by this gas price times the destination gas of the bounds,
+	/// until the configuration or the bounds are set again. The gas price of
+	/// the local EVM call is always the configured one.
+	#[pallet::storage]
+	pub type GasPrices<T: Config> = StorageMap<_, Twox64Concat, AxelarId, U256>;
+
+	/// Bounds of the gas prices accepted from the destination chains. Gas
+	/// prices reported by chains without bounds are rejected.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type GasPriceLimits<T: Config> = StorageMap<_, Twox64Concat, AxelarId, GasPriceBounds>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -157,6 +232,14 @@ pub mod pallet {
 			name: ChainName,
 			config: Box<AxelarConfig>,
 		},
+		GasPriceUpdated {
+			axelar_id: AxelarId,
+			gas_price: U256,
+		},
+		GasPriceBoundsSet {
+			axelar_id: AxelarId,
+			bounds: Option<GasPriceBounds>,
+		},
 	}
 
 	#[pallet::error]
@@ -178,11 +261,25 @@ pub mod pallet {
 
 		/// Emit when a message is received from a non configured source address
 		SourceAddressMismatch,
+
+		/// Emit when a gas price is reported by a chain without gas price
+		/// bounds
+		GasPriceUpdatesDisabled,
+
+		/// Emit when a reported gas price is outside the gas price bounds
+		GasPriceOutOfBounds,
+
+		/// Emit when the lowest gas price of the bounds exceeds the highest
+		InvalidGasPriceBounds,
+
+		/// Emit when the origin address has no representation in the
+		/// configured domain
+		InvalidOriginAddress,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().writes(2)))]
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().writes(3)))]
 		#[pallet::call_index(0)]
 		pub fn set_config(
 			origin: OriginFor<T>,
@@ -191,14 +288,12 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
 
-			match &config.domain {
-				DomainConfig::Evm(evm_config) => {
-					ChainNameById::<T>::insert(
-						AxelarId::Evm(evm_config.chain_id),
-						chain_name.clone(),
-					);
-				}
-			}
+			let axelar_id = config.domain.axelar_id();
+
+			ChainNameById::<T>::insert(&axelar_id, chain_name.clone());
+
+			// The configured gas price takes precedence over a reported one
+			GasPrices::<T>::remove(&axelar_id);
 
 			Configuration::<T>::insert(chain_name.clone(), config.clone());
 
@@ -209,9 +304,54 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set the bounds within which gas prices reported by the chain are
+		/// accepted. Without bounds, reported gas prices are rejected and the
+		/// configured one is used.
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().writes(2)))]
+		#[pallet::call_index(1)]
+		pub fn set_gas_price_bounds(
+			origin: OriginFor<T>,
+			axelar_id: AxelarId,
+			bounds: Option<GasPriceBounds>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			match &bounds {
+				Some(bounds) => {
+					ensure!(bounds.min <= bounds.max, Error::<T>::InvalidGasPriceBounds);
+					GasPriceLimits::<T>::insert(&axelar_id, bounds);
+				}
+				None => GasPriceLimits::<T>::remove(&axelar_id),
+			}
+
+			// A reported gas price is only kept if reported within the new bounds
+			GasPrices::<T>::remove(&axelar_id);
+
+			Self::deposit_event(Event::<T>::GasPriceBoundsSet { axelar_id, bounds });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Returns the fee values used for outbound messages. The value paid
+		/// for the execution on the destination chain takes into account the
+		/// latest reported gas price.
+		pub fn outbound_fee_values(axelar_id: &AxelarId, domain: &DomainConfig) -> FeeValues {
+			let configured = domain.outbound_fee_values().clone();
+
+			let reported_value = GasPrices::<T>::get(axelar_id).and_then(|gas_price| {
+				GasPriceLimits::<T>::get(axelar_id)
+					.map(|bounds| gas_price.saturating_mul(bounds.destination_gas))
+			});
+
+			FeeValues {
+				value: reported_value.unwrap_or(configured.value),
+				..configured
+			}
+		}
+
 		pub fn receive(
 			caller: H160,
 			source_chain: &[u8],
@@ -248,6 +388,25 @@ pub mod pallet {
 						payload.to_vec(),
 					)
 				}
+				DomainConfig::Other(OtherConfig {
+					chain_id,
+					app_address,
+					..
+				}) => {
+					let source_address = decode_var_source::<RAW32_ADDRESS_LEN>(source_address)
+						.ok_or(Error::<T>::InvalidSourceAddress)?;
+
+					ensure!(
+						source_address == app_address,
+						Error::<T>::SourceAddressMismatch
+					);
+
+					T::Receiver::receive(
+						AxelarId::Other(chain_id).into(),
+						Domain::Other(chain_id),
+						payload.to_vec(),
+					)
+				}
 			}
 		}
 	}
@@ -333,25 +492,80 @@ pub mod pallet {
 			let config = Configuration::<T>::get(&chain_name)
 				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
 
-			let axelar_message = wrap_into_axelar_msg(
-				message,
-				chain_name.into_inner(),
-				config.app_contract_address,
+			let app_address = match &config.domain {
+				DomainConfig::Evm(_) => config.app_contract_address.as_bytes(),
+				DomainConfig::Other(other_config) => other_config.app_address.as_slice(),
+			};
+
+			let axelar_message =
+				wrap_into_axelar_msg(message, chain_name.into_inner(), app_address)
+					.map_err(DispatchError::Other)?;
+
+			// NOTE: Messages to any domain are sent through the local EVM
+			let fee_values = Self::outbound_fee_values(&axelar_id, &config.domain);
+
+			T::Transactor::call(
+				origin.h160().ok_or(Error::<T>::InvalidOriginAddress)?,
+				config.outbound_contract_address,
+				axelar_message.as_slice(),
+				fee_values.value,
+				fee_values.gas_price,
+				fee_values.gas_limit,
 			)
-			.map_err(DispatchError::Other)?;
+			.map(|_| ())
+			.map_err(|e| e.error)
+		}
+	}
 
-			match config.domain {
-				DomainConfig::Evm(evm_config) => T::Transactor::call(
-					origin.h160(),
-					config.outbound_contract_address,
-					axelar_message.as_slice(),
-					evm_config.outbound_fee_values.value,
-					evm_config.outbound_fee_values.gas_price,
-					evm_config.outbound_fee_values.gas_limit,
-				)
-				.map(|_| ())
-				.map_err(|e| e.error),
-			}
+	impl<T: Config> MessageFeeQuoter for Pallet<T> {
+		type Balance = U256;
+		type Destination = AxelarId;
+		type Message = Vec<u8>;
+
+		/// The cost of an outbound message is the value paid for the execution
+		/// on the destination chain plus the maximum gas cost of the local EVM
+		/// call. It does not depend on the message.
+		fn quote(axelar_id: &AxelarId, _: &Self::Message) -> Result<U256, DispatchError> {
+			let chain_name = ChainNameById::<T>::get(axelar_id)
+				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
+			let config = Configuration::<T>::get(&chain_name)
+				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			let fee_values = Self::outbound_fee_values(axelar_id, &config.domain);
+
+			Ok(fee_values
+				.value
+				.saturating_add(fee_values.gas_price.saturating_mul(fee_values.gas_limit)))
+		}
+	}
+
+	impl<T: Config> GasPriceUpdater for Pallet<T> {
+		type Destination = AxelarId;
+		type Price = U256;
+
+		/// Accepts gas prices reported by the chain only within the bounds set
+		/// by `AdminOrigin`.
+		fn update_gas_price(axelar_id: &AxelarId, gas_price: U256) -> DispatchResult {
+			ensure!(
+				ChainNameById::<T>::contains_key(axelar_id),
+				Error::<T>::RouterConfigurationNotFound
+			);
+
+			let bounds =
+				GasPriceLimits::<T>::get(axelar_id).ok_or(Error::<T>::GasPriceUpdatesDisabled)?;
+			ensure!(
+				bounds.min <= gas_price && gas_price <= bounds.max,
+				Error::<T>::GasPriceOutOfBounds
+			);
+
+			GasPrices::<T>::insert(axelar_id, gas_price);
+
+			Self::deposit_event(Event::<T>::GasPriceUpdated {
+				axelar_id: axelar_id.clone(),
+				gas_price,
+			});
+
+			Ok(())
 		}
 	}
 }
@@ -368,7 +582,7 @@ pub mod pallet {
 pub fn wrap_into_axelar_msg(
 	serialized_msg: Vec<u8>,
 	target_chain: Vec<u8>,
-	target_contract: H160,
+	target_contract: &[u8],
 ) -> Result<Vec<u8>, &'static str> {
 	const AXELAR_FUNCTION_NAME: &str = "callContract";
 	const AXELAR_DESTINATION_CHAIN_PARAM: &str = "destinationChain";
@@ -419,7 +633,7 @@ pub fn wrap_into_axelar_msg(
 		//
 		// The `to_string` method on the H160 is returning a string containing an ellipsis, such
 		// as: 0x1234…7890
-		Token::String(format!("0x{}", hex::encode(target_contract.0))),
+		Token::String(format!("0x{}", hex::encode(target_contract))),
 		Token::Bytes(serialized_msg),
 	])
 	.map_err(|_| "cannot encode input for Axelar contract function")?;
@@ -428,9 +642,9 @@ pub fn wrap_into_axelar_msg(
 }
 
 /// Decodes the source address which can be:
-/// - a 20 bytes array
-/// - an hexadecimal character secuence (40 characters)
-/// - an hexadecimal character secuence (40 characters) with 0x prefix
+/// - a 20 or 32 bytes array
+/// - an hexadecimal character secuence (40 or 64 characters)
+/// - an hexadecimal character secuence (40 or 64 characters) with 0x prefix
 pub fn decode_var_source<const EXPECTED_SOURCE_ADDRESS_SIZE: usize>(
 	source_address: &[u8],
 ) -> Option<[u8; EXPECTED_SOURCE_ADDRESS_SIZE]> {
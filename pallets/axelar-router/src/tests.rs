@@ -13,6 +13,9 @@ const MESSAGE: &[u8] = &[1, 2, 3];
 const FEE_VALUE: U256 = U256::zero();
 const GAS_LIMIT: U256 = U256::one();
 const GAS_PRICE: U256 = U256::max_value();
+const OTHER_CHAIN_NAME: &str = "CHAIN_2";
+const OTHER_CHAIN_ID: u64 = 2;
+const OTHER_APP_ADDRESS: [u8; 32] = [4; 32];
 
 fn config() -> AxelarConfig {
 	AxelarConfig {
@@ -30,6 +33,21 @@ fn config() -> AxelarConfig {
 	}
 }
 
+fn other_config() -> AxelarConfig {
+	AxelarConfig {
+		domain: DomainConfig::Other(OtherConfig {
+			chain_id: OTHER_CHAIN_ID,
+			app_address: OTHER_APP_ADDRESS,
+			outbound_fee_values: FeeValues {
+				value: FEE_VALUE,
+				gas_limit: GAS_LIMIT,
+				gas_price: GAS_PRICE,
+			},
+		}),
+		..config()
+	}
+}
+
 fn correct_configuration() {
 	assert_ok!(Router::set_config(
 		RuntimeOrigin::root(),
@@ -38,8 +56,21 @@ fn correct_configuration() {
 	));
 }
 
+fn other_configuration() {
+	assert_ok!(Router::set_config(
+		RuntimeOrigin::root(),
+		OTHER_CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+		Box::new(other_config())
+	));
+}
+
 fn wrap_message(message: Vec<u8>) -> Vec<u8> {
-	wrap_into_axelar_msg(message, CHAIN_NAME.as_bytes().to_vec(), LP_CONTRACT_ADDRESS).unwrap()
+	wrap_into_axelar_msg(
+		message,
+		CHAIN_NAME.as_bytes().to_vec(),
+		LP_CONTRACT_ADDRESS.as_bytes(),
+	)
+	.unwrap()
 }
 
 mod configuration {
@@ -66,7 +97,7 @@ mod send {
 			correct_configuration();
 
 			Transactor::mock_call(move |from, to, data, value, gas_price, gas_limit| {
-				assert_eq!(from, SENDER.h160());
+				assert_eq!(Some(from), SENDER.h160());
 				assert_eq!(to, OUTBOUND_CONTRACT);
 				assert_eq!(data, &wrap_message(MESSAGE.to_vec()));
 				assert_eq!(value, FEE_VALUE);
@@ -83,6 +114,34 @@ mod send {
 		});
 	}
 
+	#[test]
+	fn success_with_other_domain() {
+		new_test_ext().execute_with(|| {
+			other_configuration();
+
+			Transactor::mock_call(move |from, to, data, _, _, _| {
+				assert_eq!(Some(from), SENDER.h160());
+				assert_eq!(to, OUTBOUND_CONTRACT);
+				assert_eq!(
+					data,
+					&wrap_into_axelar_msg(
+						MESSAGE.to_vec(),
+						OTHER_CHAIN_NAME.as_bytes().to_vec(),
+						&OTHER_APP_ADDRESS,
+					)
+					.unwrap()
+				);
+				Ok(().into())
+			});
+
+			assert_ok!(Router::send(
+				AxelarId::Other(OTHER_CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+		});
+	}
+
 	#[test]
 	fn without_configuration() {
 		new_test_ext().execute_with(|| {
@@ -108,6 +167,184 @@ mod send {
 	}
 }
 
+mod gas_price {
+	use super::*;
+
+	const NEW_GAS_PRICE: U256 = U256([10, 0, 0, 0]);
+	const DESTINATION_GAS: U256 = U256([100, 0, 0, 0]);
+	const LOCAL_GAS_PRICE: U256 = U256([2, 0, 0, 0]);
+
+	fn bounds() -> GasPriceBounds {
+		GasPriceBounds {
+			min: U256::one(),
+			max: NEW_GAS_PRICE,
+			destination_gas: DESTINATION_GAS,
+		}
+	}
+
+	fn set_bounds(bounds: Option<GasPriceBounds>) {
+		assert_ok!(Router::set_gas_price_bounds(
+			RuntimeOrigin::root(),
+			AxelarId::Evm(CHAIN_ID),
+			bounds
+		));
+	}
+
+	#[test]
+	fn send_with_updated_gas_price() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+			set_bounds(Some(bounds()));
+
+			assert_ok!(Router::update_gas_price(
+				&AxelarId::Evm(CHAIN_ID),
+				NEW_GAS_PRICE
+			));
+
+			Transactor::mock_call(move |_, _, _, value, gas_price, gas_limit| {
+				assert_eq!(value, NEW_GAS_PRICE * DESTINATION_GAS);
+				assert_eq!(gas_limit, GAS_LIMIT);
+				assert_eq!(gas_price, GAS_PRICE);
+				Ok(().into())
+			});
+
+			assert_ok!(Router::send(
+				AxelarId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+		});
+	}
+
+	#[test]
+	fn update_without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				Router::update_gas_price(&AxelarId::Evm(CHAIN_ID), NEW_GAS_PRICE),
+				Error::<Runtime>::RouterConfigurationNotFound,
+			);
+		});
+	}
+
+	#[test]
+	fn update_without_bounds() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_err!(
+				Router::update_gas_price(&AxelarId::Evm(CHAIN_ID), NEW_GAS_PRICE),
+				Error::<Runtime>::GasPriceUpdatesDisabled,
+			);
+		});
+	}
+
+	#[test]
+	fn update_out_of_bounds() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+			set_bounds(Some(bounds()));
+
+			for gas_price in [U256::zero(), NEW_GAS_PRICE + 1] {
+				assert_err!(
+					Router::update_gas_price(&AxelarId::Evm(CHAIN_ID), gas_price),
+					Error::<Runtime>::GasPriceOutOfBounds,
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn invalid_bounds() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				Router::set_gas_price_bounds(
+					RuntimeOrigin::root(),
+					AxelarId::Evm(CHAIN_ID),
+					Some(GasPriceBounds {
+						min: NEW_GAS_PRICE,
+						max: U256::one(),
+						destination_gas: DESTINATION_GAS,
+					})
+				),
+				Error::<Runtime>::InvalidGasPriceBounds,
+			);
+		});
+	}
+
+	#[test]
+	fn setting_config_or_bounds_discards_reported_gas_price() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+			set_bounds(Some(bounds()));
+
+			assert_ok!(Router::update_gas_price(
+				&AxelarId::Evm(CHAIN_ID),
+				NEW_GAS_PRICE
+			));
+			correct_configuration();
+			assert_eq!(GasPrices::<Runtime>::get(AxelarId::Evm(CHAIN_ID)), None);
+
+			assert_ok!(Router::update_gas_price(
+				&AxelarId::Evm(CHAIN_ID),
+				NEW_GAS_PRICE
+			));
+			set_bounds(None);
+			assert_eq!(GasPrices::<Runtime>::get(AxelarId::Evm(CHAIN_ID)), None);
+		});
+	}
+
+	#[test]
+	fn quote() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+			set_bounds(Some(bounds()));
+
+			// The configured gas price saturates the cost
+			assert_eq!(
+				Router::quote(&AxelarId::Evm(CHAIN_ID), &MESSAGE.to_vec()),
+				Ok(U256::max_value())
+			);
+
+			let mut config = config();
+			let DomainConfig::Evm(evm_config) = &mut config.domain else {
+				unreachable!()
+			};
+			evm_config.outbound_fee_values.gas_price = LOCAL_GAS_PRICE;
+			assert_ok!(Router::set_config(
+				RuntimeOrigin::root(),
+				CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+				Box::new(config)
+			));
+
+			assert_eq!(
+				Router::quote(&AxelarId::Evm(CHAIN_ID), &MESSAGE.to_vec()),
+				Ok(FEE_VALUE + LOCAL_GAS_PRICE * GAS_LIMIT)
+			);
+
+			assert_ok!(Router::update_gas_price(
+				&AxelarId::Evm(CHAIN_ID),
+				NEW_GAS_PRICE
+			));
+
+			// The reported gas price only applies to the destination gas
+			assert_eq!(
+				Router::quote(&AxelarId::Evm(CHAIN_ID), &MESSAGE.to_vec()),
+				Ok(NEW_GAS_PRICE * DESTINATION_GAS + LOCAL_GAS_PRICE * GAS_LIMIT)
+			);
+		});
+	}
+
+	#[test]
+	fn quote_without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				Router::quote(&AxelarId::Evm(CHAIN_ID), &MESSAGE.to_vec()),
+				Error::<Runtime>::RouterConfigurationNotFound,
+			);
+		});
+	}
+}
+
 mod receive {
 	use super::*;
 
@@ -132,6 +369,44 @@ mod receive {
 		});
 	}
 
+	#[test]
+	fn success_with_other_domain() {
+		new_test_ext().execute_with(|| {
+			other_configuration();
+
+			Receiver::mock_receive(|middleware, origin, message| {
+				assert_eq!(middleware, Middleware(AxelarId::Other(OTHER_CHAIN_ID)));
+				assert_eq!(origin, Domain::Other(OTHER_CHAIN_ID));
+				assert_eq!(&message, MESSAGE);
+				Ok(())
+			});
+
+			assert_ok!(Router::receive(
+				INBOUND_CONTRACT,
+				OTHER_CHAIN_NAME.as_bytes(),
+				format!("0x{}", hex::encode(OTHER_APP_ADDRESS)).as_bytes(),
+				MESSAGE
+			));
+		});
+	}
+
+	#[test]
+	fn with_wrong_source_address_in_other_domain() {
+		new_test_ext().execute_with(|| {
+			other_configuration();
+
+			assert_err!(
+				Router::receive(
+					INBOUND_CONTRACT,
+					OTHER_CHAIN_NAME.as_bytes(),
+					&[5; 32],
+					MESSAGE
+				),
+				Error::<Runtime>::SourceAddressMismatch
+			);
+		});
+	}
+
 	#[test]
 	fn without_configuration() {
 		new_test_ext().execute_with(|| {
@@ -66,6 +66,8 @@ pub mod pallet {
 		FeeReceiverNotSet,
 		/// Emit when the fee amount is not set
 		FeeAmountNotSet,
+		/// Emit when the receiver has no ethereum address
+		InvalidReceiver,
 	}
 
 	#[pallet::event]
@@ -91,6 +93,7 @@ pub mod pallet {
 		#[pallet::call_index(0)]
 		pub fn migrate(origin: OriginFor<T>, receiver: DomainAddress) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			let receiver_address = receiver.h160().ok_or(Error::<T>::InvalidReceiver)?;
 
 			let cfg_lock_account = T::CfgLockAccount::get().into_account_truncating();
 			let iou_cfg = T::IouCfg::get();
@@ -141,7 +144,7 @@ pub mod pallet {
 
 			Self::deposit_event(Event::CfgMigrationInitiated {
 				sender: who,
-				receiver: receiver.h160(),
+				receiver: receiver_address,
 				amount: transfer_amount,
 			});
 
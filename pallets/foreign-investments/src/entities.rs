@@ -12,7 +12,7 @@ use frame_support::{dispatch::DispatchResult, ensure, RuntimeDebugNoBound};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{EnsureAdd, EnsureAddAssign, EnsureDiv, EnsureMul, EnsureSubAssign, Zero},
+	traits::{EnsureAdd, EnsureAddAssign, EnsureDiv, EnsureMul, EnsureSub, EnsureSubAssign, Zero},
 	DispatchError,
 };
 
@@ -50,6 +50,11 @@ pub struct InvestmentInfo<T: Config> {
 	/// A possible order id associated to this investment
 	/// Could be a pool to foreign order or foreign to pool order
 	pub order_id: Option<T::OrderId>,
+
+	/// Pool amount invested from this foreign currency and not yet collected.
+	/// The investment of the account is the sum of the pool amounts of all
+	/// its foreign currencies.
+	pub pool_amount: T::PoolBalance,
 }
 
 impl<T: Config> InvestmentInfo<T> {
@@ -59,18 +64,10 @@ impl<T: Config> InvestmentInfo<T> {
 			foreign_amount: T::ForeignBalance::zero(),
 			decrease_swapped_foreign_amount: T::ForeignBalance::zero(),
 			order_id: None,
+			pool_amount: T::PoolBalance::zero(),
 		}
 	}
 
-	pub fn ensure_same_foreign(&self, foreign_currency: T::CurrencyId) -> DispatchResult {
-		ensure!(
-			self.foreign_currency == foreign_currency,
-			Error::<T>::MismatchedForeignCurrency
-		);
-
-		Ok(())
-	}
-
 	pub fn ensure_no_pending_cancel(&self, investment_id: T::InvestmentId) -> DispatchResult {
 		let pool_currency = pool_currency_of::<T>(investment_id)?;
 		ensure!(
@@ -95,7 +92,7 @@ impl<T: Config> InvestmentInfo<T> {
 		if self.foreign_currency != pool_currency {
 			self.order_id = create_or_increase_swap::<T>(
 				who,
-				(investment_id, Action::Investment),
+				(investment_id, self.foreign_currency, Action::Investment),
 				&self.order_id,
 				Swap {
 					currency_in: pool_currency,
@@ -128,6 +125,8 @@ impl<T: Config> InvestmentInfo<T> {
 				investment_id,
 				T::Investment::investment(who, investment_id)?.ensure_add(swapped_pool_amount)?,
 			)?;
+
+			self.pool_amount.ensure_add_assign(swapped_pool_amount)?;
 		}
 
 		if pending_foreign_amount.is_zero() {
@@ -144,12 +143,17 @@ impl<T: Config> InvestmentInfo<T> {
 		who: &T::AccountId,
 		investment_id: T::InvestmentId,
 	) -> Result<(T::ForeignBalance, T::PoolBalance), DispatchError> {
-		let swap_id = (investment_id, Action::Investment);
+		let swap_id = (investment_id, self.foreign_currency, Action::Investment);
 		let pool_currency = pool_currency_of::<T>(investment_id)?;
 
-		let cancel_pool_amount = T::Investment::investment(who, investment_id)?;
+		// Only the amount invested from this foreign currency is cancelled
+		let cancel_pool_amount = sp_std::mem::take(&mut self.pool_amount);
 		if !cancel_pool_amount.is_zero() {
-			T::Investment::update_investment(who, investment_id, Zero::zero())?;
+			T::Investment::update_investment(
+				who,
+				investment_id,
+				T::Investment::investment(who, investment_id)?.ensure_sub(cancel_pool_amount)?,
+			)?;
 		}
 
 		if self.foreign_currency != pool_currency {
@@ -211,7 +215,8 @@ impl<T: Config> InvestmentInfo<T> {
 		Ok(())
 	}
 
-	/// This method is performed after a collect
+	/// This method is performed after a collect, with the part of the
+	/// collected amount that belongs to this foreign currency
 	#[allow(clippy::type_complexity)]
 	pub fn post_collect(
 		&mut self,
@@ -219,16 +224,16 @@ impl<T: Config> InvestmentInfo<T> {
 		investment_id: T::InvestmentId,
 		collected: CollectedAmount<T::TrancheBalance, T::PoolBalance>,
 	) -> DispatchResult {
-		let invested = T::Investment::investment(who, investment_id)?;
+		let pool_amount_before_collecting = self.pool_amount;
+		self.pool_amount
+			.ensure_sub_assign(collected.amount_payment)?;
 
-		let collected_foreign_amount = if invested.is_zero() {
+		let collected_foreign_amount = if self.pool_amount.is_zero() {
 			// Last partial collect, we just return the tracked foreign amount
 			// to ensure the sum of all partial collects matches the amount that was
 			// incremented
 			self.foreign_amount
 		} else {
-			let pool_amount_before_collecting = invested.ensure_add(collected.amount_payment)?;
-
 			// Transform the collected pool amount into foreign amount.
 			// This transformation is done by correlation, thanks to `foreing_amount`
 			// containing the "same" amount as the investment pool amount but with different
@@ -253,12 +258,8 @@ impl<T: Config> InvestmentInfo<T> {
 		)
 	}
 
-	pub fn is_completed(
-		&self,
-		who: &T::AccountId,
-		investment_id: T::InvestmentId,
-	) -> Result<bool, DispatchError> {
-		Ok(T::Investment::investment(who, investment_id)?.is_zero() && self.order_id.is_none())
+	pub fn is_completed(&self) -> bool {
+		self.pool_amount.is_zero() && self.order_id.is_none()
 	}
 }
 
@@ -278,6 +279,11 @@ pub struct RedemptionInfo<T: Config> {
 	/// A possible order id associated to this investment
 	/// Could be a pool to foreign
 	pub order_id: Option<T::OrderId>,
+
+	/// Tranche tokens redeemed for this foreign currency and not yet
+	/// collected. The redemption of the account is the sum of the tranche
+	/// amounts of all its foreign currencies.
+	pub tranche_amount: T::TrancheBalance,
 }
 
 impl<T: Config> RedemptionInfo<T> {
@@ -287,18 +293,10 @@ impl<T: Config> RedemptionInfo<T> {
 			swapped_amount: T::ForeignBalance::default(),
 			collected_tranche_tokens: T::TrancheBalance::default(),
 			order_id: None,
+			tranche_amount: T::TrancheBalance::default(),
 		}
 	}
 
-	pub fn ensure_same_foreign(&self, foreign_currency: T::CurrencyId) -> DispatchResult {
-		ensure!(
-			self.foreign_currency == foreign_currency,
-			Error::<T>::MismatchedForeignCurrency
-		);
-
-		Ok(())
-	}
-
 	pub fn increase_redemption(
 		&mut self,
 		who: &T::AccountId,
@@ -311,6 +309,9 @@ impl<T: Config> RedemptionInfo<T> {
 				investment_id,
 				T::Investment::redemption(who, investment_id)?.ensure_add(tranche_tokens_amount)?,
 			)?;
+
+			self.tranche_amount
+				.ensure_add_assign(tranche_tokens_amount)?;
 		}
 
 		Ok(())
@@ -321,20 +322,29 @@ impl<T: Config> RedemptionInfo<T> {
 		who: &T::AccountId,
 		investment_id: T::InvestmentId,
 	) -> Result<T::TrancheBalance, DispatchError> {
-		let cancelled = T::Investment::redemption(who, investment_id)?;
+		// Only the tranche tokens redeemed for this foreign currency are cancelled
+		let cancelled = sp_std::mem::take(&mut self.tranche_amount);
 		if !cancelled.is_zero() {
-			T::Investment::update_redemption(who, investment_id, Zero::zero())?;
+			T::Investment::update_redemption(
+				who,
+				investment_id,
+				T::Investment::redemption(who, investment_id)?.ensure_sub(cancelled)?,
+			)?;
 		}
 		Ok(cancelled)
 	}
 
-	/// This method is performed after a collect and before applying the swap
+	/// This method is performed after a collect and before applying the swap,
+	/// with the part of the collected amount that belongs to this foreign
+	/// currency
 	pub fn post_collect_and_swap(
 		&mut self,
 		who: &T::AccountId,
 		investment_id: T::InvestmentId,
 		collected: CollectedAmount<T::PoolBalance, T::TrancheBalance>,
 	) -> Result<(T::ForeignBalance, T::PoolBalance), DispatchError> {
+		self.tranche_amount
+			.ensure_sub_assign(collected.amount_payment)?;
 		self.collected_tranche_tokens
 			.ensure_add_assign(collected.amount_payment)?;
 
@@ -344,7 +354,7 @@ impl<T: Config> RedemptionInfo<T> {
 		if self.foreign_currency != pool_currency {
 			self.order_id = create_or_increase_swap::<T>(
 				who,
-				(investment_id, Action::Redemption),
+				(investment_id, self.foreign_currency, Action::Redemption),
 				&self.order_id,
 				Swap {
 					currency_in: self.foreign_currency,
@@ -387,12 +397,7 @@ impl<T: Config> RedemptionInfo<T> {
 		Ok(())
 	}
 
-	pub fn is_completed(
-		&self,
-		who: &T::AccountId,
-		investment_id: T::InvestmentId,
-	) -> Result<bool, DispatchError> {
-		Ok(T::Investment::redemption(who, investment_id)?.is_zero()
-			&& self.collected_tranche_tokens.is_zero())
+	pub fn is_completed(&self) -> bool {
+		self.tranche_amount.is_zero() && self.collected_tranche_tokens.is_zero()
 	}
 }
@@ -3,14 +3,19 @@
 use cfg_traits::{investments::ForeignInvestment, swaps::SwapInfo, StatusNotificationHook};
 use cfg_types::investments::CollectedAmount;
 use frame_support::pallet_prelude::*;
-use sp_std::marker::PhantomData;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AtLeast32BitUnsigned, EnsureAdd, EnsureAddAssign, EnsureSub, EnsureSubAssign},
+	Rounding, SaturatedConversion,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use crate::{
 	entities::{InvestmentInfo, RedemptionInfo},
 	pallet::{Config, Error, ForeignInvestmentInfo, ForeignRedemptionInfo, Pallet},
 	pool_currency_of,
-	swaps::fulfilled_order,
-	Action,
+	swaps::{fulfilled_order, renew_swap},
+	Action, SwapOf,
 };
 
 impl<T: Config> ForeignInvestment<T::AccountId> for Pallet<T> {
@@ -25,15 +30,14 @@ impl<T: Config> ForeignInvestment<T::AccountId> for Pallet<T> {
 		foreign_amount: T::ForeignBalance,
 		foreign_currency: T::CurrencyId,
 	) -> DispatchResult {
-		ForeignInvestmentInfo::<T>::mutate_exists(who, investment_id, |entry| {
+		ForeignInvestmentInfo::<T>::mutate_exists((who, investment_id, foreign_currency), |entry| {
 			let info = entry.get_or_insert(InvestmentInfo::new(foreign_currency));
-			info.ensure_same_foreign(foreign_currency)?;
 			info.ensure_no_pending_cancel(investment_id)?;
 
 			let (increased, pending) = info.increase(who, investment_id, foreign_amount)?;
 			info.post_increase_swap(who, investment_id, increased, increased.into(), pending)?;
 
-			remove_entry(info.is_completed(who, investment_id)?, entry)
+			remove_entry(info.is_completed(), entry)
 		})
 	}
 
@@ -42,15 +46,14 @@ impl<T: Config> ForeignInvestment<T::AccountId> for Pallet<T> {
 		investment_id: T::InvestmentId,
 		foreign_currency: T::CurrencyId,
 	) -> DispatchResult {
-		ForeignInvestmentInfo::<T>::mutate_exists(who, investment_id, |entry| {
+		ForeignInvestmentInfo::<T>::mutate_exists((who, investment_id, foreign_currency), |entry| {
 			let info = entry.as_mut().ok_or(Error::<T>::InfoNotFound)?;
-			info.ensure_same_foreign(foreign_currency)?;
 			info.ensure_no_pending_cancel(investment_id)?;
 
 			let (cancelled, pending) = info.cancel(who, investment_id)?;
 			info.post_cancel_swap(who, investment_id, cancelled, pending)?;
 
-			remove_entry(info.is_completed(who, investment_id)?, entry)
+			remove_entry(info.is_completed(), entry)
 		})
 	}
 
@@ -60,13 +63,15 @@ impl<T: Config> ForeignInvestment<T::AccountId> for Pallet<T> {
 		tranche_tokens_amount: T::TrancheBalance,
 		payout_foreign_currency: T::CurrencyId,
 	) -> DispatchResult {
-		ForeignRedemptionInfo::<T>::mutate_exists(who, investment_id, |entry| -> DispatchResult {
-			let info = entry.get_or_insert(RedemptionInfo::new(payout_foreign_currency));
-			info.ensure_same_foreign(payout_foreign_currency)?;
-			info.increase_redemption(who, investment_id, tranche_tokens_amount)?;
+		ForeignRedemptionInfo::<T>::mutate_exists(
+			(who, investment_id, payout_foreign_currency),
+			|entry| -> DispatchResult {
+				let info = entry.get_or_insert(RedemptionInfo::new(payout_foreign_currency));
+				info.increase_redemption(who, investment_id, tranche_tokens_amount)?;
 
-			remove_entry(info.is_completed(who, investment_id)?, entry)
-		})
+				remove_entry(info.is_completed(), entry)
+			},
+		)
 	}
 
 	fn cancel_foreign_redemption(
@@ -74,14 +79,16 @@ impl<T: Config> ForeignInvestment<T::AccountId> for Pallet<T> {
 		investment_id: T::InvestmentId,
 		payout_foreign_currency: T::CurrencyId,
 	) -> Result<T::TrancheBalance, DispatchError> {
-		ForeignRedemptionInfo::<T>::mutate_exists(who, investment_id, |entry| {
-			let info = entry.as_mut().ok_or(Error::<T>::InfoNotFound)?;
-			info.ensure_same_foreign(payout_foreign_currency)?;
-			let cancelled = info.cancel_redeemption(who, investment_id)?;
+		ForeignRedemptionInfo::<T>::mutate_exists(
+			(who, investment_id, payout_foreign_currency),
+			|entry| {
+				let info = entry.as_mut().ok_or(Error::<T>::InfoNotFound)?;
+				let cancelled = info.cancel_redeemption(who, investment_id)?;
 
-			remove_entry(info.is_completed(who, investment_id)?, entry)?;
-			Ok(cancelled)
-		})
+				remove_entry(info.is_completed(), entry)?;
+				Ok(cancelled)
+			},
+		)
 	}
 }
 
@@ -91,57 +98,57 @@ impl<T: Config> StatusNotificationHook for Pallet<T> {
 	type Status = SwapInfo<T::SwapBalance, T::SwapBalance, T::CurrencyId, T::SwapRatio>;
 
 	fn notify_status_change(order_id: T::OrderId, swap_info: Self::Status) -> DispatchResult {
-		let (who, (investment_id, action)) = match fulfilled_order::<T>(&order_id, &swap_info) {
-			Some(location) => location,
-			None => return Ok(()), // notification not for FI
-		};
+		let (who, (investment_id, foreign_currency, action)) =
+			match fulfilled_order::<T>(&order_id, &swap_info) {
+				Some(location) => location,
+				None => return Ok(()), // notification not for FI
+			};
 
 		let pool_currency = pool_currency_of::<T>(investment_id)?;
 		let swapped_amount_in = swap_info.swapped_in;
 		let swapped_amount_out = swap_info.swapped_out;
 		let pending_amount = swap_info.remaining.amount_out;
 
+		let key = (&who, investment_id, foreign_currency);
 		match action {
-			Action::Investment => {
-				ForeignInvestmentInfo::<T>::mutate_exists(&who, investment_id, |entry| {
-					let info = entry.as_mut().ok_or(Error::<T>::InfoNotFound)?;
-					if pool_currency == swap_info.remaining.currency_in {
-						info.post_increase_swap(
-							&who,
-							investment_id,
-							swapped_amount_in.into(),
-							swapped_amount_out.into(),
-							pending_amount.into(),
-						)
-					} else {
-						info.post_cancel_swap(
-							&who,
-							investment_id,
-							swapped_amount_in.into(),
-							pending_amount.into(),
-						)?;
-
-						remove_entry(info.is_completed(&who, investment_id)?, entry)
-					}
-				})
-			}
-			Action::Redemption => {
-				ForeignRedemptionInfo::<T>::mutate_exists(&who, investment_id, |entry| {
-					let info = entry.as_mut().ok_or(Error::<T>::InfoNotFound)?;
-					info.post_swap(
+			Action::Investment => ForeignInvestmentInfo::<T>::mutate_exists(key, |entry| {
+				let info = entry.as_mut().ok_or(Error::<T>::InfoNotFound)?;
+				if pool_currency == swap_info.remaining.currency_in {
+					info.post_increase_swap(
+						&who,
+						investment_id,
+						swapped_amount_in.into(),
+						swapped_amount_out.into(),
+						pending_amount.into(),
+					)
+				} else {
+					info.post_cancel_swap(
 						&who,
 						investment_id,
 						swapped_amount_in.into(),
 						pending_amount.into(),
 					)?;
 
-					remove_entry(info.is_completed(&who, investment_id)?, entry)
-				})
-			}
+					remove_entry(info.is_completed(), entry)
+				}
+			}),
+			Action::Redemption => ForeignRedemptionInfo::<T>::mutate_exists(key, |entry| {
+				let info = entry.as_mut().ok_or(Error::<T>::InfoNotFound)?;
+				info.post_swap(
+					&who,
+					investment_id,
+					swapped_amount_in.into(),
+					pending_amount.into(),
+				)?;
+
+				remove_entry(info.is_completed(), entry)
+			}),
 		}
 	}
 }
 
+/// Splits the collected amount between the foreign currencies of the
+/// investment, proportionally to the pool amount invested from each of them.
 pub struct CollectedInvestmentHook<T>(PhantomData<T>);
 impl<T: Config> StatusNotificationHook for CollectedInvestmentHook<T> {
 	type Error = DispatchError;
@@ -152,19 +159,37 @@ impl<T: Config> StatusNotificationHook for CollectedInvestmentHook<T> {
 		(who, investment_id): (T::AccountId, T::InvestmentId),
 		collected: CollectedAmount<T::TrancheBalance, T::PoolBalance>,
 	) -> DispatchResult {
-		ForeignInvestmentInfo::<T>::mutate_exists(&who, investment_id, |entry| {
-			if let Some(info) = entry.as_mut() {
-				info.ensure_no_pending_cancel(investment_id)?;
-				info.post_collect(&who, investment_id, collected)?;
+		let invested = ForeignInvestmentInfo::<T>::iter_prefix((who.clone(), investment_id))
+			.filter(|(_, info)| !info.pool_amount.is_zero())
+			.map(|(foreign_currency, info)| (foreign_currency, info.pool_amount))
+			.collect::<Vec<_>>();
 
-				remove_entry(info.is_completed(&who, investment_id)?, entry)?;
-			}
+		// NOTE: The collected payment never exceeds the pool amount pending to
+		//       collect, so there is no unattributed payment to handle.
+		let (shares, _) = split(&invested, collected)?;
 
-			Ok(())
-		})
+		for (foreign_currency, share) in shares {
+			ForeignInvestmentInfo::<T>::mutate_exists(
+				(&who, investment_id, foreign_currency),
+				|entry| {
+					if let Some(info) = entry.as_mut() {
+						info.ensure_no_pending_cancel(investment_id)?;
+						info.post_collect(&who, investment_id, share)?;
+
+						remove_entry(info.is_completed(), entry)?;
+					}
+
+					Ok::<_, DispatchError>(())
+				},
+			)?;
+		}
+
+		Ok(())
 	}
 }
 
+/// Splits the collected amount between the payout foreign currencies of the
+/// redemption, proportionally to the tranche tokens redeemed for each of them.
 pub struct CollectedRedemptionHook<T>(PhantomData<T>);
 impl<T: Config> StatusNotificationHook for CollectedRedemptionHook<T> {
 	type Error = DispatchError;
@@ -175,18 +200,67 @@ impl<T: Config> StatusNotificationHook for CollectedRedemptionHook<T> {
 		(who, investment_id): (T::AccountId, T::InvestmentId),
 		collected: CollectedAmount<T::PoolBalance, T::TrancheBalance>,
 	) -> DispatchResult {
-		ForeignRedemptionInfo::<T>::mutate_exists(&who, investment_id, |entry| {
-			if let Some(info) = entry.as_mut() {
-				let (amount, pending) =
-					info.post_collect_and_swap(&who, investment_id, collected)?;
+		let redeemed = ForeignRedemptionInfo::<T>::iter_prefix((who.clone(), investment_id))
+			.filter(|(_, info)| !info.tranche_amount.is_zero())
+			.map(|(foreign_currency, info)| (foreign_currency, info.tranche_amount))
+			.collect::<Vec<_>>();
 
-				info.post_swap(&who, investment_id, amount, pending)?;
+		// NOTE: The collected payment never exceeds the tranche amount pending
+		//       to collect, so there is no unattributed payment to handle.
+		let (shares, _) = split(&redeemed, collected)?;
 
-				remove_entry(info.is_completed(&who, investment_id)?, entry)?;
-			}
+		for (foreign_currency, share) in shares {
+			ForeignRedemptionInfo::<T>::mutate_exists(
+				(&who, investment_id, foreign_currency),
+				|entry| {
+					if let Some(info) = entry.as_mut() {
+						let (amount, pending) =
+							info.post_collect_and_swap(&who, investment_id, share)?;
 
-			Ok(())
-		})
+						info.post_swap(&who, investment_id, amount, pending)?;
+
+						remove_entry(info.is_completed(), entry)?;
+					}
+
+					Ok::<_, DispatchError>(())
+				},
+			)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Places again the swaps whose orders expired, with a renewed expiry.
+/// The new orders lose the time priority of the expired ones.
+pub struct ExpiredSwapHook<T>(PhantomData<T>);
+impl<T: Config> StatusNotificationHook for ExpiredSwapHook<T> {
+	type Error = DispatchError;
+	type Id = T::OrderId;
+	type Status = SwapOf<T>;
+
+	fn notify_status_change(order_id: T::OrderId, swap: SwapOf<T>) -> DispatchResult {
+		let (who, (investment_id, foreign_currency, action), new_order_id) =
+			match renew_swap::<T>(&order_id, swap)? {
+				Some(location) => location,
+				None => return Ok(()), // notification not for FI
+			};
+
+		let key = (&who, investment_id, foreign_currency);
+		match action {
+			Action::Investment => ForeignInvestmentInfo::<T>::mutate(key, |entry| {
+				if let Some(info) = entry.as_mut() {
+					info.order_id = new_order_id;
+				}
+			}),
+			Action::Redemption => ForeignRedemptionInfo::<T>::mutate(key, |entry| {
+				if let Some(info) = entry.as_mut() {
+					info.order_id = new_order_id;
+				}
+			}),
+		}
+
+		Ok(())
 	}
 }
 
@@ -198,3 +272,81 @@ fn remove_entry<Entry>(condition: bool, entry: &mut Option<Entry>) -> DispatchRe
 
 	Ok(())
 }
+
+/// Splits a collected amount between the given currencies, proportionally to
+/// the amount each of them has pending to collect.
+///
+/// The payment share of each currency never exceeds its pending amount. The
+/// payment dust left by rounding down is given to the currencies with pending
+/// amount left, while the collected dust is given to the last currency.
+/// Returns the shares along with the payment that could not be attributed to
+/// any currency because it exceeds the total pending amount.
+pub(crate) fn split<Currency: Copy, Collected, Payment>(
+	pending: &[(Currency, Payment)],
+	collected: CollectedAmount<Collected, Payment>,
+) -> Result<
+	(
+		Vec<(Currency, CollectedAmount<Collected, Payment>)>,
+		Payment,
+	),
+	DispatchError,
+>
+where
+	Collected: AtLeast32BitUnsigned + Copy,
+	Payment: AtLeast32BitUnsigned + Copy,
+{
+	let total = pending
+		.iter()
+		.try_fold(Payment::zero(), |total, (_, part)| total.ensure_add(*part))?;
+
+	let mut remaining = collected.clone();
+	let mut shares = Vec::with_capacity(pending.len());
+
+	for (currency, part) in pending {
+		let share = CollectedAmount {
+			amount_collected: proportion(collected.amount_collected, *part, total),
+			amount_payment: proportion(collected.amount_payment, *part, total).min(*part),
+		};
+
+		remaining
+			.amount_collected
+			.ensure_sub_assign(share.amount_collected)?;
+		remaining
+			.amount_payment
+			.ensure_sub_assign(share.amount_payment)?;
+		shares.push((*currency, share));
+	}
+
+	for ((_, part), (_, share)) in pending.iter().zip(shares.iter_mut()) {
+		let dust = part
+			.ensure_sub(share.amount_payment)?
+			.min(remaining.amount_payment);
+
+		share.amount_payment.ensure_add_assign(dust)?;
+		remaining.amount_payment.ensure_sub_assign(dust)?;
+	}
+
+	if let Some((_, share)) = shares.last_mut() {
+		share
+			.amount_collected
+			.ensure_add_assign(remaining.amount_collected)?;
+	}
+
+	Ok((shares, remaining.amount_payment))
+}
+
+/// Computes `amount * part / total`, rounding down
+fn proportion<Amount, Part>(amount: Amount, part: Part, total: Part) -> Amount
+where
+	Amount: AtLeast32BitUnsigned,
+	Part: AtLeast32BitUnsigned,
+{
+	multiply_by_rational_with_rounding(
+		amount.saturated_into(),
+		part.saturated_into(),
+		total.saturated_into(),
+		Rounding::Down,
+	)
+	.unwrap_or_default()
+	.saturated_into()
+}
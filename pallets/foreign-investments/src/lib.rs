@@ -25,7 +25,8 @@
 //!   notifications for collected investments via `CollectedInvestmentHook` and
 //!   for collected redemptions via `CollectedRedemptionHook`].
 //! - The implementer of the pallet's associated `TokenSwaps` type sends
-//!   notifications for fulfilled swap orders via the `FulfilledSwapHook`.
+//!   notifications for fulfilled swap orders via the `FulfilledSwapHook` and
+//!   for expired swap orders via the `ExpiredSwapHook`.
 //! - The implementer of the pallet's associated
 //!   `DecreasedForeignInvestOrderHook` type handles the refund of the decreased
 //!   amount to the investor.
@@ -36,7 +37,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use cfg_traits::swaps::{Swap, TokenSwaps};
-pub use impls::{CollectedInvestmentHook, CollectedRedemptionHook};
+pub use impls::{CollectedInvestmentHook, CollectedRedemptionHook, ExpiredSwapHook};
 pub use pallet::*;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
@@ -49,6 +50,7 @@ mod tests;
 
 mod entities;
 mod impls;
+pub mod migrations;
 mod swaps;
 
 #[derive(
@@ -63,14 +65,20 @@ pub enum Action {
 pub type ForeignId<T> = (
 	<T as frame_system::Config>::AccountId,
 	<T as Config>::InvestmentId,
+	<T as Config>::CurrencyId,
 	Action,
 );
 
 /// Swap alias
 pub type SwapOf<T> = Swap<<T as Config>::SwapBalance, <T as Config>::CurrencyId>;
 
-/// Identification of a swap from foreing-investment perspective
-pub type SwapId<T> = (<T as Config>::InvestmentId, Action);
+/// Identification of a swap from foreing-investment perspective, by
+/// investment and foreign currency
+pub type SwapId<T> = (
+	<T as Config>::InvestmentId,
+	<T as Config>::CurrencyId,
+	Action,
+);
 
 /// TrancheId Identification
 pub type TrancheIdOf<T> = <<T as Config>::PoolInspect as cfg_traits::PoolInspect<
@@ -88,14 +96,14 @@ pub type PoolIdOf<T> = <<T as Config>::PoolInspect as cfg_traits::PoolInspect<
 pub mod pallet {
 	use cfg_traits::{
 		investments::{ForeignInvestmentHooks, Investment, TrancheCurrency},
-		PoolInspect,
+		PoolInspect, Seconds, TimeAsSecs,
 	};
 	use frame_support::pallet_prelude::*;
 	use sp_runtime::traits::{AtLeast32BitUnsigned, One};
 
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -187,31 +195,43 @@ pub mod pallet {
 
 		/// The source of truth for pool currencies.
 		type PoolInspect: PoolInspect<Self::AccountId, Self::CurrencyId>;
+
+		/// Lifetime of the swap orders, after which they expire and are placed
+		/// again. Without it, the swap orders never expire.
+		type SwapLifetime: Get<Option<Seconds>>;
+
+		/// Time provider to compute the expiry of the swap orders
+		type Time: TimeAsSecs;
 	}
 
-	/// Contains the information about the foreign investment process.
+	/// Contains the information about the foreign investment process, by
+	/// foreign currency. An account can invest in the same investment with
+	/// several foreign currencies at the same time.
 	/// The storage is killed once the investment is fully collected, or
 	/// decreased.
 	#[pallet::storage]
-	pub type ForeignInvestmentInfo<T: Config> = StorageDoubleMap<
+	pub type ForeignInvestmentInfo<T: Config> = StorageNMap<
 		_,
-		Blake2_128Concat,
-		T::AccountId,
-		Blake2_128Concat,
-		T::InvestmentId,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::InvestmentId>,
+			NMapKey<Blake2_128Concat, T::CurrencyId>,
+		),
 		entities::InvestmentInfo<T>,
 	>;
 
-	/// Contains the information about the foreign redemption process.
+	/// Contains the information about the foreign redemption process, by
+	/// payout foreign currency.
 	/// The storage is killed once the redemption is fully collected and
 	/// fully swapped or decreased
 	#[pallet::storage]
-	pub(super) type ForeignRedemptionInfo<T: Config> = StorageDoubleMap<
+	pub(super) type ForeignRedemptionInfo<T: Config> = StorageNMap<
 		_,
-		Blake2_128Concat,
-		T::AccountId,
-		Blake2_128Concat,
-		T::InvestmentId,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::InvestmentId>,
+			NMapKey<Blake2_128Concat, T::CurrencyId>,
+		),
 		entities::RedemptionInfo<T>,
 	>;
 
@@ -229,12 +249,6 @@ pub mod pallet {
 		/// Failed to retrieve the pool for the given pool id.
 		PoolNotFound,
 
-		/// An action for a different foreign currency is currently in process
-		/// for the same pool currency, account, and investment.
-		/// The currenct foreign actions must be finished before starting with a
-		/// different foreign currency investment / redemption.
-		MismatchedForeignCurrency,
-
 		/// A cancel action is in progress and it needs to finish before
 		/// increasing again
 		CancellationInProgress,
@@ -269,14 +283,17 @@ pub mod pallet {
 		pub fn order_id(
 			account_id: &T::AccountId,
 			investment_id: T::InvestmentId,
+			foreign_currency: T::CurrencyId,
 			action: Action,
 		) -> Option<T::OrderId> {
 			match action {
 				Action::Investment => {
-					ForeignInvestmentInfo::<T>::get(account_id, investment_id)?.order_id
+					ForeignInvestmentInfo::<T>::get((account_id, investment_id, foreign_currency))?
+						.order_id
 				}
 				Action::Redemption => {
-					ForeignRedemptionInfo::<T>::get(account_id, investment_id)?.order_id
+					ForeignRedemptionInfo::<T>::get((account_id, investment_id, foreign_currency))?
+						.order_id
 				}
 			}
 		}
@@ -0,0 +1,181 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+pub mod v3 {
+	use cfg_traits::investments::Investment;
+	#[cfg(feature = "try-runtime")]
+	use frame_support::{ensure, pallet_prelude::Encode};
+	use frame_support::{
+		traits::{Get, OnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use sp_std::{marker::PhantomData, vec::Vec};
+
+	use super::v2;
+	use crate::{
+		entities::{InvestmentInfo, RedemptionInfo},
+		pallet::{Config, ForeignInvestmentInfo, ForeignRedemptionInfo, OrderIdToSwapId},
+		Action,
+	};
+
+	/// Keys the foreign investments and redemptions by foreign currency.
+	///
+	/// Until now an account could only use one foreign currency per
+	/// investment, so the whole investment and redemption of the account
+	/// belong to the currency of the existing entries.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+
+			// The swaps are migrated first, reading the currency of the old entries
+			OrderIdToSwapId::<T>::translate::<(T::AccountId, (T::InvestmentId, Action)), _>(
+				|_, (who, (investment_id, action))| {
+					count += 1;
+
+					let foreign_currency = match action {
+						Action::Investment => {
+							v2::ForeignInvestmentInfo::<T>::get(&who, investment_id)
+								.map(|info| info.foreign_currency)
+						}
+						Action::Redemption => {
+							v2::ForeignRedemptionInfo::<T>::get(&who, investment_id)
+								.map(|info| info.foreign_currency)
+						}
+					}?;
+
+					Some((who, (investment_id, foreign_currency, action)))
+				},
+			);
+
+			// NOTE: The old entries share the prefix of the new ones, so they are
+			//       all drained before inserting the new ones.
+			let investments = v2::ForeignInvestmentInfo::<T>::drain().collect::<Vec<_>>();
+			let redemptions = v2::ForeignRedemptionInfo::<T>::drain().collect::<Vec<_>>();
+
+			for (who, investment_id, old) in investments {
+				count += 1;
+
+				let key = (&who, investment_id, old.foreign_currency);
+				ForeignInvestmentInfo::<T>::insert(
+					key,
+					InvestmentInfo::<T> {
+						foreign_currency: old.foreign_currency,
+						foreign_amount: old.foreign_amount,
+						decrease_swapped_foreign_amount: old.decrease_swapped_foreign_amount,
+						order_id: old.order_id,
+						pool_amount: T::Investment::investment(&who, investment_id)
+							.unwrap_or_default(),
+					},
+				);
+			}
+
+			for (who, investment_id, old) in redemptions {
+				count += 1;
+
+				let key = (&who, investment_id, old.foreign_currency);
+				ForeignRedemptionInfo::<T>::insert(
+					key,
+					RedemptionInfo::<T> {
+						foreign_currency: old.foreign_currency,
+						swapped_amount: old.swapped_amount,
+						collected_tranche_tokens: old.collected_tranche_tokens,
+						order_id: old.order_id,
+						tranche_amount: T::Investment::redemption(&who, investment_id)
+							.unwrap_or_default(),
+					},
+				);
+			}
+
+			T::DbWeight::get().reads_writes(count.saturating_mul(2), count.saturating_mul(2))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let swaps = OrderIdToSwapId::<T>::iter_keys().count() as u64;
+			let investments = v2::ForeignInvestmentInfo::<T>::iter_keys().count() as u64;
+			let redemptions = v2::ForeignRedemptionInfo::<T>::iter_keys().count() as u64;
+
+			Ok((swaps, investments, redemptions).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (swaps, investments, redemptions): (u64, u64, u64) =
+				parity_scale_codec::Decode::decode(&mut state.as_slice())
+					.map_err(|_| "Failed to decode the pre upgrade state")?;
+
+			ensure!(
+				OrderIdToSwapId::<T>::iter_keys().count() as u64 == swaps,
+				"Swaps were lost"
+			);
+			ensure!(
+				ForeignInvestmentInfo::<T>::iter_keys().count() as u64 == investments,
+				"Foreign investments were lost"
+			);
+			ensure!(
+				ForeignRedemptionInfo::<T>::iter_keys().count() as u64 == redemptions,
+				"Foreign redemptions were lost"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Storage layout before keying the foreign investments and redemptions by
+/// foreign currency.
+pub(crate) mod v2 {
+	use frame_support::{pallet_prelude::OptionQuery, storage_alias, Blake2_128Concat};
+	use parity_scale_codec::{Decode, Encode};
+
+	use crate::pallet::{Config, Pallet};
+
+	#[derive(Encode, Decode)]
+	pub(crate) struct InvestmentInfo<T: Config> {
+		pub foreign_currency: T::CurrencyId,
+		pub foreign_amount: T::ForeignBalance,
+		pub decrease_swapped_foreign_amount: T::ForeignBalance,
+		pub order_id: Option<T::OrderId>,
+	}
+
+	#[derive(Encode, Decode)]
+	pub(crate) struct RedemptionInfo<T: Config> {
+		pub foreign_currency: T::CurrencyId,
+		pub swapped_amount: T::ForeignBalance,
+		pub collected_tranche_tokens: T::TrancheBalance,
+		pub order_id: Option<T::OrderId>,
+	}
+
+	#[storage_alias]
+	pub(crate) type ForeignInvestmentInfo<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		<T as Config>::InvestmentId,
+		InvestmentInfo<T>,
+		OptionQuery,
+	>;
+
+	#[storage_alias]
+	pub(crate) type ForeignRedemptionInfo<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		<T as Config>::InvestmentId,
+		RedemptionInfo<T>,
+		OptionQuery,
+	>;
+}
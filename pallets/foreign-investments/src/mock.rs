@@ -1,4 +1,5 @@
-use frame_support::derive_impl;
+use cfg_traits::Seconds;
+use frame_support::{derive_impl, parameter_types};
 use sp_runtime::FixedU128;
 
 use crate::pallet as pallet_foreign_investments;
@@ -11,6 +12,8 @@ pub type OrderId = u64;
 pub type CurrencyId = u8;
 pub type Ratio = FixedU128;
 
+pub const SWAP_LIFETIME: Seconds = 100;
+
 frame_support::construct_runtime!(
 	pub enum Runtime {
 		System: frame_system,
@@ -18,6 +21,7 @@ frame_support::construct_runtime!(
 		MockTokenSwaps: cfg_mocks::token_swaps::pallet,
 		MockHooks: cfg_mocks::foreign_investment_hooks::pallet,
 		MockPools: cfg_mocks::pools::pallet,
+		MockTime: cfg_mocks::pallet_mock_time,
 		ForeignInvestment: pallet_foreign_investments,
 	}
 );
@@ -57,6 +61,14 @@ impl cfg_mocks::pools::pallet::Config for Runtime {
 	type TrancheId = TrancheId;
 }
 
+impl cfg_mocks::pallet_mock_time::Config for Runtime {
+	type Moment = u64;
+}
+
+parameter_types! {
+	pub const SwapLifetime: Option<Seconds> = Some(SWAP_LIFETIME);
+}
+
 impl pallet_foreign_investments::Config for Runtime {
 	type CurrencyId = CurrencyId;
 	type ForeignBalance = Balance;
@@ -69,7 +81,9 @@ impl pallet_foreign_investments::Config for Runtime {
 	type PoolInspect = MockPools;
 	type RuntimeEvent = RuntimeEvent;
 	type SwapBalance = Balance;
+	type SwapLifetime = SwapLifetime;
 	type SwapRatio = Ratio;
+	type Time = MockTime;
 	type TrancheBalance = Balance;
 }
 
@@ -3,7 +3,11 @@
 //! - OrderIdToSwapId storage
 //! - Swap events
 
-use cfg_traits::swaps::{OrderInfo, OrderRatio, Swap, SwapInfo, TokenSwaps};
+use cfg_traits::{
+	swaps::{OrderInfo, OrderOptions, OrderRatio, Swap, SwapInfo, TokenSwaps},
+	TimeAsSecs,
+};
+use frame_support::traits::Get;
 use sp_runtime::{
 	traits::{EnsureAdd, Zero},
 	DispatchError, DispatchResult,
@@ -32,6 +36,11 @@ pub fn create_swap<T: Config>(
 		swap.currency_out,
 		swap.amount_out,
 		OrderRatio::Market,
+		OrderOptions {
+			expires_at: T::SwapLifetime::get()
+				.map(|lifetime| T::Time::now().saturating_add(lifetime)),
+			..Default::default()
+		},
 	)?;
 
 	OrderIdToSwapId::<T>::insert(order_id, (who.clone(), swap_id));
@@ -110,6 +119,22 @@ pub fn cancel_swap<T: Config>(
 	}
 }
 
+/// Places again the swap of an expired order, with a renewed expiry.
+/// Returns the location of the swap and its new order id, if the order was
+/// from this pallet.
+pub fn renew_swap<T: Config>(
+	order_id: &T::OrderId,
+	swap: SwapOf<T>,
+) -> Result<Option<(T::AccountId, SwapId<T>, Option<T::OrderId>)>, DispatchError> {
+	match OrderIdToSwapId::<T>::take(order_id) {
+		Some((who, swap_id)) => {
+			let new_order_id = create_swap::<T>(&who, swap_id, swap)?;
+			Ok(Some((who, swap_id, new_order_id)))
+		}
+		None => Ok(None),
+	}
+}
+
 pub fn get_swap<T: Config>(
 	order_id: &T::OrderId,
 ) -> Option<OrderInfo<T::SwapBalance, T::CurrencyId, T::SwapRatio>> {
@@ -122,14 +147,14 @@ pub fn fulfilled_order<T: Config>(
 ) -> Option<(T::AccountId, SwapId<T>)> {
 	let swap_id = OrderIdToSwapId::<T>::get(order_id);
 
-	if let Some((who, (investment_id, action))) = swap_id.clone() {
+	if let Some((who, swap_id)) = swap_id.clone() {
 		if swap_info.remaining.amount_out.is_zero() {
 			OrderIdToSwapId::<T>::remove(order_id);
 		}
 
 		Pallet::<T>::deposit_event(Event::SwapFullfilled {
 			who: who.clone(),
-			swap_id: (investment_id, action),
+			swap_id,
 			remaining: swap_info.remaining.clone(),
 			swapped_in: swap_info.swapped_in,
 			swapped_out: swap_info.swapped_out,
@@ -1,7 +1,7 @@
 use cfg_traits::{
 	investments::{ForeignInvestment as _, Investment, InvestmentCollector, TrancheCurrency},
-	swaps::{OrderInfo, OrderRatio, Swap, SwapInfo, TokenSwaps},
-	StatusNotificationHook,
+	swaps::{OrderInfo, OrderOptions, OrderRatio, Swap, SwapInfo, TokenSwaps},
+	Seconds, StatusNotificationHook,
 };
 use cfg_types::investments::CollectedAmount;
 use frame_support::{assert_err, assert_ok};
@@ -10,7 +10,7 @@ use sp_std::sync::{Arc, Mutex};
 
 use crate::{
 	entities::{InvestmentInfo, RedemptionInfo},
-	impls::{CollectedInvestmentHook, CollectedRedemptionHook},
+	impls::{CollectedInvestmentHook, CollectedRedemptionHook, ExpiredSwapHook},
 	mock::*,
 	Action, Error, Event, ForeignInvestmentInfo, ForeignRedemptionInfo, OrderIdToSwapId,
 };
@@ -24,6 +24,7 @@ const TRANCHE_RATIO: Balance = 5; // Means: 1 pool curr is 5 tranche curr
 const AMOUNT: Balance = pool_to_foreign(200);
 const TRANCHE_AMOUNT: Balance = 1000;
 const ORDER_ID: OrderId = 23;
+const NOW: Seconds = 1000;
 
 /// foreign amount to pool amount
 pub const fn foreign_to_pool(foreign_amount: Balance) -> Balance {
@@ -75,7 +76,7 @@ mod util {
 	pub fn config_swaps() {
 		MockTokenSwaps::mock_get_order_details(|_| None);
 
-		MockTokenSwaps::mock_place_order(|_, curr_in, curr_out, amount_out, _| {
+		MockTokenSwaps::mock_place_order(|_, curr_in, curr_out, amount_out, _, options| {
 			MockTokenSwaps::mock_get_order_details(move |_| {
 				Some(OrderInfo {
 					swap: Swap {
@@ -84,6 +85,7 @@ mod util {
 						amount_out: amount_out,
 					},
 					ratio: OrderRatio::Market,
+					options,
 				})
 			});
 			Ok(23)
@@ -99,6 +101,7 @@ mod util {
 						amount_out: amount_out,
 					},
 					ratio: OrderRatio::Market,
+					options: order.options,
 				})
 			});
 			Ok(())
@@ -134,6 +137,7 @@ mod util {
 	}
 
 	pub fn base_configuration() {
+		MockTime::mock_now(|| NOW * 1000);
 		util::configure_pool();
 		util::config_swaps();
 		util::config_investments();
@@ -141,7 +145,8 @@ mod util {
 
 	/// Emulates a swap partial fulfill
 	pub fn fulfill_last_swap(action: Action, amount_out: Balance) {
-		let order_id = ForeignInvestment::order_id(&USER, INVESTMENT_ID, action).unwrap();
+		let order_id =
+			ForeignInvestment::order_id(&USER, INVESTMENT_ID, FOREIGN_CURR, action).unwrap();
 		let order = MockTokenSwaps::get_order_details(order_id).unwrap();
 		MockTokenSwaps::mock_get_order_details(move |_| {
 			Some(OrderInfo {
@@ -150,6 +155,7 @@ mod util {
 					..order.swap
 				},
 				ratio: order.ratio,
+				options: order.options,
 			})
 		});
 
@@ -214,7 +220,7 @@ mod util {
 	}
 
 	pub fn pending_amount(action: Action, currency_id: CurrencyId) -> Balance {
-		ForeignInvestment::order_id(&USER, INVESTMENT_ID, action)
+		ForeignInvestment::order_id(&USER, INVESTMENT_ID, FOREIGN_CURR, action)
 			.and_then(MockTokenSwaps::get_order_details)
 			.filter(|info| info.swap.currency_out == currency_id)
 			.map(|info| info.swap.amount_out)
@@ -259,19 +265,20 @@ mod investment {
 			));
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(InvestmentInfo {
 					foreign_currency: FOREIGN_CURR,
 					foreign_amount: 0,
 					decrease_swapped_foreign_amount: 0,
 					order_id: Some(ORDER_ID),
+					pool_amount: foreign_to_pool(0),
 				})
 			);
 
 			System::assert_has_event(
 				Event::SwapCreatedOrUpdated {
 					who: USER,
-					swap_id: (INVESTMENT_ID, Action::Investment),
+					swap_id: (INVESTMENT_ID, FOREIGN_CURR, Action::Investment),
 					swap: Swap {
 						amount_out: AMOUNT,
 						currency_out: FOREIGN_CURR,
@@ -293,6 +300,57 @@ mod investment {
 		});
 	}
 
+	#[test]
+	fn increase_and_expire() {
+		new_test_ext().execute_with(|| {
+			util::base_configuration();
+
+			assert_ok!(ForeignInvestment::increase_foreign_investment(
+				&USER,
+				INVESTMENT_ID,
+				AMOUNT,
+				FOREIGN_CURR
+			));
+
+			assert_eq!(
+				MockTokenSwaps::get_order_details(ORDER_ID).unwrap().options,
+				OrderOptions {
+					expires_at: Some(NOW + SWAP_LIFETIME),
+					..Default::default()
+				}
+			);
+
+			MockTime::mock_now(|| (NOW + SWAP_LIFETIME) * 1000);
+			MockTokenSwaps::mock_place_order(|who, curr_in, curr_out, amount_out, _, options| {
+				assert_eq!(who, USER);
+				assert_eq!(curr_in, POOL_CURR);
+				assert_eq!(curr_out, FOREIGN_CURR);
+				assert_eq!(amount_out, AMOUNT);
+				assert_eq!(options.expires_at, Some(NOW + 2 * SWAP_LIFETIME));
+				Ok(ORDER_ID + 1)
+			});
+
+			assert_ok!(ExpiredSwapHook::<Runtime>::notify_status_change(
+				ORDER_ID,
+				Swap {
+					amount_out: AMOUNT,
+					currency_out: FOREIGN_CURR,
+					currency_in: POOL_CURR,
+				},
+			));
+
+			assert_eq!(
+				ForeignInvestment::order_id(&USER, INVESTMENT_ID, FOREIGN_CURR, Action::Investment),
+				Some(ORDER_ID + 1)
+			);
+			assert_eq!(OrderIdToSwapId::<Runtime>::get(ORDER_ID), None);
+			assert_eq!(
+				OrderIdToSwapId::<Runtime>::get(ORDER_ID + 1),
+				Some((USER, (INVESTMENT_ID, Action::Investment)))
+			);
+		});
+	}
+
 	#[test]
 	fn increase_and_increase() {
 		new_test_ext().execute_with(|| {
@@ -315,7 +373,7 @@ mod investment {
 			System::assert_has_event(
 				Event::SwapCreatedOrUpdated {
 					who: USER,
-					swap_id: (INVESTMENT_ID, Action::Investment),
+					swap_id: (INVESTMENT_ID, FOREIGN_CURR, Action::Investment),
 					swap: Swap {
 						amount_out: AMOUNT + AMOUNT,
 						currency_out: FOREIGN_CURR,
@@ -326,12 +384,13 @@ mod investment {
 			);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(InvestmentInfo {
 					foreign_currency: FOREIGN_CURR,
 					foreign_amount: 0,
 					decrease_swapped_foreign_amount: 0,
 					order_id: Some(ORDER_ID),
+					pool_amount: foreign_to_pool(0),
 				})
 			);
 
@@ -360,7 +419,7 @@ mod investment {
 			));
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				None,
 			);
 		});
@@ -397,7 +456,7 @@ mod investment {
 			assert_eq!(handler.times(), 1);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				None,
 			);
 
@@ -434,12 +493,13 @@ mod investment {
 			));
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(InvestmentInfo {
 					foreign_currency: FOREIGN_CURR,
 					foreign_amount: 0,
 					decrease_swapped_foreign_amount: 0,
-					order_id: Some(ORDER_ID)
+					order_id: Some(ORDER_ID),
+					pool_amount: foreign_to_pool(0),
 				})
 			);
 
@@ -470,19 +530,20 @@ mod investment {
 			util::fulfill_last_swap(Action::Investment, AMOUNT / 4);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(InvestmentInfo {
 					foreign_currency: FOREIGN_CURR,
 					foreign_amount: AMOUNT / 4,
 					decrease_swapped_foreign_amount: 0,
 					order_id: Some(ORDER_ID),
+					pool_amount: foreign_to_pool(AMOUNT / 4),
 				})
 			);
 
 			System::assert_has_event(
 				Event::SwapFullfilled {
 					who: USER,
-					swap_id: (INVESTMENT_ID, Action::Investment),
+					swap_id: (INVESTMENT_ID, FOREIGN_CURR, Action::Investment),
 					remaining: Swap {
 						amount_out: 3 * AMOUNT / 4,
 						currency_out: FOREIGN_CURR,
@@ -521,12 +582,13 @@ mod investment {
 			util::fulfill_last_swap(Action::Investment, AMOUNT);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(InvestmentInfo {
 					foreign_currency: FOREIGN_CURR,
 					foreign_amount: AMOUNT,
 					decrease_swapped_foreign_amount: 0,
 					order_id: None,
+					pool_amount: foreign_to_pool(AMOUNT),
 				})
 			);
 
@@ -565,7 +627,7 @@ mod investment {
 			System::assert_has_event(
 				Event::SwapCancelled {
 					who: USER,
-					swap_id: (INVESTMENT_ID, Action::Investment),
+					swap_id: (INVESTMENT_ID, FOREIGN_CURR, Action::Investment),
 					swap: Swap {
 						amount_out: AMOUNT / 4,
 						currency_out: FOREIGN_CURR,
@@ -578,7 +640,7 @@ mod investment {
 			System::assert_has_event(
 				Event::SwapCreatedOrUpdated {
 					who: USER,
-					swap_id: (INVESTMENT_ID, Action::Investment),
+					swap_id: (INVESTMENT_ID, FOREIGN_CURR, Action::Investment),
 					swap: Swap {
 						amount_out: foreign_to_pool(3 * AMOUNT / 4),
 						currency_out: POOL_CURR,
@@ -589,12 +651,13 @@ mod investment {
 			);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(InvestmentInfo {
 					foreign_currency: FOREIGN_CURR,
 					foreign_amount: AMOUNT,
 					decrease_swapped_foreign_amount: AMOUNT / 4,
 					order_id: Some(ORDER_ID),
+					pool_amount: foreign_to_pool(0),
 				})
 			);
 
@@ -701,7 +764,7 @@ mod investment {
 			assert_eq!(handler.times(), 1);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				None,
 			);
 
@@ -741,7 +804,7 @@ mod investment {
 			assert_eq!(handler.times(), 1);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				None,
 			);
 
@@ -776,12 +839,13 @@ mod investment {
 			util::fulfill_last_swap(Action::Investment, foreign_to_pool(AMOUNT / 2));
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(InvestmentInfo {
 					foreign_currency: FOREIGN_CURR,
 					foreign_amount: AMOUNT,
 					decrease_swapped_foreign_amount: AMOUNT / 2,
 					order_id: Some(ORDER_ID),
+					pool_amount: foreign_to_pool(0),
 				})
 			);
 
@@ -831,7 +895,7 @@ mod investment {
 			assert_eq!(handler.times(), 1);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				None,
 			);
 
@@ -872,12 +936,13 @@ mod investment {
 			assert_eq!(handler.times(), 1);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(InvestmentInfo {
 					foreign_currency: FOREIGN_CURR,
 					foreign_amount: AMOUNT / 4,
 					decrease_swapped_foreign_amount: 0,
 					order_id: Some(ORDER_ID),
+					pool_amount: foreign_to_pool(AMOUNT / 4),
 				})
 			);
 
@@ -931,7 +996,7 @@ mod investment {
 			assert_eq!(handler.times(), 1);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				None,
 			);
 
@@ -969,7 +1034,7 @@ mod investment {
 			assert_eq!(handler.times(), 1);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				None,
 			);
 
@@ -1065,12 +1130,13 @@ mod investment {
 			assert_eq!(*foreign_fulfilled.lock().unwrap(), AMOUNT - REMAINDER);
 
 			assert_eq!(
-				ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(InvestmentInfo {
 					foreign_currency: FOREIGN_CURR,
 					foreign_amount: REMAINDER,
 					decrease_swapped_foreign_amount: 0,
 					order_id: None,
+					pool_amount: REMAINDER,
 				})
 			);
 
@@ -1112,12 +1178,13 @@ mod investment {
 				));
 
 				assert_eq!(
-					ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, POOL_CURR)),
 					Some(InvestmentInfo {
 						foreign_currency: POOL_CURR,
 						foreign_amount: foreign_to_pool(AMOUNT),
 						decrease_swapped_foreign_amount: 0,
 						order_id: None,
+						pool_amount: foreign_to_pool(AMOUNT),
 					})
 				);
 
@@ -1162,7 +1229,7 @@ mod investment {
 				assert_eq!(handler.times(), 1);
 
 				assert_eq!(
-					ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, POOL_CURR)),
 					None,
 				);
 
@@ -1216,7 +1283,7 @@ mod investment {
 				assert_eq!(handler.times(), 1);
 
 				assert_eq!(
-					ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 					None,
 				);
 
@@ -1264,7 +1331,7 @@ mod investment {
 				assert_eq!(handler.times(), 1);
 
 				assert_eq!(
-					ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 					None,
 				);
 
@@ -1315,7 +1382,7 @@ mod investment {
 				assert_eq!(handler.times(), 1);
 
 				assert_eq!(
-					ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 					None,
 				);
 
@@ -1369,7 +1436,7 @@ mod investment {
 				assert_eq!(handler.times(), 1);
 
 				assert_eq!(
-					ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 					None,
 				);
 
@@ -1403,7 +1470,12 @@ mod investment {
 
 				// There is no pending swap.
 				assert_eq!(
-					ForeignInvestment::order_id(&USER, INVESTMENT_ID, Action::Investment),
+					ForeignInvestment::order_id(
+						&USER,
+						INVESTMENT_ID,
+						FOREIGN_CURR,
+						Action::Investment
+					),
 					None
 				);
 
@@ -1425,7 +1497,7 @@ mod investment {
 				assert_eq!(handler.times(), 1);
 
 				assert_eq!(
-					ForeignInvestmentInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 					None,
 				);
 
@@ -1433,6 +1505,163 @@ mod investment {
 			});
 		}
 	}
+	mod multiple_currencies {
+		use super::*;
+
+		fn increase_pool_and_foreign() {
+			assert_ok!(ForeignInvestment::increase_foreign_investment(
+				&USER,
+				INVESTMENT_ID,
+				foreign_to_pool(AMOUNT),
+				POOL_CURR
+			));
+
+			assert_ok!(ForeignInvestment::increase_foreign_investment(
+				&USER,
+				INVESTMENT_ID,
+				AMOUNT,
+				FOREIGN_CURR
+			));
+
+			util::fulfill_last_swap(Action::Investment, AMOUNT);
+		}
+
+		#[test]
+		fn increase() {
+			new_test_ext().execute_with(|| {
+				util::base_configuration();
+
+				increase_pool_and_foreign();
+
+				assert_eq!(
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, POOL_CURR))
+						.map(|info| info.pool_amount),
+					Some(foreign_to_pool(AMOUNT))
+				);
+				assert_eq!(
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR))
+						.map(|info| info.pool_amount),
+					Some(foreign_to_pool(AMOUNT))
+				);
+				assert_eq!(
+					MockInvestment::investment(&USER, INVESTMENT_ID),
+					Ok(foreign_to_pool(AMOUNT) * 2)
+				);
+			});
+		}
+
+		#[test]
+		fn increase_and_partial_collect() {
+			new_test_ext().execute_with(|| {
+				util::base_configuration();
+
+				increase_pool_and_foreign();
+
+				let collected = Arc::new(Mutex::new(Vec::new()));
+				let handler = MockHooks::mock_fulfill_collect_investment({
+					let collected = collected.clone();
+					move |_, _, currency, amount_collected, tranche_tokens| {
+						collected.lock().unwrap().push((
+							currency,
+							amount_collected,
+							tranche_tokens,
+						));
+						Ok(())
+					}
+				});
+
+				util::process_investment(foreign_to_pool(AMOUNT));
+				assert_ok!(MockInvestment::collect_investment(USER, INVESTMENT_ID));
+
+				assert_eq!(handler.times(), 2);
+
+				let mut collected = collected.lock().unwrap().clone();
+				collected.sort();
+				assert_eq!(
+					collected,
+					vec![
+						(
+							FOREIGN_CURR,
+							AMOUNT / 2,
+							pool_to_tranche(foreign_to_pool(AMOUNT) / 2)
+						),
+						(
+							POOL_CURR,
+							foreign_to_pool(AMOUNT) / 2,
+							pool_to_tranche(foreign_to_pool(AMOUNT) / 2)
+						),
+					]
+				);
+
+				assert_eq!(
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR))
+						.map(|info| info.pool_amount),
+					Some(foreign_to_pool(AMOUNT) / 2)
+				);
+			});
+		}
+
+		#[test]
+		fn increase_and_collect() {
+			new_test_ext().execute_with(|| {
+				util::base_configuration();
+
+				increase_pool_and_foreign();
+
+				let handler = MockHooks::mock_fulfill_collect_investment(|_, _, _, _, _| Ok(()));
+
+				util::process_investment(foreign_to_pool(AMOUNT) * 2);
+				assert_ok!(MockInvestment::collect_investment(USER, INVESTMENT_ID));
+
+				assert_eq!(handler.times(), 2);
+				assert_eq!(
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, POOL_CURR)),
+					None
+				);
+				assert_eq!(
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
+					None
+				);
+			});
+		}
+
+		#[test]
+		fn increase_and_cancel_one() {
+			new_test_ext().execute_with(|| {
+				util::base_configuration();
+
+				increase_pool_and_foreign();
+
+				let handler =
+					MockHooks::mock_fulfill_cancel_investment(|_, _, currency, decreased, _| {
+						assert_eq!(currency, POOL_CURR);
+						assert_eq!(decreased, foreign_to_pool(AMOUNT));
+						Ok(())
+					});
+
+				assert_ok!(ForeignInvestment::cancel_foreign_investment(
+					&USER,
+					INVESTMENT_ID,
+					POOL_CURR
+				));
+
+				assert_eq!(handler.times(), 1);
+				assert_eq!(
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, POOL_CURR)),
+					None
+				);
+				assert_eq!(
+					ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR))
+						.map(|info| info.pool_amount),
+					Some(foreign_to_pool(AMOUNT))
+				);
+				assert_eq!(
+					MockInvestment::investment(&USER, INVESTMENT_ID),
+					Ok(foreign_to_pool(AMOUNT))
+				);
+			});
+		}
+	}
 }
 
 mod redemption {
@@ -1463,12 +1692,13 @@ mod redemption {
 			));
 
 			assert_eq!(
-				ForeignRedemptionInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(RedemptionInfo {
 					foreign_currency: FOREIGN_CURR,
 					swapped_amount: 0,
 					collected_tranche_tokens: 0,
 					order_id: None,
+					tranche_amount: TRANCHE_AMOUNT,
 				})
 			);
 
@@ -1499,12 +1729,13 @@ mod redemption {
 			));
 
 			assert_eq!(
-				ForeignRedemptionInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(RedemptionInfo {
 					foreign_currency: FOREIGN_CURR,
 					swapped_amount: 0,
 					collected_tranche_tokens: 0,
 					order_id: None,
+					tranche_amount: TRANCHE_AMOUNT + TRANCHE_AMOUNT,
 				})
 			);
 
@@ -1534,7 +1765,7 @@ mod redemption {
 			));
 
 			assert_eq!(
-				ForeignRedemptionInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				None,
 			);
 
@@ -1559,12 +1790,13 @@ mod redemption {
 			assert_ok!(MockInvestment::collect_redemption(USER, INVESTMENT_ID));
 
 			assert_eq!(
-				ForeignRedemptionInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(RedemptionInfo {
 					foreign_currency: FOREIGN_CURR,
 					swapped_amount: 0,
 					collected_tranche_tokens: 3 * TRANCHE_AMOUNT / 4,
 					order_id: Some(ORDER_ID),
+					tranche_amount: TRANCHE_AMOUNT / 4,
 				})
 			);
 
@@ -1598,12 +1830,13 @@ mod redemption {
 			util::fulfill_last_swap(Action::Redemption, tranche_to_pool(TRANCHE_AMOUNT / 2));
 
 			assert_eq!(
-				ForeignRedemptionInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(RedemptionInfo {
 					foreign_currency: FOREIGN_CURR,
 					swapped_amount: pool_to_foreign(tranche_to_pool(TRANCHE_AMOUNT / 2)),
 					collected_tranche_tokens: 3 * TRANCHE_AMOUNT / 4,
 					order_id: Some(ORDER_ID),
+					tranche_amount: TRANCHE_AMOUNT / 4,
 				})
 			);
 
@@ -1650,12 +1883,13 @@ mod redemption {
 			assert_eq!(handler.times(), 1);
 
 			assert_eq!(
-				ForeignRedemptionInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				Some(RedemptionInfo {
 					foreign_currency: FOREIGN_CURR,
 					swapped_amount: 0,
 					collected_tranche_tokens: 0,
 					order_id: None,
+					tranche_amount: TRANCHE_AMOUNT / 4,
 				})
 			);
 
@@ -1697,7 +1931,7 @@ mod redemption {
 			assert_eq!(handler.times(), 1);
 
 			assert_eq!(
-				ForeignRedemptionInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+				ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
 				None,
 			);
 
@@ -1735,7 +1969,7 @@ mod redemption {
 				assert_eq!(handler.times(), 1);
 
 				assert_eq!(
-					ForeignRedemptionInfo::<Runtime>::get(&USER, INVESTMENT_ID),
+					ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, POOL_CURR)),
 					None,
 				);
 
@@ -1743,6 +1977,65 @@ mod redemption {
 			});
 		}
 	}
+	mod multiple_currencies {
+		use super::*;
+
+		#[test]
+		fn increase_and_partial_collect() {
+			new_test_ext().execute_with(|| {
+				util::base_configuration();
+
+				assert_ok!(ForeignInvestment::increase_foreign_redemption(
+					&USER,
+					INVESTMENT_ID,
+					TRANCHE_AMOUNT,
+					POOL_CURR,
+				));
+
+				assert_ok!(ForeignInvestment::increase_foreign_redemption(
+					&USER,
+					INVESTMENT_ID,
+					TRANCHE_AMOUNT,
+					FOREIGN_CURR,
+				));
+
+				// Only the pool currency part is sent, the foreign part needs a swap
+				let handler = MockHooks::mock_fulfill_collect_redemption(
+					|_, _, currency, tranche_tokens_collected, amount_payout| {
+						assert_eq!(currency, POOL_CURR);
+						assert_eq!(amount_payout, tranche_to_pool(TRANCHE_AMOUNT / 2));
+						assert_eq!(tranche_tokens_collected, TRANCHE_AMOUNT / 2);
+						Ok(())
+					},
+				);
+
+				util::process_redemption(TRANCHE_AMOUNT);
+				assert_ok!(MockInvestment::collect_redemption(USER, INVESTMENT_ID));
+				assert_eq!(handler.times(), 1);
+
+				assert_eq!(
+					ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, POOL_CURR)),
+					Some(RedemptionInfo {
+						foreign_currency: POOL_CURR,
+						swapped_amount: 0,
+						collected_tranche_tokens: 0,
+						order_id: None,
+						tranche_amount: TRANCHE_AMOUNT / 2,
+					})
+				);
+				assert_eq!(
+					ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
+					Some(RedemptionInfo {
+						foreign_currency: FOREIGN_CURR,
+						swapped_amount: 0,
+						collected_tranche_tokens: TRANCHE_AMOUNT / 2,
+						order_id: Some(ORDER_ID),
+						tranche_amount: TRANCHE_AMOUNT / 2,
+					})
+				);
+			});
+		}
+	}
 }
 
 mod notifications {
@@ -1773,4 +2066,157 @@ mod notifications {
 			));
 		});
 	}
+	#[test]
+	fn expired_swap_not_fail_if_not_found() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(ExpiredSwapHook::<Runtime>::notify_status_change(
+				ORDER_ID,
+				Swap {
+					amount_out: AMOUNT,
+					currency_out: FOREIGN_CURR,
+					currency_in: POOL_CURR,
+				},
+			));
+		});
+	}
+}
+
+mod split {
+	use super::*;
+	use crate::impls::split;
+
+	#[test]
+	fn payment_shares_never_exceed_pending_amounts() {
+		let (shares, unattributed) = split(
+			&[(1u8, 1u128), (2, 1), (3, 1)],
+			CollectedAmount::<u128, u128> {
+				amount_collected: 10,
+				amount_payment: 2,
+			},
+		)
+		.unwrap();
+
+		assert_eq!(
+			shares,
+			vec![
+				(
+					1,
+					CollectedAmount {
+						amount_collected: 3,
+						amount_payment: 1
+					}
+				),
+				(
+					2,
+					CollectedAmount {
+						amount_collected: 3,
+						amount_payment: 1
+					}
+				),
+				(
+					3,
+					CollectedAmount {
+						amount_collected: 4,
+						amount_payment: 0
+					}
+				),
+			]
+		);
+		assert_eq!(unattributed, 0);
+	}
+
+	#[test]
+	fn payment_above_pending_amounts_is_unattributed() {
+		let (shares, unattributed) = split(
+			&[(1u8, 1u128), (2, 2)],
+			CollectedAmount::<u128, u128> {
+				amount_collected: 0,
+				amount_payment: 5,
+			},
+		)
+		.unwrap();
+
+		assert_eq!(shares[0].1.amount_payment, 1);
+		assert_eq!(shares[1].1.amount_payment, 2);
+		assert_eq!(unattributed, 2);
+	}
+}
+
+mod migrations {
+	use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade};
+
+	use super::*;
+	use crate::migrations::{v2, v3};
+
+	const REDEMPTION_ORDER_ID: OrderId = ORDER_ID + 1;
+
+	#[test]
+	fn v3_keys_entries_by_foreign_currency() {
+		new_test_ext().execute_with(|| {
+			v2::ForeignInvestmentInfo::<Runtime>::insert(
+				USER,
+				INVESTMENT_ID,
+				v2::InvestmentInfo::<Runtime> {
+					foreign_currency: FOREIGN_CURR,
+					foreign_amount: AMOUNT,
+					decrease_swapped_foreign_amount: 0,
+					order_id: Some(ORDER_ID),
+				},
+			);
+			v2::ForeignRedemptionInfo::<Runtime>::insert(
+				USER,
+				INVESTMENT_ID,
+				v2::RedemptionInfo::<Runtime> {
+					foreign_currency: FOREIGN_CURR,
+					swapped_amount: 0,
+					collected_tranche_tokens: TRANCHE_AMOUNT,
+					order_id: Some(REDEMPTION_ORDER_ID),
+				},
+			);
+			unhashed::put(
+				&OrderIdToSwapId::<Runtime>::hashed_key_for(ORDER_ID),
+				&(USER, (INVESTMENT_ID, Action::Investment)),
+			);
+			unhashed::put(
+				&OrderIdToSwapId::<Runtime>::hashed_key_for(REDEMPTION_ORDER_ID),
+				&(USER, (INVESTMENT_ID, Action::Redemption)),
+			);
+
+			MockInvestment::mock_investment(|_, _| Ok(foreign_to_pool(AMOUNT)));
+			MockInvestment::mock_redemption(|_, _| Ok(TRANCHE_AMOUNT));
+
+			v3::Migration::<Runtime>::on_runtime_upgrade();
+
+			assert_eq!(
+				OrderIdToSwapId::<Runtime>::get(ORDER_ID),
+				Some((USER, (INVESTMENT_ID, FOREIGN_CURR, Action::Investment)))
+			);
+			assert_eq!(
+				OrderIdToSwapId::<Runtime>::get(REDEMPTION_ORDER_ID),
+				Some((USER, (INVESTMENT_ID, FOREIGN_CURR, Action::Redemption)))
+			);
+			assert_eq!(
+				ForeignInvestmentInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
+				Some(InvestmentInfo {
+					foreign_currency: FOREIGN_CURR,
+					foreign_amount: AMOUNT,
+					decrease_swapped_foreign_amount: 0,
+					order_id: Some(ORDER_ID),
+					pool_amount: foreign_to_pool(AMOUNT),
+				})
+			);
+			assert_eq!(
+				ForeignRedemptionInfo::<Runtime>::get((USER, INVESTMENT_ID, FOREIGN_CURR)),
+				Some(RedemptionInfo {
+					foreign_currency: FOREIGN_CURR,
+					swapped_amount: 0,
+					collected_tranche_tokens: TRANCHE_AMOUNT,
+					order_id: Some(REDEMPTION_ORDER_ID),
+					tranche_amount: TRANCHE_AMOUNT,
+				})
+			);
+			assert_eq!(ForeignInvestmentInfo::<Runtime>::iter_keys().count(), 1);
+			assert_eq!(ForeignRedemptionInfo::<Runtime>::iter_keys().count(), 1);
+		});
+	}
 }
@@ -13,7 +13,8 @@
 
 use cfg_traits::{
 	benchmarking::{FundedPoolBenchmarkHelper, InvestmentIdBenchmarkHelper},
-	investments::{Investment, InvestmentAccountant, OrderManager},
+	investments::{Investment, InvestmentAccountant, OrderManager, TrancheCurrency},
+	PreConditions,
 };
 use cfg_types::orders::FulfillmentWithPrice;
 use frame_benchmarking::{account, impl_benchmark_test_suite, v2::*, whitelisted_caller};
@@ -151,6 +152,22 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_auto_collect() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		crate::mock::configure_accountant_mock();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let pool_id = Helper::<T>::get_investment_id().of_pool();
+
+		T::IsPoolAdmin::satisfy((caller.clone(), pool_id));
+
+		#[extrinsic_call]
+		set_auto_collect(RawOrigin::Signed(caller), pool_id, true);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::TestExternalitiesBuilder::build(),
@@ -18,17 +18,23 @@
 
 use cfg_primitives::OrderId;
 use cfg_traits::{
-	investments::{Investment, InvestmentAccountant, InvestmentCollector, OrderManager},
+	investments::{
+		Investment, InvestmentAccountant, InvestmentCollector, OrderManager, TrancheCurrency,
+	},
 	PreConditions, StatusNotificationHook,
 };
 use cfg_types::{
 	fixed_point::FixedPointNumberExtension,
-	investments::{CollectedAmount, InvestCollection, InvestmentAccount, RedeemCollection},
+	investments::{
+		CollectedAmount, CostBasis, InvestCollection, InvestmentAccount, InvestmentPosition,
+		RedeemCollection,
+	},
 	orders::{FulfillmentWithPrice, Order, TotalOrder},
 };
 use frame_support::{
 	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
 	pallet_prelude::*,
+	storage::with_storage_layer,
 	traits::tokens::{
 		fungibles::{Inspect, Mutate},
 		Preservation,
@@ -37,7 +43,10 @@ use frame_support::{
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_runtime::{
-	traits::{AccountIdConversion, CheckedAdd, CheckedSub, EnsureAddAssign, EnsureSub, One, Zero},
+	traits::{
+		AccountIdConversion, CheckedAdd, CheckedSub, EnsureAddAssign, EnsureSub, EnsureSubAssign,
+		One, Zero,
+	},
 	ArithmeticError, FixedPointNumber,
 };
 use sp_std::{
@@ -46,6 +55,7 @@ use sp_std::{
 	vec::Vec,
 };
 
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -88,6 +98,18 @@ pub enum CollectOutcome {
 /// A newtype for Order
 pub type OrderOf<T> = Order<<T as Config>::Amount, OrderId>;
 
+/// The state of an automatic collect of all orders of an investment.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AutoCollect<AccountId> {
+	/// The last cleared order id the collect runs for
+	pub order_id: OrderId,
+	/// The last visited investor. The walk over the orders resumes after it.
+	pub cursor: Option<AccountId>,
+	/// Whether another order was cleared during the walk, so the investors
+	/// visited before it are visited again once the walk ends.
+	pub revisit: bool,
+}
+
 /// Defining how the collect logic runs.
 /// CollectType::Closing will ensure, that all unfulfilled investments
 /// are returned to the user account.
@@ -118,7 +140,21 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// The underlying investments one can invest into
-		type InvestmentId: Member + Parameter + Copy + MaxEncodedLen + Into<CurrencyOf<Self>>;
+		type InvestmentId: Member
+			+ Parameter
+			+ Copy
+			+ MaxEncodedLen
+			+ Into<CurrencyOf<Self>>
+			+ TrancheCurrency<Self::PoolId, Self::TrancheId>;
+
+		/// The pool identifier of an investment
+		type PoolId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// The tranche identifier of an investment
+		type TrancheId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Checks whether an account is the admin of a pool
+		type IsPoolAdmin: PreConditions<(Self::AccountId, Self::PoolId), Result = bool>;
 
 		/// Something that knows how to handle accounting for the given
 		/// investments and provides metadata about them
@@ -191,7 +227,7 @@ pub mod pallet {
 		type WeightInfo: weights::WeightInfo;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -269,6 +305,54 @@ pub mod pallet {
 		FulfillmentWithPrice<T::BalanceRatio>,
 	>;
 
+	/// The cost basis of the tranche tokens an account obtained by collecting
+	/// invest orders of an investment.
+	///
+	/// NOTE: It is not seeded for tranche tokens collected before it was
+	/// introduced or received by a transfer, as their cost is unknown. Those
+	/// holders start with an empty cost basis and their redemptions are not
+	/// accounted in it.
+	#[pallet::storage]
+	pub type InvestorCostBasis<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::InvestmentId,
+		CostBasis<T::Amount>,
+		ValueQuery,
+	>;
+
+	/// Accounts with an invest order per investment, to visit the orders of
+	/// an investment without iterating over all of them.
+	#[pallet::storage]
+	pub(crate) type InvestOrderAccounts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::InvestmentId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Accounts with a redeem order per investment, to visit the orders of an
+	/// investment without iterating over all of them.
+	#[pallet::storage]
+	pub(crate) type RedeemOrderAccounts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::InvestmentId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Pools whose orders are collected automatically after each
+	/// fulfillment.
+	#[pallet::storage]
+	pub type AutoCollectPools<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, bool, ValueQuery>;
+
+	/// Investments with fulfilled invest orders pending to be collected
+	/// automatically.
+	#[pallet::storage]
+	pub(crate) type AutoCollectInvestments<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::InvestmentId, AutoCollect<T::AccountId>>;
+
+	/// Investments with fulfilled redeem orders pending to be collected
+	/// automatically.
+	#[pallet::storage]
+	pub(crate) type AutoCollectRedemptions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::InvestmentId, AutoCollect<T::AccountId>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -356,6 +440,20 @@ pub mod pallet {
 			who: T::AccountId,
 			investment_id: T::InvestmentId,
 		},
+		/// The automatic collect of a pool was enabled or disabled
+		AutoCollectUpdated { pool_id: T::PoolId, enabled: bool },
+		/// All invest orders cleared up to the given order id were
+		/// automatically collected
+		InvestOrdersAutoCollected {
+			investment_id: T::InvestmentId,
+			order_id: OrderId,
+		},
+		/// All redeem orders cleared up to the given order id were
+		/// automatically collected
+		RedeemOrdersAutoCollected {
+			investment_id: T::InvestmentId,
+			order_id: OrderId,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -387,6 +485,18 @@ pub mod pallet {
 		NoActiveInvestOrder,
 		/// User has currently no redeem orders active and can not collect
 		NoActiveRedeemOrder,
+		/// Only the PoolAdmin can execute the given operation
+		NotPoolAdmin,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let weight = Self::auto_collect_investments(remaining_weight);
+			weight.saturating_add(Self::auto_collect_redemptions(
+				remaining_weight.saturating_sub(weight),
+			))
+		}
 	}
 
 	#[pallet::call]
@@ -434,7 +544,7 @@ pub mod pallet {
 		/// Collect the results of a user's invest orders for the given
 		/// investment. If any amounts are not fulfilled they are directly
 		/// appended to the next active order for this investment.
-		#[pallet::weight(T::WeightInfo::collect_investments(T::MaxOutstandingCollects::get()))]
+		#[pallet::weight(Pallet::<T>::collect_investments_weight())]
 		#[pallet::call_index(2)]
 		pub fn collect_investments(
 			origin: OriginFor<T>,
@@ -448,7 +558,7 @@ pub mod pallet {
 		/// Collect the results of a user's redeem orders for the given
 		/// investment. If any amounts are not fulfilled they are directly
 		/// appended to the next active order for this investment.
-		#[pallet::weight(T::WeightInfo::collect_redemptions(T::MaxOutstandingCollects::get()))]
+		#[pallet::weight(Pallet::<T>::collect_redemptions_weight())]
 		#[pallet::call_index(3)]
 		pub fn collect_redemptions(
 			origin: OriginFor<T>,
@@ -462,7 +572,7 @@ pub mod pallet {
 		/// Collect the results of another users invest orders for the given
 		/// investment. If any amounts are not fulfilled they are directly
 		/// appended to the next active order for this investment.
-		#[pallet::weight(T::WeightInfo::collect_investments(T::MaxOutstandingCollects::get()))]
+		#[pallet::weight(Pallet::<T>::collect_investments_weight())]
 		#[pallet::call_index(4)]
 		pub fn collect_investments_for(
 			origin: OriginFor<T>,
@@ -477,7 +587,7 @@ pub mod pallet {
 		/// Collect the results of another users redeem orders for the given
 		/// investment. If any amounts are not fulfilled they are directly
 		/// appended to the next active order for this investment.
-		#[pallet::weight(T::WeightInfo::collect_redemptions(T::MaxOutstandingCollects::get()))]
+		#[pallet::weight(Pallet::<T>::collect_redemptions_weight())]
 		#[pallet::call_index(5)]
 		pub fn collect_redemptions_for(
 			origin: OriginFor<T>,
@@ -488,10 +598,61 @@ pub mod pallet {
 
 			Self::do_collect_redeem(who, investment_id)
 		}
+
+		/// Enable or disable the automatic collect of all orders of a pool.
+		///
+		/// If enabled, the orders of every investor are collected in the
+		/// idle time of the following blocks after each fulfillment.
+		///
+		/// Origin must be the PoolAdmin of the pool.
+		#[pallet::weight(T::WeightInfo::set_auto_collect())]
+		#[pallet::call_index(6)]
+		pub fn set_auto_collect(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			enabled: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who, pool_id)),
+				Error::<T>::NotPoolAdmin
+			);
+
+			if enabled {
+				AutoCollectPools::<T>::insert(pool_id, true);
+			} else {
+				AutoCollectPools::<T>::remove(pool_id);
+			}
+
+			Self::deposit_event(Event::AutoCollectUpdated { pool_id, enabled });
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The weight of collecting the invest orders of an account, together
+	/// with the read and write of its cost basis.
+	///
+	/// TODO: Drop the cost basis access once the weights are regenerated from
+	/// the benchmarks, which already cover it.
+	pub(crate) fn collect_investments_weight() -> Weight {
+		T::WeightInfo::collect_investments(T::MaxOutstandingCollects::get())
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
+	/// The weight of collecting the redeem orders of an account, together
+	/// with the read and write of its cost basis.
+	///
+	/// TODO: Drop the cost basis access once the weights are regenerated from
+	/// the benchmarks, which already cover it.
+	pub(crate) fn collect_redemptions_weight() -> Weight {
+		T::WeightInfo::collect_redemptions(T::MaxOutstandingCollects::get())
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
 	pub(crate) fn do_update_investment(
 		who: T::AccountId,
 		investment_id: T::InvestmentId,
@@ -542,6 +703,11 @@ impl<T: Config> Pallet<T> {
 			},
 		)?;
 
+		match amount.is_zero() {
+			true => InvestOrderAccounts::<T>::remove(investment_id, &who),
+			false => InvestOrderAccounts::<T>::insert(investment_id, &who, ()),
+		}
+
 		Self::deposit_event(Event::InvestOrderUpdated {
 			investment_id,
 			submitted_at: cur_order_id,
@@ -600,6 +766,12 @@ impl<T: Config> Pallet<T> {
 				)
 			},
 		)?;
+
+		match amount.is_zero() {
+			true => RedeemOrderAccounts::<T>::remove(investment_id, &who),
+			false => RedeemOrderAccounts::<T>::insert(investment_id, &who, ()),
+		}
+
 		Self::deposit_event(Event::RedeemOrderUpdated {
 			investment_id,
 			submitted_at: cur_order_id,
@@ -712,6 +884,10 @@ impl<T: Config> Pallet<T> {
 					},
 				);
 
+				if maybe_order.is_none() {
+					InvestOrderAccounts::<T>::remove(investment_id, &who);
+				}
+
 				let collected_investment = CollectedAmount {
 					amount_collected: collection.payout_investment_invest,
 					amount_payment,
@@ -735,6 +911,8 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		if collected_investment != Default::default() {
+			Self::note_collected_investment(&who, investment_id, &collected_investment)?;
+
 			// Assumption: NOOP if investment is not foreign
 			T::CollectedInvestmentHook::notify_status_change(
 				(who, investment_id),
@@ -836,6 +1014,10 @@ impl<T: Config> Pallet<T> {
 					},
 				);
 
+				if maybe_order.is_none() {
+					RedeemOrderAccounts::<T>::remove(investment_id, &who);
+				}
+
 				let collected_redemption = CollectedAmount {
 					amount_collected: collection.payout_investment_redeem,
 					amount_payment,
@@ -859,6 +1041,8 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		if collected_redemption != Default::default() {
+			Self::note_collected_redemption(&who, investment_id, &collected_redemption)?;
+
 			// Assumption: NOOP if investment is not foreign
 			T::CollectedRedemptionHook::notify_status_change(
 				(who, investment_id),
@@ -869,6 +1053,149 @@ impl<T: Config> Pallet<T> {
 		Ok(post_dispatch_info)
 	}
 
+	/// Adds the tranche tokens and the paid pool currency of a collected
+	/// investment to the cost basis of the investor.
+	fn note_collected_investment(
+		who: &T::AccountId,
+		investment_id: T::InvestmentId,
+		collected: &CollectedAmount<T::Amount, T::Amount>,
+	) -> DispatchResult {
+		InvestorCostBasis::<T>::try_mutate(who, investment_id, |cost_basis| -> DispatchResult {
+			cost_basis
+				.tranche_tokens
+				.ensure_add_assign(collected.amount_collected)?;
+			cost_basis
+				.cost
+				.ensure_add_assign(collected.amount_payment)?;
+
+			Ok(())
+		})
+	}
+
+	/// Realizes the cost of the redeemed tranche tokens of a collected
+	/// redemption in the cost basis of the investor.
+	///
+	/// NOTE: Tranche tokens which were not obtained by collecting invest
+	/// orders, i.e. transferred ones, have no known cost and are not
+	/// accounted for.
+	fn note_collected_redemption(
+		who: &T::AccountId,
+		investment_id: T::InvestmentId,
+		collected: &CollectedAmount<T::Amount, T::Amount>,
+	) -> DispatchResult {
+		if !InvestorCostBasis::<T>::contains_key(who, investment_id) {
+			return Ok(());
+		}
+
+		InvestorCostBasis::<T>::try_mutate(who, investment_id, |cost_basis| -> DispatchResult {
+			let redeemed = min(collected.amount_payment, cost_basis.tranche_tokens);
+			let cost = Self::share_of(cost_basis.cost, redeemed, cost_basis.tranche_tokens)?;
+			let proceeds = Self::share_of(
+				collected.amount_collected,
+				redeemed,
+				collected.amount_payment,
+			)?;
+
+			cost_basis.tranche_tokens.ensure_sub_assign(redeemed)?;
+			cost_basis.cost.ensure_sub_assign(cost)?;
+			cost_basis.realized_cost.ensure_add_assign(cost)?;
+			cost_basis.realized_proceeds.ensure_add_assign(proceeds)?;
+
+			Ok(())
+		})
+	}
+
+	/// Returns the share `part / total` of the given amount, rounded down.
+	fn share_of(
+		amount: T::Amount,
+		part: T::Amount,
+		total: T::Amount,
+	) -> Result<T::Amount, DispatchError> {
+		if part.is_zero() {
+			return Ok(Zero::zero());
+		}
+
+		if part == total {
+			return Ok(amount);
+		}
+
+		T::BalanceRatio::checked_from_rational(part, total)
+			.and_then(|ratio| ratio.checked_mul_int(amount))
+			.ok_or(ArithmeticError::Overflow.into())
+	}
+
+	/// Returns the positions of an account in all investments it holds a cost
+	/// basis or an order for. The current value of the tranche tokens is
+	/// computed with the given price of an investment.
+	#[allow(clippy::type_complexity)]
+	pub fn investment_positions(
+		who: &T::AccountId,
+		price_of: impl Fn(T::InvestmentId) -> Option<T::BalanceRatio>,
+	) -> Vec<(
+		T::InvestmentId,
+		InvestmentPosition<T::Amount, T::BalanceRatio>,
+	)> {
+		let mut investment_ids = Vec::new();
+		for investment_id in InvestorCostBasis::<T>::iter_key_prefix(who)
+			.chain(InvestOrders::<T>::iter_key_prefix(who))
+			.chain(RedeemOrders::<T>::iter_key_prefix(who))
+		{
+			if !investment_ids.contains(&investment_id) {
+				investment_ids.push(investment_id);
+			}
+		}
+
+		investment_ids
+			.into_iter()
+			.map(|investment_id| {
+				let cost_basis = InvestorCostBasis::<T>::get(who, investment_id);
+				let price = price_of(investment_id);
+				let invest_order = InvestOrders::<T>::get(who, investment_id);
+				let redeem_order = RedeemOrders::<T>::get(who, investment_id);
+
+				let cleared_invest_orders = invest_order
+					.as_ref()
+					.map(|order| {
+						(order.submitted_at()..InvestOrderId::<T>::get(investment_id))
+							.filter_map(|order_id| {
+								ClearedInvestOrders::<T>::get(investment_id, order_id)
+									.map(|fulfillment| (order_id, fulfillment))
+							})
+							.collect()
+					})
+					.unwrap_or_default();
+
+				let cleared_redeem_orders = redeem_order
+					.as_ref()
+					.map(|order| {
+						(order.submitted_at()..RedeemOrderId::<T>::get(investment_id))
+							.filter_map(|order_id| {
+								ClearedRedeemOrders::<T>::get(investment_id, order_id)
+									.map(|fulfillment| (order_id, fulfillment))
+							})
+							.collect()
+					})
+					.unwrap_or_default();
+
+				let position = InvestmentPosition {
+					average_price: T::BalanceRatio::checked_from_rational(
+						cost_basis.cost,
+						cost_basis.tranche_tokens,
+					),
+					value: price.and_then(|price| price.checked_mul_int(cost_basis.tranche_tokens)),
+					price,
+					cost_basis,
+					invest_order,
+					redeem_order,
+					cleared_invest_orders,
+					cleared_redeem_orders,
+				};
+
+				(investment_id, position)
+			})
+			.collect()
+	}
+
 	pub(crate) fn do_update_invest_order(
 		total_order: &mut TotalOrder<T::Amount>,
 		who: &T::AccountId,
@@ -952,6 +1279,183 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Collects the invest orders of the investments pending to be collected
+	/// automatically, bounded by the given weight.
+	///
+	/// The walk over the orders is resumed in the next call if the weight is
+	/// exhausted before all investors were visited.
+	pub(crate) fn auto_collect_investments(max_weight: Weight) -> Weight {
+		let read_weight = T::DbWeight::get().reads(1);
+		let write_weight = T::DbWeight::get().writes(1);
+		let collect_weight = Self::collect_investments_weight();
+		let mut weight = Weight::zero();
+
+		loop {
+			if weight.saturating_add(read_weight).any_gt(max_weight) {
+				return weight;
+			}
+
+			weight.saturating_accrue(read_weight);
+
+			let Some((investment_id, mut auto_collect)) =
+				AutoCollectInvestments::<T>::iter().next()
+			else {
+				return weight;
+			};
+
+			let mut accounts = match &auto_collect.cursor {
+				Some(who) => InvestOrderAccounts::<T>::iter_key_prefix_from(
+					investment_id,
+					InvestOrderAccounts::<T>::hashed_key_for(investment_id, who),
+				),
+				None => InvestOrderAccounts::<T>::iter_key_prefix(investment_id),
+			};
+
+			loop {
+				let step_weight = read_weight
+					.saturating_mul(2)
+					.saturating_add(write_weight)
+					.saturating_add(collect_weight);
+
+				if weight.saturating_add(step_weight).any_gt(max_weight) {
+					AutoCollectInvestments::<T>::insert(investment_id, auto_collect);
+					return weight.saturating_add(write_weight);
+				}
+
+				weight.saturating_accrue(read_weight);
+
+				let Some(who) = accounts.next() else {
+					break;
+				};
+
+				auto_collect.cursor = Some(who.clone());
+
+				weight.saturating_accrue(read_weight);
+
+				let pending = InvestOrders::<T>::get(&who, investment_id)
+					.map_or(false, |order| order.submitted_at() <= auto_collect.order_id);
+
+				if pending {
+					weight.saturating_accrue(collect_weight);
+
+					// NOTE: A failing collect, i.e. of a frozen investor, must not
+					// block the collect of the remaining investors. They can still
+					// collect manually.
+					let _ = with_storage_layer(|| Self::do_collect_invest(who, investment_id));
+				}
+			}
+
+			weight.saturating_accrue(write_weight);
+
+			if auto_collect.revisit {
+				AutoCollectInvestments::<T>::insert(
+					investment_id,
+					AutoCollect {
+						order_id: auto_collect.order_id,
+						cursor: None,
+						revisit: false,
+					},
+				);
+				continue;
+			}
+
+			AutoCollectInvestments::<T>::remove(investment_id);
+
+			Self::deposit_event(Event::InvestOrdersAutoCollected {
+				investment_id,
+				order_id: auto_collect.order_id,
+			});
+		}
+	}
+
+	/// Collects the redeem orders of the investments pending to be collected
+	/// automatically, bounded by the given weight.
+	///
+	/// The walk over the orders is resumed in the next call if the weight is
+	/// exhausted before all investors were visited.
+	pub(crate) fn auto_collect_redemptions(max_weight: Weight) -> Weight {
+		let read_weight = T::DbWeight::get().reads(1);
+		let write_weight = T::DbWeight::get().writes(1);
+		let collect_weight = Self::collect_redemptions_weight();
+		let mut weight = Weight::zero();
+
+		loop {
+			if weight.saturating_add(read_weight).any_gt(max_weight) {
+				return weight;
+			}
+
+			weight.saturating_accrue(read_weight);
+
+			let Some((investment_id, mut auto_collect)) =
+				AutoCollectRedemptions::<T>::iter().next()
+			else {
+				return weight;
+			};
+
+			let mut accounts = match &auto_collect.cursor {
+				Some(who) => RedeemOrderAccounts::<T>::iter_key_prefix_from(
+					investment_id,
+					RedeemOrderAccounts::<T>::hashed_key_for(investment_id, who),
+				),
+				None => RedeemOrderAccounts::<T>::iter_key_prefix(investment_id),
+			};
+
+			loop {
+				let step_weight = read_weight
+					.saturating_mul(2)
+					.saturating_add(write_weight)
+					.saturating_add(collect_weight);
+
+				if weight.saturating_add(step_weight).any_gt(max_weight) {
+					AutoCollectRedemptions::<T>::insert(investment_id, auto_collect);
+					return weight.saturating_add(write_weight);
+				}
+
+				weight.saturating_accrue(read_weight);
+
+				let Some(who) = accounts.next() else {
+					break;
+				};
+
+				auto_collect.cursor = Some(who.clone());
+
+				weight.saturating_accrue(read_weight);
+
+				let pending = RedeemOrders::<T>::get(&who, investment_id)
+					.map_or(false, |order| order.submitted_at() <= auto_collect.order_id);
+
+				if pending {
+					weight.saturating_accrue(collect_weight);
+
+					// NOTE: A failing collect must not block the collect of the
+					// remaining investors. They can still collect manually.
+					let _ = with_storage_layer(|| Self::do_collect_redeem(who, investment_id));
+				}
+			}
+
+			weight.saturating_accrue(write_weight);
+
+			if auto_collect.revisit {
+				AutoCollectRedemptions::<T>::insert(
+					investment_id,
+					AutoCollect {
+						order_id: auto_collect.order_id,
+						cursor: None,
+						revisit: false,
+					},
+				);
+				continue;
+			}
+
+			AutoCollectRedemptions::<T>::remove(investment_id);
+
+			Self::deposit_event(Event::RedeemOrdersAutoCollected {
+				investment_id,
+				order_id: auto_collect.order_id,
+			});
+		}
+	}
+
 	/// Increments an accounts' investment payout amount based on the remaining
 	/// amount and the fulfillment price.
 	///
@@ -1284,6 +1788,24 @@ impl<T: Config> OrderManager for Pallet<T> {
 
 				ClearedInvestOrders::<T>::insert(investment_id, order_id, fulfillment);
 
+				// A pending walk continues from its cursor, not to starve the investors
+				// after it, and visits again the ones before it once it ends.
+				if AutoCollectPools::<T>::get(investment_id.of_pool()) {
+					AutoCollectInvestments::<T>::mutate(investment_id, |entry| match entry {
+						Some(auto_collect) => {
+							auto_collect.order_id = order_id;
+							auto_collect.revisit |= auto_collect.cursor.is_some();
+						}
+						None => {
+							*entry = Some(AutoCollect {
+								order_id,
+								cursor: None,
+								revisit: false,
+							})
+						}
+					});
+				}
+
 				// Append the outstanding, i.e. unfulfilled orders to the current active order
 				// amount.
 				ActiveInvestOrders::<T>::try_mutate(
@@ -1366,6 +1888,24 @@ impl<T: Config> OrderManager for Pallet<T> {
 
 				ClearedRedeemOrders::<T>::insert(investment_id, order_id, fulfillment);
 
+				// A pending walk continues from its cursor, not to starve the investors
+				// after it, and visits again the ones before it once it ends.
+				if AutoCollectPools::<T>::get(investment_id.of_pool()) {
+					AutoCollectRedemptions::<T>::mutate(investment_id, |entry| match entry {
+						Some(auto_collect) => {
+							auto_collect.order_id = order_id;
+							auto_collect.revisit |= auto_collect.cursor.is_some();
+						}
+						None => {
+							*entry = Some(AutoCollect {
+								order_id,
+								cursor: None,
+								revisit: false,
+							})
+						}
+					});
+				}
+
 				// Append the outstanding, i.e. unfulfilled orders to the current active order
 				// amount.
 				ActiveRedeemOrders::<T>::try_mutate(
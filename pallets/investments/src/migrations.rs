@@ -0,0 +1,45 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+pub mod v2 {
+	use frame_support::{
+		traits::{Get, OnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use sp_std::marker::PhantomData;
+
+	use crate::pallet::{
+		Config, InvestOrderAccounts, InvestOrders, RedeemOrderAccounts, RedeemOrders,
+	};
+
+	/// Indexes the accounts of the existing invest and redeem orders by
+	/// investment, so they can be collected automatically.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+
+			for (who, investment_id) in InvestOrders::<T>::iter_keys() {
+				count += 1;
+				InvestOrderAccounts::<T>::insert(investment_id, who, ());
+			}
+
+			for (who, investment_id) in RedeemOrders::<T>::iter_keys() {
+				count += 1;
+				RedeemOrderAccounts::<T>::insert(investment_id, who, ());
+			}
+
+			T::DbWeight::get().reads_writes(count, count)
+		}
+	}
+}
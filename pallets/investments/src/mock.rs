@@ -125,6 +125,7 @@ impl cfg_traits::StatusNotificationHook for NoopCollectHook {
 
 parameter_types! {
 	pub const MaxOutstandingCollect: u32 = 10;
+	pub static PoolAdmin: AccountId = Owner::get();
 }
 
 impl pallet_investments::Config for Runtime {
@@ -134,10 +135,13 @@ impl pallet_investments::Config for Runtime {
 	type CollectedInvestmentHook = NoopCollectHook;
 	type CollectedRedemptionHook = NoopCollectHook;
 	type InvestmentId = InvestmentId;
+	type IsPoolAdmin = IsPoolAdmin;
 	type MaxOutstandingCollects = MaxOutstandingCollect;
+	type PoolId = PoolId;
 	type PreConditions = AlwaysWithOneException;
 	type RuntimeEvent = RuntimeEvent;
 	type Tokens = OrmlTokens;
+	type TrancheId = TrancheId;
 	type WeightInfo = ();
 }
 
@@ -163,6 +167,20 @@ where
 	}
 }
 
+pub struct IsPoolAdmin;
+impl PreConditions<(AccountId, PoolId)> for IsPoolAdmin {
+	type Result = bool;
+
+	fn check((who, _): (AccountId, PoolId)) -> bool {
+		who == PoolAdmin::get()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn satisfy((who, _): (AccountId, PoolId)) {
+		PoolAdmin::set(who);
+	}
+}
+
 // Test externalities builder
 //
 // This type is mainly used for mocking storage in tests. It is the type alias
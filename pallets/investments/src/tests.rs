@@ -2976,3 +2976,281 @@ fn collecting_redemption_without_preconditions_success() {
 		),);
 	})
 }
+
+#[test]
+fn set_auto_collect_requires_pool_admin() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		assert_noop!(
+			Investments::set_auto_collect(RuntimeOrigin::signed(InvestorA::get()), POOL_ID, true),
+			Error::<Runtime>::NotPoolAdmin
+		);
+
+		assert_ok!(Investments::set_auto_collect(
+			RuntimeOrigin::signed(Owner::get()),
+			POOL_ID,
+			true
+		));
+		assert!(AutoCollectPools::<Runtime>::get(POOL_ID));
+		assert_eq!(
+			last_event(),
+			Event::AutoCollectUpdated {
+				pool_id: POOL_ID,
+				enabled: true
+			}
+			.into()
+		);
+
+		assert_ok!(Investments::set_auto_collect(
+			RuntimeOrigin::signed(Owner::get()),
+			POOL_ID,
+			false
+		));
+		assert!(!AutoCollectPools::<Runtime>::contains_key(POOL_ID));
+	})
+}
+
+#[test]
+fn auto_collect_works() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		let fulfillment = fulfillment_of(Perquintill::one(), price_of(1, 0, 1));
+
+		assert_ok!(Investments::set_auto_collect(
+			RuntimeOrigin::signed(Owner::get()),
+			POOL_ID,
+			true
+		));
+		assert_ok!(invest_x_fulfill_x(50 * CURRENCY, fulfillment));
+		assert_ok!(redeem_x_fulfill_x(50 * CURRENCY, fulfillment));
+
+		assert_eq!(
+			AutoCollectInvestments::<Runtime>::get(INVESTMENT_0_0),
+			Some(AutoCollect {
+				order_id: 0,
+				cursor: None,
+				revisit: false,
+			})
+		);
+		assert_eq!(
+			AutoCollectRedemptions::<Runtime>::get(INVESTMENT_0_0),
+			Some(AutoCollect {
+				order_id: 0,
+				cursor: None,
+				revisit: false,
+			})
+		);
+
+		assert_eq!(
+			InvestOrderAccounts::<Runtime>::iter_key_prefix(INVESTMENT_0_0).count(),
+			3
+		);
+
+		Investments::on_idle(1, Weight::MAX);
+
+		assert_eq!(
+			InvestOrderAccounts::<Runtime>::iter_key_prefix(INVESTMENT_0_0).count(),
+			0
+		);
+		assert_eq!(
+			RedeemOrderAccounts::<Runtime>::iter_key_prefix(INVESTMENT_0_0).count(),
+			0
+		);
+
+		for investor in [InvestorA::get(), InvestorB::get(), InvestorC::get()] {
+			assert_eq!(InvestOrders::<Runtime>::get(investor, INVESTMENT_0_0), None);
+			assert_eq!(
+				free_balance_of(investor, INVESTMENT_0_0.into()),
+				50 * CURRENCY
+			);
+		}
+		for holder in [
+			TrancheHolderA::get(),
+			TrancheHolderB::get(),
+			TrancheHolderC::get(),
+		] {
+			assert_eq!(RedeemOrders::<Runtime>::get(holder, INVESTMENT_0_0), None);
+			assert_eq!(free_balance_of(holder, AUSD_CURRENCY_ID), 50 * CURRENCY);
+		}
+
+		assert_eq!(AutoCollectInvestments::<Runtime>::get(INVESTMENT_0_0), None);
+		assert_eq!(AutoCollectRedemptions::<Runtime>::get(INVESTMENT_0_0), None);
+
+		let events = frame_system::Pallet::<Runtime>::events();
+		assert!(events.iter().any(|record| record.event
+			== Event::InvestOrdersAutoCollected {
+				investment_id: INVESTMENT_0_0,
+				order_id: 0
+			}
+			.into()));
+		assert_eq!(
+			last_event(),
+			Event::RedeemOrdersAutoCollected {
+				investment_id: INVESTMENT_0_0,
+				order_id: 0
+			}
+			.into()
+		);
+	})
+}
+
+#[test]
+fn auto_collect_revisits_investors_before_cursor() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		let fulfillment = fulfillment_of(Perquintill::one(), price_of(1, 0, 1));
+
+		assert_ok!(Investments::set_auto_collect(
+			RuntimeOrigin::signed(Owner::get()),
+			POOL_ID,
+			true
+		));
+		assert_ok!(invest_x_fulfill_x(50 * CURRENCY, fulfillment));
+
+		// A walk that already visited every investor before another order was
+		// cleared
+		let last = InvestOrderAccounts::<Runtime>::iter_key_prefix(INVESTMENT_0_0).last();
+		AutoCollectInvestments::<Runtime>::insert(
+			INVESTMENT_0_0,
+			AutoCollect {
+				order_id: 0,
+				cursor: last,
+				revisit: true,
+			},
+		);
+
+		Investments::on_idle(1, Weight::MAX);
+
+		for investor in [InvestorA::get(), InvestorB::get(), InvestorC::get()] {
+			assert_eq!(InvestOrders::<Runtime>::get(investor, INVESTMENT_0_0), None);
+			assert_eq!(
+				free_balance_of(investor, INVESTMENT_0_0.into()),
+				50 * CURRENCY
+			);
+		}
+		assert_eq!(AutoCollectInvestments::<Runtime>::get(INVESTMENT_0_0), None);
+	})
+}
+
+#[test]
+fn auto_collect_disabled_keeps_orders() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		let fulfillment = fulfillment_of(Perquintill::one(), price_of(1, 0, 1));
+
+		assert_ok!(invest_x_fulfill_x(50 * CURRENCY, fulfillment));
+		assert_eq!(AutoCollectInvestments::<Runtime>::get(INVESTMENT_0_0), None);
+
+		Investments::on_idle(1, Weight::MAX);
+
+		assert!(InvestOrders::<Runtime>::get(InvestorA::get(), INVESTMENT_0_0).is_some());
+		assert_eq!(free_balance_of(InvestorA::get(), INVESTMENT_0_0.into()), 0);
+	})
+}
+
+#[test]
+fn cost_basis_works() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		let investor = InvestorA::get();
+
+		assert_ok!(invest_x_per_fulfill_x(
+			vec![(investor, 50 * CURRENCY)],
+			fulfillment_of(Perquintill::one(), price_of(1, 0, 1))
+		));
+		assert_ok!(Investments::collect_investments(
+			RuntimeOrigin::signed(investor),
+			INVESTMENT_0_0
+		));
+
+		assert_eq!(
+			InvestorCostBasis::<Runtime>::get(investor, INVESTMENT_0_0),
+			CostBasis {
+				tranche_tokens: 50 * CURRENCY,
+				cost: 50 * CURRENCY,
+				realized_cost: 0,
+				realized_proceeds: 0,
+			}
+		);
+
+		assert_ok!(redeem_x_per_fulfill_x(
+			vec![(investor, 20 * CURRENCY)],
+			fulfillment_of(Perquintill::one(), price_of(2, 0, 1))
+		));
+		assert_ok!(Investments::collect_redemptions(
+			RuntimeOrigin::signed(investor),
+			INVESTMENT_0_0
+		));
+
+		assert_eq!(
+			InvestorCostBasis::<Runtime>::get(investor, INVESTMENT_0_0),
+			CostBasis {
+				tranche_tokens: 30 * CURRENCY,
+				cost: 30 * CURRENCY,
+				realized_cost: 20 * CURRENCY,
+				realized_proceeds: 40 * CURRENCY,
+			}
+		);
+	})
+}
+
+#[test]
+fn cost_basis_ignores_transferred_tranche_tokens() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		let holder = TrancheHolderA::get();
+
+		assert_ok!(redeem_x_per_fulfill_x(
+			vec![(holder, 20 * CURRENCY)],
+			fulfillment_of(Perquintill::one(), price_of(2, 0, 1))
+		));
+		assert_ok!(Investments::collect_redemptions(
+			RuntimeOrigin::signed(holder),
+			INVESTMENT_0_0
+		));
+
+		assert!(!InvestorCostBasis::<Runtime>::contains_key(
+			holder,
+			INVESTMENT_0_0
+		));
+	})
+}
+
+#[test]
+fn investment_positions_works() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		let investor = InvestorA::get();
+		let fulfillment = fulfillment_of(Perquintill::one(), price_of(1, 0, 1));
+
+		assert_ok!(invest_x_per_fulfill_x(
+			vec![(investor, 50 * CURRENCY)],
+			fulfillment
+		));
+		assert_ok!(Investments::collect_investments(
+			RuntimeOrigin::signed(investor),
+			INVESTMENT_0_0
+		));
+		assert_ok!(invest_x_per_fulfill_x(
+			vec![(investor, 10 * CURRENCY)],
+			fulfillment
+		));
+
+		let positions = Investments::investment_positions(&investor, |_| Some(price_of(2, 0, 1)));
+
+		assert_eq!(
+			positions,
+			vec![(
+				INVESTMENT_0_0,
+				InvestmentPosition {
+					cost_basis: CostBasis {
+						tranche_tokens: 50 * CURRENCY,
+						cost: 50 * CURRENCY,
+						realized_cost: 0,
+						realized_proceeds: 0,
+					},
+					average_price: Some(price_of(1, 0, 1)),
+					price: Some(price_of(2, 0, 1)),
+					value: Some(100 * CURRENCY),
+					invest_order: InvestOrders::<Runtime>::get(investor, INVESTMENT_0_0),
+					redeem_order: None,
+					cleared_invest_orders: vec![(1, fulfillment)],
+					cleared_redeem_orders: vec![],
+				}
+			)]
+		);
+	})
+}
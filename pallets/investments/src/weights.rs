@@ -11,13 +11,24 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use frame_support::weights::Weight;
+use frame_support::weights::{constants::RocksDbWeight, Weight};
 
 pub trait WeightInfo {
 	fn update_invest_order() -> Weight;
 	fn update_redeem_order() -> Weight;
 	fn collect_investments(n: u32) -> Weight;
 	fn collect_redemptions(n: u32) -> Weight;
+
+	fn set_auto_collect() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one reads the permissions of the caller and writes the
+		//       pool flag.
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
 
 impl WeightInfo for () {
@@ -36,4 +47,8 @@ impl WeightInfo for () {
 	fn collect_redemptions(_: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn set_auto_collect() -> Weight {
+		Weight::zero()
+	}
 }
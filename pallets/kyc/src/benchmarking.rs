@@ -0,0 +1,197 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge Chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_traits::{PreConditions, TimeAsSecs};
+use cfg_types::domain_address::DomainAddress;
+use frame_benchmarking::v2::*;
+use frame_support::{dispatch::RawOrigin, pallet_prelude::*, traits::EnsureOrigin};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner};
+
+use super::*;
+use crate::Pallet as Kyc;
+
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"kycb");
+const JURISDICTION: Jurisdiction = *b"CH";
+const VALIDITY: u64 = 365 * 24 * 60 * 60;
+
+fn worst_case_rules<T: Config>() -> KycRulesOf<T> {
+	KycRules {
+		allowed_jurisdictions: sp_std::vec![JURISDICTION; T::MaxJurisdictions::get() as usize]
+			.try_into()
+			.expect("Rules have exactly the max number of jurisdictions"),
+		accreditation_required: true,
+	}
+}
+
+fn worst_case_transfer_rules<T: Config>() -> TransferRulesOf<T> {
+	TransferRules {
+		max_holders: Some(u32::MAX),
+		min_holding: Some(1u32.into()),
+		holding_period: Some(VALIDITY),
+		blocked_jurisdictions: sp_std::vec![JURISDICTION; T::MaxJurisdictions::get() as usize]
+			.try_into()
+			.expect("Rules have exactly the max number of jurisdictions"),
+		accreditation_required: true,
+	}
+}
+
+fn pool_admin<T: Config>(pool_id: T::PoolId) -> T::AccountId {
+	let admin: T::AccountId = whitelisted_caller();
+	T::IsPoolAdmin::satisfy((admin.clone(), pool_id));
+	admin
+}
+
+#[benchmarks(
+	where
+		T::PoolId: Default,
+		T::TrancheId: Default,
+		T::Signature: From<MultiSignature>,
+)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn add_issuer() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issuer = account::<T::AccountId>("issuer", 0, 0);
+
+		#[extrinsic_call]
+		add_issuer(origin as T::RuntimeOrigin, issuer.clone());
+
+		assert!(Issuers::<T>::contains_key(issuer));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_issuer() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issuer = account::<T::AccountId>("issuer", 0, 0);
+		Issuers::<T>::insert(&issuer, ());
+
+		#[extrinsic_call]
+		remove_issuer(origin as T::RuntimeOrigin, issuer.clone());
+
+		assert!(!Issuers::<T>::contains_key(issuer));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_rules() -> Result<(), BenchmarkError> {
+		let pool_id = T::PoolId::default();
+		let admin = pool_admin::<T>(pool_id);
+
+		#[extrinsic_call]
+		set_rules(RawOrigin::Signed(admin), pool_id, worst_case_rules::<T>());
+
+		assert!(Rules::<T>::contains_key(pool_id));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_rules() -> Result<(), BenchmarkError> {
+		let pool_id = T::PoolId::default();
+		let admin = pool_admin::<T>(pool_id);
+		Rules::<T>::insert(pool_id, worst_case_rules::<T>());
+
+		#[extrinsic_call]
+		remove_rules(RawOrigin::Signed(admin), pool_id);
+
+		assert!(!Rules::<T>::contains_key(pool_id));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_claim() -> Result<(), BenchmarkError> {
+		let pool_id = T::PoolId::default();
+		let tranche_id = T::TrancheId::default();
+		Rules::<T>::insert(pool_id, worst_case_rules::<T>());
+
+		let public = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+		let issuer = MultiSigner::from(public).into_account();
+		Issuers::<T>::insert(&issuer, ());
+
+		let investor = account::<T::AccountId>("investor", 0, 0);
+		let claim = KycClaim {
+			subject: DomainAddress::Centrifuge(investor.clone()),
+			issuer,
+			jurisdiction: JURISDICTION,
+			accredited: true,
+			valid_till: T::Time::now().saturating_add(VALIDITY),
+			nonce: 0,
+			deadline: T::Time::now().saturating_add(VALIDITY),
+		};
+		let signature = sp_io::crypto::sr25519_sign(
+			KEY_TYPE,
+			&public,
+			&Kyc::<T>::claim_message(pool_id, tranche_id, &claim),
+		)
+		.ok_or(BenchmarkError::Stop("Claim can not be signed"))?;
+
+		#[extrinsic_call]
+		submit_claim(
+			RawOrigin::Signed(whitelisted_caller()),
+			pool_id,
+			tranche_id,
+			claim,
+			MultiSignature::from(signature).into(),
+		);
+
+		assert_eq!(ClaimNonces::<T>::get(&investor), 1);
+		assert!(Attestations::<T>::contains_key(investor));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_transfer_rules() -> Result<(), BenchmarkError> {
+		let pool_id = T::PoolId::default();
+		let tranche_id = T::TrancheId::default();
+		let admin = pool_admin::<T>(pool_id);
+
+		#[extrinsic_call]
+		set_transfer_rules(
+			RawOrigin::Signed(admin),
+			pool_id,
+			tranche_id,
+			worst_case_transfer_rules::<T>(),
+		);
+
+		assert!(TrancheTransferRules::<T>::contains_key(pool_id, tranche_id));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_transfer_rules() -> Result<(), BenchmarkError> {
+		let pool_id = T::PoolId::default();
+		let tranche_id = T::TrancheId::default();
+		let admin = pool_admin::<T>(pool_id);
+		TrancheTransferRules::<T>::insert(pool_id, tranche_id, worst_case_transfer_rules::<T>());
+
+		#[extrinsic_call]
+		remove_transfer_rules(RawOrigin::Signed(admin), pool_id, tranche_id);
+
+		assert!(!TrancheTransferRules::<T>::contains_key(
+			pool_id, tranche_id
+		));
+
+		Ok(())
+	}
+}
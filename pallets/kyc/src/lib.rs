@@ -0,0 +1,693 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! # KYC Pallet
+//!
+//! Grants the `TrancheInvestor` role based on KYC claims. A claim covers the
+//! jurisdiction and accreditation of an account or a remote
+//! [`DomainAddress`] and is valid until a given time. Claims are signed
+//! off-chain by registered issuers and can be submitted by the investor
+//! themselves or by any relayer.
+//!
+//! Pool admins set the rules a claim must satisfy for their pool. An accepted
+//! claim grants or renews the `TrancheInvestor` role of the subject until the
+//! claim expires. Remote investors are announced to their domain at the
+//! cost of the submitter.
+//!
+//! Pool admins can additionally set compliance rules per tranche, which are
+//! checked for every transfer of tranche tokens between investors. Rules
+//! cover the maximum number of holders, a minimum holding amount, a holding
+//! period after acquiring tokens, blocked jurisdictions and accreditation of
+//! the receiver. The runtime checks every transfer of tranche tokens against
+//! these rules through [`Pallet::check_transfer`] and keeps the holders up to
+//! date through the `note_*` methods. System accounts, such as the pool,
+//! investment and domain accounts, are neither checked nor counted as holders.
+//!
+//! ## Assumptions
+//!
+//! - Roles granted by a claim are not revoked when the issuer is removed.
+//!   Investor admins remove them manually if required.
+//! - The holding period starts with the first acquisition of an account and
+//!   restarts only after the account stopped holding tokens of the tranche.
+//! - Holders must be seeded by the runtime when the rules are introduced.
+//!   Acquisitions are only tracked from the moment the runtime notes them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use cfg_traits::{
+		liquidity_pools::MemberUpdater, Permissions, PreConditions, Seconds, TimeAsSecs,
+	};
+	use cfg_types::{
+		domain_address::DomainAddress,
+		permissions::{PermissionScope, PoolRole, Role},
+	};
+	use frame_support::{pallet_prelude::*, traits::Contains};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AtLeast32BitUnsigned, EnsureAdd, Saturating, Verify, Zero},
+		AccountId32, MultiSigner,
+	};
+	use sp_std::vec::Vec;
+
+	use super::*;
+
+	/// The prefix of the signed message of a claim, preventing signatures
+	/// from being reused for other messages.
+	pub const CLAIM_PREFIX: &[u8] = b"cfg-kyc-claim:";
+
+	/// ISO 3166-1 alpha-2 country code.
+	pub type Jurisdiction = [u8; 2];
+
+	/// A KYC claim over an investor, signed by an issuer.
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub struct KycClaim<AccountId> {
+		/// The investor the claim is about.
+		pub subject: DomainAddress,
+		/// The issuer signing the claim.
+		pub issuer: AccountId,
+		pub jurisdiction: Jurisdiction,
+		pub accredited: bool,
+		/// The time until which the claim is valid.
+		pub valid_till: Seconds,
+		/// The claim nonce of the subject, preventing the claim from being
+		/// submitted more than once.
+		pub nonce: u64,
+		/// The time until which the claim can be submitted.
+		pub deadline: Seconds,
+	}
+
+	/// The rules a claim must satisfy to grant roles in a pool.
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(MaxJurisdictions))]
+	pub struct KycRules<MaxJurisdictions: Get<u32>> {
+		/// The jurisdictions allowed to invest. Empty if any jurisdiction is
+		/// allowed.
+		pub allowed_jurisdictions: BoundedVec<Jurisdiction, MaxJurisdictions>,
+		pub accreditation_required: bool,
+	}
+
+	/// The last accepted claim of an investor.
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub struct Attestation<AccountId> {
+		pub issuer: AccountId,
+		pub jurisdiction: Jurisdiction,
+		pub accredited: bool,
+		pub valid_till: Seconds,
+	}
+
+	/// The compliance rules for transfers of the tokens of a tranche.
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(MaxJurisdictions))]
+	pub struct TransferRules<Balance, MaxJurisdictions: Get<u32>> {
+		/// The maximum number of accounts holding the tokens.
+		pub max_holders: Option<u32>,
+		/// The minimum amount an account must hold, unless it holds nothing.
+		pub min_holding: Option<Balance>,
+		/// The time tokens must be held after acquiring them before they can
+		/// be transferred.
+		pub holding_period: Option<Seconds>,
+		/// The jurisdictions receivers must not be attested in.
+		pub blocked_jurisdictions: BoundedVec<Jurisdiction, MaxJurisdictions>,
+		/// Whether receivers must be attested as accredited.
+		pub accreditation_required: bool,
+	}
+
+	impl<Balance, MaxJurisdictions: Get<u32>> TransferRules<Balance, MaxJurisdictions> {
+		fn requires_attestation(&self) -> bool {
+			!self.blocked_jurisdictions.is_empty() || self.accreditation_required
+		}
+	}
+
+	pub type KycRulesOf<T> = KycRules<<T as Config>::MaxJurisdictions>;
+
+	pub type TransferRulesOf<T> =
+		TransferRules<<T as Config>::Balance, <T as Config>::MaxJurisdictions>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config<AccountId = AccountId32> {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The signature of a claim by an issuer
+		type Signature: Verify<Signer = MultiSigner> + Member + Parameter;
+
+		type PoolId: Member + Parameter + MaxEncodedLen + Copy;
+
+		type TrancheId: Member + Parameter + MaxEncodedLen + Copy;
+
+		type CurrencyId: Member + Parameter + MaxEncodedLen + Copy;
+
+		type Balance: Member + Parameter + MaxEncodedLen + AtLeast32BitUnsigned + Copy;
+
+		/// Used to grant the tranche investor roles
+		type Permissions: Permissions<
+			Self::AccountId,
+			Scope = PermissionScope<Self::PoolId, Self::CurrencyId>,
+			Role = Role<Self::TrancheId>,
+			Error = DispatchError,
+		>;
+
+		/// Used to announce remote investors to their domain
+		type MemberUpdater: MemberUpdater<
+			Self::AccountId,
+			PoolId = Self::PoolId,
+			TrancheId = Self::TrancheId,
+			Member = DomainAddress,
+		>;
+
+		/// Used to verify pool admin permissions
+		type IsPoolAdmin: PreConditions<(Self::AccountId, Self::PoolId), Result = bool>;
+
+		/// The system accounts holding tranche tokens, which are neither
+		/// checked against the transfer rules nor counted as holders
+		type SystemAccounts: Contains<Self::AccountId>;
+
+		/// The origin allowed to register and remove issuers
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Fetching method for the time of the current block
+		type Time: TimeAsSecs;
+
+		/// The maximum number of allowed jurisdictions per pool
+		#[pallet::constant]
+		type MaxJurisdictions: Get<u32>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The issuers whose claims are accepted.
+	#[pallet::storage]
+	pub type Issuers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// The rules of a pool. Claims can only be submitted for pools with rules.
+	#[pallet::storage]
+	pub type Rules<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, KycRulesOf<T>>;
+
+	/// The last accepted claim per investor account.
+	#[pallet::storage]
+	pub type Attestations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Attestation<T::AccountId>>;
+
+	/// The nonce the next claim over an investor account must carry.
+	#[pallet::storage]
+	pub type ClaimNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// The compliance rules for transfers per tranche.
+	#[pallet::storage]
+	pub type TrancheTransferRules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::TrancheId,
+		TransferRulesOf<T>,
+	>;
+
+	/// The number of accounts holding the tokens of a tranche.
+	#[pallet::storage]
+	pub type HolderCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::TrancheId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The time an account started holding tokens of a tranche.
+	#[pallet::storage]
+	pub type AcquiredAt<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::PoolId>,
+			NMapKey<Blake2_128Concat, T::TrancheId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		Seconds,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		IssuerAdded {
+			issuer: T::AccountId,
+		},
+		IssuerRemoved {
+			issuer: T::AccountId,
+		},
+		RulesUpdated {
+			pool_id: T::PoolId,
+			rules: KycRulesOf<T>,
+		},
+		RulesRemoved {
+			pool_id: T::PoolId,
+		},
+		ClaimAccepted {
+			subject: DomainAddress,
+			issuer: T::AccountId,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			valid_till: Seconds,
+		},
+		TransferRulesUpdated {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			rules: TransferRulesOf<T>,
+		},
+		TransferRulesRemoved {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The issuer is already registered
+		IssuerAlreadyRegistered,
+		/// The issuer is not registered
+		IssuerNotRegistered,
+		/// The caller is not an admin of the pool
+		NotPoolAdmin,
+		/// The pool has no rules to check claims against
+		RulesNotFound,
+		/// The signature does not match the claim and issuer
+		InvalidSignature,
+		/// The claim is no longer valid
+		ClaimExpired,
+		/// The deadline to submit the claim has passed
+		ClaimDeadlinePassed,
+		/// The claim nonce does not match the nonce of the subject
+		InvalidClaimNonce,
+		/// The jurisdiction of the claim is not allowed by the pool
+		JurisdictionNotAllowed,
+		/// The pool requires accredited investors
+		NotAccredited,
+		/// The tranche has no transfer rules
+		TransferRulesNotFound,
+		/// The transfer would exceed the maximum number of holders of the
+		/// tranche
+		MaxHoldersReached,
+		/// The transfer would leave the sender or receiver with less than the
+		/// minimum holding of the tranche
+		BelowMinHolding,
+		/// The sender acquired the tokens too recently
+		HoldingPeriodNotOver,
+		/// The receiver is attested in a blocked jurisdiction
+		JurisdictionBlocked,
+		/// The receiver has no valid attestation
+		ReceiverNotAttested,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register an issuer whose claims are accepted.
+		#[pallet::weight(T::WeightInfo::add_issuer())]
+		#[pallet::call_index(0)]
+		pub fn add_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!Issuers::<T>::contains_key(&issuer),
+				Error::<T>::IssuerAlreadyRegistered
+			);
+
+			Issuers::<T>::insert(&issuer, ());
+
+			Self::deposit_event(Event::<T>::IssuerAdded { issuer });
+
+			Ok(())
+		}
+
+		/// Remove an issuer. Claims signed by it are no longer accepted.
+		#[pallet::weight(T::WeightInfo::remove_issuer())]
+		#[pallet::call_index(1)]
+		pub fn remove_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Issuers::<T>::take(&issuer).ok_or(Error::<T>::IssuerNotRegistered)?;
+
+			Self::deposit_event(Event::<T>::IssuerRemoved { issuer });
+
+			Ok(())
+		}
+
+		/// Set the rules claims must satisfy for the given pool.
+		#[pallet::weight(T::WeightInfo::set_rules())]
+		#[pallet::call_index(2)]
+		pub fn set_rules(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			rules: KycRulesOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who, pool_id)),
+				Error::<T>::NotPoolAdmin
+			);
+
+			Rules::<T>::insert(pool_id, rules.clone());
+
+			Self::deposit_event(Event::<T>::RulesUpdated { pool_id, rules });
+
+			Ok(())
+		}
+
+		/// Remove the rules of the given pool. No more claims are accepted
+		/// for it.
+		#[pallet::weight(T::WeightInfo::remove_rules())]
+		#[pallet::call_index(3)]
+		pub fn remove_rules(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who, pool_id)),
+				Error::<T>::NotPoolAdmin
+			);
+
+			Rules::<T>::take(pool_id).ok_or(Error::<T>::RulesNotFound)?;
+
+			Self::deposit_event(Event::<T>::RulesRemoved { pool_id });
+
+			Ok(())
+		}
+
+		/// Submit a signed claim, granting or renewing the tranche investor
+		/// role of the claim subject until the claim expires.
+		///
+		/// Can be called by any account, allowing relayers to submit claims
+		/// of remote investors. These are announced to their domain, paid by
+		/// the caller. A claim is accepted once, before its deadline, and only
+		/// for the tranche it was signed for.
+		#[pallet::weight(T::WeightInfo::submit_claim())]
+		#[pallet::call_index(4)]
+		pub fn submit_claim(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			claim: KycClaim<T::AccountId>,
+			signature: T::Signature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Issuers::<T>::contains_key(&claim.issuer),
+				Error::<T>::IssuerNotRegistered
+			);
+			ensure!(
+				signature.verify(
+					Self::claim_message(pool_id, tranche_id, &claim).as_slice(),
+					&claim.issuer
+				),
+				Error::<T>::InvalidSignature
+			);
+
+			let now = T::Time::now();
+			ensure!(claim.deadline >= now, Error::<T>::ClaimDeadlinePassed);
+			ensure!(claim.valid_till > now, Error::<T>::ClaimExpired);
+
+			let investor = claim.subject.account();
+			ensure!(
+				claim.nonce == ClaimNonces::<T>::get(&investor),
+				Error::<T>::InvalidClaimNonce
+			);
+
+			let rules = Rules::<T>::get(pool_id).ok_or(Error::<T>::RulesNotFound)?;
+			ensure!(
+				rules.allowed_jurisdictions.is_empty()
+					|| rules.allowed_jurisdictions.contains(&claim.jurisdiction),
+				Error::<T>::JurisdictionNotAllowed
+			);
+			ensure!(
+				claim.accredited || !rules.accreditation_required,
+				Error::<T>::NotAccredited
+			);
+
+			T::Permissions::add(
+				PermissionScope::Pool(pool_id),
+				investor.clone(),
+				Role::PoolRole(PoolRole::TrancheInvestor(
+					tranche_id,
+					claim.valid_till.saturating_sub(now),
+				)),
+			)?;
+
+			if !matches!(claim.subject, DomainAddress::Centrifuge(_)) {
+				T::MemberUpdater::update_member(
+					who,
+					pool_id,
+					tranche_id,
+					claim.subject.clone(),
+					claim.valid_till,
+				)?;
+			}
+
+			ClaimNonces::<T>::insert(&investor, claim.nonce.saturating_add(1));
+
+			Attestations::<T>::insert(
+				investor,
+				Attestation {
+					issuer: claim.issuer.clone(),
+					jurisdiction: claim.jurisdiction,
+					accredited: claim.accredited,
+					valid_till: claim.valid_till,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::ClaimAccepted {
+				subject: claim.subject,
+				issuer: claim.issuer,
+				pool_id,
+				tranche_id,
+				valid_till: claim.valid_till,
+			});
+
+			Ok(())
+		}
+
+		/// Set the compliance rules for transfers of the tokens of the given
+		/// tranche.
+		#[pallet::weight(T::WeightInfo::set_transfer_rules())]
+		#[pallet::call_index(5)]
+		pub fn set_transfer_rules(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			rules: TransferRulesOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who, pool_id)),
+				Error::<T>::NotPoolAdmin
+			);
+
+			TrancheTransferRules::<T>::insert(pool_id, tranche_id, rules.clone());
+
+			Self::deposit_event(Event::<T>::TransferRulesUpdated {
+				pool_id,
+				tranche_id,
+				rules,
+			});
+
+			Ok(())
+		}
+
+		/// Remove the compliance rules of the given tranche.
+		#[pallet::weight(T::WeightInfo::remove_transfer_rules())]
+		#[pallet::call_index(6)]
+		pub fn remove_transfer_rules(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who, pool_id)),
+				Error::<T>::NotPoolAdmin
+			);
+
+			TrancheTransferRules::<T>::take(pool_id, tranche_id)
+				.ok_or(Error::<T>::TransferRulesNotFound)?;
+
+			Self::deposit_event(Event::<T>::TransferRulesRemoved {
+				pool_id,
+				tranche_id,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The message an issuer signs for a claim. It is bound to this chain
+		/// through the genesis hash and to the tranche the claim grants access
+		/// to.
+		pub fn claim_message(
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			claim: &KycClaim<T::AccountId>,
+		) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+
+			(CLAIM_PREFIX, genesis_hash, pool_id, tranche_id, claim).encode()
+		}
+
+		/// Checks a transfer of tranche tokens against the compliance rules
+		/// of the tranche. Balances are the ones before the transfer. The
+		/// rules of a side are not checked if it is a system account.
+		#[allow(clippy::too_many_arguments)]
+		pub fn check_transfer(
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			send: &T::AccountId,
+			recv: &T::AccountId,
+			amount: T::Balance,
+			send_balance: T::Balance,
+			recv_balance: T::Balance,
+		) -> DispatchResult {
+			let Some(rules) = TrancheTransferRules::<T>::get(pool_id, tranche_id) else {
+				return Ok(());
+			};
+
+			let now = T::Time::now();
+			let send_is_system = T::SystemAccounts::contains(send);
+			let recv_is_system = T::SystemAccounts::contains(recv);
+
+			let send_remaining = send_balance.saturating_sub(amount);
+			let recv_resulting = recv_balance.ensure_add(amount)?;
+
+			if !send_is_system {
+				if let Some(holding_period) = rules.holding_period {
+					if let Some(acquired_at) = AcquiredAt::<T>::get((pool_id, tranche_id, send)) {
+						ensure!(
+							acquired_at.saturating_add(holding_period) <= now,
+							Error::<T>::HoldingPeriodNotOver
+						);
+					}
+				}
+
+				if let Some(min_holding) = rules.min_holding {
+					ensure!(
+						send_remaining.is_zero() || send_remaining >= min_holding,
+						Error::<T>::BelowMinHolding
+					);
+				}
+			}
+
+			if recv_is_system {
+				return Ok(());
+			}
+
+			if let Some(min_holding) = rules.min_holding {
+				ensure!(recv_resulting >= min_holding, Error::<T>::BelowMinHolding);
+			}
+
+			if let Some(max_holders) = rules.max_holders {
+				// The number of holders only grows if the receiver is new and
+				// the sender is a system account or keeps holding tokens
+				let holders_grow =
+					recv_balance.is_zero() && (send_is_system || !send_remaining.is_zero());
+
+				ensure!(
+					!holders_grow || HolderCount::<T>::get(pool_id, tranche_id) < max_holders,
+					Error::<T>::MaxHoldersReached
+				);
+			}
+
+			if rules.requires_attestation() {
+				let attestation = Attestations::<T>::get(recv)
+					.filter(|attestation| attestation.valid_till >= now)
+					.ok_or(Error::<T>::ReceiverNotAttested)?;
+
+				ensure!(
+					!rules
+						.blocked_jurisdictions
+						.contains(&attestation.jurisdiction),
+					Error::<T>::JurisdictionBlocked
+				);
+				ensure!(
+					attestation.accredited || !rules.accreditation_required,
+					Error::<T>::NotAccredited
+				);
+			}
+
+			Ok(())
+		}
+
+		/// Notes that an account started holding tokens of a tranche.
+		pub fn note_holder_added(pool_id: T::PoolId, tranche_id: T::TrancheId, who: &T::AccountId) {
+			if T::SystemAccounts::contains(who) {
+				return;
+			}
+
+			HolderCount::<T>::mutate(pool_id, tranche_id, |count| {
+				*count = count.saturating_add(1)
+			});
+		}
+
+		/// Notes that an account no longer holds tokens of a tranche.
+		pub fn note_holder_removed(
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			who: &T::AccountId,
+		) {
+			if T::SystemAccounts::contains(who) {
+				return;
+			}
+
+			HolderCount::<T>::mutate(pool_id, tranche_id, |count| {
+				*count = count.saturating_sub(1)
+			});
+			AcquiredAt::<T>::remove((pool_id, tranche_id, who));
+		}
+
+		/// Notes that an account acquired tokens of a tranche, starting its
+		/// holding period if it did not hold tokens of the tranche yet.
+		pub fn note_acquired(pool_id: T::PoolId, tranche_id: T::TrancheId, who: &T::AccountId) {
+			if T::SystemAccounts::contains(who) {
+				return;
+			}
+
+			AcquiredAt::<T>::mutate((pool_id, tranche_id, who), |acquired_at| {
+				acquired_at.get_or_insert_with(T::Time::now);
+			});
+		}
+
+		/// Sets the number of holders of a tranche, used by the runtime to
+		/// seed the holders that existed before the rules were introduced.
+		pub fn seed_holder_count(pool_id: T::PoolId, tranche_id: T::TrancheId, count: u32) {
+			HolderCount::<T>::insert(pool_id, tranche_id, count);
+		}
+	}
+}
@@ -0,0 +1,135 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_mocks::{
+	pallet_mock_member_updater, pallet_mock_permissions, pallet_mock_pre_conditions,
+	pallet_mock_time,
+};
+use cfg_primitives::TrancheId;
+use cfg_types::{domain_address::DomainAddress, permissions::PermissionScope};
+use frame_support::{derive_impl, parameter_types, traits::Equals};
+use frame_system::EnsureRoot;
+use sp_core::{sr25519, Pair};
+use sp_io::TestExternalities;
+use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage, MultiSignature};
+
+use crate::{pallet as pallet_kyc, KycClaim};
+
+pub type AccountId = AccountId32;
+pub type PoolId = u64;
+pub type CurrencyId = u32;
+
+pub const POOL_A: PoolId = 1;
+pub const TRANCHE_A: TrancheId = [1; 16];
+pub const POOL_ADMIN: AccountId = AccountId::new([1; 32]);
+pub const RELAYER: AccountId = AccountId::new([2; 32]);
+pub const INVESTOR: AccountId = AccountId::new([3; 32]);
+pub const OTHER_INVESTOR: AccountId = AccountId::new([4; 32]);
+pub const SYSTEM_ACCOUNT: AccountId = AccountId::new([5; 32]);
+
+/// Current time in seconds
+pub const NOW: u64 = 1_000;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		MockTime: pallet_mock_time,
+		MockPermissions: pallet_mock_permissions,
+		MockIsAdmin: pallet_mock_pre_conditions,
+		MockMemberUpdater: pallet_mock_member_updater,
+		Kyc: pallet_kyc,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Block = frame_system::mocking::MockBlock<Runtime>;
+	type Lookup = IdentityLookup<AccountId>;
+}
+
+impl pallet_mock_time::Config for Runtime {
+	type Moment = u64;
+}
+
+impl pallet_mock_permissions::Config for Runtime {
+	type Scope = PermissionScope<PoolId, CurrencyId>;
+}
+
+impl pallet_mock_pre_conditions::Config for Runtime {
+	type Conditions = (AccountId, PoolId);
+	type Result = bool;
+}
+
+impl pallet_mock_member_updater::Config for Runtime {
+	type Member = DomainAddress;
+	type PoolId = PoolId;
+	type TrancheId = TrancheId;
+}
+
+parameter_types! {
+	pub const MaxJurisdictions: u32 = 4;
+	pub const SystemAccount: AccountId = SYSTEM_ACCOUNT;
+}
+
+impl pallet_kyc::Config for Runtime {
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type Balance = u128;
+	type CurrencyId = CurrencyId;
+	type IsPoolAdmin = MockIsAdmin;
+	type MaxJurisdictions = MaxJurisdictions;
+	type MemberUpdater = MockMemberUpdater;
+	type Permissions = MockPermissions;
+	type PoolId = PoolId;
+	type RuntimeEvent = RuntimeEvent;
+	type Signature = MultiSignature;
+	type SystemAccounts = Equals<SystemAccount>;
+	type Time = MockTime;
+	type TrancheId = TrancheId;
+	type WeightInfo = ();
+}
+
+pub fn issuer_pair() -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[7; 32])
+}
+
+pub fn issuer() -> AccountId {
+	issuer_pair().public().into()
+}
+
+pub fn sign(pair: &sr25519::Pair, claim: &KycClaim<AccountId>) -> MultiSignature {
+	sign_for(pair, POOL_A, TRANCHE_A, claim)
+}
+
+pub fn sign_for(
+	pair: &sr25519::Pair,
+	pool_id: PoolId,
+	tranche_id: TrancheId,
+	claim: &KycClaim<AccountId>,
+) -> MultiSignature {
+	pair.sign(&Kyc::claim_message(pool_id, tranche_id, claim))
+		.into()
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let storage = frame_system::GenesisConfig::<Runtime>::default()
+		.build_storage()
+		.unwrap();
+
+	let mut ext = TestExternalities::new(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		MockTime::mock_now(|| NOW * 1000);
+		MockIsAdmin::mock_check(|(who, pool_id)| who == POOL_ADMIN && pool_id == POOL_A);
+	});
+	ext
+}
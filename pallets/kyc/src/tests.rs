@@ -0,0 +1,678 @@
+use cfg_types::{
+	domain_address::DomainAddress,
+	permissions::{PermissionScope, PoolRole, Role},
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{sr25519, Pair, H160};
+use sp_runtime::{DispatchError, DispatchResult};
+
+use crate::{mock::*, pallet::*};
+
+fn rules(jurisdictions: &[Jurisdiction], accreditation_required: bool) -> KycRulesOf<Runtime> {
+	KycRules {
+		allowed_jurisdictions: jurisdictions.to_vec().try_into().unwrap(),
+		accreditation_required,
+	}
+}
+
+fn transfer_rules() -> TransferRulesOf<Runtime> {
+	TransferRules {
+		max_holders: None,
+		min_holding: None,
+		holding_period: None,
+		blocked_jurisdictions: Default::default(),
+		accreditation_required: false,
+	}
+}
+
+fn set_transfer_rules(rules: TransferRulesOf<Runtime>) {
+	assert_ok!(Kyc::set_transfer_rules(
+		RuntimeOrigin::signed(POOL_ADMIN),
+		POOL_A,
+		TRANCHE_A,
+		rules
+	));
+}
+
+fn attest(who: AccountId, jurisdiction: Jurisdiction, accredited: bool) {
+	Attestations::<Runtime>::insert(
+		who,
+		Attestation {
+			issuer: issuer(),
+			jurisdiction,
+			accredited,
+			valid_till: NOW + 100,
+		},
+	);
+}
+
+fn check_transfer(amount: u128, send_balance: u128, recv_balance: u128) -> DispatchResult {
+	Kyc::check_transfer(
+		POOL_A,
+		TRANCHE_A,
+		&INVESTOR,
+		&OTHER_INVESTOR,
+		amount,
+		send_balance,
+		recv_balance,
+	)
+}
+
+fn claim(subject: DomainAddress) -> KycClaim<AccountId> {
+	KycClaim {
+		subject,
+		issuer: issuer(),
+		jurisdiction: *b"CH",
+		accredited: true,
+		valid_till: NOW + 100,
+		nonce: 0,
+		deadline: NOW + 10,
+	}
+}
+
+fn setup() {
+	assert_ok!(Kyc::add_issuer(RuntimeOrigin::root(), issuer()));
+	assert_ok!(Kyc::set_rules(
+		RuntimeOrigin::signed(POOL_ADMIN),
+		POOL_A,
+		rules(&[*b"CH", *b"DE"], true)
+	));
+}
+
+mod issuers {
+	use super::*;
+
+	#[test]
+	fn add_and_remove() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Kyc::add_issuer(RuntimeOrigin::signed(POOL_ADMIN), issuer()),
+				DispatchError::BadOrigin
+			);
+
+			assert_ok!(Kyc::add_issuer(RuntimeOrigin::root(), issuer()));
+			assert!(Issuers::<Runtime>::contains_key(issuer()));
+			assert_noop!(
+				Kyc::add_issuer(RuntimeOrigin::root(), issuer()),
+				Error::<Runtime>::IssuerAlreadyRegistered
+			);
+
+			assert_ok!(Kyc::remove_issuer(RuntimeOrigin::root(), issuer()));
+			assert!(!Issuers::<Runtime>::contains_key(issuer()));
+			assert_noop!(
+				Kyc::remove_issuer(RuntimeOrigin::root(), issuer()),
+				Error::<Runtime>::IssuerNotRegistered
+			);
+		});
+	}
+}
+
+mod rules {
+	use super::*;
+
+	#[test]
+	fn set_and_remove() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Kyc::set_rules(RuntimeOrigin::signed(RELAYER), POOL_A, rules(&[], false)),
+				Error::<Runtime>::NotPoolAdmin
+			);
+
+			assert_ok!(Kyc::set_rules(
+				RuntimeOrigin::signed(POOL_ADMIN),
+				POOL_A,
+				rules(&[*b"CH"], false)
+			));
+			assert_eq!(Rules::<Runtime>::get(POOL_A), Some(rules(&[*b"CH"], false)));
+
+			assert_noop!(
+				Kyc::remove_rules(RuntimeOrigin::signed(RELAYER), POOL_A),
+				Error::<Runtime>::NotPoolAdmin
+			);
+			assert_ok!(Kyc::remove_rules(RuntimeOrigin::signed(POOL_ADMIN), POOL_A));
+			assert!(Rules::<Runtime>::get(POOL_A).is_none());
+			assert_noop!(
+				Kyc::remove_rules(RuntimeOrigin::signed(POOL_ADMIN), POOL_A),
+				Error::<Runtime>::RulesNotFound
+			);
+		});
+	}
+}
+
+mod submit_claim {
+	use super::*;
+
+	#[test]
+	fn grants_tranche_investor_role() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			MockPermissions::mock_add(|scope, who, role| {
+				assert_eq!(scope, PermissionScope::Pool(POOL_A));
+				assert_eq!(who, INVESTOR);
+				assert_eq!(
+					role,
+					Role::PoolRole(PoolRole::TrancheInvestor(TRANCHE_A, 100))
+				);
+				Ok(())
+			});
+
+			let claim = claim(DomainAddress::Centrifuge(INVESTOR));
+			assert_ok!(Kyc::submit_claim(
+				RuntimeOrigin::signed(RELAYER),
+				POOL_A,
+				TRANCHE_A,
+				claim.clone(),
+				sign(&issuer_pair(), &claim)
+			));
+
+			assert_eq!(
+				Attestations::<Runtime>::get(INVESTOR),
+				Some(Attestation {
+					issuer: issuer(),
+					jurisdiction: *b"CH",
+					accredited: true,
+					valid_till: NOW + 100,
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn grants_role_to_remote_investor() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			let subject = DomainAddress::Evm(1, H160::repeat_byte(3));
+			let account = subject.account();
+			MockPermissions::mock_add(move |_, who, _| {
+				assert_eq!(who, account);
+				Ok(())
+			});
+
+			let member = subject.clone();
+			MockMemberUpdater::mock_update_member(
+				move |sender, pool_id, tranche_id, subject, valid_until| {
+					assert_eq!(sender, RELAYER);
+					assert_eq!(pool_id, POOL_A);
+					assert_eq!(tranche_id, TRANCHE_A);
+					assert_eq!(subject, member);
+					assert_eq!(valid_until, NOW + 100);
+					Ok(())
+				},
+			);
+
+			let claim = claim(subject.clone());
+			assert_ok!(Kyc::submit_claim(
+				RuntimeOrigin::signed(RELAYER),
+				POOL_A,
+				TRANCHE_A,
+				claim.clone(),
+				sign(&issuer_pair(), &claim)
+			));
+
+			assert!(Attestations::<Runtime>::get(subject.account()).is_some());
+		});
+	}
+
+	#[test]
+	fn with_wrong_signature() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			let claim = claim(DomainAddress::Centrifuge(INVESTOR));
+			let other_pair = sr25519::Pair::from_seed(&[8; 32]);
+
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim.clone(),
+					sign(&other_pair, &claim)
+				),
+				Error::<Runtime>::InvalidSignature
+			);
+
+			let tampered = KycClaim {
+				valid_till: NOW + 1_000,
+				..claim.clone()
+			};
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					tampered,
+					sign(&issuer_pair(), &claim)
+				),
+				Error::<Runtime>::InvalidSignature
+			);
+		});
+	}
+
+	#[test]
+	fn signed_for_other_tranche() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			let claim = claim(DomainAddress::Centrifuge(INVESTOR));
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim.clone(),
+					sign_for(&issuer_pair(), POOL_A + 1, TRANCHE_A, &claim)
+				),
+				Error::<Runtime>::InvalidSignature
+			);
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim.clone(),
+					sign_for(&issuer_pair(), POOL_A, [2; 16], &claim)
+				),
+				Error::<Runtime>::InvalidSignature
+			);
+		});
+	}
+
+	#[test]
+	fn replayed() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			let claim = claim(DomainAddress::Centrifuge(INVESTOR));
+			assert_ok!(Kyc::submit_claim(
+				RuntimeOrigin::signed(RELAYER),
+				POOL_A,
+				TRANCHE_A,
+				claim.clone(),
+				sign(&issuer_pair(), &claim)
+			));
+			assert_eq!(ClaimNonces::<Runtime>::get(INVESTOR), 1);
+
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim.clone(),
+					sign(&issuer_pair(), &claim)
+				),
+				Error::<Runtime>::InvalidClaimNonce
+			);
+
+			let renewal = KycClaim { nonce: 1, ..claim };
+			assert_ok!(Kyc::submit_claim(
+				RuntimeOrigin::signed(RELAYER),
+				POOL_A,
+				TRANCHE_A,
+				renewal.clone(),
+				sign(&issuer_pair(), &renewal)
+			));
+			assert_eq!(ClaimNonces::<Runtime>::get(INVESTOR), 2);
+		});
+	}
+
+	#[test]
+	fn after_deadline() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			let claim = KycClaim {
+				deadline: NOW - 1,
+				..claim(DomainAddress::Centrifuge(INVESTOR))
+			};
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim.clone(),
+					sign(&issuer_pair(), &claim)
+				),
+				Error::<Runtime>::ClaimDeadlinePassed
+			);
+		});
+	}
+
+	#[test]
+	fn with_unregistered_issuer() {
+		new_test_ext().execute_with(|| {
+			setup();
+			assert_ok!(Kyc::remove_issuer(RuntimeOrigin::root(), issuer()));
+
+			let claim = claim(DomainAddress::Centrifuge(INVESTOR));
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim.clone(),
+					sign(&issuer_pair(), &claim)
+				),
+				Error::<Runtime>::IssuerNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn with_expired_claim() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			let claim = KycClaim {
+				valid_till: NOW,
+				..claim(DomainAddress::Centrifuge(INVESTOR))
+			};
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim.clone(),
+					sign(&issuer_pair(), &claim)
+				),
+				Error::<Runtime>::ClaimExpired
+			);
+		});
+	}
+
+	#[test]
+	fn without_rules() {
+		new_test_ext().execute_with(|| {
+			setup();
+			assert_ok!(Kyc::remove_rules(RuntimeOrigin::signed(POOL_ADMIN), POOL_A));
+
+			let claim = claim(DomainAddress::Centrifuge(INVESTOR));
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim.clone(),
+					sign(&issuer_pair(), &claim)
+				),
+				Error::<Runtime>::RulesNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn not_satisfying_rules() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			let claim_us = KycClaim {
+				jurisdiction: *b"US",
+				..claim(DomainAddress::Centrifuge(INVESTOR))
+			};
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim_us.clone(),
+					sign(&issuer_pair(), &claim_us)
+				),
+				Error::<Runtime>::JurisdictionNotAllowed
+			);
+
+			let claim_retail = KycClaim {
+				accredited: false,
+				..claim(DomainAddress::Centrifuge(INVESTOR))
+			};
+			assert_noop!(
+				Kyc::submit_claim(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					claim_retail.clone(),
+					sign(&issuer_pair(), &claim_retail)
+				),
+				Error::<Runtime>::NotAccredited
+			);
+		});
+	}
+}
+
+mod transfer_rules {
+	use super::*;
+
+	#[test]
+	fn set_and_remove() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Kyc::set_transfer_rules(
+					RuntimeOrigin::signed(RELAYER),
+					POOL_A,
+					TRANCHE_A,
+					transfer_rules()
+				),
+				Error::<Runtime>::NotPoolAdmin
+			);
+
+			set_transfer_rules(transfer_rules());
+			assert_eq!(
+				TrancheTransferRules::<Runtime>::get(POOL_A, TRANCHE_A),
+				Some(transfer_rules())
+			);
+
+			assert_ok!(Kyc::remove_transfer_rules(
+				RuntimeOrigin::signed(POOL_ADMIN),
+				POOL_A,
+				TRANCHE_A
+			));
+			assert_noop!(
+				Kyc::remove_transfer_rules(RuntimeOrigin::signed(POOL_ADMIN), POOL_A, TRANCHE_A),
+				Error::<Runtime>::TransferRulesNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn without_rules() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(check_transfer(100, 100, 0));
+		});
+	}
+
+	#[test]
+	fn max_holders() {
+		new_test_ext().execute_with(|| {
+			set_transfer_rules(TransferRules {
+				max_holders: Some(1),
+				..transfer_rules()
+			});
+			Kyc::note_holder_added(POOL_A, TRANCHE_A, &INVESTOR);
+
+			// System accounts are not counted as holders
+			Kyc::note_holder_added(POOL_A, TRANCHE_A, &SYSTEM_ACCOUNT);
+			assert_eq!(HolderCount::<Runtime>::get(POOL_A, TRANCHE_A), 1);
+
+			assert_noop!(
+				check_transfer(50, 100, 0),
+				Error::<Runtime>::MaxHoldersReached
+			);
+
+			// A system account sending to a new holder grows the holders
+			assert_noop!(
+				Kyc::check_transfer(
+					POOL_A,
+					TRANCHE_A,
+					&SYSTEM_ACCOUNT,
+					&OTHER_INVESTOR,
+					100,
+					100,
+					0
+				),
+				Error::<Runtime>::MaxHoldersReached
+			);
+
+			// The sender leaves, the receiver is already a holder
+			assert_ok!(check_transfer(100, 100, 0));
+			assert_ok!(check_transfer(50, 100, 10));
+
+			Kyc::note_holder_removed(POOL_A, TRANCHE_A, &RELAYER);
+			assert_ok!(check_transfer(50, 100, 0));
+		});
+	}
+
+	#[test]
+	fn min_holding() {
+		new_test_ext().execute_with(|| {
+			set_transfer_rules(TransferRules {
+				min_holding: Some(10),
+				..transfer_rules()
+			});
+
+			assert_noop!(
+				check_transfer(95, 100, 0),
+				Error::<Runtime>::BelowMinHolding
+			);
+			assert_noop!(check_transfer(5, 100, 0), Error::<Runtime>::BelowMinHolding);
+
+			assert_ok!(check_transfer(5, 100, 10));
+			assert_ok!(check_transfer(100, 100, 0));
+		});
+	}
+
+	#[test]
+	fn holding_period() {
+		new_test_ext().execute_with(|| {
+			set_transfer_rules(TransferRules {
+				holding_period: Some(10),
+				..transfer_rules()
+			});
+			Kyc::note_acquired(POOL_A, TRANCHE_A, &INVESTOR);
+
+			assert_noop!(
+				check_transfer(50, 100, 0),
+				Error::<Runtime>::HoldingPeriodNotOver
+			);
+
+			MockTime::mock_now(|| (NOW + 10) * 1000);
+			assert_ok!(check_transfer(50, 100, 0));
+
+			// A new acquisition keeps the holding period of the first one
+			Kyc::note_acquired(POOL_A, TRANCHE_A, &INVESTOR);
+			assert_ok!(check_transfer(50, 100, 0));
+
+			// Acquiring again after leaving restarts the holding period
+			Kyc::note_holder_removed(POOL_A, TRANCHE_A, &INVESTOR);
+			Kyc::note_acquired(POOL_A, TRANCHE_A, &INVESTOR);
+			assert_noop!(
+				check_transfer(50, 100, 0),
+				Error::<Runtime>::HoldingPeriodNotOver
+			);
+		});
+	}
+
+	#[test]
+	fn blocked_jurisdictions() {
+		new_test_ext().execute_with(|| {
+			set_transfer_rules(TransferRules {
+				blocked_jurisdictions: vec![*b"US"].try_into().unwrap(),
+				..transfer_rules()
+			});
+
+			assert_noop!(
+				check_transfer(50, 100, 0),
+				Error::<Runtime>::ReceiverNotAttested
+			);
+
+			attest(OTHER_INVESTOR, *b"US", false);
+			assert_noop!(
+				check_transfer(50, 100, 0),
+				Error::<Runtime>::JurisdictionBlocked
+			);
+
+			attest(OTHER_INVESTOR, *b"CH", false);
+			assert_ok!(check_transfer(50, 100, 0));
+
+			MockTime::mock_now(|| (NOW + 101) * 1000);
+			assert_noop!(
+				check_transfer(50, 100, 0),
+				Error::<Runtime>::ReceiverNotAttested
+			);
+		});
+	}
+
+	#[test]
+	fn accreditation_required() {
+		new_test_ext().execute_with(|| {
+			set_transfer_rules(TransferRules {
+				accreditation_required: true,
+				..transfer_rules()
+			});
+
+			attest(OTHER_INVESTOR, *b"CH", false);
+			assert_noop!(check_transfer(50, 100, 0), Error::<Runtime>::NotAccredited);
+
+			attest(OTHER_INVESTOR, *b"CH", true);
+			assert_ok!(check_transfer(50, 100, 0));
+		});
+	}
+
+	#[test]
+	fn system_accounts_are_not_checked() {
+		new_test_ext().execute_with(|| {
+			set_transfer_rules(TransferRules {
+				min_holding: Some(10),
+				holding_period: Some(10),
+				accreditation_required: true,
+				..transfer_rules()
+			});
+			Kyc::note_acquired(POOL_A, TRANCHE_A, &SYSTEM_ACCOUNT);
+			Kyc::note_acquired(POOL_A, TRANCHE_A, &INVESTOR);
+
+			assert_eq!(
+				AcquiredAt::<Runtime>::get((POOL_A, TRANCHE_A, SYSTEM_ACCOUNT)),
+				None
+			);
+
+			// Sending to a system account only checks the sender
+			let send_to_system = |amount| {
+				Kyc::check_transfer(
+					POOL_A,
+					TRANCHE_A,
+					&INVESTOR,
+					&SYSTEM_ACCOUNT,
+					amount,
+					100,
+					0,
+				)
+			};
+			assert_noop!(send_to_system(100), Error::<Runtime>::HoldingPeriodNotOver);
+
+			MockTime::mock_now(|| (NOW + 10) * 1000);
+			assert_noop!(send_to_system(95), Error::<Runtime>::BelowMinHolding);
+			assert_ok!(send_to_system(100));
+
+			// Receiving from a system account only checks the receiver
+			let receive_from_system = |amount| {
+				Kyc::check_transfer(
+					POOL_A,
+					TRANCHE_A,
+					&SYSTEM_ACCOUNT,
+					&OTHER_INVESTOR,
+					amount,
+					100,
+					0,
+				)
+			};
+			assert_noop!(receive_from_system(5), Error::<Runtime>::BelowMinHolding);
+			assert_noop!(
+				receive_from_system(50),
+				Error::<Runtime>::ReceiverNotAttested
+			);
+
+			attest(OTHER_INVESTOR, *b"CH", true);
+			assert_ok!(receive_from_system(50));
+		});
+	}
+}
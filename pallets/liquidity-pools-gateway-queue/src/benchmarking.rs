@@ -0,0 +1,49 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_benchmarking::v2::*;
+use sp_runtime::traits::{TrailingZeroInput, Zero};
+
+use super::*;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn discard_failed_message() -> Result<(), BenchmarkError> {
+		let nonce = T::MessageNonce::one();
+		let message = T::Message::decode(&mut TrailingZeroInput::zeroes())
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		FailedMessageQueue::<T>::insert(nonce, (message, DispatchError::Unavailable));
+		FailedMessageRetries::<T>::insert(
+			nonce,
+			RetryInfo {
+				attempts: 0,
+				next_retry_at: Zero::zero(),
+			},
+		);
+
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		discard_failed_message(origin as T::RuntimeOrigin, nonce);
+
+		assert!(DiscardedMessages::<T>::contains_key(nonce));
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
+}
@@ -21,12 +21,40 @@ pub use pallet::*;
 use parity_scale_codec::FullCodec;
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::BaseArithmetic;
-use sp_runtime::traits::{EnsureAddAssign, One};
-
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, EnsureAddAssign, Hash, One, Saturating};
+use sp_std::vec::Vec;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
+pub mod weights;
+
+/// Retry state of a message stored in the `FailedMessageQueue`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RetryInfo<BlockNumber> {
+	/// Number of automatic retries already performed.
+	pub attempts: u32,
+
+	/// Block from which the next automatic retry can be performed.
+	pub next_retry_at: BlockNumber,
+}
+
+/// A failed message as exposed through the runtime API.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct FailedMessage<MessageNonce, Message, BlockNumber> {
+	pub nonce: MessageNonce,
+	pub message: Message,
+	pub error: DispatchError,
+
+	/// `None` if the message is not scheduled for any more automatic retries.
+	pub retry: Option<RetryInfo<BlockNumber>>,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -51,9 +79,28 @@ pub mod pallet {
 
 		/// Type used for processing messages.
 		type MessageProcessor: MessageProcessor<Message = Self::Message>;
+
+		/// Origin allowed to discard failed messages.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of automatic retries of a failed message. Once
+		/// reached, the message can only be processed manually or discarded.
+		#[pallet::constant]
+		type MaxRetryAttempts: Get<u32>;
+
+		/// Delay before the first automatic retry of a failed message. Each
+		/// subsequent retry doubles the previous delay.
+		#[pallet::constant]
+		type RetryBaseDelay: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -77,6 +124,23 @@ pub mod pallet {
 	pub type FailedMessageQueue<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::MessageNonce, (T::Message, DispatchError)>;
 
+	/// Storage for the retry state of failed messages that are still
+	/// scheduled for automatic retries.
+	#[pallet::storage]
+	#[pallet::getter(fn failed_message_retries)]
+	pub type FailedMessageRetries<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MessageNonce, RetryInfo<BlockNumberFor<T>>>;
+
+	/// The last failed message visited while retrying failed messages. The
+	/// next retries resume after it.
+	#[pallet::storage]
+	pub(crate) type RetryCursor<T: Config> = StorageValue<_, T::MessageNonce, OptionQuery>;
+
+	/// Storage for the hashes of failed messages that were discarded.
+	#[pallet::storage]
+	#[pallet::getter(fn discarded_messages)]
+	pub type DiscardedMessages<T: Config> = StorageMap<_, Blake2_128Concat, T::MessageNonce, H256>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -103,6 +167,25 @@ pub mod pallet {
 		MaxNumberOfMessagesReached {
 			last_processed_nonce: T::MessageNonce,
 		},
+
+		/// A failed message was scheduled for an automatic retry.
+		FailedMessageRetryScheduled {
+			nonce: T::MessageNonce,
+			attempts: u32,
+			next_retry_at: BlockNumberFor<T>,
+		},
+
+		/// A failed message reached the maximum number of automatic retries.
+		FailedMessageRetriesExhausted {
+			nonce: T::MessageNonce,
+			attempts: u32,
+		},
+
+		/// A failed message was discarded.
+		FailedMessageDiscarded {
+			nonce: T::MessageNonce,
+			message_hash: H256,
+		},
 	}
 
 	#[pallet::error]
@@ -113,8 +196,13 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_idle(_now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
-			Self::service_message_queue(max_weight)
+		fn on_idle(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+			let weight_used = Self::service_message_queue(now, max_weight);
+
+			weight_used.saturating_add(Self::service_failed_message_queue(
+				now,
+				max_weight.saturating_sub(weight_used),
+			))
 		}
 	}
 
@@ -147,8 +235,8 @@ pub mod pallet {
 				Self::process_message_and_deposit_event(nonce, message.clone());
 
 			if let Err(e) = result {
-				FailedMessageQueue::<T>::insert(nonce, (message, e));
-				weight.saturating_accrue(T::DbWeight::get().writes(1));
+				let now = frame_system::Pallet::<T>::block_number();
+				weight.saturating_accrue(Self::store_failed_message(nonce, message, e, now));
 			}
 
 			// Add write from MessageQueue::take
@@ -185,7 +273,8 @@ pub mod pallet {
 
 			if result.is_ok() {
 				FailedMessageQueue::<T>::remove(nonce);
-				weight.saturating_accrue(T::DbWeight::get().writes(1));
+				FailedMessageRetries::<T>::remove(nonce);
+				weight.saturating_accrue(T::DbWeight::get().writes(2));
 			}
 
 			// Add read from FailedMessageQueue
@@ -193,9 +282,118 @@ pub mod pallet {
 				weight.saturating_add(T::DbWeight::get().reads(1)),
 			)))
 		}
+
+		/// Discard a failed message.
+		///
+		/// The message is removed from the `FailedMessageQueue` storage and
+		/// only its hash is kept in the `DiscardedMessages` storage.
+		#[pallet::weight(T::WeightInfo::discard_failed_message())]
+		#[pallet::call_index(2)]
+		pub fn discard_failed_message(
+			origin: OriginFor<T>,
+			nonce: T::MessageNonce,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let (message, _) =
+				FailedMessageQueue::<T>::take(nonce).ok_or(Error::<T>::MessageNotFound)?;
+
+			FailedMessageRetries::<T>::remove(nonce);
+
+			let message_hash = BlakeTwo256::hash_of(&message);
+
+			DiscardedMessages::<T>::insert(nonce, message_hash);
+
+			Self::deposit_event(Event::<T>::FailedMessageDiscarded {
+				nonce,
+				message_hash,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Returns up to `limit` failed messages, starting after the message
+		/// with nonce `start_after` if given.
+		///
+		/// NOTE: The messages are returned in storage order, not in nonce
+		/// order. The nonce of the last returned message can be used as
+		/// `start_after` to request the next page.
+		pub fn failed_messages(
+			start_after: Option<T::MessageNonce>,
+			limit: u32,
+		) -> Vec<FailedMessage<T::MessageNonce, T::Message, BlockNumberFor<T>>> {
+			let iter = match start_after {
+				Some(nonce) => FailedMessageQueue::<T>::iter_from(
+					FailedMessageQueue::<T>::hashed_key_for(nonce),
+				),
+				None => FailedMessageQueue::<T>::iter(),
+			};
+
+			iter.take(limit as usize)
+				.map(|(nonce, (message, error))| FailedMessage {
+					nonce,
+					message,
+					error,
+					retry: FailedMessageRetries::<T>::get(nonce),
+				})
+				.collect()
+		}
+
+		/// Stores a failed message and schedules its first automatic retry.
+		fn store_failed_message(
+			nonce: T::MessageNonce,
+			message: T::Message,
+			error: DispatchError,
+			now: BlockNumberFor<T>,
+		) -> Weight {
+			FailedMessageQueue::<T>::insert(nonce, (message, error));
+
+			// 1 write for the failed message
+			T::DbWeight::get()
+				.writes(1)
+				.saturating_add(Self::schedule_retry(nonce, 0, now))
+		}
+
+		/// Schedules the next automatic retry of a failed message, using an
+		/// exponential backoff based on the number of attempts already
+		/// performed.
+		fn schedule_retry(nonce: T::MessageNonce, attempts: u32, now: BlockNumberFor<T>) -> Weight {
+			if attempts >= T::MaxRetryAttempts::get() {
+				FailedMessageRetries::<T>::remove(nonce);
+
+				if attempts > 0 {
+					Self::deposit_event(Event::<T>::FailedMessageRetriesExhausted {
+						nonce,
+						attempts,
+					});
+				}
+
+				return T::DbWeight::get().writes(1);
+			}
+
+			let delay =
+				T::RetryBaseDelay::get().saturating_mul(2u32.saturating_pow(attempts).into());
+			let next_retry_at = now.saturating_add(delay);
+
+			FailedMessageRetries::<T>::insert(
+				nonce,
+				RetryInfo {
+					attempts,
+					next_retry_at,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::FailedMessageRetryScheduled {
+				nonce,
+				attempts,
+				next_retry_at,
+			});
+
+			T::DbWeight::get().writes(1)
+		}
+
 		fn process_message_and_deposit_event(
 			nonce: T::MessageNonce,
 			message: T::Message,
@@ -218,7 +416,7 @@ pub mod pallet {
 			}
 		}
 
-		fn service_message_queue(max_weight: Weight) -> Weight {
+		fn service_message_queue(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
 			let mut last_processed_nonce = LastProcessedNonce::<T>::get();
 
 			// 1 read for the last processed nonce
@@ -271,12 +469,12 @@ pub mod pallet {
 					message.clone(),
 				) {
 					(Ok(()), weight) => weight,
-					(Err(e), weight) => {
-						FailedMessageQueue::<T>::insert(last_processed_nonce, (message, e));
-
-						// 1 write for the failed message
-						weight.saturating_add(T::DbWeight::get().writes(1))
-					}
+					(Err(e), weight) => weight.saturating_add(Self::store_failed_message(
+						last_processed_nonce,
+						message,
+						e,
+						now,
+					)),
 				};
 
 				weight_used.saturating_accrue(processing_weight);
@@ -292,6 +490,115 @@ pub mod pallet {
 
 			weight_used
 		}
+
+		/// Retries the failed messages which are due, while there is enough
+		/// weight left. When running out of weight, the next service resumes
+		/// after the last visited message instead of starting over.
+		fn service_failed_message_queue(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+			// 1 read for the cursor
+			let mut weight_used = T::DbWeight::get().reads(1);
+
+			let cursor = RetryCursor::<T>::get();
+			let mut retries = match cursor {
+				Some(nonce) => FailedMessageRetries::<T>::iter_from(
+					FailedMessageRetries::<T>::hashed_key_for(nonce),
+				),
+				None => FailedMessageRetries::<T>::iter(),
+			};
+			let mut last_visited = None;
+
+			loop {
+				// 1 read for the retry info
+				// 1 read for the failed message
+				// 1 write for the cursor
+				if max_weight
+					.saturating_sub(weight_used)
+					.any_lt(T::DbWeight::get().reads_writes(2, 1))
+				{
+					break;
+				}
+
+				weight_used.saturating_accrue(T::DbWeight::get().reads(1));
+
+				let Some((nonce, retry)) = retries.next() else {
+					if cursor.is_some() {
+						RetryCursor::<T>::kill();
+
+						// 1 write for removing the cursor
+						weight_used.saturating_accrue(T::DbWeight::get().writes(1));
+					}
+
+					return weight_used;
+				};
+
+				if retry.next_retry_at > now {
+					last_visited = Some(nonce);
+					continue;
+				}
+
+				weight_used.saturating_accrue(T::DbWeight::get().reads(1));
+
+				let message = match FailedMessageQueue::<T>::get(nonce) {
+					Some((msg, _)) => msg,
+					// The message was processed or discarded in the meantime.
+					None => {
+						FailedMessageRetries::<T>::remove(nonce);
+
+						// 1 write for removing the retry info
+						weight_used.saturating_accrue(T::DbWeight::get().writes(1));
+
+						last_visited = Some(nonce);
+						continue;
+					}
+				};
+
+				let remaining_weight = max_weight.saturating_sub(weight_used);
+				let next_weight = T::MessageProcessor::max_processing_weight(&message)
+					.saturating_add(T::DbWeight::get().writes(3));
+
+				// We ensure we have still capacity in the block before processing the message
+				if remaining_weight.any_lt(next_weight) {
+					break;
+				}
+
+				let processing_weight =
+					match Self::process_message_and_deposit_event(nonce, message.clone()) {
+						(Ok(()), weight) => {
+							FailedMessageQueue::<T>::remove(nonce);
+							FailedMessageRetries::<T>::remove(nonce);
+
+							// 1 write for removing the failed message
+							// 1 write for removing the retry info
+							weight.saturating_add(T::DbWeight::get().writes(2))
+						}
+						(Err(e), weight) => {
+							FailedMessageQueue::<T>::insert(nonce, (message, e));
+
+							// 1 write for updating the failed message
+							weight
+								.saturating_add(T::DbWeight::get().writes(1))
+								.saturating_add(Self::schedule_retry(
+									nonce,
+									retry.attempts.saturating_add(1),
+									now,
+								))
+						}
+					};
+
+				weight_used.saturating_accrue(processing_weight);
+
+				last_visited = Some(nonce);
+			}
+
+			if let Some(nonce) = last_visited {
+				RetryCursor::<T>::put(nonce);
+
+				// 1 write for setting the cursor
+				weight_used.saturating_accrue(T::DbWeight::get().writes(1));
+			}
+
+			weight_used
+		}
 	}
 
 	impl<T: Config> MessageQueueT for Pallet<T> {
@@ -0,0 +1,59 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+pub mod v1 {
+	use frame_support::{
+		traits::{Get, OnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use sp_runtime::Saturating;
+	use sp_std::marker::PhantomData;
+
+	use crate::{pallet::Config, FailedMessageQueue, FailedMessageRetries, RetryInfo};
+
+	const LOG_PREFIX: &str = "LiquidityPoolsGatewayQueue-v1";
+
+	/// Schedules the first automatic retry of the failed messages stored
+	/// before automatic retries were introduced.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+			let mut scheduled: u64 = 0;
+
+			if T::MaxRetryAttempts::get() > 0 {
+				let next_retry_at = frame_system::Pallet::<T>::block_number()
+					.saturating_add(T::RetryBaseDelay::get());
+
+				for nonce in FailedMessageQueue::<T>::iter_keys() {
+					count += 1;
+
+					if !FailedMessageRetries::<T>::contains_key(nonce) {
+						FailedMessageRetries::<T>::insert(
+							nonce,
+							RetryInfo {
+								attempts: 0,
+								next_retry_at,
+							},
+						);
+						scheduled += 1;
+					}
+				}
+			}
+
+			log::info!("{LOG_PREFIX} Scheduled {scheduled} of {count} failed messages for retry");
+
+			T::DbWeight::get().reads_writes(count.saturating_mul(2), scheduled)
+		}
+	}
+}
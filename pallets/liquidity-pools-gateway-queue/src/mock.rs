@@ -12,7 +12,11 @@
 // GNU General Public License for more details.
 
 use cfg_mocks::pallet_mock_liquidity_pools_gateway;
-use frame_support::derive_impl;
+use frame_support::{
+	derive_impl,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
 
 use crate::{self as pallet_liquidity_pools_gateway_queue, Config};
 
@@ -38,10 +42,14 @@ impl pallet_mock_liquidity_pools_gateway::Config for Runtime {
 }
 
 impl Config for Runtime {
+	type AdminOrigin = EnsureRoot<u64>;
+	type MaxRetryAttempts = ConstU32<3>;
 	type Message = u32;
 	type MessageNonce = Nonce;
 	type MessageProcessor = Processor;
+	type RetryBaseDelay = ConstU64<10>;
 	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
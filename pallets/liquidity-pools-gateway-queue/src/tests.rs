@@ -2,11 +2,15 @@ use cfg_traits::liquidity_pools::MessageQueue as _;
 use frame_support::{
 	assert_noop, assert_ok, dispatch::RawOrigin, pallet_prelude::Hooks, weights::Weight,
 };
-use sp_runtime::{traits::BadOrigin, DispatchError};
+use sp_runtime::{
+	traits::{BadOrigin, BlakeTwo256, Hash},
+	DispatchError,
+};
 
 use crate::{
 	mock::{new_test_ext, Processor, Queue, Runtime, RuntimeEvent as MockEvent, RuntimeOrigin},
-	Error, Event, FailedMessageQueue, LastProcessedNonce, MessageQueue,
+	DiscardedMessages, Error, Event, FailedMessageQueue, FailedMessageRetries, LastProcessedNonce,
+	MessageQueue, RetryCursor, RetryInfo,
 };
 
 mod utils {
@@ -86,6 +90,14 @@ mod process_message {
 				FailedMessageQueue::<Runtime>::get(nonce),
 				Some((message, error))
 			);
+			// Block number of the test externalities is 1.
+			assert_eq!(
+				FailedMessageRetries::<Runtime>::get(nonce),
+				Some(RetryInfo {
+					attempts: 0,
+					next_retry_at: 11,
+				})
+			);
 
 			event_exists(Event::<Runtime>::MessageExecutionFailure {
 				nonce,
@@ -107,6 +119,13 @@ mod process_failed_message {
 			let error = DispatchError::Unavailable;
 
 			FailedMessageQueue::<Runtime>::insert(nonce, (message, error));
+			FailedMessageRetries::<Runtime>::insert(
+				nonce,
+				RetryInfo {
+					attempts: 1,
+					next_retry_at: 20,
+				},
+			);
 
 			Processor::mock_process(move |msg| {
 				assert_eq!(msg, message);
@@ -120,6 +139,7 @@ mod process_failed_message {
 			));
 
 			assert!(FailedMessageQueue::<Runtime>::get(nonce).is_none());
+			assert!(FailedMessageRetries::<Runtime>::get(nonce).is_none());
 
 			event_exists(Event::<Runtime>::MessageExecutionSuccess { nonce, message })
 		});
@@ -180,6 +200,97 @@ mod process_failed_message {
 	}
 }
 
+mod discard_failed_message {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			let message = 1;
+			let nonce = 1;
+			let error = DispatchError::Unavailable;
+
+			FailedMessageQueue::<Runtime>::insert(nonce, (message, error));
+			FailedMessageRetries::<Runtime>::insert(
+				nonce,
+				RetryInfo {
+					attempts: 0,
+					next_retry_at: 10,
+				},
+			);
+
+			assert_ok!(Queue::discard_failed_message(RuntimeOrigin::root(), nonce));
+
+			let message_hash = BlakeTwo256::hash_of(&message);
+
+			assert!(FailedMessageQueue::<Runtime>::get(nonce).is_none());
+			assert!(FailedMessageRetries::<Runtime>::get(nonce).is_none());
+			assert_eq!(DiscardedMessages::<Runtime>::get(nonce), Some(message_hash));
+
+			event_exists(Event::<Runtime>::FailedMessageDiscarded {
+				nonce,
+				message_hash,
+			})
+		});
+	}
+
+	#[test]
+	fn failure_bad_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Queue::discard_failed_message(RuntimeOrigin::signed(1), 0),
+				BadOrigin,
+			);
+		});
+	}
+
+	#[test]
+	fn failure_message_not_found() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Queue::discard_failed_message(RuntimeOrigin::root(), 0),
+				Error::<Runtime>::MessageNotFound,
+			);
+		});
+	}
+}
+
+mod failed_messages {
+	use super::*;
+
+	#[test]
+	fn paginates() {
+		new_test_ext().execute_with(|| {
+			let error = DispatchError::Unavailable;
+
+			(1..=5).for_each(|nonce| {
+				FailedMessageQueue::<Runtime>::insert(nonce, (nonce as u32 * 10, error))
+			});
+
+			let first_page = Queue::failed_messages(None, 3);
+			assert_eq!(first_page.len(), 3);
+
+			let second_page = Queue::failed_messages(Some(first_page[2].nonce), 3);
+			assert_eq!(second_page.len(), 2);
+
+			let mut nonces = first_page
+				.iter()
+				.chain(second_page.iter())
+				.map(|failed| {
+					assert_eq!(failed.message, failed.nonce as u32 * 10);
+					assert_eq!(failed.error, error);
+					assert_eq!(failed.retry, None);
+
+					failed.nonce
+				})
+				.collect::<Vec<_>>();
+			nonces.sort();
+
+			assert_eq!(nonces, vec![1, 2, 3, 4, 5]);
+		});
+	}
+}
+
 mod message_queue_impl {
 	use sp_arithmetic::ArithmeticError::Overflow;
 
@@ -328,4 +439,161 @@ mod on_idle {
 			})
 		});
 	}
+
+	#[test]
+	fn retries_failed_messages_with_backoff() {
+		new_test_ext().execute_with(|| {
+			Queue::queue(10).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_LIMIT_WEIGHT);
+			let handle =
+				Processor::mock_process(|_| (Err(DispatchError::Unavailable), PROCESS_WEIGHT));
+
+			Queue::on_idle(0, TOTAL_WEIGHT);
+			assert_eq!(handle.times(), 1);
+			assert_eq!(
+				FailedMessageRetries::<Runtime>::get(1),
+				Some(RetryInfo {
+					attempts: 0,
+					next_retry_at: 10,
+				})
+			);
+
+			// Not yet due
+			Queue::on_idle(9, TOTAL_WEIGHT);
+			assert_eq!(handle.times(), 1);
+
+			Queue::on_idle(10, TOTAL_WEIGHT);
+			assert_eq!(handle.times(), 2);
+			assert_eq!(
+				FailedMessageRetries::<Runtime>::get(1),
+				Some(RetryInfo {
+					attempts: 1,
+					next_retry_at: 30,
+				})
+			);
+
+			Queue::on_idle(30, TOTAL_WEIGHT);
+			assert_eq!(handle.times(), 3);
+			assert_eq!(
+				FailedMessageRetries::<Runtime>::get(1),
+				Some(RetryInfo {
+					attempts: 2,
+					next_retry_at: 70,
+				})
+			);
+
+			Queue::on_idle(70, TOTAL_WEIGHT);
+			assert_eq!(handle.times(), 4);
+			assert_eq!(FailedMessageRetries::<Runtime>::get(1), None);
+			assert!(FailedMessageQueue::<Runtime>::get(1).is_some());
+
+			event_exists(Event::<Runtime>::FailedMessageRetriesExhausted {
+				nonce: 1,
+				attempts: 3,
+			});
+
+			// No more automatic retries
+			Queue::on_idle(1000, TOTAL_WEIGHT);
+			assert_eq!(handle.times(), 4);
+		});
+	}
+
+	#[test]
+	fn retries_failed_messages_until_success() {
+		new_test_ext().execute_with(|| {
+			Queue::queue(10).unwrap();
+
+			Processor::mock_max_processing_weight(|_| PROCESS_LIMIT_WEIGHT);
+			Processor::mock_process(|_| (Err(DispatchError::Unavailable), PROCESS_WEIGHT));
+
+			Queue::on_idle(0, TOTAL_WEIGHT);
+
+			let handle = Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			let weight = Queue::on_idle(10, TOTAL_WEIGHT);
+
+			assert_eq!(weight, PROCESS_WEIGHT);
+			assert_eq!(handle.times(), 1);
+			assert_eq!(FailedMessageQueue::<Runtime>::iter().count(), 0);
+			assert_eq!(FailedMessageRetries::<Runtime>::iter().count(), 0);
+
+			event_exists(Event::<Runtime>::MessageExecutionSuccess {
+				nonce: 1,
+				message: 10,
+			});
+		});
+	}
+
+	#[test]
+	fn retries_resume_after_the_last_visited_message() {
+		new_test_ext().execute_with(|| {
+			for nonce in 1..=3 {
+				FailedMessageQueue::<Runtime>::insert(nonce, (10, DispatchError::Unavailable));
+				FailedMessageRetries::<Runtime>::insert(
+					nonce,
+					RetryInfo {
+						attempts: 0,
+						next_retry_at: 0,
+					},
+				);
+			}
+
+			Processor::mock_max_processing_weight(|_| PROCESS_LIMIT_WEIGHT);
+			let handle =
+				Processor::mock_process(|_| (Err(DispatchError::Unavailable), PROCESS_WEIGHT));
+
+			let retried = || {
+				FailedMessageRetries::<Runtime>::iter_values()
+					.filter(|retry| retry.attempts == 1)
+					.count()
+			};
+
+			// Only two retries fit in the block
+			Queue::on_idle(0, PROCESS_LIMIT_WEIGHT + PROCESS_WEIGHT);
+			assert_eq!(handle.times(), 2);
+			assert_eq!(retried(), 2);
+			assert!(RetryCursor::<Runtime>::get().is_some());
+
+			// The next block retries the remaining one and starts over afterwards
+			Queue::on_idle(0, PROCESS_LIMIT_WEIGHT + PROCESS_WEIGHT);
+			assert_eq!(handle.times(), 3);
+			assert_eq!(retried(), 3);
+			assert_eq!(RetryCursor::<Runtime>::get(), None);
+		});
+	}
+}
+
+mod migrations {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	use super::*;
+	use crate::migrations::v1::Migration;
+
+	#[test]
+	fn schedules_failed_messages_without_retry() {
+		new_test_ext().execute_with(|| {
+			frame_system::Pallet::<Runtime>::set_block_number(5);
+
+			let scheduled = RetryInfo {
+				attempts: 2,
+				next_retry_at: 70,
+			};
+
+			FailedMessageQueue::<Runtime>::insert(1, (10, DispatchError::Unavailable));
+			FailedMessageQueue::<Runtime>::insert(2, (20, DispatchError::Unavailable));
+			FailedMessageRetries::<Runtime>::insert(2, scheduled.clone());
+
+			Migration::<Runtime>::on_runtime_upgrade();
+
+			assert_eq!(
+				FailedMessageRetries::<Runtime>::get(1),
+				Some(RetryInfo {
+					attempts: 0,
+					next_retry_at: 15,
+				})
+			);
+			assert_eq!(FailedMessageRetries::<Runtime>::get(2), Some(scheduled));
+		});
+	}
 }
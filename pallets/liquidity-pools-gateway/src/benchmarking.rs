@@ -0,0 +1,130 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_benchmarking::v2::*;
+
+use super::*;
+
+const DOMAIN: Domain = Domain::Evm(1);
+
+#[cfg(test)]
+fn init_mocks() {
+	use crate::mock::{MockLiquidityPoolsGatewayQueue, MockMessageSender};
+
+	MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+	MockMessageSender::mock_quote(|_, _| Ok(U256::from(100)));
+}
+
+struct Helper<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> Helper<T> {
+	pub fn setup_routers() {
+		Routers::<T>::set(BoundedVec::truncate_from(
+			T::RouterProvider::routers_for_domain(DOMAIN),
+		));
+	}
+
+	pub fn message() -> T::Message {
+		T::Message::initiate_recovery_message([1; 32], [2; 32])
+	}
+
+	/// Builds the biggest batch that auto batching can buffer.
+	pub fn full_batch() -> T::Message {
+		let mut batch = Self::message();
+
+		loop {
+			let mut packed = batch.clone();
+			let fits = packed.pack_with(Self::message()).is_ok()
+				&& packed.serialize().len() <= T::MaxOutboundBatchSize::get() as usize;
+
+			if !fits {
+				break batch;
+			}
+
+			batch = packed;
+		}
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_domain_auto_batching() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		Helper::<T>::setup_routers();
+		DomainAutoBatching::<T>::insert(DOMAIN, true);
+		OutboundBatch::<T>::insert(DOMAIN, Helper::<T>::full_batch());
+
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		// Disabling is the expensive one, it queues the buffered batch
+		#[extrinsic_call]
+		set_domain_auto_batching(origin as T::RuntimeOrigin, DOMAIN, false);
+
+		assert!(OutboundBatch::<T>::get(DOMAIN).is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn flush_outbound_batch() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		Helper::<T>::setup_routers();
+		OutboundBatch::<T>::insert(DOMAIN, Helper::<T>::full_batch());
+
+		#[block]
+		{
+			Pallet::<T>::service_outbound_batches(Weight::MAX);
+		}
+
+		assert!(OutboundBatch::<T>::get(DOMAIN).is_none());
+
+		Ok(())
+	}
+
+	/// Measures the bridge cost of a full batch against the cost of sending
+	/// its messages one by one.
+	#[benchmark]
+	fn quote_outbound_batch() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		Helper::<T>::setup_routers();
+		let message = Helper::<T>::message();
+		let batch = Helper::<T>::full_batch();
+
+		// Skipped if the routers of the domain are not configured for quoting
+		let message_cost = <Pallet<T> as MessageFeeQuoter>::quote(&DOMAIN, &message)
+			.map_err(|_| BenchmarkError::Skip)?;
+
+		#[block]
+		{
+			<Pallet<T> as MessageFeeQuoter>::quote(&DOMAIN, &batch)?;
+		}
+
+		let batch_cost = <Pallet<T> as MessageFeeQuoter>::quote(&DOMAIN, &batch)?;
+		let unbatched_cost = message_cost.saturating_mul(batch.submessages().len().into());
+
+		assert!(batch_cost <= unbatched_cost);
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
+}
@@ -24,15 +24,20 @@
 //! gets finalized. Each message in the `OutboundMessageQueue` has a `Domain`
 //! assigned to it, and that `Domain` should have a corresponding `DomainRouter`
 //! which should be set prior to sending the message.
+//!
+//! If auto batching is enabled for a `Domain`, outgoing messages to it are
+//! buffered and packed into size-bounded batch messages, which are queued
+//! once full or when the block has idle weight left. This reduces the number
+//! of bridge calls, and therefore the bridge fees, of bursts of messages.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use core::fmt::Debug;
 
 use cfg_primitives::LP_DEFENSIVE_WEIGHT;
 use cfg_traits::liquidity_pools::{
-	InboundMessageHandler, LpMessageBatch, LpMessageProof, LpMessageRecovery, LpMessageSerializer,
-	MessageHash, MessageProcessor, MessageQueue, MessageReceiver, MessageSender,
-	OutboundMessageHandler, RouterProvider,
+	GasPriceUpdater, InboundMessageHandler, LpMessageBatch, LpMessageProof, LpMessageRecovery,
+	LpMessageSerializer, MessageFeeQuoter, MessageHash, MessageProcessor, MessageQueue,
+	MessageReceiver, MessageSender, OutboundMessageHandler, RouterProvider,
 };
 use cfg_types::domain_address::{Domain, DomainAddress};
 use frame_support::{
@@ -40,12 +45,13 @@ use frame_support::{
 	pallet_prelude::*,
 	storage::{with_transaction, TransactionOutcome},
 };
-use frame_system::pallet_prelude::{ensure_signed, OriginFor};
+use frame_system::pallet_prelude::{ensure_signed, BlockNumberFor, OriginFor};
 use message::GatewayMessage;
 use orml_traits::GetByKey;
 pub use pallet::*;
 use parity_scale_codec::FullCodec;
 use sp_arithmetic::traits::{BaseArithmetic, EnsureAddAssign, One};
+use sp_core::U256;
 use sp_std::convert::TryInto;
 
 use crate::{
@@ -57,6 +63,9 @@ pub mod message;
 
 pub mod weights;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 mod message_processing;
 
 #[cfg(test)]
@@ -69,7 +78,7 @@ mod tests;
 pub mod pallet {
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -99,10 +108,11 @@ pub mod pallet {
 
 		/// The target of the messages coming from this chain
 		type MessageSender: MessageSender<
-			Middleware = Self::RouterId,
-			Origin = DomainAddress,
-			Message = Self::Message,
-		>;
+				Middleware = Self::RouterId,
+				Origin = DomainAddress,
+				Message = Self::Message,
+			> + MessageFeeQuoter<Destination = Self::RouterId, Message = Self::Message, Balance = U256>
+			+ GasPriceUpdater<Destination = Self::RouterId, Price = U256>;
 
 		/// An identification of a router
 		type RouterId: Parameter + MaxEncodedLen + Into<Domain>;
@@ -133,6 +143,16 @@ pub mod pallet {
 
 		/// Type for identifying sessions of inbound routers.
 		type SessionId: Parameter + Member + BaseArithmetic + Default + Copy + MaxEncodedLen;
+
+		/// Maximum serialized size of a batch message packed automatically
+		/// for a domain with auto batching enabled.
+		#[pallet::constant]
+		type MaxOutboundBatchSize: Get<u32>;
+
+		/// Maximum number of buffered outbound batches queued at the start of
+		/// each block, regardless of the idle weight left.
+		#[pallet::constant]
+		type MaxOutboundBatchFlushes: Get<u32>;
 	}
 
 	#[pallet::event]
@@ -198,6 +218,30 @@ pub mod pallet {
 			recovery_router: [u8; 32],
 			messaging_router: T::RouterId,
 		},
+
+		/// Auto batching of outbound messages was enabled or disabled for a
+		/// domain.
+		DomainAutoBatchingSet { domain: Domain, enabled: bool },
+
+		/// The buffered outbound batch of a domain was queued.
+		OutboundBatchQueued {
+			domain: Domain,
+			message_hash: MessageHash,
+		},
+
+		/// The buffered outbound batch of a domain could not be queued and
+		/// was kept for a later attempt.
+		OutboundBatchQueueFailed {
+			domain: Domain,
+			error: DispatchError,
+		},
+
+		/// The 32 bytes domain hook address of a non-EVM domain was
+		/// initialized or updated.
+		DomainRaw32HookAddressSet {
+			domain: Domain,
+			hook_address: [u8; 32],
+		},
 	}
 
 	/// Storage for routers.
@@ -212,10 +256,11 @@ pub mod pallet {
 	///
 	/// Lifetime: Indefinitely.
 	///
-	/// NOTE: Must only be changeable via `AdminOrigin`.
+	/// NOTE: Must only be changeable via `AdminOrigin`. EVM hooks are read
+	/// from the first 20 bytes.
 	#[pallet::storage]
 	pub type DomainHookAddress<T: Config> =
-		StorageMap<_, Blake2_128Concat, Domain, [u8; 20], OptionQuery>;
+		StorageMap<_, Blake2_128Concat, Domain, [u8; 32], OptionQuery>;
 
 	/// Stores a batch message, not ready yet to be enqueued.
 	/// Lifetime handled by `start_batch_message()` and `end_batch_message()`
@@ -224,6 +269,23 @@ pub mod pallet {
 	pub(crate) type PackedMessage<T: Config> =
 		StorageMap<_, Blake2_128Concat, (T::AccountId, Domain), T::Message>;
 
+	/// Stores whether outbound messages to a domain are automatically packed
+	/// into batch messages.
+	///
+	/// Lifetime: Indefinitely.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type DomainAutoBatching<T: Config> =
+		StorageMap<_, Blake2_128Concat, Domain, bool, ValueQuery>;
+
+	/// Stores the outbound messages of a domain buffered by auto batching, not
+	/// queued yet.
+	///
+	/// Lifetime: Until the batch is full or the next block is initialized.
+	#[pallet::storage]
+	pub type OutboundBatch<T: Config> = StorageMap<_, Blake2_128Concat, Domain, T::Message>;
+
 	/// Storage for pending inbound messages.
 	#[pallet::storage]
 	#[pallet::getter(fn pending_inbound_entries)]
@@ -278,6 +340,22 @@ pub mod pallet {
 		NotEnoughRoutersForDomain,
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			let max_weight = T::DbWeight::get().reads(1).saturating_add(
+				T::WeightInfo::flush_outbound_batch()
+					.saturating_mul(T::MaxOutboundBatchFlushes::get().into()),
+			);
+
+			Self::service_outbound_batches(max_weight)
+		}
+
+		fn on_idle(_now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+			Self::service_outbound_batches(max_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Sets the IDs of the routers that are used when receiving and sending
@@ -319,7 +397,10 @@ pub mod pallet {
 			T::AdminOrigin::ensure_origin(origin)?;
 
 			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
-			DomainHookAddress::<T>::insert(domain, hook_address);
+
+			let mut bytes = [0; 32];
+			bytes[..20].copy_from_slice(&hook_address);
+			DomainHookAddress::<T>::insert(domain, bytes);
 
 			Self::deposit_event(Event::DomainHookAddressSet {
 				domain,
@@ -482,6 +563,61 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Enable or disable the auto batching of outbound messages for a
+		/// domain. When disabling it, the buffered batch is queued right away.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_domain_auto_batching())]
+		#[pallet::call_index(14)]
+		pub fn set_domain_auto_batching(
+			origin: OriginFor<T>,
+			domain: Domain,
+			enabled: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
+
+			DomainAutoBatching::<T>::insert(domain, enabled);
+
+			if !enabled {
+				if let Some(batch) = OutboundBatch::<T>::take(domain) {
+					Self::queue_outbound_batch(domain, batch)?;
+				}
+			}
+
+			Self::deposit_event(Event::DomainAutoBatchingSet { domain, enabled });
+
+			Ok(())
+		}
+
+		/// Set the 32 bytes address of the domain hook of a non-EVM domain.
+		/// EVM domains are set through [`Pallet::set_domain_hook_address`].
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_domain_hook_address())]
+		#[pallet::call_index(15)]
+		pub fn set_domain_raw32_hook_address(
+			origin: OriginFor<T>,
+			domain: Domain,
+			hook_address: [u8; 32],
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				matches!(domain, Domain::Other(_)),
+				Error::<T>::DomainNotSupported
+			);
+			DomainHookAddress::<T>::insert(domain, hook_address);
+
+			Self::deposit_event(Event::DomainRaw32HookAddressSet {
+				domain,
+				hook_address,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -518,13 +654,71 @@ pub mod pallet {
 
 			PackedMessage::<T>::mutate((&from, destination), |batch| match batch {
 				Some(batch) => batch.pack_with(message),
+				None if DomainAutoBatching::<T>::get(destination) => {
+					Self::buffer_outbound_message(destination, message)
+				}
 				None => Self::queue_outbound_message(destination, message),
 			})
 		}
+
+		/// A message is packed if it is added to a batch started by the
+		/// sender which already holds messages, or to a buffered batch of the
+		/// destination with room left.
+		fn is_packed(from: &Self::Sender, destination: &Domain, message: &Self::Message) -> bool {
+			match PackedMessage::<T>::get((from, destination)) {
+				Some(batch) => !batch.submessages().is_empty(),
+				None if DomainAutoBatching::<T>::get(destination) => {
+					OutboundBatch::<T>::get(destination).map_or(false, |batch| {
+						Self::pack_into_batch(&batch, message.clone()).is_some()
+					})
+				}
+				None => false,
+			}
+		}
 	}
 
-	impl<T: Config> GetByKey<Domain, Option<[u8; 20]>> for Pallet<T> {
-		fn get(domain: &Domain) -> Option<[u8; 20]> {
+	impl<T: Config> MessageFeeQuoter for Pallet<T> {
+		type Balance = U256;
+		type Destination = Domain;
+		type Message = T::Message;
+
+		/// Returns the cost of sending the message through the first router
+		/// and its proofs through the remaining routers of the domain.
+		fn quote(destination: &Domain, message: &T::Message) -> Result<U256, DispatchError> {
+			let router_ids = Self::get_router_ids_for_domain(*destination)?;
+			let proof_message = message.to_proof_message();
+
+			router_ids
+				.iter()
+				.enumerate()
+				.try_fold(U256::zero(), |total, (i, router_id)| {
+					let router_msg = match i {
+						0 => message,
+						_ => &proof_message,
+					};
+
+					Ok::<_, DispatchError>(
+						total.saturating_add(T::MessageSender::quote(router_id, router_msg)?),
+					)
+				})
+		}
+	}
+
+	impl<T: Config> GasPriceUpdater for Pallet<T> {
+		type Destination = Domain;
+		type Price = U256;
+
+		fn update_gas_price(destination: &Domain, price: U256) -> DispatchResult {
+			for router_id in Self::get_router_ids_for_domain(*destination)? {
+				T::MessageSender::update_gas_price(&router_id, price)?;
+			}
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> GetByKey<Domain, Option<[u8; 32]>> for Pallet<T> {
+		fn get(domain: &Domain) -> Option<[u8; 32]> {
 			DomainHookAddress::<T>::get(domain)
 		}
 	}
@@ -533,6 +727,8 @@ pub mod pallet {
 		type Message = GatewayMessage<T::Message, T::RouterId>;
 
 		fn process(msg: Self::Message) -> (DispatchResult, Weight) {
+			let weight = Self::max_processing_weight(&msg);
+
 			// The #[transactional] macro only works for functions that return a
 			// `DispatchResult` therefore, we need to manually add this here.
 			let res = with_transaction(|| {
@@ -554,12 +750,16 @@ pub mod pallet {
 				}
 			});
 
-			(res, LP_DEFENSIVE_WEIGHT)
+			(res, weight)
 		}
 
 		/// Returns the maximum weight for processing one message.
-		fn max_processing_weight(_: &Self::Message) -> Weight {
-			LP_DEFENSIVE_WEIGHT
+		fn max_processing_weight(msg: &Self::Message) -> Weight {
+			match msg {
+				GatewayMessage::Inbound { message, .. } => LP_DEFENSIVE_WEIGHT
+					.saturating_add(T::InboundMessageHandler::extra_weight(message)),
+				GatewayMessage::Outbound { .. } => LP_DEFENSIVE_WEIGHT,
+			}
 		}
 	}
 
@@ -1,11 +1,13 @@
 use cfg_traits::liquidity_pools::{
-	InboundMessageHandler, LpMessageHash, LpMessageProof, MessageHash, MessageQueue, RouterProvider,
+	InboundMessageHandler, LpMessageBatch, LpMessageHash, LpMessageProof, LpMessageSerializer,
+	MessageHash, MessageQueue, RouterProvider,
 };
 use cfg_types::domain_address::Domain;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	pallet_prelude::{Decode, Encode, TypeInfo},
+	pallet_prelude::{Decode, Encode, Get, TypeInfo, Weight},
+	storage::with_storage_layer,
 };
 use parity_scale_codec::MaxEncodedLen;
 use sp_arithmetic::traits::{EnsureAddAssign, EnsureSub, SaturatedConversion};
@@ -13,8 +15,8 @@ use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
 
 use crate::{
-	message::GatewayMessage, Config, Error, Event, Pallet, PendingInboundEntries, Routers,
-	SessionIdStore,
+	message::GatewayMessage, weights::WeightInfo, Config, Error, Event, OutboundBatch, Pallet,
+	PendingInboundEntries, Routers, SessionIdStore,
 };
 
 /// Type that holds the information needed for inbound message entries.
@@ -481,4 +483,87 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Packs the message into the outbound batch buffered for the
+	/// destination. If the message does not fit into the batch, either
+	/// because of its size or its type, the buffered batch is queued and the
+	/// message starts a new one.
+	pub(crate) fn buffer_outbound_message(
+		destination: Domain,
+		message: T::Message,
+	) -> DispatchResult {
+		// Ensure the message can be queued later on.
+		Self::get_router_ids_for_domain(destination)?;
+
+		OutboundBatch::<T>::try_mutate(destination, |buffer| {
+			let batch = match buffer.take() {
+				Some(batch) => batch,
+				None => {
+					*buffer = Some(message);
+					return Ok(());
+				}
+			};
+
+			match Self::pack_into_batch(&batch, message.clone()) {
+				Some(packed) => *buffer = Some(packed),
+				None => {
+					Self::queue_outbound_batch(destination, batch)?;
+					*buffer = Some(message);
+				}
+			}
+
+			Ok(())
+		})
+	}
+
+	/// Returns the batch with the message packed into it, if it still fits
+	/// into an outbound batch.
+	pub(crate) fn pack_into_batch(batch: &T::Message, message: T::Message) -> Option<T::Message> {
+		let mut packed = batch.clone();
+		let fits = packed.pack_with(message).is_ok()
+			&& packed.serialize().len() <= T::MaxOutboundBatchSize::get() as usize;
+
+		fits.then_some(packed)
+	}
+
+	/// Queues a batch taken from the outbound buffer of the destination.
+	pub(crate) fn queue_outbound_batch(destination: Domain, batch: T::Message) -> DispatchResult {
+		let message_hash = batch.get_message_hash();
+
+		Self::queue_outbound_message(destination, batch)?;
+
+		Self::deposit_event(Event::<T>::OutboundBatchQueued {
+			domain: destination,
+			message_hash,
+		});
+
+		Ok(())
+	}
+
+	/// Queues the buffered outbound batches while there is enough weight
+	/// left. Batches that fail to be queued are kept in the buffer.
+	pub(crate) fn service_outbound_batches(max_weight: Weight) -> Weight {
+		let flush_weight = T::WeightInfo::flush_outbound_batch();
+		let mut weight_used = T::DbWeight::get().reads(1);
+
+		let mut batches = OutboundBatch::<T>::drain();
+
+		while weight_used.saturating_add(flush_weight).all_lte(max_weight) {
+			let Some((domain, batch)) = batches.next() else {
+				break;
+			};
+
+			weight_used.saturating_accrue(flush_weight);
+
+			let res = with_storage_layer(|| Self::queue_outbound_batch(domain, batch.clone()));
+
+			if let Err(error) = res {
+				OutboundBatch::<T>::insert(domain, batch);
+
+				Self::deposit_event(Event::<T>::OutboundBatchQueueFailed { domain, error });
+			}
+		}
+
+		weight_used
+	}
 }
@@ -32,6 +32,7 @@ pub const LP_ADMIN_ACCOUNT: AccountId32 = AccountId32::new([u8::MAX; 32]);
 
 pub const MAX_PACKED_MESSAGES_ERR: &str = "packed limit error";
 pub const MAX_PACKED_MESSAGES: usize = 10;
+pub const MAX_OUTBOUND_BATCH_SIZE: u32 = 5;
 
 pub const MESSAGE_HASH: [u8; 32] = [1; 32];
 
@@ -147,7 +148,7 @@ impl RouterProvider<Domain> for TestRouterProvider {
 
 	fn routers_for_domain(domain: Domain) -> Vec<Self::RouterId> {
 		match domain {
-			Domain::Centrifuge => vec![],
+			Domain::Centrifuge | Domain::Other(_) => vec![],
 			Domain::Evm(_) => vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3],
 		}
 	}
@@ -197,12 +198,16 @@ frame_support::parameter_types! {
 	pub const MaxIncomingMessageSize: u32 = 1024;
 	pub const LpAdminAccount: AccountId32 = LP_ADMIN_ACCOUNT;
 	pub const MaxRouterCount: u32 = 8;
+	pub const MaxOutboundBatchSize: u32 = MAX_OUTBOUND_BATCH_SIZE;
+	pub const MaxOutboundBatchFlushes: u32 = 2;
 }
 
 impl pallet_liquidity_pools_gateway::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId32>;
 	type InboundMessageHandler = MockLiquidityPools;
 	type MaxIncomingMessageSize = MaxIncomingMessageSize;
+	type MaxOutboundBatchFlushes = MaxOutboundBatchFlushes;
+	type MaxOutboundBatchSize = MaxOutboundBatchSize;
 	type MaxRouterCount = MaxRouterCount;
 	type Message = Message;
 	type MessageQueue = MockLiquidityPoolsGatewayQueue;
@@ -1,13 +1,19 @@
 use std::collections::HashMap;
 
 use cfg_primitives::LP_DEFENSIVE_WEIGHT;
-use cfg_traits::liquidity_pools::{LpMessageHash, MessageProcessor, OutboundMessageHandler};
+use cfg_traits::liquidity_pools::{
+	GasPriceUpdater, LpMessageHash, MessageFeeQuoter, MessageProcessor, OutboundMessageHandler,
+};
 use cfg_types::domain_address::*;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	traits::{Get, Hooks},
+	weights::Weight,
+};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use sp_arithmetic::ArithmeticError::{Overflow, Underflow};
-use sp_core::{bounded::BoundedVec, crypto::AccountId32};
+use sp_core::{bounded::BoundedVec, crypto::AccountId32, U256};
 use sp_runtime::{
 	DispatchError,
 	DispatchError::{Arithmetic, BadOrigin},
@@ -19,6 +25,7 @@ use super::{
 };
 use crate::{
 	message_processing::{InboundEntry, MessageEntry, ProofEntry},
+	weights::WeightInfo,
 	GatewayMessage,
 };
 
@@ -169,6 +176,39 @@ mod extrinsics {
 				);
 			});
 		}
+
+		#[test]
+		fn success_raw32() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Other(0);
+
+				assert_ok!(LiquidityPoolsGateway::set_domain_raw32_hook_address(
+					RuntimeOrigin::root(),
+					domain,
+					[11; 32]
+				));
+
+				assert_eq!(DomainHookAddress::<Runtime>::get(domain), Some([11; 32]));
+				event_exists(Event::<Runtime>::DomainRaw32HookAddressSet {
+					domain,
+					hook_address: [11; 32],
+				});
+			});
+		}
+
+		#[test]
+		fn raw32_domain_not_supported() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_domain_raw32_hook_address(
+						RuntimeOrigin::root(),
+						Domain::Evm(0),
+						[11; 32]
+					),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
 	}
 
 	mod batches {
@@ -311,6 +351,88 @@ mod extrinsics {
 		}
 	}
 
+	mod set_domain_auto_batching {
+		use super::*;
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_domain_auto_batching(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					true,
+				));
+
+				assert!(DomainAutoBatching::<Runtime>::get(TEST_DOMAIN));
+
+				event_exists(Event::<Runtime>::DomainAutoBatchingSet {
+					domain: TEST_DOMAIN,
+					enabled: true,
+				});
+			});
+		}
+
+		#[test]
+		fn disabling_queues_buffered_batch() {
+			new_test_ext().execute_with(|| {
+				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+				OutboundBatch::<Runtime>::insert(
+					TEST_DOMAIN,
+					Message::Pack(vec![Message::Simple, Message::Simple]),
+				);
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|msg| {
+					assert_eq!(
+						msg,
+						GatewayMessage::Outbound {
+							message: Message::Pack(vec![Message::Simple, Message::Simple]),
+							router_id: ROUTER_ID_1,
+						}
+					);
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::set_domain_auto_batching(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					false,
+				));
+
+				assert_eq!(handler.times(), 1);
+				assert!(!DomainAutoBatching::<Runtime>::get(TEST_DOMAIN));
+				assert!(OutboundBatch::<Runtime>::get(TEST_DOMAIN).is_none());
+			});
+		}
+
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_domain_auto_batching(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+						true,
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn domain_not_supported() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_domain_auto_batching(
+						RuntimeOrigin::root(),
+						Domain::Centrifuge,
+						true,
+					),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
+	}
+
 	mod execute_message_recovery {
 		use super::*;
 
@@ -896,6 +1018,257 @@ mod implementations {
 		}
 	}
 
+	mod auto_batching {
+		use super::*;
+
+		fn setup() {
+			Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+			DomainAutoBatching::<Runtime>::insert(TEST_DOMAIN, true);
+		}
+
+		#[test]
+		fn buffers_messages() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+				for _ in 0..MAX_OUTBOUND_BATCH_SIZE {
+					assert_ok!(LiquidityPoolsGateway::handle(
+						get_test_account_id(),
+						TEST_DOMAIN,
+						Message::Simple
+					));
+				}
+
+				assert_eq!(handler.times(), 0);
+				assert_eq!(
+					OutboundBatch::<Runtime>::get(TEST_DOMAIN),
+					Some(Message::Pack(vec![
+						Message::Simple;
+						MAX_OUTBOUND_BATCH_SIZE as usize
+					]))
+				);
+			});
+		}
+
+		#[test]
+		fn queues_full_batch() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|msg| {
+					assert_eq!(
+						msg,
+						GatewayMessage::Outbound {
+							message: Message::Pack(vec![
+								Message::Simple;
+								MAX_OUTBOUND_BATCH_SIZE as usize
+							]),
+							router_id: ROUTER_ID_1,
+						}
+					);
+					Ok(())
+				});
+
+				for _ in 0..MAX_OUTBOUND_BATCH_SIZE + 1 {
+					assert_ok!(LiquidityPoolsGateway::handle(
+						get_test_account_id(),
+						TEST_DOMAIN,
+						Message::Simple
+					));
+				}
+
+				assert_eq!(handler.times(), 1);
+				assert_eq!(
+					OutboundBatch::<Runtime>::get(TEST_DOMAIN),
+					Some(Message::Simple)
+				);
+			});
+		}
+
+		#[test]
+		fn manual_batch_takes_precedence() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				assert_ok!(LiquidityPoolsGateway::start_batch_message(
+					RuntimeOrigin::signed(get_test_account_id()),
+					TEST_DOMAIN
+				));
+
+				assert_ok!(LiquidityPoolsGateway::handle(
+					get_test_account_id(),
+					TEST_DOMAIN,
+					Message::Simple
+				));
+
+				assert!(OutboundBatch::<Runtime>::get(TEST_DOMAIN).is_none());
+			});
+		}
+
+		#[test]
+		fn routers_not_found() {
+			new_test_ext().execute_with(|| {
+				DomainAutoBatching::<Runtime>::insert(TEST_DOMAIN, true);
+
+				assert_noop!(
+					LiquidityPoolsGateway::handle(
+						get_test_account_id(),
+						TEST_DOMAIN,
+						Message::Simple
+					),
+					Error::<Runtime>::NotEnoughRoutersForDomain
+				);
+			});
+		}
+
+		#[test]
+		fn on_idle_queues_batches() {
+			new_test_ext().execute_with(|| {
+				setup();
+				OutboundBatch::<Runtime>::insert(
+					TEST_DOMAIN,
+					Message::Pack(vec![Message::Simple, Message::Simple]),
+				);
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+				LiquidityPoolsGateway::on_idle(1, Weight::MAX);
+
+				assert_eq!(handler.times(), 1);
+				assert!(OutboundBatch::<Runtime>::get(TEST_DOMAIN).is_none());
+
+				event_exists(Event::<Runtime>::OutboundBatchQueued {
+					domain: TEST_DOMAIN,
+					message_hash: MESSAGE_HASH,
+				});
+			});
+		}
+
+		#[test]
+		fn on_initialize_queues_batches() {
+			new_test_ext().execute_with(|| {
+				setup();
+				OutboundBatch::<Runtime>::insert(TEST_DOMAIN, Message::Simple);
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+				let weight = LiquidityPoolsGateway::on_initialize(1);
+
+				assert_eq!(handler.times(), 1);
+				assert!(OutboundBatch::<Runtime>::get(TEST_DOMAIN).is_none());
+				assert_eq!(
+					weight,
+					<() as WeightInfo>::flush_outbound_batch().saturating_add(
+						<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+					)
+				);
+			});
+		}
+
+		#[test]
+		fn on_idle_without_weight() {
+			new_test_ext().execute_with(|| {
+				setup();
+				OutboundBatch::<Runtime>::insert(TEST_DOMAIN, Message::Simple);
+
+				LiquidityPoolsGateway::on_idle(1, Weight::zero());
+
+				assert_eq!(
+					OutboundBatch::<Runtime>::get(TEST_DOMAIN),
+					Some(Message::Simple)
+				);
+			});
+		}
+
+		#[test]
+		fn on_idle_queue_error_keeps_batch() {
+			new_test_ext().execute_with(|| {
+				setup();
+				OutboundBatch::<Runtime>::insert(TEST_DOMAIN, Message::Simple);
+
+				let handler =
+					MockLiquidityPoolsGatewayQueue::mock_queue(|_| Err(DispatchError::Unavailable));
+
+				LiquidityPoolsGateway::on_idle(1, Weight::MAX);
+
+				assert_eq!(handler.times(), 1);
+				assert_eq!(
+					OutboundBatch::<Runtime>::get(TEST_DOMAIN),
+					Some(Message::Simple)
+				);
+
+				event_exists(Event::<Runtime>::OutboundBatchQueueFailed {
+					domain: TEST_DOMAIN,
+					error: DispatchError::Unavailable,
+				});
+			});
+		}
+	}
+
+	mod message_fee_quoter {
+		use super::*;
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				let msg = Message::Simple;
+
+				assert_ok!(LiquidityPoolsGateway::set_routers(
+					RuntimeOrigin::root(),
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
+				));
+
+				MockMessageSender::mock_quote(|router_id, message| match (router_id, message) {
+					(&ROUTER_ID_1, Message::Simple) => Ok(U256::from(100)),
+					(_, Message::Proof(_)) => Ok(U256::from(10)),
+					_ => panic!("unexpected message for router"),
+				});
+
+				assert_eq!(
+					LiquidityPoolsGateway::quote(&TEST_DOMAIN, &msg),
+					Ok(U256::from(120))
+				);
+			});
+		}
+
+		#[test]
+		fn routers_not_found() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::quote(&TEST_DOMAIN, &Message::Simple),
+					Error::<Runtime>::NotEnoughRoutersForDomain
+				);
+			});
+		}
+	}
+
+	mod gas_price_updater {
+		use super::*;
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				let price = U256::from(10);
+
+				assert_ok!(LiquidityPoolsGateway::set_routers(
+					RuntimeOrigin::root(),
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+				));
+
+				let handler = MockMessageSender::mock_update_gas_price(move |_, mock_price| {
+					assert_eq!(mock_price, price);
+
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::update_gas_price(&TEST_DOMAIN, price));
+				assert_eq!(handler.times(), 2);
+			});
+		}
+	}
+
 	mod message_processor {
 		use super::*;
 
@@ -23,6 +23,8 @@ pub trait WeightInfo {
 	fn execute_message_recovery() -> Weight;
 	fn initiate_message_recovery() -> Weight;
 	fn dispute_message_recovery() -> Weight;
+	fn set_domain_auto_batching() -> Weight;
+	fn flush_outbound_batch() -> Weight;
 }
 
 // NOTE: We use temporary weights here. `execute_epoch` is by far our heaviest
@@ -146,4 +148,28 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
+
+	fn set_domain_auto_batching() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one read and one write for sure and possible one
+		//       read for `AdminOrigin`. Disabling auto batching also flushes
+		//       the buffered batch, see `flush_outbound_batch()`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(Self::flush_outbound_batch())
+	}
+
+	fn flush_outbound_batch() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one takes the buffered batch, reads the routers and
+		//       queues the batch once for each of the at most 8 routers
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
 }
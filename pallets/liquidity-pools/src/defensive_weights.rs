@@ -28,6 +28,14 @@ pub trait WeightInfo {
 	fn freeze_investor() -> Weight;
 	fn unfreeze_investor() -> Weight;
 	fn update_tranche_hook() -> Weight;
+	fn set_outbound_fee_currency() -> Weight;
+	fn charge_outbound_fee() -> Weight;
+	fn pre_transfer_filter() -> Weight;
+	fn allow_remote_call() -> Weight;
+	fn disallow_remote_call() -> Weight;
+	fn propose_recovery() -> Weight;
+	fn veto_recovery() -> Weight;
+	fn execute_recovery() -> Weight;
 }
 
 // NOTE: We use temporary weights here. `execute_epoch` is by far our heaviest
@@ -35,6 +43,10 @@ pub trait WeightInfo {
 //       should be enough.
 const N: u64 = 4;
 
+/// NOTE: The maximum number of routers of a domain used by the runtimes. Each
+/// one is quoted when charging the fee of an outbound message.
+const ROUTERS: u64 = 8;
+
 /// NOTE: Defensive hardcoded weight taken from pool_system::execute_epoch. Will
 /// be replaced with real benchmark soon.
 
@@ -116,4 +128,62 @@ impl WeightInfo for () {
 		// Writes: MessageNonceStore, MessageQueue
 		RocksDbWeight::get().reads_writes(3, 2)
 	}
+
+	fn set_outbound_fee_currency() -> Weight {
+		// Writes: OutboundFeeCurrencies
+		RocksDbWeight::get().writes(1)
+	}
+
+	fn charge_outbound_fee() -> Weight {
+		// Reads: PackedMessage, DomainAutoBatching, OutboundBatch, Routers,
+		// OutboundFeeCurrencies, 2x market ratio, 2x Accounts, and per router
+		// ChainNameById, Configuration, GasPrices
+		// Writes: 2x Accounts
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8_000).saturating_mul(ROUTERS))
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(ROUTERS)))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn pre_transfer_filter() -> Weight {
+		// Reads: Time, and for the specific currency and all currencies
+		// AccountCurrencyTransferCountDelay, AccountCurrencyTransferAllowance,
+		// ApprovalPolicies
+		// Writes: 2x AccountCurrencyTransferAllowance
+		Weight::from_parts(20_000_000, 18_000)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn allow_remote_call() -> Weight {
+		// Reads: Permissions
+		// Writes: AllowedRemoteCalls
+		RocksDbWeight::get().reads_writes(1, 1)
+	}
+
+	fn disallow_remote_call() -> Weight {
+		// Reads: Permissions
+		// Writes: AllowedRemoteCalls
+		RocksDbWeight::get().reads_writes(1, 1)
+	}
+
+	fn propose_recovery() -> Weight {
+		// Reads: Pool, Tranche, Permissions, Investor permissions, Time
+		// Writes: NotedChange, PendingRecoveries
+		RocksDbWeight::get().reads_writes(5, 2)
+	}
+
+	fn veto_recovery() -> Weight {
+		// Reads: Permissions, PendingRecoveries, NotedChange
+		// Writes: PendingRecoveries, NotedChange
+		RocksDbWeight::get().reads_writes(3, 2)
+	}
+
+	fn execute_recovery() -> Weight {
+		// Reads: PendingRecoveries, NotedChange, Time, Accounts, MessageNonceStore
+		// Writes: PendingRecoveries, NotedChange, Accounts, MessageNonceStore,
+		// MessageQueue
+		RocksDbWeight::get().reads_writes(5, 5)
+	}
 }
@@ -11,16 +11,33 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::{investments::ForeignInvestment, liquidity_pools::OutboundMessageHandler};
-use cfg_types::domain_address::{Domain, DomainAddress};
+use cfg_traits::{
+	investments::ForeignInvestment, liquidity_pools::OutboundMessageHandler, Permissions,
+};
+use cfg_types::{
+	domain_address::{Domain, DomainAddress},
+	permissions::{PermissionScope, PoolRole, Role},
+};
 use frame_support::{
+	dispatch::GetDispatchInfo,
 	ensure,
-	traits::{fungibles::Mutate, tokens::Preservation, OriginTrait},
+	traits::{fungibles::Mutate, tokens::Preservation, Contains, OriginTrait},
+	weights::Weight,
 };
+use parity_scale_codec::DecodeLimit;
 use sp_core::Get;
-use sp_runtime::{traits::Zero, DispatchResult};
+use sp_runtime::{
+	traits::{Dispatchable, Zero},
+	DispatchError, DispatchResult,
+};
 
-use crate::{pallet::Error, Config, GeneralCurrencyIndexOf, Message, Pallet};
+use crate::{
+	pallet::{AllowedRemoteCalls, Error, Event},
+	Config, GeneralCurrencyIndexOf, Message, Pallet, RemoteCall,
+};
+
+/// Max nesting depth allowed when decoding a call sent by a remote pool admin
+const REMOTE_CALL_DECODE_DEPTH_LIMIT: u32 = 8;
 
 impl<T: Config> Pallet<T> {
 	/// Executes a transfer from another domain exclusively for
@@ -215,4 +232,83 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Executes a call on behalf of a pool admin living on another domain.
+	///
+	/// The call is dispatched with the signed origin of the account derived
+	/// from the admin's domain address, which must be a pool admin, and only
+	/// if the call has been allowed for the pool.
+	pub fn handle_execute_call(
+		pool_id: T::PoolId,
+		admin: DomainAddress,
+		call: RemoteCall,
+	) -> DispatchResult {
+		let who = admin.account();
+
+		ensure!(
+			T::Permission::has(
+				PermissionScope::Pool(pool_id),
+				who.clone(),
+				Role::PoolRole(PoolRole::PoolAdmin)
+			),
+			Error::<T>::NotPoolAdmin
+		);
+
+		let call_index = match call.as_slice() {
+			[pallet_index, call_index, ..] => (*pallet_index, *call_index),
+			_ => return Err(Error::<T>::InvalidRemoteCall.into()),
+		};
+
+		ensure!(
+			AllowedRemoteCalls::<T>::contains_key(pool_id, call_index),
+			Error::<T>::RemoteCallNotAllowed
+		);
+
+		let runtime_call = Self::decode_remote_call(&call)?;
+
+		ensure!(
+			T::RemoteCallFilter::contains(&(pool_id, runtime_call.clone())),
+			Error::<T>::RemoteCallNotAllowed
+		);
+
+		ensure!(
+			runtime_call
+				.get_dispatch_info()
+				.weight
+				.all_lte(T::MaxRemoteCallWeight::get()),
+			Error::<T>::RemoteCallOverweight
+		);
+
+		runtime_call
+			.dispatch(T::RuntimeOrigin::signed(who))
+			.map_err(|e| e.error)?;
+
+		Self::deposit_event(Event::<T>::RemoteCallExecuted {
+			pool_id,
+			admin,
+			call_index,
+		});
+
+		Ok(())
+	}
+
+	/// Weight of the call sent by a remote pool admin, bounded by
+	/// `MaxRemoteCallWeight` given heavier calls are never dispatched.
+	pub(crate) fn remote_call_weight(call: &RemoteCall) -> Weight {
+		Self::decode_remote_call(call)
+			.map(|call| {
+				call.get_dispatch_info()
+					.weight
+					.min(T::MaxRemoteCallWeight::get())
+			})
+			.unwrap_or_default()
+	}
+
+	fn decode_remote_call(call: &RemoteCall) -> Result<<T as Config>::RuntimeCall, DispatchError> {
+		<T as Config>::RuntimeCall::decode_all_with_depth_limit(
+			REMOTE_CALL_DECODE_DEPTH_LIMIT,
+			&mut call.as_slice(),
+		)
+		.map_err(|_| Error::<T>::InvalidRemoteCall.into())
+	}
 }
@@ -42,8 +42,12 @@
 use core::convert::TryFrom;
 
 use cfg_traits::{
+	changes::ChangeGuard,
 	investments::ForeignInvestment,
-	liquidity_pools::{InboundMessageHandler, OutboundMessageHandler},
+	liquidity_pools::{
+		GasPriceUpdater, InboundMessageHandler, LpMessageBatch, MemberUpdater, MessageFeeQuoter,
+		OutboundMessageHandler,
+	},
 	swaps::TokenSwaps,
 	CurrencyInspect, Permissions, PoolInspect, PreConditions, Seconds, TimeAsSecs,
 	TrancheTokenPrice,
@@ -56,11 +60,12 @@ use cfg_types::{
 };
 use cfg_utils::vec_to_fixed_array;
 use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
 	pallet_prelude::*,
 	traits::{
 		fungibles::{Inspect, Mutate},
 		tokens::{Fortitude, Precision, Preservation},
-		PalletInfo,
+		Contains, PalletInfo,
 	},
 	transactional,
 };
@@ -71,10 +76,10 @@ use orml_traits::{
 };
 pub use pallet::*;
 use parity_scale_codec::HasCompact;
-use sp_core::{crypto::AccountId32, H160, U256};
+use sp_core::{crypto::AccountId32, H160, H256, U256};
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, EnsureMul, Zero},
-	DispatchError, FixedPointNumber, SaturatedConversion,
+	traits::{AtLeast32BitUnsigned, Dispatchable, EnsureMul, Saturating, Zero},
+	ArithmeticError, DispatchError, FixedPointNumber, Perbill, SaturatedConversion,
 };
 use sp_std::{convert::TryInto, vec};
 use staging_xcm::{
@@ -101,7 +106,7 @@ mod gmpf {
 }
 
 mod message;
-pub use message::Message;
+pub use message::{Message, RemoteCall};
 
 pub mod hooks;
 mod inbound;
@@ -117,6 +122,46 @@ pub type GeneralCurrencyIndexType = u128;
 pub type GeneralCurrencyIndexOf<T> =
 	GeneralCurrencyIndex<GeneralCurrencyIndexType, <T as pallet::Config>::GeneralCurrencyPrefix>;
 
+/// The currency in which an account pays the fees of the outbound messages
+/// triggered by its transfers.
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum OutboundFeeCurrency {
+	/// The native currency.
+	#[default]
+	Native,
+	/// The currency being transferred, converted with the market ratio.
+	/// Transfers of tranche tokens are always charged in native currency.
+	Transferred,
+}
+
+/// A forced transfer of tranche tokens from a lost or sanctioned holder to
+/// its replacement, backed by a court order.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Recovery<TrancheId, Balance> {
+	pub tranche_id: TrancheId,
+	/// The holder the tokens are recovered from.
+	pub from: DomainAddress,
+	/// The replacement receiving the tokens. Must live on the same domain as
+	/// the holder.
+	pub to: DomainAddress,
+	pub amount: Balance,
+	/// The hash of the evidence backing the recovery, i.e. the IPFS hash of
+	/// the court order.
+	pub evidence: H256,
+}
+
+pub type RecoveryOf<T> = Recovery<<T as Config>::TrancheId, <T as Config>::Balance>;
+
+/// Represents changes which require to complete further guarding checks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub enum Change<T: Config> {
+	/// Recover tranche tokens of a pool.
+	Recovery(RecoveryOf<T>),
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -243,13 +288,16 @@ pub mod pallet {
 			+ CurrencyInspect<CurrencyId = Self::CurrencyId>
 			+ From<(Self::PoolId, Self::TrancheId)>;
 
-		/// The type for processing outgoing messages and retrieving the domain
-		/// hook address.
+		/// The type for processing outgoing messages, retrieving the domain
+		/// hook address, quoting the cost of outgoing messages and updating
+		/// the gas price of a domain.
 		type OutboundMessageHandler: OutboundMessageHandler<
 				Sender = Self::AccountId,
 				Message = Message,
 				Destination = Domain,
-			> + GetByKey<Domain, Option<[u8; 20]>>;
+			> + GetByKey<Domain, Option<[u8; 32]>>
+			+ MessageFeeQuoter<Destination = Domain, Message = Message, Balance = U256>
+			+ GasPriceUpdater<Destination = Domain, Price = U256>;
 
 		/// The prefix for currencies added via the LiquidityPools feature.
 		#[pallet::constant]
@@ -265,7 +313,12 @@ pub mod pallet {
 		type TreasuryAccount: Get<Self::AccountId>;
 
 		type PreTransferFilter: PreConditions<
-			(Self::AccountId, DomainAddress, Self::CurrencyId),
+			(
+				Self::AccountId,
+				DomainAddress,
+				Self::CurrencyId,
+				Self::Balance,
+			),
 			Result = DispatchResult,
 		>;
 
@@ -276,9 +329,72 @@ pub mod pallet {
 			Ratio = Self::BalanceRatio,
 		>;
 
+		/// The native currency in which outbound message fees are quoted.
+		#[pallet::constant]
+		type NativeCurrency: Get<Self::CurrencyId>;
+
+		/// The margin added on top of the quoted cost of an outbound message
+		/// when charging it to the user.
+		#[pallet::constant]
+		type OutboundFeeMargin: Get<Perbill>;
+
+		/// The overarching call type, used to dispatch calls sent by remote
+		/// pool admins.
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo;
+
+		/// The maximum weight a call sent by a remote pool admin can consume.
+		#[pallet::constant]
+		type MaxRemoteCallWeight: Get<Weight>;
+
+		/// Filters the calls remote pool admins can execute for a pool on top
+		/// of `AllowedRemoteCalls`. It must reject calls wrapping other calls,
+		/// like utility or proxy calls, at any depth, and calls acting on a
+		/// pool other than the given one.
+		type RemoteCallFilter: Contains<(Self::PoolId, <Self as Config>::RuntimeCall)>;
+
+		/// The source of truth for runtime changes.
+		type RuntimeChange: From<Change<Self>> + TryInto<Change<Self>>;
+
+		/// Used to delay recoveries of tranche tokens, giving pool admins the
+		/// time to veto them.
+		type ChangeGuard: ChangeGuard<
+			PoolId = Self::PoolId,
+			ChangeId = Self::Hash,
+			Change = Self::RuntimeChange,
+		>;
+
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 	}
 
+	/// Stores the currency in which an account pays the fees of its outbound
+	/// transfer messages.
+	///
+	/// Lifetime: Indefinitely.
+	#[pallet::storage]
+	pub type OutboundFeeCurrencies<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, OutboundFeeCurrency, ValueQuery>;
+
+	/// Stores the calls, identified by their `(pallet_index, call_index)`,
+	/// that remote pool admins are allowed to execute for a pool.
+	///
+	/// Lifetime: Until disallowed by a local pool admin.
+	#[pallet::storage]
+	pub type AllowedRemoteCalls<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::PoolId, Blake2_128Concat, (u8, u8), ()>;
+
+	/// Stores the proposers of the recoveries of tranche tokens which were
+	/// proposed but neither executed nor vetoed yet, identified by their change
+	/// id.
+	///
+	/// Lifetime: Until executed or vetoed.
+	#[pallet::storage]
+	pub type PendingRecoveries<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::PoolId, Blake2_128Concat, T::Hash, T::AccountId>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	#[allow(clippy::large_enum_variant)]
@@ -286,6 +402,60 @@ pub mod pallet {
 		/// An incoming LP message was
 		/// detected and is further processed
 		IncomingMessage { sender: Domain, message: Message },
+
+		/// The currency used to pay outbound message fees was set.
+		OutboundFeeCurrencySet {
+			who: T::AccountId,
+			fee_currency: OutboundFeeCurrency,
+		},
+
+		/// The fee of an outbound message was charged.
+		OutboundFeeCharged {
+			who: T::AccountId,
+			destination: Domain,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+		},
+
+		/// A call was allowed to be executed by remote pool admins.
+		RemoteCallAllowed {
+			pool_id: T::PoolId,
+			call_index: (u8, u8),
+		},
+
+		/// A call was disallowed to be executed by remote pool admins.
+		RemoteCallDisallowed {
+			pool_id: T::PoolId,
+			call_index: (u8, u8),
+		},
+
+		/// A call sent by a remote pool admin was executed.
+		RemoteCallExecuted {
+			pool_id: T::PoolId,
+			admin: DomainAddress,
+			call_index: (u8, u8),
+		},
+
+		/// A recovery of tranche tokens was proposed and can be vetoed by the
+		/// pool admins until it is released.
+		RecoveryProposed {
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+			recovery: RecoveryOf<T>,
+		},
+
+		/// A proposed recovery of tranche tokens was vetoed.
+		RecoveryVetoed {
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		},
+
+		/// A recovery of tranche tokens was executed.
+		RecoveryExecuted {
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+			recovery: RecoveryOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -341,6 +511,25 @@ pub mod pallet {
 		/// This pallet does not expect to receive direclty a batch message,
 		/// instead it expects several calls to it with different messages.
 		UnsupportedBatchMessage,
+		/// The call is not allowed to be executed by remote pool admins.
+		RemoteCallNotAllowed,
+		/// Failed to decode the call sent by a remote pool admin.
+		InvalidRemoteCall,
+		/// The call sent by a remote pool admin exceeds the maximum weight.
+		RemoteCallOverweight,
+		/// Only the RecoveryAdmin can execute a given operation.
+		NotRecoveryAdmin,
+		/// A recovery must be backed by the hash of its evidence.
+		MissingRecoveryEvidence,
+		/// The holder and the replacement of a recovery live on different
+		/// domains.
+		RecoveryDomainMismatch,
+		/// The recovery was not proposed, or was already executed or vetoed.
+		RecoveryNotFound,
+		/// The released change is not a recovery.
+		ChangeIdNotRecovery,
+		/// A recovery can not be vetoed by its proposer.
+		RecoveryVetoedByProposer,
 	}
 
 	#[pallet::call]
@@ -410,13 +599,11 @@ pub mod pallet {
 			let token_name = vec_to_fixed_array(metadata.name);
 			let token_symbol = vec_to_fixed_array(metadata.symbol);
 
-			// Determine hook from EVM chain id and 20 byte hook stored in Gateway
+			ensure!(domain != Domain::Centrifuge, Error::<T>::InvalidDomain);
+
+			// Determine hook from the domain and the hook stored in Gateway
 			let hook_bytes = T::OutboundMessageHandler::get(&domain)
 				.ok_or(Error::<T>::DomainHookAddressNotFound)?;
-			let evm_chain_id = match domain {
-				Domain::Evm(id) => Ok(id),
-				_ => Err(Error::<T>::InvalidDomain),
-			}?;
 
 			// Send the message to the domain
 			T::OutboundMessageHandler::handle(
@@ -428,7 +615,7 @@ pub mod pallet {
 					decimals: metadata.decimals.saturated_into(),
 					token_name,
 					token_symbol,
-					hook: DomainAddress::Evm(evm_chain_id, hook_bytes.into()).bytes(),
+					hook: DomainAddress::new(domain, hook_bytes).bytes(),
 				},
 			)?;
 
@@ -496,42 +683,15 @@ pub mod pallet {
 			domain_address: DomainAddress,
 			valid_until: Seconds,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-
-			ensure!(
-				T::PoolInspect::pool_exists(pool_id),
-				Error::<T>::PoolNotFound
-			);
-			ensure!(
-				T::PoolInspect::tranche_exists(pool_id, tranche_id),
-				Error::<T>::TrancheNotFound
-			);
-
-			// Ensure that the destination address has been whitelisted as a TrancheInvestor
-			// beforehand.
-			ensure!(
-				T::Permission::has(
-					PermissionScope::Pool(pool_id),
-					domain_address.account(),
-					Role::PoolRole(PoolRole::TrancheInvestor(tranche_id, valid_until))
-				),
-				Error::<T>::InvestorDomainAddressNotAMember
-			);
+			let who = ensure_signed(origin)?;
 
-			T::OutboundMessageHandler::handle(
+			<Self as MemberUpdater<T::AccountId>>::update_member(
 				who,
-				domain_address.domain(),
-				Message::UpdateRestriction {
-					pool_id: pool_id.into(),
-					tranche_id: tranche_id.into(),
-					update: UpdateRestrictionMessage::UpdateMember {
-						member: domain_address.bytes(),
-						valid_until,
-					},
-				},
-			)?;
-
-			Ok(())
+				pool_id,
+				tranche_id,
+				domain_address,
+				valid_until,
+			)
 		}
 
 		/// Transfer tranche tokens to a given address.
@@ -540,7 +700,7 @@ pub mod pallet {
 		///
 		/// NOTE: The transferring account is not kept alive as we allow its
 		/// death.
-		#[pallet::weight(T::WeightInfo::transfer())]
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(T::WeightInfo::charge_outbound_fee()).saturating_add(T::WeightInfo::pre_transfer_filter()))]
 		#[pallet::call_index(6)]
 		pub fn transfer_tranche_tokens(
 			origin: OriginFor<T>,
@@ -557,7 +717,12 @@ pub mod pallet {
 
 			// Ensure pool and tranche exist and derive invest id
 			let invest_id = Self::derive_invest_id(pool_id, tranche_id)?;
-			T::PreTransferFilter::check((who.clone(), domain_address.clone(), invest_id.into()))?;
+			T::PreTransferFilter::check((
+				who.clone(),
+				domain_address.clone(),
+				invest_id.into(),
+				amount,
+			))?;
 
 			// Transfer to the domain account for bookkeeping
 			T::Tokens::transfer(
@@ -569,17 +734,19 @@ pub mod pallet {
 				Preservation::Expendable,
 			)?;
 
-			T::OutboundMessageHandler::handle(
-				who.clone(),
-				domain_address.domain(),
-				Message::TransferTrancheTokens {
-					pool_id: pool_id.into(),
-					tranche_id: tranche_id.into(),
-					amount: amount.into(),
-					domain: domain_address.domain().into(),
-					receiver: domain_address.bytes(),
-				},
-			)?;
+			let message = Message::TransferTrancheTokens {
+				pool_id: pool_id.into(),
+				tranche_id: tranche_id.into(),
+				amount: amount.into(),
+				domain: domain_address.domain().into(),
+				receiver: domain_address.bytes(),
+			};
+
+			// NOTE: Tranche tokens have no market with the native currency, so the fee is
+			// always charged in native currency.
+			Self::charge_outbound_fee(&who, domain_address.domain(), &message, None)?;
+
+			T::OutboundMessageHandler::handle(who.clone(), domain_address.domain(), message)?;
 
 			Ok(())
 		}
@@ -590,7 +757,7 @@ pub mod pallet {
 		///
 		/// NOTE: The transferring account is not kept alive as we allow its
 		/// death.
-		#[pallet::weight(T::WeightInfo::transfer())]
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(T::WeightInfo::charge_outbound_fee()).saturating_add(T::WeightInfo::pre_transfer_filter()))]
 		#[pallet::call_index(7)]
 		pub fn transfer(
 			origin: OriginFor<T>,
@@ -614,7 +781,7 @@ pub mod pallet {
 				Error::<T>::InvalidDomain
 			);
 
-			T::PreTransferFilter::check((who.clone(), receiver.clone(), currency_id))?;
+			T::PreTransferFilter::check((who.clone(), receiver.clone(), currency_id, amount))?;
 
 			// NOTE: This check is needed as `burn_from` has not a good error resolution and
 			//       might return `Arithmetic` errors.
@@ -642,15 +809,15 @@ pub mod pallet {
 				Fortitude::Polite,
 			)?;
 
-			T::OutboundMessageHandler::handle(
-				who.clone(),
-				receiver.domain(),
-				Message::TransferAssets {
-					amount: amount.into(),
-					currency,
-					receiver: receiver.bytes(),
-				},
-			)?;
+			let message = Message::TransferAssets {
+				amount: amount.into(),
+				currency,
+				receiver: receiver.bytes(),
+			};
+
+			Self::charge_outbound_fee(&who, receiver.domain(), &message, Some(currency_id))?;
+
+			T::OutboundMessageHandler::handle(who.clone(), receiver.domain(), message)?;
 
 			Ok(())
 		}
@@ -941,41 +1108,19 @@ pub mod pallet {
 			domain: Domain,
 			hook: [u8; 20],
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-
-			ensure!(
-				T::PoolInspect::pool_exists(pool_id),
-				Error::<T>::PoolNotFound
-			);
-			ensure!(
-				T::PoolInspect::tranche_exists(pool_id, tranche_id),
-				Error::<T>::TrancheNotFound
-			);
-			ensure!(
-				T::Permission::has(
-					PermissionScope::Pool(pool_id),
-					who.clone(),
-					Role::PoolRole(PoolRole::PoolAdmin)
-				),
-				Error::<T>::NotPoolAdmin
-			);
+			let who = ensure_signed(origin)?;
 
 			let evm_chain_id = match domain {
 				Domain::Evm(id) => Ok(id),
 				_ => Err(Error::<T>::InvalidDomain),
 			}?;
 
-			T::OutboundMessageHandler::handle(
+			Self::do_update_tranche_hook(
 				who,
-				domain,
-				Message::UpdateTrancheHook {
-					pool_id: pool_id.into(),
-					tranche_id: tranche_id.into(),
-					hook: DomainAddress::Evm(evm_chain_id, hook.into()).bytes(),
-				},
-			)?;
-
-			Ok(())
+				pool_id,
+				tranche_id,
+				DomainAddress::Evm(evm_chain_id, hook.into()),
+			)
 		}
 
 		/// Initiate the recovery of assets which were sent to an incorrect
@@ -1015,9 +1160,375 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set the currency in which the fees of outbound transfer messages
+		/// are paid by the caller.
+		///
+		/// Origin: Anyone.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_outbound_fee_currency())]
+		pub fn set_outbound_fee_currency(
+			origin: OriginFor<T>,
+			fee_currency: OutboundFeeCurrency,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			OutboundFeeCurrencies::<T>::insert(&who, fee_currency);
+
+			Self::deposit_event(Event::<T>::OutboundFeeCurrencySet { who, fee_currency });
+
+			Ok(())
+		}
+
+		/// Allow remote pool admins to execute the call identified by
+		/// `(pallet_index, call_index)` for the pool.
+		///
+		/// Origin: Pool admin
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::allow_remote_call())]
+		pub fn allow_remote_call(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			call_index: (u8, u8),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who,
+					Role::PoolRole(PoolRole::PoolAdmin)
+				),
+				Error::<T>::NotPoolAdmin
+			);
+
+			AllowedRemoteCalls::<T>::insert(pool_id, call_index, ());
+
+			Self::deposit_event(Event::<T>::RemoteCallAllowed {
+				pool_id,
+				call_index,
+			});
+
+			Ok(())
+		}
+
+		/// Disallow remote pool admins to execute the call identified by
+		/// `(pallet_index, call_index)` for the pool.
+		///
+		/// Origin: Pool admin
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::disallow_remote_call())]
+		pub fn disallow_remote_call(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			call_index: (u8, u8),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who,
+					Role::PoolRole(PoolRole::PoolAdmin)
+				),
+				Error::<T>::NotPoolAdmin
+			);
+
+			AllowedRemoteCalls::<T>::remove(pool_id, call_index);
+
+			Self::deposit_event(Event::<T>::RemoteCallDisallowed {
+				pool_id,
+				call_index,
+			});
+
+			Ok(())
+		}
+
+		/// Propose to move tranche tokens from a lost or sanctioned holder to
+		/// its replacement. The recovery can be executed once released by the
+		/// change guard, and vetoed by the pool admins until then.
+		///
+		/// Origin: Recovery admin
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::propose_recovery())]
+		pub fn propose_recovery(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			recovery: RecoveryOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::PoolInspect::pool_exists(pool_id),
+				Error::<T>::PoolNotFound
+			);
+			ensure!(
+				T::PoolInspect::tranche_exists(pool_id, recovery.tranche_id),
+				Error::<T>::TrancheNotFound
+			);
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who.clone(),
+					Role::PoolRole(PoolRole::RecoveryAdmin)
+				),
+				Error::<T>::NotRecoveryAdmin
+			);
+			ensure!(
+				!recovery.evidence.is_zero(),
+				Error::<T>::MissingRecoveryEvidence
+			);
+			ensure!(
+				!recovery.amount.is_zero(),
+				Error::<T>::InvalidTransferAmount
+			);
+			ensure!(
+				recovery.from.domain() == recovery.to.domain(),
+				Error::<T>::RecoveryDomainMismatch
+			);
+			Self::validate_investor_can_transfer(
+				recovery.to.account(),
+				pool_id,
+				recovery.tranche_id,
+			)?;
+
+			let change_id =
+				T::ChangeGuard::note(pool_id, Change::Recovery(recovery.clone()).into())?;
+			PendingRecoveries::<T>::insert(pool_id, change_id, who);
+
+			Self::deposit_event(Event::<T>::RecoveryProposed {
+				pool_id,
+				change_id,
+				recovery,
+			});
+
+			Ok(())
+		}
+
+		/// Veto a proposed recovery of tranche tokens before it is executed.
+		/// The noted change is discarded from the change guard.
+		///
+		/// Origin: Pool admin other than the proposer
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::veto_recovery())]
+		pub fn veto_recovery(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who.clone(),
+					Role::PoolRole(PoolRole::PoolAdmin)
+				),
+				Error::<T>::NotPoolAdmin
+			);
+
+			let proposer = PendingRecoveries::<T>::take(pool_id, change_id)
+				.ok_or(Error::<T>::RecoveryNotFound)?;
+			ensure!(who != proposer, Error::<T>::RecoveryVetoedByProposer);
+
+			T::ChangeGuard::discard(pool_id, change_id)?;
+
+			Self::deposit_event(Event::<T>::RecoveryVetoed { pool_id, change_id });
+
+			Ok(())
+		}
+
+		/// Execute a released recovery of tranche tokens. Tokens of local
+		/// holders are moved directly, while the domain of remote holders is
+		/// notified to move them.
+		///
+		/// Origin: Anyone, the recovery is guarded by the change guard.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::execute_recovery())]
+		pub fn execute_recovery(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			PendingRecoveries::<T>::take(pool_id, change_id).ok_or(Error::<T>::RecoveryNotFound)?;
+
+			let Change::Recovery(recovery) = T::ChangeGuard::released(pool_id, change_id)?
+				.try_into()
+				.map_err(|_| Error::<T>::ChangeIdNotRecovery)?;
+
+			match recovery.from.domain() {
+				Domain::Centrifuge => {
+					T::Tokens::transfer(
+						(pool_id, recovery.tranche_id).into(),
+						&recovery.from.account(),
+						&recovery.to.account(),
+						recovery.amount,
+						Preservation::Expendable,
+					)?;
+				}
+				domain => {
+					T::OutboundMessageHandler::handle(
+						T::TreasuryAccount::get(),
+						domain,
+						Message::RecoverTrancheTokens {
+							pool_id: pool_id.into(),
+							tranche_id: recovery.tranche_id.into(),
+							from: recovery.from.bytes(),
+							to: recovery.to.bytes(),
+							amount: recovery.amount.into(),
+						},
+					)?;
+				}
+			}
+
+			Self::deposit_event(Event::<T>::RecoveryExecuted {
+				pool_id,
+				change_id,
+				recovery,
+			});
+
+			Ok(())
+		}
+
+		/// Notify the specified non-EVM destination domain about a tranche
+		/// hook address update. EVM domains are notified through
+		/// [`Pallet::update_tranche_hook`].
+		///
+		/// Origin: Pool admin
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::update_tranche_hook())]
+		pub fn update_tranche_hook_raw32(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			domain: Domain,
+			hook: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let chain_id = match domain {
+				Domain::Other(id) => Ok(id),
+				_ => Err(Error::<T>::InvalidDomain),
+			}?;
+
+			Self::do_update_tranche_hook(
+				who,
+				pool_id,
+				tranche_id,
+				DomainAddress::Raw32(chain_id, hook),
+			)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		fn do_update_tranche_hook(
+			who: T::AccountId,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			hook: DomainAddress,
+		) -> DispatchResult {
+			ensure!(
+				T::PoolInspect::pool_exists(pool_id),
+				Error::<T>::PoolNotFound
+			);
+			ensure!(
+				T::PoolInspect::tranche_exists(pool_id, tranche_id),
+				Error::<T>::TrancheNotFound
+			);
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who.clone(),
+					Role::PoolRole(PoolRole::PoolAdmin)
+				),
+				Error::<T>::NotPoolAdmin
+			);
+
+			T::OutboundMessageHandler::handle(
+				who,
+				hook.domain(),
+				Message::UpdateTrancheHook {
+					pool_id: pool_id.into(),
+					tranche_id: tranche_id.into(),
+					hook: hook.bytes(),
+				},
+			)
+		}
+
+		/// Returns the cost in native currency, including the configured
+		/// margin, of sending the message to the destination.
+		pub fn quote_outbound_fee(
+			destination: Domain,
+			message: &Message,
+		) -> Result<T::Balance, DispatchError> {
+			let cost = T::OutboundMessageHandler::quote(&destination, message)?;
+			let cost: T::Balance = u128::try_from(cost)
+				.map_err(|_| ArithmeticError::Overflow)?
+				.into();
+
+			Ok(cost.saturating_add(T::OutboundFeeMargin::get().mul_ceil(cost)))
+		}
+
+		/// Charges the cost of sending the message to the destination to
+		/// `who`, either in native currency or in the transferred currency,
+		/// depending on the preference of `who`. Without a transferred
+		/// currency which can be charged, the fee is paid in native currency.
+		///
+		/// Messages packed into a batch which is already pending to be sent
+		/// are not charged, as the batch is sent as a single message.
+		///
+		/// NOTE: The fee is moved to the `TreasuryAccount` which funds the
+		/// dispatch of outbound messages.
+		fn charge_outbound_fee(
+			who: &T::AccountId,
+			destination: Domain,
+			message: &Message,
+			transferred_currency: Option<T::CurrencyId>,
+		) -> DispatchResult {
+			if T::OutboundMessageHandler::is_packed(who, &destination, message) {
+				return Ok(());
+			}
+
+			let fee = Self::quote_outbound_fee(destination, message)?;
+
+			if fee.is_zero() {
+				return Ok(());
+			}
+
+			let native_currency = T::NativeCurrency::get();
+			let (currency_id, amount) =
+				match (OutboundFeeCurrencies::<T>::get(who), transferred_currency) {
+					(OutboundFeeCurrency::Transferred, Some(transferred_currency)) => (
+						transferred_currency,
+						T::MarketRatio::convert_by_market(
+							transferred_currency,
+							native_currency,
+							fee,
+						)?,
+					),
+					_ => (native_currency, fee),
+				};
+
+			T::Tokens::transfer(
+				currency_id,
+				who,
+				&T::TreasuryAccount::get(),
+				amount,
+				Preservation::Preserve,
+			)?;
+
+			Self::deposit_event(Event::<T>::OutboundFeeCharged {
+				who: who.clone(),
+				destination,
+				currency_id,
+				amount,
+			});
+
+			Ok(())
+		}
+
 		/// Returns the `u128` general index of a currency as the concatenation
 		/// of the configured `GeneralCurrencyPrefix` and its local currency
 		/// identifier.
@@ -1180,6 +1691,53 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> MemberUpdater<T::AccountId> for Pallet<T> {
+		type Member = DomainAddress;
+		type PoolId = T::PoolId;
+		type TrancheId = T::TrancheId;
+
+		fn update_member(
+			sender: T::AccountId,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			member: DomainAddress,
+			valid_until: Seconds,
+		) -> DispatchResult {
+			ensure!(
+				T::PoolInspect::pool_exists(pool_id),
+				Error::<T>::PoolNotFound
+			);
+			ensure!(
+				T::PoolInspect::tranche_exists(pool_id, tranche_id),
+				Error::<T>::TrancheNotFound
+			);
+
+			// Ensure that the destination address has been whitelisted as a TrancheInvestor
+			// beforehand.
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					member.account(),
+					Role::PoolRole(PoolRole::TrancheInvestor(tranche_id, valid_until))
+				),
+				Error::<T>::InvestorDomainAddressNotAMember
+			);
+
+			T::OutboundMessageHandler::handle(
+				sender,
+				member.domain(),
+				Message::UpdateRestriction {
+					pool_id: pool_id.into(),
+					tranche_id: tranche_id.into(),
+					update: UpdateRestrictionMessage::UpdateMember {
+						member: member.bytes(),
+						valid_until,
+					},
+				},
+			)
+		}
+	}
+
 	impl<T: Config> InboundMessageHandler for Pallet<T> {
 		type Message = Message;
 		type Sender = Domain;
@@ -1262,11 +1820,34 @@ pub mod pallet {
 					currency.into(),
 					sender,
 				),
+				Message::UpdateDomainGasPrice { price } => {
+					T::OutboundMessageHandler::update_gas_price(&sender, price.into())
+				}
+				Message::ExecuteCall {
+					pool_id,
+					admin,
+					call,
+				} => Self::handle_execute_call(
+					pool_id.into(),
+					DomainAddress::new(sender, admin),
+					call,
+				),
 				Message::Batch(_) => Err(Error::<T>::UnsupportedBatchMessage.into()),
 				_ => Err(Error::<T>::InvalidIncomingMessage.into()),
 			}?;
 
 			Ok(())
 		}
+
+		fn extra_weight(msg: &Message) -> Weight {
+			msg.submessages()
+				.iter()
+				.fold(Weight::zero(), |weight, msg| match msg {
+					Message::ExecuteCall { call, .. } => {
+						weight.saturating_add(Self::remote_call_weight(call))
+					}
+					_ => weight,
+				})
+		}
 	}
 }
@@ -45,6 +45,9 @@ pub const TOKEN_SYMBOL_SIZE: usize = 32;
 // Max amount of messages a batch can have
 const MAX_BATCH_MESSAGES: u32 = 16;
 
+// Max size of the SCALE-encoded call carried by an `ExecuteCall` message
+pub const MAX_REMOTE_CALL_SIZE: u32 = 512;
+
 /// An isometric type to `Domain` that serializes as expected
 #[derive(
 	Encode,
@@ -65,6 +68,7 @@ impl From<Domain> for SerializableDomain {
 		match domain {
 			Domain::Centrifuge => Self(0, 0),
 			Domain::Evm(chain_id) => Self(1, chain_id),
+			Domain::Other(chain_id) => Self(2, chain_id),
 		}
 	}
 }
@@ -76,6 +80,7 @@ impl TryInto<Domain> for SerializableDomain {
 		match self.0 {
 			0 => Ok(Domain::Centrifuge),
 			1 => Ok(Domain::Evm(self.1)),
+			2 => Ok(Domain::Other(self.1)),
 			_ => Err(DispatchError::Other("Unknown domain")),
 		}
 	}
@@ -198,6 +203,78 @@ impl BatchMessages {
 	}
 }
 
+/// A SCALE-encoded call sent by a remote pool admin.
+///
+/// NOTE: Serialized as a `u16` length prefix followed by the encoded call.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct RemoteCall(BoundedVec<u8, ConstU32<MAX_REMOTE_CALL_SIZE>>);
+
+impl Serialize for RemoteCall {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let len: u16 = self
+			.0
+			.len()
+			.try_into()
+			.map_err(|_| S::Error::custom("call too big"))?;
+
+		let mut tuple = serializer.serialize_tuple(self.0.len() + 1)?;
+		tuple.serialize_element(&len)?;
+		for byte in self.0.iter() {
+			tuple.serialize_element(byte)?;
+		}
+		tuple.end()
+	}
+}
+
+impl<'de> Deserialize<'de> for RemoteCall {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct CallVisitor;
+
+		impl<'de> Visitor<'de> for CallVisitor {
+			type Value = RemoteCall;
+
+			fn expecting(&self, formatter: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+				formatter.write_str("A length-prefixed encoded call")
+			}
+
+			fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let len: u16 = seq
+					.next_element()?
+					.ok_or(A::Error::custom("expected call length"))?;
+
+				let mut call = Vec::with_capacity(len.into());
+				for _ in 0..len {
+					call.push(
+						seq.next_element()?
+							.ok_or(A::Error::custom("expected call byte"))?,
+					);
+				}
+
+				RemoteCall::try_from(call).map_err(|e| A::Error::custom::<&'static str>(e.into()))
+			}
+		}
+
+		let limit = MAX_REMOTE_CALL_SIZE as usize + 1; // Length and call bytes
+		deserializer.deserialize_tuple(limit, CallVisitor)
+	}
+}
+
+impl TryFrom<Vec<u8>> for RemoteCall {
+	type Error = DispatchError;
+
+	fn try_from(call: Vec<u8>) -> Result<Self, DispatchError> {
+		Ok(Self(call.try_into().map_err(|_| {
+			DispatchError::Other("Remote call size limit reached")
+		})?))
+	}
+}
+
+impl RemoteCall {
+	pub fn as_slice(&self) -> &[u8] {
+		self.0.as_slice()
+	}
+}
+
 /// A message type that cannot be forwarded.
 #[derive(Encode, Decode, Serialize, Deserialize, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct NonForwardMessage(Box<Message>);
@@ -581,6 +658,43 @@ pub enum Message<BatchContent = BatchMessages, ForwardContent = NonForwardMessag
 		/// The message
 		message: ForwardContent,
 	},
+	// --- Gas service ---
+	/// Updates the gas price of the source domain which is used to quote the
+	/// value paid for executing messages sent to it. The price is only accepted
+	/// within the bounds set for the domain by the admin of the router.
+	///
+	/// Directionality: Centrifuge <- EVM Domain.
+	UpdateDomainGasPrice {
+		/// The new price of a unit of gas executed on the source domain,
+		/// converted to Centrifuge native currency
+		price: u128,
+	},
+	// --- Governance ---
+	/// Executes a call on behalf of a pool admin living on the source domain.
+	/// The call must be allowed for the pool.
+	///
+	/// Directionality: Centrifuge <- EVM Domain.
+	ExecuteCall {
+		/// The pool id
+		pool_id: u64,
+		/// The address of the pool admin on the source domain
+		admin: Address,
+		/// The SCALE-encoded call to execute
+		call: RemoteCall,
+	},
+	/// Moves tranche tokens from a lost or sanctioned holder to its
+	/// replacement, as ordered by the legal admin of the pool.
+	///
+	/// Directionality: Centrifuge -> EVM Domain.
+	RecoverTrancheTokens {
+		pool_id: u64,
+		tranche_id: TrancheId,
+		/// The holder the tokens are recovered from
+		from: Address,
+		/// The replacement receiving the tokens
+		to: Address,
+		amount: u128,
+	},
 }
 
 impl LpMessageSerializer for Message {
@@ -879,6 +993,48 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn execute_call() {
+		test_encode_decode_identity(
+			Message::ExecuteCall {
+				pool_id: 1,
+				admin: default_address_32(),
+				call: RemoteCall::try_from(vec![0x1a, 0x02, 0xff]).unwrap(),
+			},
+			"1f0000000000000001456456456456456456456456456456456456456456456456456456456456456400031a02ff",
+		);
+	}
+
+	#[test]
+	fn recover_tranche_tokens() {
+		test_encode_decode_identity(
+			Message::RecoverTrancheTokens {
+				pool_id: 1,
+				tranche_id: default_tranche_id(),
+				from: default_address_32(),
+				to: vec_to_fixed_array(default_address_20()),
+				amount: AMOUNT,
+			},
+			"200000000000000001811acd5b3f17c06841c7e41e9e04cb1b45645645645645645645645645645645645645645645645645645645645645641231231231231231231231231231231231231231000000000000000000000000000000000052b7d2dcc80cd2e4000000",
+		);
+	}
+
+	#[test]
+	fn transfer_tranche_tokens_to_other_domain() {
+		let domain_address = DomainAddress::Raw32(7, default_address_32());
+
+		test_encode_decode_identity(
+			Message::TransferTrancheTokens {
+				pool_id: 1,
+				tranche_id: default_tranche_id(),
+				domain: domain_address.domain().into(),
+				receiver: domain_address.bytes(),
+				amount: AMOUNT,
+			},
+			"120000000000000001811acd5b3f17c06841c7e41e9e04cb1b0200000000000000074564564564564564564564564564564564564564564564564564564564564564000000000052b7d2dcc80cd2e4000000"
+		);
+	}
+
 	#[test]
 	fn transfer_tranche_tokens_to_centrifuge() {
 		test_encode_decode_identity(
@@ -1143,6 +1299,17 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn update_domain_gas_price() {
+		test_encode_decode_identity(
+			Message::UpdateDomainGasPrice { price: 1_000 },
+			concat!(
+				"1e",                               // UpdateDomainGasPrice index
+				"000000000000000000000000000003e8", // price
+			),
+		)
+	}
+
 	/// Verify the identity property of decode . encode on a Message value and
 	/// that it in fact encodes to and can be decoded from a given hex string.
 	fn test_encode_decode_identity(msg: Message, expected_hex: &str) {
@@ -8,10 +8,14 @@ use cfg_types::{
 		LocalAssetId,
 	},
 };
-use frame_support::{derive_impl, traits::PalletInfo as _};
+use frame_support::{
+	derive_impl,
+	traits::{Contains, PalletInfo as _},
+	weights::Weight,
+};
 use orml_traits::parameter_type_with_key;
-use sp_core::H160;
-use sp_runtime::{traits::IdentityLookup, AccountId32, DispatchResult, FixedU128};
+use sp_core::{H160, H256};
+use sp_runtime::{traits::IdentityLookup, AccountId32, DispatchResult, FixedU128, Perbill};
 use staging_xcm::{
 	v4::{Junction::*, Location, NetworkId},
 	VersionedLocation,
@@ -35,6 +39,11 @@ pub const ALICE_EVM_DOMAIN_ADDRESS: DomainAddress = DomainAddress::Evm(CHAIN_ID,
 pub const ALICE_LOCAL_DOMAIN_ADDRESS: DomainAddress = DomainAddress::Centrifuge(ALICE);
 
 pub const DOMAIN_HOOK_ADDRESS: H160 = H160::repeat_byte(10);
+pub const DOMAIN_HOOK_BYTES: [u8; 32] = [10; 32];
+
+pub const OTHER_CHAIN_ID: u64 = 2;
+pub const OTHER_DOMAIN: Domain = Domain::Other(OTHER_CHAIN_ID);
+pub const OTHER_DOMAIN_HOOK_BYTES: [u8; 32] = [11; 32];
 
 pub const AMOUNT: Balance = 100;
 pub const CURRENCY_ID: CurrencyId = CurrencyId::ForeignAsset(1);
@@ -62,6 +71,7 @@ frame_support::construct_runtime!(
 		Gateway: cfg_mocks::pallet_mock_liquidity_pools_gateway,
 		TransferFilter: cfg_mocks::pre_conditions::pallet,
 		MarketRatio: cfg_mocks::token_swaps::pallet,
+		ChangeGuard: cfg_mocks::pallet_mock_change_guard,
 		Tokens: orml_tokens,
 		LiquidityPools: pallet_liquidity_pools,
 	}
@@ -110,7 +120,7 @@ impl cfg_mocks::pallet_mock_liquidity_pools_gateway::Config for Runtime {
 }
 
 impl cfg_mocks::pre_conditions::pallet::Config for Runtime {
-	type Conditions = (AccountId, DomainAddress, CurrencyId);
+	type Conditions = (AccountId, DomainAddress, CurrencyId, Balance);
 	type Result = DispatchResult;
 }
 
@@ -122,6 +132,12 @@ impl cfg_mocks::token_swaps::pallet::Config for Runtime {
 	type Ratio = Ratio;
 }
 
+impl cfg_mocks::pallet_mock_change_guard::Config for Runtime {
+	type Change = crate::Change<Runtime>;
+	type ChangeId = H256;
+	type PoolId = PoolId;
+}
+
 parameter_type_with_key! {
 	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
 		Default::default()
@@ -145,21 +161,47 @@ impl orml_tokens::Config for Runtime {
 frame_support::parameter_types! {
 	pub CurrencyPrefix: [u8; 12] = [1; 12];
 	pub TreasuryAccount: AccountId = [2; 32].into();
+	pub const NativeCurrency: CurrencyId = CurrencyId::Native;
+	pub const OutboundFeeMargin: Perbill = Perbill::from_percent(10);
+	pub const MaxRemoteCallWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+}
+
+pub struct RemoteCallFilter;
+impl Contains<(PoolId, RuntimeCall)> for RemoteCallFilter {
+	fn contains((pool_id, call): &(PoolId, RuntimeCall)) -> bool {
+		match call {
+			RuntimeCall::LiquidityPools(
+				pallet_liquidity_pools::Call::set_outbound_fee_currency { .. },
+			) => true,
+			RuntimeCall::LiquidityPools(pallet_liquidity_pools::Call::allow_remote_call {
+				pool_id: call_pool_id,
+				..
+			}) => call_pool_id == pool_id,
+			_ => false,
+		}
+	}
 }
 
 impl pallet_liquidity_pools::Config for Runtime {
 	type AssetRegistry = AssetRegistry;
 	type Balance = Balance;
 	type BalanceRatio = Ratio;
+	type ChangeGuard = ChangeGuard;
 	type CurrencyId = CurrencyId;
 	type ForeignInvestment = ForeignInvestment;
 	type GeneralCurrencyPrefix = CurrencyPrefix;
 	type MarketRatio = MarketRatio;
+	type MaxRemoteCallWeight = MaxRemoteCallWeight;
+	type NativeCurrency = NativeCurrency;
+	type OutboundFeeMargin = OutboundFeeMargin;
 	type OutboundMessageHandler = Gateway;
 	type Permission = Permissions;
 	type PoolId = PoolId;
 	type PoolInspect = Pools;
 	type PreTransferFilter = TransferFilter;
+	type RemoteCallFilter = RemoteCallFilter;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeChange = crate::Change<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
 	type Time = Time;
 	type Tokens = Tokens;
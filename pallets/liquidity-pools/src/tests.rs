@@ -10,9 +10,12 @@ use frame_support::{
 	assert_noop, assert_ok,
 	traits::fungibles::{Inspect as _, Mutate as _},
 };
+use sp_core::U256;
 use sp_runtime::{traits::Saturating, DispatchError, TokenError};
 
-use crate::{mock::*, Error, Message, UpdateRestrictionMessage};
+use crate::{
+	mock::*, AllowedRemoteCalls, Error, Message, OutboundFeeCurrency, UpdateRestrictionMessage,
+};
 
 mod inbound;
 
@@ -24,6 +27,8 @@ mod transfer {
 		System::externalities().execute_with(|| {
 			AssetRegistry::mock_metadata(|_| Some(util::locatable_transferable_metadata()));
 			TransferFilter::mock_check(|_| Ok(()));
+			Gateway::mock_is_packed(|_, _, _| false);
+			Gateway::mock_quote(|_, _| Ok(U256::zero()));
 			Gateway::mock_handle(|sender, destination, msg| {
 				assert_eq!(sender, ALICE);
 				assert_eq!(destination, CONTRACT_DOMAIN_ADDRESS.domain());
@@ -51,6 +56,127 @@ mod transfer {
 		})
 	}
 
+	mod outbound_fee {
+		use super::*;
+
+		const FEE: Balance = 10;
+		// FEE plus the 10% OutboundFeeMargin
+		const CHARGED_FEE: Balance = 11;
+
+		fn config_mocks() {
+			AssetRegistry::mock_metadata(|_| Some(util::locatable_transferable_metadata()));
+			TransferFilter::mock_check(|_| Ok(()));
+			Gateway::mock_is_packed(|_, _, _| false);
+			Gateway::mock_quote(|destination, msg| {
+				assert_eq!(*destination, CONTRACT_DOMAIN_ADDRESS.domain());
+				assert!(matches!(msg, Message::TransferAssets { .. }));
+				Ok(U256::from(FEE))
+			});
+			Gateway::mock_handle(|_, _, _| Ok(()));
+		}
+
+		#[test]
+		fn charged_in_native_currency() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				Tokens::mint_into(CURRENCY_ID, &ALICE, AMOUNT).unwrap();
+				Tokens::mint_into(CurrencyId::Native, &ALICE, CHARGED_FEE + 1).unwrap();
+
+				assert_ok!(LiquidityPools::transfer(
+					RuntimeOrigin::signed(ALICE),
+					CURRENCY_ID,
+					CONTRACT_DOMAIN_ADDRESS,
+					AMOUNT
+				));
+
+				assert_eq!(Tokens::balance(CurrencyId::Native, &ALICE), 1);
+				assert_eq!(
+					Tokens::balance(CurrencyId::Native, &TreasuryAccount::get()),
+					CHARGED_FEE
+				);
+			})
+		}
+
+		#[test]
+		fn charged_in_transferred_currency() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+				MarketRatio::mock_convert_by_market(|to, from, amount_from| {
+					assert_eq!(to, CURRENCY_ID);
+					assert_eq!(from, CurrencyId::Native);
+					Ok(amount_from * 2)
+				});
+
+				assert_ok!(LiquidityPools::set_outbound_fee_currency(
+					RuntimeOrigin::signed(ALICE),
+					OutboundFeeCurrency::Transferred,
+				));
+
+				Tokens::mint_into(CURRENCY_ID, &ALICE, AMOUNT + CHARGED_FEE * 2 + 1).unwrap();
+
+				assert_ok!(LiquidityPools::transfer(
+					RuntimeOrigin::signed(ALICE),
+					CURRENCY_ID,
+					CONTRACT_DOMAIN_ADDRESS,
+					AMOUNT
+				));
+
+				assert_eq!(Tokens::balance(CURRENCY_ID, &ALICE), 1);
+				assert_eq!(
+					Tokens::balance(CURRENCY_ID, &TreasuryAccount::get()),
+					CHARGED_FEE * 2
+				);
+			})
+		}
+
+		#[test]
+		fn not_charged_when_packed() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+				Gateway::mock_is_packed(|sender, destination, msg| {
+					assert_eq!(*sender, ALICE);
+					assert_eq!(*destination, CONTRACT_DOMAIN_ADDRESS.domain());
+					assert!(matches!(msg, Message::TransferAssets { .. }));
+					true
+				});
+
+				Tokens::mint_into(CURRENCY_ID, &ALICE, AMOUNT).unwrap();
+
+				assert_ok!(LiquidityPools::transfer(
+					RuntimeOrigin::signed(ALICE),
+					CURRENCY_ID,
+					CONTRACT_DOMAIN_ADDRESS,
+					AMOUNT
+				));
+
+				assert_eq!(
+					Tokens::balance(CurrencyId::Native, &TreasuryAccount::get()),
+					0
+				);
+			})
+		}
+
+		#[test]
+		fn with_insufficient_balance() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				Tokens::mint_into(CURRENCY_ID, &ALICE, AMOUNT).unwrap();
+
+				assert_noop!(
+					LiquidityPools::transfer(
+						RuntimeOrigin::signed(ALICE),
+						CURRENCY_ID,
+						CONTRACT_DOMAIN_ADDRESS,
+						AMOUNT
+					),
+					orml_tokens::Error::<Runtime>::BalanceTooLow,
+				);
+			})
+		}
+	}
+
 	mod erroring_out {
 		use super::*;
 
@@ -235,6 +361,11 @@ mod transfer_tranche_tokens {
 		Pools::mock_pool_exists(|_| true);
 		Pools::mock_tranche_exists(|_, _| true);
 		TransferFilter::mock_check(|_| Ok(()));
+		Gateway::mock_is_packed(|_, _, _| false);
+		Gateway::mock_quote(|destination, _| {
+			assert_eq!(*destination, CONTRACT_DOMAIN_ADDRESS.domain());
+			Ok(U256::from(10))
+		});
 		Gateway::mock_handle(|sender, destination, msg| {
 			assert_eq!(sender, ALICE);
 			assert_eq!(destination, CONTRACT_DOMAIN_ADDRESS.domain());
@@ -258,6 +389,7 @@ mod transfer_tranche_tokens {
 			config_mocks();
 
 			Tokens::mint_into(TRANCHE_CURRENCY, &ALICE, AMOUNT).unwrap();
+			Tokens::mint_into(CurrencyId::Native, &ALICE, 12).unwrap();
 
 			assert_ok!(LiquidityPools::transfer_tranche_tokens(
 				RuntimeOrigin::signed(ALICE),
@@ -270,6 +402,40 @@ mod transfer_tranche_tokens {
 			let destination = CONTRACT_DOMAIN_ADDRESS.domain().into_account();
 			assert_eq!(Tokens::balance(TRANCHE_CURRENCY, &ALICE), 0);
 			assert_eq!(Tokens::balance(TRANCHE_CURRENCY, &destination), AMOUNT);
+			// The quoted fee plus the 10% OutboundFeeMargin
+			assert_eq!(
+				Tokens::balance(CurrencyId::Native, &TreasuryAccount::get()),
+				11
+			);
+		})
+	}
+
+	#[test]
+	fn fee_charged_in_native_currency_with_transferred_preference() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+
+			assert_ok!(LiquidityPools::set_outbound_fee_currency(
+				RuntimeOrigin::signed(ALICE),
+				OutboundFeeCurrency::Transferred,
+			));
+
+			Tokens::mint_into(TRANCHE_CURRENCY, &ALICE, AMOUNT).unwrap();
+			Tokens::mint_into(CurrencyId::Native, &ALICE, 12).unwrap();
+
+			assert_ok!(LiquidityPools::transfer_tranche_tokens(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				TRANCHE_ID,
+				CONTRACT_DOMAIN_ADDRESS,
+				AMOUNT
+			));
+
+			assert_eq!(Tokens::balance(CurrencyId::Native, &ALICE), 1);
+			assert_eq!(
+				Tokens::balance(CurrencyId::Native, &TreasuryAccount::get()),
+				11
+			);
 		})
 	}
 
@@ -521,7 +687,7 @@ mod add_tranche {
 		AssetRegistry::mock_metadata(|_| Some(util::default_metadata()));
 		Gateway::mock_get(move |domain| {
 			assert_eq!(domain, &CONTRACT_DOMAIN_ADDRESS.domain());
-			Some(DOMAIN_HOOK_ADDRESS.into())
+			Some(DOMAIN_HOOK_BYTES)
 		});
 		Gateway::mock_handle(move |sender, destination, msg| {
 			assert_eq!(sender, ALICE);
@@ -555,7 +721,35 @@ mod add_tranche {
 		})
 	}
 
+	#[test]
+	fn success_with_other_domain() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+			Gateway::mock_get(|domain| {
+				assert_eq!(domain, &OTHER_DOMAIN);
+				Some(OTHER_DOMAIN_HOOK_BYTES)
+			});
+			Gateway::mock_handle(|_, destination, msg| {
+				assert_eq!(destination, OTHER_DOMAIN);
+				assert!(matches!(
+					msg,
+					Message::AddTranche { hook, .. } if hook == OTHER_DOMAIN_HOOK_BYTES
+				));
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::add_tranche(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				TRANCHE_ID,
+				OTHER_DOMAIN,
+			));
+		})
+	}
+
 	mod erroring_out {
+		use cfg_types::domain_address::Domain;
+
 		use super::*;
 
 		#[test]
@@ -646,6 +840,23 @@ mod add_tranche {
 				);
 			})
 		}
+
+		#[test]
+		fn with_centrifuge_domain() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				assert_noop!(
+					LiquidityPools::add_tranche(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						Domain::Centrifuge,
+					),
+					Error::<Runtime>::InvalidDomain,
+				);
+			})
+		}
 	}
 }
 
@@ -1900,6 +2111,29 @@ mod update_tranche_hook {
 		});
 	}
 
+	#[test]
+	fn success_with_other_domain() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+			Gateway::mock_handle(|_, destination, msg| {
+				assert_eq!(destination, OTHER_DOMAIN);
+				assert!(matches!(
+					msg,
+					Message::UpdateTrancheHook { hook, .. } if hook == OTHER_DOMAIN_HOOK_BYTES
+				));
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::update_tranche_hook_raw32(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				TRANCHE_ID,
+				OTHER_DOMAIN,
+				OTHER_DOMAIN_HOOK_BYTES
+			));
+		});
+	}
+
 	mod erroring_out {
 		use cfg_types::domain_address::Domain;
 
@@ -2010,6 +2244,42 @@ mod update_tranche_hook {
 				);
 			});
 		}
+
+		#[test]
+		fn with_non_evm_domain() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				assert_noop!(
+					LiquidityPools::update_tranche_hook(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						OTHER_DOMAIN,
+						DOMAIN_HOOK_ADDRESS.into()
+					),
+					Error::<Runtime>::InvalidDomain
+				);
+			});
+		}
+
+		#[test]
+		fn with_evm_domain_raw32() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				assert_noop!(
+					LiquidityPools::update_tranche_hook_raw32(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						EVM_DOMAIN,
+						DOMAIN_HOOK_BYTES
+					),
+					Error::<Runtime>::InvalidDomain
+				);
+			});
+		}
 	}
 }
 
@@ -2110,3 +2380,319 @@ mod recover_assets {
 		}
 	}
 }
+
+mod allow_remote_call {
+	use super::*;
+
+	const CALL_INDEX: (u8, u8) = (10, 18);
+
+	fn config_mocks() {
+		Permissions::mock_has(move |scope, who, role| {
+			assert!(matches!(scope, PermissionScope::Pool(POOL_ID)));
+			match role {
+				Role::PoolRole(PoolRole::PoolAdmin) => {
+					assert_eq!(who, ALICE);
+					true
+				}
+				_ => false,
+			}
+		});
+	}
+
+	#[test]
+	fn success() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+
+			assert_ok!(LiquidityPools::allow_remote_call(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				CALL_INDEX,
+			));
+
+			assert!(AllowedRemoteCalls::<Runtime>::contains_key(
+				POOL_ID, CALL_INDEX
+			));
+
+			assert_ok!(LiquidityPools::disallow_remote_call(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				CALL_INDEX,
+			));
+
+			assert!(!AllowedRemoteCalls::<Runtime>::contains_key(
+				POOL_ID, CALL_INDEX
+			));
+		});
+	}
+
+	mod erroring_out {
+		use super::*;
+
+		#[test]
+		fn with_origin_not_admin() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+				Permissions::mock_has(|_, _, _| false);
+
+				assert_noop!(
+					LiquidityPools::allow_remote_call(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						CALL_INDEX,
+					),
+					Error::<Runtime>::NotPoolAdmin
+				);
+
+				assert_noop!(
+					LiquidityPools::disallow_remote_call(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						CALL_INDEX,
+					),
+					Error::<Runtime>::NotPoolAdmin
+				);
+			});
+		}
+	}
+}
+
+mod recovery {
+	use sp_core::{H160, H256};
+
+	use super::*;
+	use crate::{Change, PendingRecoveries, Recovery, RecoveryOf};
+
+	const HOLDER: AccountId = AccountId::new([3; 32]);
+	const REPLACEMENT: AccountId = AccountId::new([4; 32]);
+	const VETOER: AccountId = AccountId::new([5; 32]);
+	const CHANGE_ID: H256 = H256::repeat_byte(0x42);
+	const EVIDENCE: H256 = H256::repeat_byte(0x11);
+
+	fn local_recovery() -> RecoveryOf<Runtime> {
+		Recovery {
+			tranche_id: TRANCHE_ID,
+			from: DomainAddress::Centrifuge(HOLDER),
+			to: DomainAddress::Centrifuge(REPLACEMENT),
+			amount: AMOUNT,
+			evidence: EVIDENCE,
+		}
+	}
+
+	fn remote_recovery() -> RecoveryOf<Runtime> {
+		Recovery {
+			from: ALICE_EVM_DOMAIN_ADDRESS,
+			to: DomainAddress::Evm(CHAIN_ID, H160::repeat_byte(4)),
+			..local_recovery()
+		}
+	}
+
+	fn config_mocks(recovery: RecoveryOf<Runtime>) {
+		Pools::mock_pool_exists(|_| true);
+		Pools::mock_tranche_exists(|_, _| true);
+		Permissions::mock_has(move |scope, who, role| {
+			assert!(matches!(scope, PermissionScope::Pool(POOL_ID)));
+			match role {
+				Role::PoolRole(PoolRole::RecoveryAdmin) => who == ALICE,
+				Role::PoolRole(PoolRole::PoolAdmin) => who == ALICE || who == VETOER,
+				_ => false,
+			}
+		});
+		Permissions::mock_get(move |(scope, _, tranche_id)| {
+			assert!(matches!(scope, PermissionScope::Pool(POOL_ID)));
+			Some(TrancheInvestorInfo::<TrancheId>::dummy(*tranche_id))
+		});
+
+		let noted = recovery.clone();
+		ChangeGuard::mock_note(move |pool_id, change| {
+			assert_eq!(pool_id, POOL_ID);
+			assert_eq!(change, Change::Recovery(noted.clone()));
+			Ok(CHANGE_ID)
+		});
+		ChangeGuard::mock_released(move |pool_id, change_id| {
+			assert_eq!(pool_id, POOL_ID);
+			assert_eq!(change_id, CHANGE_ID);
+			Ok(Change::Recovery(recovery.clone()))
+		});
+	}
+
+	#[test]
+	fn success_with_local_holder() {
+		System::externalities().execute_with(|| {
+			config_mocks(local_recovery());
+			Tokens::mint_into(TRANCHE_CURRENCY, &HOLDER, AMOUNT).unwrap();
+
+			assert_ok!(LiquidityPools::propose_recovery(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				local_recovery(),
+			));
+			assert!(PendingRecoveries::<Runtime>::contains_key(
+				POOL_ID, CHANGE_ID
+			));
+
+			assert_ok!(LiquidityPools::execute_recovery(
+				RuntimeOrigin::signed(REPLACEMENT),
+				POOL_ID,
+				CHANGE_ID,
+			));
+
+			assert!(!PendingRecoveries::<Runtime>::contains_key(
+				POOL_ID, CHANGE_ID
+			));
+			assert_eq!(Tokens::balance(TRANCHE_CURRENCY, &HOLDER), 0);
+			assert_eq!(Tokens::balance(TRANCHE_CURRENCY, &REPLACEMENT), AMOUNT);
+		});
+	}
+
+	#[test]
+	fn success_with_remote_holder() {
+		System::externalities().execute_with(|| {
+			config_mocks(remote_recovery());
+			Gateway::mock_handle(|sender, destination, msg| {
+				assert_eq!(sender, TreasuryAccount::get());
+				assert_eq!(destination, EVM_DOMAIN);
+				assert_eq!(
+					msg,
+					Message::RecoverTrancheTokens {
+						pool_id: POOL_ID,
+						tranche_id: TRANCHE_ID,
+						from: remote_recovery().from.bytes(),
+						to: remote_recovery().to.bytes(),
+						amount: AMOUNT,
+					}
+				);
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::propose_recovery(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				remote_recovery(),
+			));
+			assert_ok!(LiquidityPools::execute_recovery(
+				RuntimeOrigin::signed(REPLACEMENT),
+				POOL_ID,
+				CHANGE_ID,
+			));
+		});
+	}
+
+	#[test]
+	fn vetoed() {
+		System::externalities().execute_with(|| {
+			config_mocks(local_recovery());
+
+			assert_ok!(LiquidityPools::propose_recovery(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				local_recovery(),
+			));
+
+			assert_noop!(
+				LiquidityPools::veto_recovery(RuntimeOrigin::signed(HOLDER), POOL_ID, CHANGE_ID),
+				Error::<Runtime>::NotPoolAdmin
+			);
+			assert_noop!(
+				LiquidityPools::veto_recovery(RuntimeOrigin::signed(ALICE), POOL_ID, CHANGE_ID),
+				Error::<Runtime>::RecoveryVetoedByProposer
+			);
+
+			ChangeGuard::mock_discard(|pool_id, change_id| {
+				assert_eq!(pool_id, POOL_ID);
+				assert_eq!(change_id, CHANGE_ID);
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::veto_recovery(
+				RuntimeOrigin::signed(VETOER),
+				POOL_ID,
+				CHANGE_ID,
+			));
+
+			assert_noop!(
+				LiquidityPools::execute_recovery(
+					RuntimeOrigin::signed(REPLACEMENT),
+					POOL_ID,
+					CHANGE_ID,
+				),
+				Error::<Runtime>::RecoveryNotFound
+			);
+		});
+	}
+
+	mod erroring_out {
+		use super::*;
+
+		#[test]
+		fn with_origin_not_recovery_admin() {
+			System::externalities().execute_with(|| {
+				config_mocks(local_recovery());
+
+				assert_noop!(
+					LiquidityPools::propose_recovery(
+						RuntimeOrigin::signed(HOLDER),
+						POOL_ID,
+						local_recovery(),
+					),
+					Error::<Runtime>::NotRecoveryAdmin
+				);
+			});
+		}
+
+		#[test]
+		fn without_evidence() {
+			System::externalities().execute_with(|| {
+				config_mocks(local_recovery());
+
+				assert_noop!(
+					LiquidityPools::propose_recovery(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						Recovery {
+							evidence: H256::zero(),
+							..local_recovery()
+						},
+					),
+					Error::<Runtime>::MissingRecoveryEvidence
+				);
+			});
+		}
+
+		#[test]
+		fn with_replacement_on_other_domain() {
+			System::externalities().execute_with(|| {
+				config_mocks(local_recovery());
+
+				assert_noop!(
+					LiquidityPools::propose_recovery(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						Recovery {
+							to: ALICE_EVM_DOMAIN_ADDRESS,
+							..local_recovery()
+						},
+					),
+					Error::<Runtime>::RecoveryDomainMismatch
+				);
+			});
+		}
+
+		#[test]
+		fn with_replacement_not_investor() {
+			System::externalities().execute_with(|| {
+				config_mocks(local_recovery());
+				Permissions::mock_get(|_| None);
+
+				assert_noop!(
+					LiquidityPools::propose_recovery(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						local_recovery(),
+					),
+					Error::<Runtime>::UnauthorizedTransfer
+				);
+			});
+		}
+	}
+}
@@ -791,3 +791,234 @@ mod handle_cancel_redeem_order {
 		}
 	}
 }
+
+mod handle_update_domain_gas_price {
+	use super::*;
+
+	#[test]
+	fn success() {
+		System::externalities().execute_with(|| {
+			Gateway::mock_update_gas_price(|domain, price| {
+				assert_eq!(*domain, EVM_DOMAIN);
+				assert_eq!(price, 1000.into());
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::handle(
+				EVM_DOMAIN,
+				Message::UpdateDomainGasPrice { price: 1000 },
+			));
+		});
+	}
+}
+
+mod handle_execute_call {
+	use cfg_primitives::PoolId;
+	use cfg_traits::liquidity_pools::LpMessageBatch;
+	use frame_support::dispatch::GetDispatchInfo;
+	use parity_scale_codec::Encode;
+
+	use super::*;
+	use crate::{AllowedRemoteCalls, OutboundFeeCurrencies, OutboundFeeCurrency, RemoteCall};
+
+	fn set_outbound_fee_currency() -> RuntimeCall {
+		RuntimeCall::LiquidityPools(crate::Call::set_outbound_fee_currency {
+			fee_currency: OutboundFeeCurrency::Transferred,
+		})
+	}
+
+	fn allow_remote_call(pool_id: PoolId) -> RuntimeCall {
+		RuntimeCall::LiquidityPools(crate::Call::allow_remote_call {
+			pool_id,
+			call_index: (0, 0),
+		})
+	}
+
+	fn call_index(call: &RuntimeCall) -> (u8, u8) {
+		let encoded = call.encode();
+		(encoded[0], encoded[1])
+	}
+
+	fn remote_call() -> RemoteCall {
+		RemoteCall::try_from(set_outbound_fee_currency().encode()).unwrap()
+	}
+
+	fn message(call: RemoteCall) -> Message {
+		Message::ExecuteCall {
+			pool_id: POOL_ID,
+			admin: ALICE_EVM_DOMAIN_ADDRESS.bytes(),
+			call,
+		}
+	}
+
+	fn config_mocks() {
+		Permissions::mock_has(move |scope, who, role| {
+			assert!(matches!(scope, PermissionScope::Pool(POOL_ID)));
+			match role {
+				Role::PoolRole(PoolRole::PoolAdmin) => {
+					assert_eq!(who, ALICE_EVM_DOMAIN_ADDRESS.account());
+					true
+				}
+				_ => false,
+			}
+		});
+	}
+
+	#[test]
+	fn success() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+			AllowedRemoteCalls::<Runtime>::insert(
+				POOL_ID,
+				call_index(&set_outbound_fee_currency()),
+				(),
+			);
+
+			assert_ok!(LiquidityPools::handle(EVM_DOMAIN, message(remote_call())));
+
+			assert_eq!(
+				OutboundFeeCurrencies::<Runtime>::get(ALICE_EVM_DOMAIN_ADDRESS.account()),
+				OutboundFeeCurrency::Transferred
+			);
+		});
+	}
+
+	#[test]
+	fn charges_call_weight() {
+		System::externalities().execute_with(|| {
+			assert_eq!(
+				LiquidityPools::extra_weight(&message(remote_call())),
+				set_outbound_fee_currency().get_dispatch_info().weight
+			);
+
+			let mut batch = message(remote_call());
+			batch.pack_with(message(remote_call())).unwrap();
+
+			assert_eq!(
+				LiquidityPools::extra_weight(&batch),
+				set_outbound_fee_currency()
+					.get_dispatch_info()
+					.weight
+					.saturating_mul(2)
+			);
+		});
+	}
+
+	#[test]
+	fn success_with_call_for_pool() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+			let call = allow_remote_call(POOL_ID);
+			AllowedRemoteCalls::<Runtime>::insert(POOL_ID, call_index(&call), ());
+
+			assert_ok!(LiquidityPools::handle(
+				EVM_DOMAIN,
+				message(RemoteCall::try_from(call.encode()).unwrap())
+			));
+
+			assert!(AllowedRemoteCalls::<Runtime>::contains_key(POOL_ID, (0, 0)));
+		});
+	}
+
+	mod erroring_out {
+		use super::*;
+
+		#[test]
+		fn with_call_for_other_pool() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+				let call = allow_remote_call(POOL_ID + 1);
+				AllowedRemoteCalls::<Runtime>::insert(POOL_ID, call_index(&call), ());
+
+				assert_noop!(
+					LiquidityPools::handle(
+						EVM_DOMAIN,
+						message(RemoteCall::try_from(call.encode()).unwrap())
+					),
+					Error::<Runtime>::RemoteCallNotAllowed,
+				);
+			});
+		}
+
+		#[test]
+		fn with_filtered_call() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+				let call = RuntimeCall::LiquidityPools(crate::Call::disallow_remote_call {
+					pool_id: POOL_ID,
+					call_index: (0, 0),
+				});
+				AllowedRemoteCalls::<Runtime>::insert(POOL_ID, call_index(&call), ());
+
+				assert_noop!(
+					LiquidityPools::handle(
+						EVM_DOMAIN,
+						message(RemoteCall::try_from(call.encode()).unwrap())
+					),
+					Error::<Runtime>::RemoteCallNotAllowed,
+				);
+			});
+		}
+
+		#[test]
+		fn with_admin_not_pool_admin() {
+			System::externalities().execute_with(|| {
+				Permissions::mock_has(|_, _, _| false);
+				AllowedRemoteCalls::<Runtime>::insert(
+					POOL_ID,
+					call_index(&set_outbound_fee_currency()),
+					(),
+				);
+
+				assert_noop!(
+					LiquidityPools::handle(EVM_DOMAIN, message(remote_call())),
+					Error::<Runtime>::NotPoolAdmin,
+				);
+			});
+		}
+
+		#[test]
+		fn with_call_not_allowed() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				assert_noop!(
+					LiquidityPools::handle(EVM_DOMAIN, message(remote_call())),
+					Error::<Runtime>::RemoteCallNotAllowed,
+				);
+			});
+		}
+
+		#[test]
+		fn with_invalid_call() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+				AllowedRemoteCalls::<Runtime>::insert(
+					POOL_ID,
+					call_index(&set_outbound_fee_currency()),
+					(),
+				);
+
+				let (pallet_index, call_index) = call_index(&set_outbound_fee_currency());
+				let call = RemoteCall::try_from(vec![pallet_index, call_index, 0xff]).unwrap();
+
+				assert_noop!(
+					LiquidityPools::handle(EVM_DOMAIN, message(call)),
+					Error::<Runtime>::InvalidRemoteCall,
+				);
+			});
+		}
+
+		#[test]
+		fn with_empty_call() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				assert_noop!(
+					LiquidityPools::handle(EVM_DOMAIN, message(RemoteCall::default())),
+					Error::<Runtime>::InvalidRemoteCall,
+				);
+			});
+		}
+	}
+}
@@ -243,6 +243,15 @@ impl<T: Config> ActiveLoan<T> {
 		}
 	}
 
+	/// The oracle price used by an external loan is frozen by a deviation
+	/// breach and no longer follows the oracle values.
+	pub fn has_frozen_price(&self, pool_id: T::PoolId) -> bool {
+		match &self.pricing {
+			ActivePricing::Internal(_) => false,
+			ActivePricing::External(inner) => inner.has_frozen_price(pool_id),
+		}
+	}
+
 	pub fn principal(&self) -> Result<T::Balance, DispatchError> {
 		Ok(self
 			.total_borrowed
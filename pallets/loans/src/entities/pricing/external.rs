@@ -147,6 +147,12 @@ impl<T: Config> ExternalActivePricing<T> {
 		T::PriceRegistry::get(&self.info.price_id, &pool_id).is_ok()
 	}
 
+	/// The registered price is frozen, so it no longer follows the oracle
+	/// values until the freeze is lifted.
+	pub fn has_frozen_price(&self, pool_id: T::PoolId) -> bool {
+		T::PriceRegistry::is_frozen(&self.info.price_id, &pool_id)
+	}
+
 	pub fn last_updated(&self, pool_id: T::PoolId) -> Seconds {
 		match T::PriceRegistry::get(&self.info.price_id, &pool_id) {
 			Ok((_, timestamp)) => timestamp.into_seconds(),
@@ -1263,6 +1263,17 @@ pub mod pallet {
 				.map(|(_, loan)| loan.expected_cashflows())
 				.ok_or(Error::<T>::LoanNotActiveOrNotFound)?
 		}
+
+		pub fn has_frozen_price(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+		) -> Result<bool, DispatchError> {
+			ActiveLoans::<T>::get(pool_id)
+				.into_iter()
+				.find(|(id, _)| *id == loan_id)
+				.map(|(_, loan)| loan.has_frozen_price(pool_id))
+				.ok_or(Error::<T>::LoanNotActiveOrNotFound.into())
+		}
 	}
 
 	// TODO: This implementation can be cleaned once #908 be solved
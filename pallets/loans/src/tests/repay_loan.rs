@@ -918,6 +918,7 @@ fn with_external_pricing() {
 
 		let amount = ExternalAmount::new(Quantity::one(), PRICE_VALUE);
 		config_mocks(amount.balance().unwrap());
+		MockPrices::mock_is_frozen(|_, _| false);
 
 		let repay_amount = RepaidInput {
 			principal: PrincipalInput::External(amount),
@@ -979,6 +980,27 @@ fn with_external_pricing() {
 	});
 }
 
+#[test]
+fn with_frozen_external_price() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_external_loan());
+
+		let amount = ExternalAmount::new(QUANTITY, PRICE_VALUE);
+		util::borrow_loan(loan_id, PrincipalInput::External(amount));
+
+		config_mocks(0);
+		MockPrices::mock_is_frozen(|id, pool_id| {
+			assert_eq!(*pool_id, POOL_A);
+			*id == REGISTER_PRICE_ID
+		});
+
+		let info = ActiveLoanInfo::try_from((POOL_A, util::get_loan(loan_id))).unwrap();
+
+		assert_eq!(Loans::has_frozen_price(POOL_A, loan_id), Ok(true));
+		assert_eq!(info.current_price, Some(PRICE_VALUE));
+	});
+}
+
 #[test]
 fn decrease_debt_does_not_deposit() {
 	new_test_ext().execute_with(|| {
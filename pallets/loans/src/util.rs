@@ -12,7 +12,7 @@
 // GNU General Public License for more details.
 
 use cfg_traits::changes::ChangeGuard;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::marker::PhantomData;
 
 use crate::{entities::changes::Change, pallet::Config};
@@ -35,4 +35,8 @@ impl<T: Config> ChangeGuard for NoLoanChanges<T> {
 	fn released(_: T::PoolId, _: Self::ChangeId) -> Result<Self::Change, DispatchError> {
 		Err(DEFAULT_CHANGE_ERR)
 	}
+
+	fn discard(_: T::PoolId, _: Self::ChangeId) -> DispatchResult {
+		Err(DEFAULT_CHANGE_ERR)
+	}
 }
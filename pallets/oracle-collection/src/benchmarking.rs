@@ -3,10 +3,11 @@ use cfg_traits::{
 };
 use frame_benchmarking::{v2::*, whitelisted_caller};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Zero;
 
 use crate::{
-	pallet::{Call, Collection, Config, Pallet},
-	types::{Change, CollectionInfo},
+	pallet::{Call, Collection, Config, FrozenKeys, Pallet},
+	types::{AggregationStrategy, Change, CollectionInfo, FrozenKey},
 };
 
 #[cfg(test)]
@@ -15,6 +16,7 @@ fn init_mocks() {
 
 	MockIsAdmin::mock_check(|_| true);
 	MockProvider::mock_get(|_, _| Ok(Some((Default::default(), Default::default()))));
+	MockProvider::mock_history(|_, _| Ok(vec![(Default::default(), Default::default())]));
 	MockChangeGuard::mock_note(|_, change| {
 		MockChangeGuard::mock_released(move |_, _| Ok(change.clone()));
 		Ok(Default::default())
@@ -34,17 +36,37 @@ mod util {
 	{
 		let feeders = crate::util::feeders_from(feeders).unwrap();
 
-		// Emulate to note a change to later apply it
+		// Emulate to note a change to later apply it.
+		// TWAP is the most expensive aggregation, it also reads the history.
+		// Keeping the price history also writes it on each update.
 		T::ChangeGuard::note(
 			T::CollectionId::default(),
 			Change::<T>::CollectionInfo(CollectionInfo {
 				feeders,
+				aggregation: AggregationStrategy::Twap {
+					window: T::Timestamp::zero(),
+				},
+				keep_history: true,
 				..Default::default()
 			})
 			.into(),
 		)
 		.unwrap()
 	}
+
+	pub fn freeze_key<T: Config>(key: T::OracleKey)
+	where
+		T::CollectionId: Default,
+	{
+		FrozenKeys::<T>::insert(
+			T::CollectionId::default(),
+			key,
+			FrozenKey {
+				last_good: Default::default(),
+				rejected: Default::default(),
+			},
+		);
+	}
 }
 
 #[benchmarks(
@@ -154,5 +176,72 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn propose_unfreeze_key() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let admin: T::AccountId = whitelisted_caller();
+
+		T::ChangeGuard::bench_create_pool(T::CollectionId::default(), &admin);
+
+		let key = T::OracleKey::default();
+		util::freeze_key::<T>(key);
+
+		#[extrinsic_call]
+		propose_unfreeze_key(RawOrigin::Signed(admin), T::CollectionId::default(), key);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn apply_unfreeze_key(n: Linear<1, 5>) -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let admin: T::AccountId = whitelisted_caller();
+
+		T::ChangeGuard::bench_create_pool(T::CollectionId::default(), &admin);
+
+		let feeder_ids = (0..n).map(Into::<T::FeederId>::into);
+		let feeders = crate::util::feeders_from::<_, T::MaxFeedersPerKey>(feeder_ids)?;
+
+		let key = T::OracleKey::default();
+		for feeder in feeders.iter() {
+			T::OracleProvider::set(
+				&(feeder.clone(), T::CollectionId::default()),
+				&key,
+				Default::default(),
+			);
+		}
+
+		Pallet::<T>::apply_update_collection_info(
+			RawOrigin::Signed(admin.clone()).into(),
+			T::CollectionId::default(),
+			util::emulate_collection_id_change::<T>(feeders),
+		)?;
+
+		util::freeze_key::<T>(key);
+
+		let change_id = T::ChangeGuard::note(
+			T::CollectionId::default(),
+			Change::<T>::UnfreezeKey(key).into(),
+		)?;
+
+		#[extrinsic_call]
+		apply_unfreeze_key(
+			RawOrigin::Signed(admin),
+			T::CollectionId::default(),
+			change_id,
+		);
+
+		assert!(!FrozenKeys::<T>::contains_key(
+			T::CollectionId::default(),
+			key
+		));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -21,6 +21,23 @@
 //! configuration of the collection. The resulting collection is optimized to
 //! iterate through all values in just one read.
 //!
+//! Each collection chooses how the values of its feeders are aggregated:
+//! - Median of the last values.
+//! - Time-weighted average (TWAP) of the feeders' value history over a window.
+//! - Mean of the last values after trimming the outliers.
+//! - Median of the last values weighted per feeder.
+//!
+//! Each update of a collection is a round where the feeders are rated by how
+//! far their values are from the median of the values fed for each key.
+//!
+//! A collection can opt-in to keep the price history of its keys. Each update
+//! records the aggregated values into bounded periods (e.g. days) of open,
+//! high, low and close values, being the close the last value of the period.
+//!
+//! A collection can bound how much an aggregated value can deviate from the
+//! previous one. A breach freezes the key, which keeps its last good value
+//! until the admin confirms unfreezing it through the change guard.
+//!
 //! # Assumptions
 //!
 //! This pallet is not fed with external values, you need to configure a
@@ -37,6 +54,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
 pub use pallet::*;
@@ -44,21 +62,30 @@ pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use cfg_traits::{changes::ChangeGuard, data::DataRegistry, PreConditions, ValueProvider};
+	use cfg_traits::{
+		changes::ChangeGuard, data::DataRegistry, FeederAccuracy, PreConditions, ValueProvider,
+	};
 	use frame_support::{
 		pallet_prelude::*, storage::bounded_btree_map::BoundedBTreeMap, traits::Time,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{EnsureAddAssign, EnsureSub, EnsureSubAssign, Zero};
+	use sp_runtime::{
+		traits::{
+			AtLeast32BitUnsigned, EnsureAddAssign, EnsureSub, EnsureSubAssign, Saturating, Zero,
+		},
+		Perbill,
+	};
 	use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 	use crate::{
-		traits::AggregationProvider,
-		types::{self, CachedCollection, Change, OracleValuePair},
+		types::{
+			self, AggregationStrategy, CachedCollection, Change, FrozenKey, Ohlc, OracleValuePair,
+		},
+		util,
 		weights::WeightInfo,
 	};
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -81,10 +108,16 @@ pub mod pallet {
 		type OracleKey: Parameter + Member + Copy + MaxEncodedLen + Ord;
 
 		/// Represent an oracle value
-		type OracleValue: Parameter + Member + Copy + MaxEncodedLen + Ord;
+		type OracleValue: Parameter + Member + Copy + MaxEncodedLen + Ord + AtLeast32BitUnsigned;
 
 		/// Represent the time moment when the value was fed
-		type Timestamp: Parameter + Member + Copy + MaxEncodedLen + Ord + EnsureSub;
+		type Timestamp: Parameter
+			+ Member
+			+ Copy
+			+ MaxEncodedLen
+			+ Ord
+			+ EnsureSub
+			+ AtLeast32BitUnsigned;
 
 		/// A way to obtain the current time
 		type Time: Time<Moment = Self::Timestamp>;
@@ -96,10 +129,6 @@ pub mod pallet {
 			Value = OracleValuePair<Self>,
 		>;
 
-		/// A way to perform aggregations from a list of feeders feeding the
-		/// same keys
-		type AggregationProvider: AggregationProvider<Self::OracleValue, Self::Timestamp>;
-
 		/// Used to verify collection admin permissions
 		type IsAdmin: PreConditions<(Self::AccountId, Self::CollectionId), Result = bool>;
 
@@ -119,6 +148,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxFeedersPerKey: Get<u32> + Parameter;
 
+		/// Max number of collections a key can be registered in
+		#[pallet::constant]
+		type MaxCollectionsPerKey: Get<u32>;
+
+		/// Duration of each period of the price history
+		#[pallet::constant]
+		type HistoryPeriod: Get<Self::Timestamp>;
+
+		/// Max number of periods kept in the price history of a key.
+		/// Older periods are discarded.
+		#[pallet::constant]
+		type MaxHistoryPeriods: Get<u32>;
+
 		/// The weight information for this pallet extrinsics.
 		type WeightInfo: WeightInfo;
 	}
@@ -146,11 +188,53 @@ pub mod pallet {
 	pub(crate) type CollectionInfo<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::CollectionId, types::CollectionInfo<T>, ValueQuery>;
 
+	/// Store the collections each key is registered in, used to rate the
+	/// values fed for a key against the ones of its collections
+	#[pallet::storage]
+	pub(crate) type KeyCollections<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::OracleKey,
+		BoundedVec<T::CollectionId, T::MaxCollectionsPerKey>,
+		ValueQuery,
+	>;
+
 	/// Store all oracle values indexed by feeder
 	#[pallet::storage]
 	pub(crate) type CollectionKeyCount<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, ValueQuery>;
 
+	/// Store the keys frozen because their aggregated value breached the
+	/// maximum deviation of the collection
+	#[pallet::storage]
+	pub(crate) type FrozenKeys<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::OracleKey,
+		FrozenKey<T>,
+	>;
+
+	/// Store the price history of the keys of the collections that keep it,
+	/// one period per slot. Slots are reused once `MaxHistoryPeriods` is
+	/// reached, discarding the oldest period.
+	#[pallet::storage]
+	pub(crate) type PriceHistory<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(T::CollectionId, T::OracleKey),
+		Twox64Concat,
+		u32,
+		Ohlc<T>,
+	>;
+
+	/// Store the index of the last period recorded in the price history of a
+	/// key, whose slot is the index modulo `MaxHistoryPeriods`
+	#[pallet::storage]
+	pub(crate) type LastHistoryPeriod<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::CollectionId, Blake2_128Concat, T::OracleKey, u32>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -170,6 +254,16 @@ pub mod pallet {
 			collection_id: T::CollectionId,
 			keys_updated: u32,
 		},
+		KeyFrozen {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			last_good: OracleValuePair<T>,
+			rejected: OracleValuePair<T>,
+		},
+		KeyUnfrozen {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+		},
 	}
 
 	#[pallet::error]
@@ -194,6 +288,12 @@ pub mod pallet {
 
 		/// The amount of feeders for a key is not enough
 		NotEnoughFeeders,
+
+		/// The key is not frozen
+		KeyNotFrozen,
+
+		/// The key is registered in the max number of collections
+		MaxCollectionsPerKey,
 	}
 
 	#[pallet::call]
@@ -234,9 +334,10 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_signed(origin)?;
 
-			let Change::CollectionInfo(info) = T::ChangeGuard::released(collection_id, change_id)?
-				.try_into()
-				.map_err(|_| Error::<T>::NoOracleCollectionChangeId)?;
+			let Change::CollectionInfo(info) = Self::released_change(collection_id, change_id)?
+			else {
+				Err(Error::<T>::NoOracleCollectionChangeId)?
+			};
 
 			CollectionInfo::<T>::insert(collection_id, info.clone());
 			Collection::<T>::remove(collection_id);
@@ -253,10 +354,14 @@ pub mod pallet {
 		/// process.
 		///
 		/// This call is permissionless.
+		// NOTE: The benchmark does not cover yet the frozen key and the last
+		//       price history lookups done per key.
 		#[pallet::weight(T::WeightInfo::update_collection(
 			T::MaxFeedersPerKey::get(),
 			T::MaxCollectionSize::get(),
-		))]
+		).saturating_add(T::DbWeight::get().reads(
+			u64::from(T::MaxCollectionSize::get()).saturating_mul(3),
+		)))]
 		#[pallet::call_index(2)]
 		pub fn update_collection(
 			origin: OriginFor<T>,
@@ -264,18 +369,27 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_signed(origin)?;
 
-			let mut older_value_timestamp = T::Time::now();
+			let now = T::Time::now();
+			let mut older_value_timestamp = now;
+
+			let previous = Collection::<T>::get(collection_id);
+			let info = CollectionInfo::<T>::get(collection_id);
 
 			let values = Keys::<T>::iter_key_prefix(collection_id)
 				.filter_map(|key| {
-					let value = <Self as DataRegistry<T::OracleKey, T::CollectionId>>::get(
+					let value = Self::guarded_value(
 						&key,
 						&collection_id,
+						previous.content.get(&key),
+						info.max_deviation,
 					);
 
 					match value {
-						Ok((value, timestamp)) => {
-							if timestamp < older_value_timestamp {
+						Ok(((value, timestamp), frozen)) => {
+							// Frozen keys keep their last good value, which
+							// should not outdate the whole collection. They
+							// are exposed through `DataRegistry::is_frozen`.
+							if !frozen && timestamp < older_value_timestamp {
 								older_value_timestamp = timestamp;
 							}
 							Some(Ok((key, (value, timestamp))))
@@ -289,6 +403,12 @@ pub mod pallet {
 			let collection =
 				BoundedBTreeMap::try_from(values).map_err(|()| Error::<T>::MaxCollectionSize)?;
 
+			if info.keep_history {
+				for (key, value) in collection.iter() {
+					Self::note_history(collection_id, *key, *value);
+				}
+			}
+
 			let len = collection.len();
 
 			Collection::<T>::insert(
@@ -296,7 +416,7 @@ pub mod pallet {
 				CachedCollection {
 					content: collection,
 					older_value_timestamp,
-					last_updated: T::Time::now(),
+					last_updated: now,
 				},
 			);
 
@@ -307,6 +427,74 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Propose to unfreeze a key frozen by a deviation breach.
+		/// The key will only be unfrozen once [`Pallet::apply_unfreeze_key`]
+		/// is called.
+		#[pallet::weight(T::WeightInfo::propose_unfreeze_key())]
+		#[pallet::call_index(3)]
+		pub fn propose_unfreeze_key(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsAdmin::check((who, collection_id)),
+				Error::<T>::IsNotAdmin
+			);
+
+			ensure!(
+				FrozenKeys::<T>::contains_key(collection_id, key),
+				Error::<T>::KeyNotFrozen
+			);
+
+			T::ChangeGuard::note(collection_id, Change::UnfreezeKey(key).into())?;
+
+			Ok(())
+		}
+
+		/// Apply an unfreeze previously proposed by
+		/// [`Pallet::propose_unfreeze_key`] if the conditions to get it ready
+		/// are fullfilled. The current aggregated value of the key is
+		/// accepted as the new reference value.
+		///
+		/// This call is permissionless.
+		#[pallet::weight(T::WeightInfo::apply_unfreeze_key(T::MaxFeedersPerKey::get()))]
+		#[pallet::call_index(4)]
+		pub fn apply_unfreeze_key(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let Change::UnfreezeKey(key) = Self::released_change(collection_id, change_id)? else {
+				Err(Error::<T>::NoOracleCollectionChangeId)?
+			};
+
+			FrozenKeys::<T>::take(collection_id, key).ok_or(Error::<T>::KeyNotFrozen)?;
+
+			if let Ok(value) = Self::aggregated_value(&key, &collection_id) {
+				Collection::<T>::try_mutate(collection_id, |collection| {
+					collection
+						.content
+						.try_insert(key, value)
+						.map_err(|_| Error::<T>::MaxCollectionSize)?;
+
+					if value.1 < collection.older_value_timestamp {
+						collection.older_value_timestamp = value.1;
+					}
+
+					Ok::<_, DispatchError>(())
+				})?;
+			}
+
+			Self::deposit_event(Event::<T>::KeyUnfrozen { collection_id, key });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> DataRegistry<T::OracleKey, T::CollectionId> for Pallet<T> {
@@ -317,35 +505,14 @@ pub mod pallet {
 			key: &T::OracleKey,
 			collection_id: &T::CollectionId,
 		) -> Result<Self::Data, DispatchError> {
-			let info = CollectionInfo::<T>::get(collection_id);
-
-			let fed_values = info
-				.feeders
-				.into_iter()
-				.filter_map(|feeder| {
-					T::OracleProvider::get(&(feeder, *collection_id), key).transpose()
-				})
-				.collect::<Result<Vec<_>, _>>()?;
-
-			if fed_values.len() < (info.min_feeders as usize) {
-				Err(Error::<T>::NotEnoughFeeders)?
+			match FrozenKeys::<T>::get(collection_id, key) {
+				Some(frozen) => Ok(frozen.last_good),
+				None => Self::aggregated_value(key, collection_id),
 			}
+		}
 
-			let updated_fed_values = fed_values
-				.into_iter()
-				.filter(|(_, timestamp)| {
-					Self::ensure_valid_timestamp(collection_id, *timestamp).is_ok()
-				})
-				.collect::<Vec<_>>();
-
-			if updated_fed_values.len() < (info.min_feeders as usize) {
-				Err(Error::<T>::OracleValueOutdated)?
-			}
-
-			let (value, timestamp) = T::AggregationProvider::aggregate(updated_fed_values)
-				.ok_or(Error::<T>::KeyNotInCollection)?;
-
-			Ok((value, timestamp))
+		fn is_frozen(key: &T::OracleKey, collection_id: &T::CollectionId) -> bool {
+			FrozenKeys::<T>::contains_key(collection_id, key)
 		}
 
 		fn collection(collection_id: &T::CollectionId) -> Result<Self::Collection, DispatchError> {
@@ -371,6 +538,12 @@ pub mod pallet {
 
 						Ok(())
 					})?;
+
+					KeyCollections::<T>::try_mutate(key, |collections| {
+						collections
+							.try_push(*collection_id)
+							.map_err(|_| Error::<T>::MaxCollectionsPerKey)
+					})?;
 				}
 
 				usage_refs.ensure_add_assign(1)?;
@@ -407,6 +580,22 @@ pub mod pallet {
 						},
 					)?;
 
+					let _ = PriceHistory::<T>::clear_prefix(
+						(*collection_id, *key),
+						T::MaxHistoryPeriods::get(),
+						None,
+					);
+					LastHistoryPeriod::<T>::remove(collection_id, key);
+
+					KeyCollections::<T>::mutate_exists(key, |maybe_collections| {
+						if let Some(collections) = maybe_collections {
+							collections.retain(|id| id != collection_id);
+							if collections.is_empty() {
+								*maybe_collections = None;
+							}
+						}
+					});
+
 					*maybe_info = None;
 				}
 
@@ -415,7 +604,264 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> FeederAccuracy<T::FeederId, T::OracleKey> for Pallet<T> {
+		/// Rates the value of a feeder against the cached value of the first
+		/// collection the key is registered in and the feeder feeds.
+		fn deviation(feeder: &T::FeederId, key: &T::OracleKey) -> Option<Perbill> {
+			let collection_id = KeyCollections::<T>::get(key)
+				.into_iter()
+				.find(|id| CollectionInfo::<T>::get(id).feeders.contains(feeder))?;
+
+			let (reference, _) = *Collection::<T>::get(collection_id).content.get(key)?;
+			let (value, _) =
+				T::OracleProvider::get(&(feeder.clone(), collection_id), key).ok()??;
+
+			Some(util::deviation(reference, value))
+		}
+	}
+
 	impl<T: Config> Pallet<T> {
+		/// Aggregates the values fed for a key, without the deviation guard.
+		fn aggregated_value(
+			key: &T::OracleKey,
+			collection_id: &T::CollectionId,
+		) -> Result<OracleValuePair<T>, DispatchError> {
+			let info = CollectionInfo::<T>::get(collection_id);
+			let updated_fed_values = Self::updated_fed_values(&info, key, collection_id)?;
+
+			let (value, timestamp) =
+				Self::aggregate(&info.aggregation, collection_id, key, updated_fed_values)?
+					.ok_or(Error::<T>::KeyNotInCollection)?;
+
+			Ok((value, timestamp))
+		}
+
+		/// Returns the values fed for a key that are not outdated, ensuring
+		/// there are enough of them.
+		fn updated_fed_values(
+			info: &types::CollectionInfo<T>,
+			key: &T::OracleKey,
+			collection_id: &T::CollectionId,
+		) -> Result<Vec<(T::FeederId, OracleValuePair<T>)>, DispatchError> {
+			let fed_values = info
+				.feeders
+				.iter()
+				.filter_map(|feeder| {
+					T::OracleProvider::get(&(feeder.clone(), *collection_id), key)
+						.map(|fed_value| fed_value.map(|fed_value| (feeder.clone(), fed_value)))
+						.transpose()
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+
+			if fed_values.len() < (info.min_feeders as usize) {
+				Err(Error::<T>::NotEnoughFeeders)?
+			}
+
+			let updated_fed_values = fed_values
+				.into_iter()
+				.filter(|(_, (_, timestamp))| {
+					Self::ensure_valid_timestamp(collection_id, *timestamp).is_ok()
+				})
+				.collect::<Vec<_>>();
+
+			if updated_fed_values.len() < (info.min_feeders as usize) {
+				Err(Error::<T>::OracleValueOutdated)?
+			}
+
+			Ok(updated_fed_values)
+		}
+
+		/// Returns the aggregated value of a key and whether the key is frozen.
+		/// If the key is frozen, or the value deviates from the previous one
+		/// more than the collection allows, the last good value is returned
+		/// instead and the key remains or gets frozen. Without a previous
+		/// value in the collection, the last one of the price history is used.
+		fn guarded_value(
+			key: &T::OracleKey,
+			collection_id: &T::CollectionId,
+			previous: Option<&OracleValuePair<T>>,
+			max_deviation: Option<Perbill>,
+		) -> Result<(OracleValuePair<T>, bool), DispatchError> {
+			if let Some(frozen) = FrozenKeys::<T>::get(collection_id, key) {
+				return Ok((frozen.last_good, true));
+			}
+
+			let value = Self::aggregated_value(key, collection_id)?;
+
+			if let Some(max_deviation) = max_deviation {
+				let previous = previous
+					.copied()
+					.or_else(|| Self::last_history_value(collection_id, key));
+
+				if let Some(previous) = previous {
+					if util::deviation(previous.0, value.0) > max_deviation {
+						FrozenKeys::<T>::insert(
+							collection_id,
+							key,
+							FrozenKey {
+								last_good: previous,
+								rejected: value,
+							},
+						);
+
+						Self::deposit_event(Event::<T>::KeyFrozen {
+							collection_id: *collection_id,
+							key: *key,
+							last_good: previous,
+							rejected: value,
+						});
+
+						return Ok((previous, true));
+					}
+				}
+			}
+
+			Ok((value, false))
+		}
+
+		/// Returns the last value recorded in the price history of a key
+		fn last_history_value(
+			collection_id: &T::CollectionId,
+			key: &T::OracleKey,
+		) -> Option<OracleValuePair<T>> {
+			let max_periods = T::MaxHistoryPeriods::get();
+			if max_periods.is_zero() {
+				return None;
+			}
+
+			let index = LastHistoryPeriod::<T>::get(collection_id, key)?;
+			PriceHistory::<T>::get((*collection_id, *key), index % max_periods)
+				.map(|ohlc| ohlc.close)
+		}
+
+		/// Records a value into the period of the price history it belongs to.
+		/// Values not newer than the last recorded one are ignored.
+		fn note_history(
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			(value, timestamp): OracleValuePair<T>,
+		) {
+			let period = T::HistoryPeriod::get();
+			let period_start = match period.is_zero() {
+				true => timestamp,
+				false => timestamp.saturating_sub(timestamp % period),
+			};
+
+			let max_periods = T::MaxHistoryPeriods::get();
+			if max_periods.is_zero() {
+				return;
+			}
+
+			let history_key = (collection_id, key);
+			let last_index = LastHistoryPeriod::<T>::get(collection_id, key);
+
+			if let Some(index) = last_index {
+				if let Some(mut last) = PriceHistory::<T>::get(history_key, index % max_periods) {
+					if timestamp <= last.close.1 {
+						return;
+					}
+
+					if last.period_start == period_start {
+						last.note(value, timestamp);
+						PriceHistory::<T>::insert(history_key, index % max_periods, last);
+						return;
+					}
+				}
+			}
+
+			let index = last_index.map_or(0, |index| index.wrapping_add(1));
+			PriceHistory::<T>::insert(
+				history_key,
+				index % max_periods,
+				Ohlc::new(period_start, value, timestamp),
+			);
+			LastHistoryPeriod::<T>::insert(collection_id, key, index);
+		}
+
+		/// Returns the price history periods of a key in a collection that
+		/// start between `from` and `to`, both included, oldest first.
+		pub fn price_history(
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			from: T::Timestamp,
+			to: T::Timestamp,
+		) -> Vec<Ohlc<T>> {
+			let mut history = PriceHistory::<T>::iter_prefix_values((collection_id, key))
+				.filter(|ohlc| ohlc.period_start >= from && ohlc.period_start <= to)
+				.collect::<Vec<_>>();
+
+			history.sort_by_key(|ohlc| ohlc.period_start);
+			history
+		}
+
+		fn released_change(
+			collection_id: T::CollectionId,
+			change_id: T::Hash,
+		) -> Result<Change<T>, DispatchError> {
+			T::ChangeGuard::released(collection_id, change_id)?
+				.try_into()
+				.map_err(|_| Error::<T>::NoOracleCollectionChangeId.into())
+		}
+
+		/// Aggregates the last values fed for a key using the strategy of the
+		/// collection. The resulting timestamp is always the median of the
+		/// timestamps of the last values.
+		fn aggregate(
+			aggregation: &AggregationStrategy<T>,
+			collection_id: &T::CollectionId,
+			key: &T::OracleKey,
+			fed_values: Vec<(T::FeederId, OracleValuePair<T>)>,
+		) -> Result<Option<OracleValuePair<T>>, DispatchError> {
+			let mut timestamps = fed_values
+				.iter()
+				.map(|(_, (_, timestamp))| *timestamp)
+				.collect::<Vec<_>>();
+
+			let Some(timestamp) = util::median(&mut timestamps).copied() else {
+				return Ok(None);
+			};
+
+			let mut values = fed_values
+				.iter()
+				.map(|(_, (value, _))| *value)
+				.collect::<Vec<_>>();
+
+			let value = match aggregation {
+				AggregationStrategy::Median => util::median(&mut values).copied(),
+				AggregationStrategy::TrimmedMean { trim } => util::trimmed_mean(&mut values, *trim),
+				AggregationStrategy::WeightedMedian { weights } => {
+					let mut weighted_values = fed_values
+						.iter()
+						.map(|(feeder, (value, _))| {
+							(*value, weights.get(feeder).copied().unwrap_or(1))
+						})
+						.collect::<Vec<_>>();
+
+					util::weighted_median(&mut weighted_values)
+				}
+				AggregationStrategy::Twap { window } => {
+					let now = T::Time::now();
+					let from = now.saturating_sub(*window);
+
+					let mut averages = fed_values
+						.into_iter()
+						.map(|(feeder, _)| {
+							let history =
+								T::OracleProvider::history(&(feeder, *collection_id), key)?;
+							Ok(util::twap(&history, from, now))
+						})
+						.collect::<Result<Vec<_>, DispatchError>>()?
+						.into_iter()
+						.flatten()
+						.collect::<Vec<_>>();
+
+					util::median(&mut averages).copied()
+				}
+			};
+
+			Ok(value.map(|value| (value, timestamp)))
+		}
+
 		fn ensure_valid_timestamp(
 			collection_id: &T::CollectionId,
 			timestamp: T::Timestamp,
@@ -479,13 +925,43 @@ pub mod types {
 		traits::Time,
 		RuntimeDebugNoBound,
 	};
-	use sp_runtime::RuntimeDebug;
+	use sp_runtime::{Perbill, RuntimeDebug};
 	use sp_std::vec::Vec;
 
 	use crate::pallet::{Config, Error};
 
 	pub type OracleValuePair<T> = (<T as Config>::OracleValue, <T as Config>::Timestamp);
 
+	/// Strategy used to aggregate the values of the feeders of a collection
+	#[derive(
+		Encode, Decode, PartialEq, Eq, Clone, TypeInfo, RuntimeDebugNoBound, MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub enum AggregationStrategy<T: Config> {
+		/// Median of the last values.
+		Median,
+
+		/// Median of the time-weighted average of each feeder, computed from
+		/// the history of values fed during the last `window`.
+		Twap { window: T::Timestamp },
+
+		/// Mean of the last values after discarding the `trim` proportion of
+		/// the lowest values and the same proportion of the highest ones.
+		TrimmedMean { trim: Perbill },
+
+		/// Median of the last values, where each value counts as many times as
+		/// the weight of its feeder. Feeders without a weight count as 1.
+		WeightedMedian {
+			weights: BoundedBTreeMap<T::FeederId, u32, T::MaxFeedersPerKey>,
+		},
+	}
+
+	impl<T: Config> Default for AggregationStrategy<T> {
+		fn default() -> Self {
+			Self::Median
+		}
+	}
+
 	/// Information of a collection
 	#[derive(
 		Encode, Decode, PartialEq, Eq, Clone, TypeInfo, RuntimeDebugNoBound, MaxEncodedLen,
@@ -502,6 +978,16 @@ pub mod types {
 
 		/// List of feeders attached to a collection
 		pub feeders: BoundedBTreeSet<T::FeederId, T::MaxFeedersPerKey>,
+
+		/// Strategy used to aggregate the values of the feeders
+		pub aggregation: AggregationStrategy<T>,
+
+		/// Maximum deviation of an aggregated value relative to the previous
+		/// one. A breach freezes the key.
+		pub max_deviation: Option<Perbill>,
+
+		/// Whether the price history of the keys is recorded
+		pub keep_history: bool,
 	}
 
 	impl<T: Config> Default for CollectionInfo<T> {
@@ -510,10 +996,72 @@ pub mod types {
 				value_lifetime: None,
 				min_feeders: 0,
 				feeders: Default::default(),
+				aggregation: Default::default(),
+				max_deviation: None,
+				keep_history: false,
 			}
 		}
 	}
 
+	/// A key frozen by a deviation breach
+	#[derive(
+		Encode, Decode, PartialEq, Eq, Clone, TypeInfo, RuntimeDebugNoBound, MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct FrozenKey<T: Config> {
+		/// Last value that did not breach the deviation, used while frozen
+		pub last_good: OracleValuePair<T>,
+
+		/// Value that breached the deviation
+		pub rejected: OracleValuePair<T>,
+	}
+
+	/// Open, high, low and close values of a key during a period of the
+	/// price history
+	#[derive(
+		Encode, Decode, PartialEq, Eq, Clone, TypeInfo, RuntimeDebugNoBound, MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Ohlc<T: Config> {
+		/// Moment when the period starts
+		pub period_start: T::Timestamp,
+
+		/// First value of the period
+		pub open: T::OracleValue,
+
+		/// Highest value of the period
+		pub high: T::OracleValue,
+
+		/// Lowest value of the period
+		pub low: T::OracleValue,
+
+		/// Last value of the period with its timestamp
+		pub close: OracleValuePair<T>,
+	}
+
+	impl<T: Config> Ohlc<T> {
+		pub fn new(
+			period_start: T::Timestamp,
+			value: T::OracleValue,
+			timestamp: T::Timestamp,
+		) -> Self {
+			Self {
+				period_start,
+				open: value,
+				high: value,
+				low: value,
+				close: (value, timestamp),
+			}
+		}
+
+		/// Notes a newer value of the period
+		pub fn note(&mut self, value: T::OracleValue, timestamp: T::Timestamp) {
+			self.high = self.high.max(value);
+			self.low = self.low.min(value);
+			self.close = (value, timestamp);
+		}
+	}
+
 	/// A collection cached in memory
 	#[derive(Encode, Decode, Clone, TypeInfo, RuntimeDebug, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
@@ -521,7 +1069,8 @@ pub mod types {
 		/// Collection data
 		pub content: BoundedBTreeMap<T::OracleKey, OracleValuePair<T>, T::MaxCollectionSize>,
 
-		/// Timestamp of the older value of the collection
+		/// Timestamp of the older value of the collection, not counting the
+		/// values of frozen keys
 		pub older_value_timestamp: T::Timestamp,
 
 		/// Last time the collection was updated
@@ -568,48 +1117,20 @@ pub mod types {
 	#[scale_info(skip_type_params(T))]
 	pub enum Change<T: Config> {
 		CollectionInfo(CollectionInfo<T>),
-	}
-}
-
-/// Traits specifically used by this pallet
-pub mod traits {
-	/// Defined an aggregation behavior
-	pub trait AggregationProvider<Value, Timestamp> {
-		fn aggregate(
-			pairs: impl IntoIterator<Item = (Value, Timestamp)>,
-		) -> Option<(Value, Timestamp)>;
+		UnfreezeKey(T::OracleKey),
 	}
 }
 
 /// Provide types to use in runtime to configure this pallet
 pub mod util {
 	use frame_support::{storage::bounded_btree_set::BoundedBTreeSet, traits::Get};
-	use sp_runtime::DispatchError;
+	use sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{AtLeast32BitUnsigned, Saturating, Zero},
+		DispatchError, Perbill, Rounding, SaturatedConversion,
+	};
 	use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
-	use super::traits::AggregationProvider;
-
-	/// Type that performs an aggregation using the median for values and
-	/// timestamps
-	pub struct MedianAggregation;
-
-	impl<Value, Timestamp> AggregationProvider<Value, Timestamp> for MedianAggregation
-	where
-		Value: Ord + Clone,
-		Timestamp: Ord + Clone,
-	{
-		fn aggregate(
-			pairs: impl IntoIterator<Item = (Value, Timestamp)>,
-		) -> Option<(Value, Timestamp)> {
-			let (mut values, mut timestamps): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
-
-			let value = median(&mut values)?.clone();
-			let timestamp = median(&mut timestamps)?.clone();
-
-			Some((value, timestamp))
-		}
-	}
-
 	/// Computes fastly the median of a list of values
 	/// Extracted from orml
 	pub fn median<T: Ord>(items: &mut [T]) -> Option<&T> {
@@ -624,6 +1145,125 @@ pub mod util {
 		Some(item)
 	}
 
+	/// Computes the deviation of a value relative to a previous one,
+	/// saturating at 100%.
+	pub fn deviation<T: AtLeast32BitUnsigned + Copy>(previous: T, value: T) -> Perbill {
+		let delta = if value > previous {
+			value - previous
+		} else {
+			previous - value
+		};
+
+		if delta.is_zero() {
+			return Perbill::zero();
+		}
+
+		Perbill::from_rational(
+			delta.saturated_into::<u128>(),
+			previous.saturated_into::<u128>(),
+		)
+	}
+
+	/// Computes the mean of a list of values after discarding the `trim`
+	/// proportion of the lowest values and the same proportion of the highest
+	/// ones. At least one value is always kept.
+	pub fn trimmed_mean<T: AtLeast32BitUnsigned + Copy>(
+		items: &mut [T],
+		trim: Perbill,
+	) -> Option<T> {
+		if items.is_empty() {
+			return None;
+		}
+
+		items.sort_unstable();
+
+		let max_trimmed = (items.len() - 1) / 2;
+		let trimmed = trim.mul_floor(items.len()).min(max_trimmed);
+		let kept = &items[trimmed..items.len() - trimmed];
+
+		let sum = kept
+			.iter()
+			.try_fold(T::zero(), |sum, item| sum.checked_add(item))?;
+
+		Some(sum / T::from(kept.len() as u32))
+	}
+
+	/// Computes the median of a list of `(value, weight)` pairs, where each
+	/// value counts as many times as its weight.
+	pub fn weighted_median<T: Ord + Copy>(items: &mut [(T, u32)]) -> Option<T> {
+		let total_weight = items.iter().fold(0u64, |total, (_, weight)| {
+			total.saturating_add(*weight as u64)
+		});
+
+		if total_weight.is_zero() {
+			return None;
+		}
+
+		items.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+		let mut accumulated = 0u64;
+		items.iter().find_map(|(value, weight)| {
+			accumulated = accumulated.saturating_add(*weight as u64);
+			(accumulated.saturating_mul(2) >= total_weight).then_some(*value)
+		})
+	}
+
+	/// Computes the time-weighted average of a history of values, sorted
+	/// from the oldest to the newest, in the `[from, to]` window.
+	///
+	/// Each value is considered valid until the next value was fed. If the
+	/// window has no duration, the last value is returned.
+	pub fn twap<Value, Timestamp>(
+		history: &[(Value, Timestamp)],
+		from: Timestamp,
+		to: Timestamp,
+	) -> Option<Value>
+	where
+		Value: AtLeast32BitUnsigned + Copy,
+		Timestamp: AtLeast32BitUnsigned + Copy,
+	{
+		let (last_value, _) = history.last()?;
+
+		let durations = history
+			.iter()
+			.enumerate()
+			.map(|(index, (value, timestamp))| {
+				let start = (*timestamp).max(from);
+				let end = history
+					.get(index + 1)
+					.map(|(_, next_timestamp)| *next_timestamp)
+					.unwrap_or(to)
+					.min(to);
+
+				(*value, end.saturating_sub(start).saturated_into::<u128>())
+			})
+			.filter(|(_, duration)| !duration.is_zero())
+			.collect::<Vec<_>>();
+
+		let total_duration = durations.iter().fold(0u128, |total, (_, duration)| {
+			total.saturating_add(*duration)
+		});
+
+		if total_duration.is_zero() {
+			return Some(*last_value);
+		}
+
+		let average = durations
+			.into_iter()
+			.try_fold(0u128, |average, (value, duration)| {
+				let weighted = multiply_by_rational_with_rounding(
+					value.saturated_into(),
+					duration,
+					total_duration,
+					Rounding::Down,
+				)?;
+
+				average.checked_add(weighted)
+			})?;
+
+		Some(average.saturated_into())
+	}
+
 	pub fn feeders_from<T: Ord, Size: Get<u32>>(
 		feeders: impl IntoIterator<Item = T>,
 	) -> Result<BoundedBTreeSet<T, Size>, DispatchError> {
@@ -0,0 +1,101 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+pub mod v2 {
+	use frame_support::{
+		pallet_prelude::{Decode, Encode},
+		storage::bounded_btree_set::BoundedBTreeSet,
+		traits::{Get, OnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use sp_std::marker::PhantomData;
+
+	use crate::{
+		pallet::{CollectionInfo, Config, KeyCollections, Keys},
+		types::{self, AggregationStrategy, Change},
+	};
+
+	/// Collection info before the aggregation strategy was added
+	#[derive(Encode, Decode)]
+	pub struct CollectionInfoV1<T: Config> {
+		value_lifetime: Option<T::Timestamp>,
+		min_feeders: u32,
+		feeders: BoundedBTreeSet<T::FeederId, T::MaxFeedersPerKey>,
+	}
+
+	impl<T: Config> CollectionInfoV1<T> {
+		/// Keeps the median aggregation used so far, no deviation bound and
+		/// no price history
+		pub fn migrate(self) -> types::CollectionInfo<T> {
+			types::CollectionInfo {
+				value_lifetime: self.value_lifetime,
+				min_feeders: self.min_feeders,
+				feeders: self.feeders,
+				aggregation: AggregationStrategy::Median,
+				max_deviation: None,
+				keep_history: false,
+			}
+		}
+	}
+
+	/// Change noted before the aggregation strategy was added to the
+	/// collection info
+	#[derive(Encode, Decode)]
+	pub enum ChangeV1<T: Config> {
+		CollectionInfo(CollectionInfoV1<T>),
+		UnfreezeKey(T::OracleKey),
+	}
+
+	impl<T: Config> ChangeV1<T> {
+		pub fn migrate(self) -> Change<T> {
+			match self {
+				Self::CollectionInfo(info) => Change::CollectionInfo(info.migrate()),
+				Self::UnfreezeKey(key) => Change::UnfreezeKey(key),
+			}
+		}
+	}
+
+	/// Adds the aggregation strategy, the maximum deviation and the price
+	/// history opt-in to the collection info, keeping the median aggregation
+	/// used so far, no deviation bound and no price history for the existing
+	/// collections. It also indexes the collections each registered key is in.
+	///
+	/// NOTE: Collection info changes noted but not yet applied are stored by
+	/// the change guard, which must migrate them with [`ChangeV1::migrate`]
+	/// along with this migration.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+
+			CollectionInfo::<T>::translate::<CollectionInfoV1<T>, _>(|_, info| {
+				count += 1;
+				Some(info.migrate())
+			});
+
+			for (collection_id, key) in Keys::<T>::iter_keys() {
+				count += 1;
+
+				let pushed = KeyCollections::<T>::mutate(key, |collections| {
+					collections.try_push(collection_id).is_ok()
+				});
+
+				if !pushed {
+					frame_support::defensive!("key registered in too many collections");
+				}
+			}
+
+			T::DbWeight::get().reads_writes(count, count)
+		}
+	}
+}
@@ -16,10 +16,13 @@ pub type CollectionId = u32;
 pub type ChangeId = H256;
 
 pub const NOW: Timestamp = 1000;
+pub const HISTORY_PERIOD: Timestamp = 100;
+pub const MAX_HISTORY_PERIODS: u32 = 3;
 
 frame_support::parameter_types! {
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxFeedersPerKey: u32 = 5;
+	pub const HistoryPeriod: Timestamp = HISTORY_PERIOD;
 }
 
 frame_support::construct_runtime!(
@@ -60,13 +63,15 @@ impl cfg_mocks::time::pallet::Config for Runtime {
 }
 
 impl pallet_oracle_collection::Config for Runtime {
-	type AggregationProvider = crate::util::MedianAggregation;
 	type ChangeGuard = MockChangeGuard;
 	type CollectionId = CollectionId;
 	type FeederId = AccountId;
+	type HistoryPeriod = HistoryPeriod;
 	type IsAdmin = MockIsAdmin;
 	type MaxCollectionSize = ConstU32<100>;
+	type MaxCollectionsPerKey = ConstU32<2>;
 	type MaxFeedersPerKey = MaxFeedersPerKey;
+	type MaxHistoryPeriods = ConstU32<MAX_HISTORY_PERIODS>;
 	type OracleKey = OracleKey;
 	type OracleProvider = MockProvider;
 	type OracleValue = OracleValue;
@@ -1,11 +1,11 @@
-use cfg_traits::data::DataRegistry;
+use cfg_traits::{data::DataRegistry, FeederAccuracy};
 use frame_support::{assert_err, assert_ok};
-use sp_runtime::{testing::H256, traits::Get, DispatchError};
+use sp_runtime::{testing::H256, traits::Get, DispatchError, Perbill};
 
 use crate::{
 	mock::*,
-	pallet::{Config, Error, Event, Keys},
-	types::{Change, CollectionInfo},
+	pallet::{Collection, Config, Error, Event, FrozenKeys, Keys, LastHistoryPeriod},
+	types::{AggregationStrategy, Change, CollectionInfo},
 };
 
 const ADMIN: AccountId = 1;
@@ -82,12 +82,17 @@ mod util {
 		min_feeders: u32,
 		feeders: impl IntoIterator<Item = AccountId>,
 	) {
-		let info = CollectionInfo {
+		set_collection_info(CollectionInfo {
 			value_lifetime,
 			min_feeders,
 			feeders: crate::util::feeders_from(feeders).unwrap(),
-		};
+			aggregation: AggregationStrategy::Median,
+			max_deviation: None,
+			keep_history: false,
+		});
+	}
 
+	pub fn set_collection_info(info: CollectionInfo<Runtime>) {
 		MockChangeGuard::mock_note(|_, _| Ok(CHANGE_ID));
 		MockChangeGuard::mock_released({
 			let info = info.clone();
@@ -122,6 +127,9 @@ fn updating_collection_info() {
 			value_lifetime: Some(50),
 			min_feeders: 2,
 			feeders: crate::util::feeders_from([FEEDER_1, FEEDER_2]).unwrap(),
+			aggregation: AggregationStrategy::Twap { window: 100 },
+			max_deviation: Some(Perbill::from_percent(10)),
+			keep_history: true,
 		};
 
 		mock::prepare_update_collection_info(&info);
@@ -306,6 +314,65 @@ fn update_collection() {
 	});
 }
 
+#[test]
+fn feeder_deviation() {
+	new_test_ext().execute_with(|| {
+		util::update_collection_info(None, 0, vec![FEEDER_1, FEEDER_2, FEEDER_3]);
+
+		assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+		assert_ok!(OracleCollection::register_id(&KEY_B, &COLLECTION_ID));
+
+		mock::prepare_provider();
+
+		// Without a cached value there is no reference to rate against
+		assert_eq!(OracleCollection::deviation(&FEEDER_1, &KEY_A), None);
+
+		assert_ok!(OracleCollection::update_collection(
+			RuntimeOrigin::signed(ANY),
+			COLLECTION_ID
+		));
+
+		assert_eq!(
+			OracleCollection::deviation(&FEEDER_1, &KEY_A),
+			Some(Perbill::from_rational(1u32, 101))
+		);
+		assert_eq!(
+			OracleCollection::deviation(&FEEDER_2, &KEY_A),
+			Some(Perbill::zero())
+		);
+		assert_eq!(
+			OracleCollection::deviation(&FEEDER_1, &KEY_B),
+			Some(Perbill::zero())
+		);
+
+		// Feeders of no collection of the key and unregistered keys are not rated
+		assert_eq!(OracleCollection::deviation(&ANY, &KEY_A), None);
+		assert_eq!(OracleCollection::deviation(&FEEDER_1, &KEY_NONE), None);
+	});
+}
+
+#[test]
+fn register_in_max_collections_per_key() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+		assert_ok!(OracleCollection::register_id(&KEY_A, &(COLLECTION_ID + 1)));
+
+		// Registering again in the same collection does not index it again
+		assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+
+		assert_err!(
+			OracleCollection::register_id(&KEY_A, &(COLLECTION_ID + 2)),
+			Error::<Runtime>::MaxCollectionsPerKey
+		);
+
+		assert_ok!(OracleCollection::unregister_id(
+			&KEY_A,
+			&(COLLECTION_ID + 1)
+		));
+		assert_ok!(OracleCollection::register_id(&KEY_A, &(COLLECTION_ID + 2)));
+	});
+}
+
 #[test]
 fn update_collection_with_max_age() {
 	new_test_ext().execute_with(|| {
@@ -507,3 +574,524 @@ fn update_collection_exceed_size() {
 		);
 	});
 }
+
+mod aggregation {
+	use std::collections::BTreeMap;
+
+	use super::*;
+
+	fn prepare_provider() {
+		MockProvider::mock_get(|(account, _), _| match *account {
+			FEEDER_1 => Ok(Some((100, NOW - 20))),
+			FEEDER_2 => Ok(Some((110, NOW - 10))),
+			FEEDER_3 => Ok(Some((1000, NOW))),
+			_ => unreachable!(),
+		});
+	}
+
+	fn set_aggregation(aggregation: AggregationStrategy<Runtime>) {
+		util::set_collection_info(CollectionInfo {
+			feeders: crate::util::feeders_from([FEEDER_1, FEEDER_2, FEEDER_3]).unwrap(),
+			aggregation,
+			..Default::default()
+		});
+	}
+
+	#[test]
+	fn median() {
+		new_test_ext().execute_with(|| {
+			set_aggregation(AggregationStrategy::Median);
+
+			prepare_provider();
+			assert_ok!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				(110, NOW - 10)
+			);
+		});
+	}
+
+	#[test]
+	fn trimmed_mean() {
+		new_test_ext().execute_with(|| {
+			set_aggregation(AggregationStrategy::TrimmedMean {
+				trim: Perbill::zero(),
+			});
+
+			prepare_provider();
+			assert_ok!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				(403, NOW - 10) // (100 + 110 + 1000) / 3
+			);
+
+			set_aggregation(AggregationStrategy::TrimmedMean {
+				trim: Perbill::from_percent(34),
+			});
+
+			// The lowest and the highest values are discarded
+			assert_ok!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				(110, NOW - 10)
+			);
+		});
+	}
+
+	#[test]
+	fn weighted_median() {
+		new_test_ext().execute_with(|| {
+			set_aggregation(AggregationStrategy::WeightedMedian {
+				weights: [(FEEDER_3, 5)]
+					.into_iter()
+					.collect::<BTreeMap<_, _>>()
+					.try_into()
+					.unwrap(),
+			});
+
+			prepare_provider();
+			assert_ok!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				(1000, NOW - 10)
+			);
+		});
+	}
+
+	#[test]
+	fn twap() {
+		new_test_ext().execute_with(|| {
+			set_aggregation(AggregationStrategy::Twap { window: 100 });
+
+			prepare_provider();
+			MockProvider::mock_history(|(account, _), _| match *account {
+				// Half of the window with 100 and half with 200
+				FEEDER_1 => Ok(vec![(50, NOW - 300), (100, NOW - 200), (200, NOW - 50)]),
+				// Only one value during the window
+				FEEDER_2 => Ok(vec![(120, NOW - 10)]),
+				// A value without duration
+				FEEDER_3 => Ok(vec![(300, NOW)]),
+				_ => unreachable!(),
+			});
+
+			assert_ok!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				(150, NOW - 10) // Median of [150, 120, 300]
+			);
+		});
+	}
+
+	#[test]
+	fn twap_without_history() {
+		new_test_ext().execute_with(|| {
+			set_aggregation(AggregationStrategy::Twap { window: 100 });
+
+			prepare_provider();
+			MockProvider::mock_history(|_, _| Ok(vec![]));
+
+			assert_err!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				Error::<Runtime>::KeyNotInCollection
+			);
+		});
+	}
+
+	#[test]
+	fn twap_util() {
+		use crate::util::twap;
+
+		let history: [(OracleValue, Timestamp); 2] = [(100, 10), (300, 30)];
+
+		assert_eq!(twap(&history, 0, 50), Some(200)); // 100 * 20 + 300 * 20
+		assert_eq!(twap(&history, 20, 40), Some(200)); // 100 * 10 + 300 * 10
+		assert_eq!(twap(&history, 30, 50), Some(300));
+		assert_eq!(twap(&history, 30, 30), Some(300));
+		assert_eq!(twap::<OracleValue, Timestamp>(&[], 0, 50), None);
+	}
+}
+
+mod deviation {
+	use super::*;
+
+	const UNFREEZE_CHANGE_ID: ChangeId = H256::repeat_byte(0x43);
+
+	fn prepare_provider(value: OracleValue) {
+		MockProvider::mock_get(move |(account, _), key| match (*account, *key) {
+			(FEEDER_1, KEY_A) => Ok(Some((value, NOW))),
+			_ => unreachable!(),
+		});
+	}
+
+	fn setup_collection(first_value: OracleValue) {
+		util::set_collection_info(CollectionInfo {
+			feeders: crate::util::feeders_from([FEEDER_1]).unwrap(),
+			max_deviation: Some(Perbill::from_percent(10)),
+			..Default::default()
+		});
+
+		assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+
+		prepare_provider(first_value);
+		assert_ok!(OracleCollection::update_collection(
+			RuntimeOrigin::signed(ANY),
+			COLLECTION_ID
+		));
+	}
+
+	fn prepare_unfreeze() {
+		MockIsAdmin::mock_check(|(admin, collection_id)| {
+			assert_eq!(admin, ADMIN);
+			assert_eq!(collection_id, COLLECTION_ID);
+			true
+		});
+		MockChangeGuard::mock_note(|collection_id, change| {
+			assert_eq!(collection_id, COLLECTION_ID);
+			assert_eq!(change, Change::UnfreezeKey(KEY_A));
+			Ok(UNFREEZE_CHANGE_ID)
+		});
+		MockChangeGuard::mock_released(|collection_id, change_id| {
+			assert_eq!(collection_id, COLLECTION_ID);
+			assert_eq!(change_id, UNFREEZE_CHANGE_ID);
+			Ok(Change::UnfreezeKey(KEY_A))
+		});
+	}
+
+	#[test]
+	fn update_within_bounds() {
+		new_test_ext().execute_with(|| {
+			setup_collection(100);
+
+			prepare_provider(109);
+			assert_ok!(OracleCollection::update_collection(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID
+			));
+
+			let collection = OracleCollection::collection(&COLLECTION_ID).unwrap();
+			assert_eq!(collection.as_vec(), vec![(KEY_A, (109, NOW))]);
+			assert!(!OracleCollection::is_frozen(&KEY_A, &COLLECTION_ID));
+		});
+	}
+
+	#[test]
+	fn update_breaching_deviation() {
+		new_test_ext().execute_with(|| {
+			setup_collection(100);
+
+			prepare_provider(150);
+			assert_ok!(OracleCollection::update_collection(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID
+			));
+
+			System::assert_has_event(
+				Event::<Runtime>::KeyFrozen {
+					collection_id: COLLECTION_ID,
+					key: KEY_A,
+					last_good: (100, NOW),
+					rejected: (150, NOW),
+				}
+				.into(),
+			);
+
+			let collection = OracleCollection::collection(&COLLECTION_ID).unwrap();
+			assert_eq!(collection.as_vec(), vec![(KEY_A, (100, NOW))]);
+			assert!(OracleCollection::is_frozen(&KEY_A, &COLLECTION_ID));
+			assert_ok!(OracleCollection::get(&KEY_A, &COLLECTION_ID), (100, NOW));
+
+			// A frozen key keeps its last good value even if the feeders go back
+			prepare_provider(105);
+			assert_ok!(OracleCollection::update_collection(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID
+			));
+
+			let collection = OracleCollection::collection(&COLLECTION_ID).unwrap();
+			assert_eq!(collection.as_vec(), vec![(KEY_A, (100, NOW))]);
+		});
+	}
+
+	#[test]
+	fn frozen_key_does_not_outdate_collection() {
+		new_test_ext().execute_with(|| {
+			setup_collection(100);
+
+			prepare_provider(150);
+			assert_ok!(OracleCollection::update_collection(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID
+			));
+
+			MockTime::mock_now(|| NOW + ENOUGH_MAX_AGE);
+			assert_ok!(OracleCollection::update_collection(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID
+			));
+
+			let collection = Collection::<Runtime>::get(COLLECTION_ID);
+			assert_eq!(collection.older_value_timestamp, NOW + ENOUGH_MAX_AGE);
+			assert_eq!(collection.as_vec(), vec![(KEY_A, (100, NOW))]);
+			assert!(OracleCollection::is_frozen(&KEY_A, &COLLECTION_ID));
+		});
+	}
+
+	#[test]
+	fn unfreeze_key() {
+		new_test_ext().execute_with(|| {
+			setup_collection(100);
+
+			prepare_provider(150);
+			assert_ok!(OracleCollection::update_collection(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID
+			));
+
+			prepare_unfreeze();
+			assert_ok!(OracleCollection::propose_unfreeze_key(
+				RuntimeOrigin::signed(ADMIN),
+				COLLECTION_ID,
+				KEY_A
+			));
+
+			assert_ok!(OracleCollection::apply_unfreeze_key(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID,
+				UNFREEZE_CHANGE_ID
+			));
+
+			System::assert_last_event(
+				Event::<Runtime>::KeyUnfrozen {
+					collection_id: COLLECTION_ID,
+					key: KEY_A,
+				}
+				.into(),
+			);
+
+			assert!(!FrozenKeys::<Runtime>::contains_key(COLLECTION_ID, KEY_A));
+
+			let collection = OracleCollection::collection(&COLLECTION_ID).unwrap();
+			assert_eq!(collection.as_vec(), vec![(KEY_A, (150, NOW))]);
+			assert_ok!(OracleCollection::get(&KEY_A, &COLLECTION_ID), (150, NOW));
+		});
+	}
+
+	#[test]
+	fn unfreeze_key_not_frozen() {
+		new_test_ext().execute_with(|| {
+			setup_collection(100);
+
+			prepare_unfreeze();
+			assert_err!(
+				OracleCollection::propose_unfreeze_key(
+					RuntimeOrigin::signed(ADMIN),
+					COLLECTION_ID,
+					KEY_A
+				),
+				Error::<Runtime>::KeyNotFrozen
+			);
+
+			assert_err!(
+				OracleCollection::apply_unfreeze_key(
+					RuntimeOrigin::signed(ANY),
+					COLLECTION_ID,
+					UNFREEZE_CHANGE_ID
+				),
+				Error::<Runtime>::KeyNotFrozen
+			);
+		});
+	}
+
+	#[test]
+	fn unfreeze_key_wrong_admin() {
+		new_test_ext().execute_with(|| {
+			MockIsAdmin::mock_check(|_| false);
+
+			assert_err!(
+				OracleCollection::propose_unfreeze_key(
+					RuntimeOrigin::signed(ANY),
+					COLLECTION_ID,
+					KEY_A
+				),
+				Error::<Runtime>::IsNotAdmin
+			);
+		});
+	}
+
+	#[test]
+	fn deviation_util() {
+		use crate::util::deviation;
+
+		assert_eq!(deviation(100u64, 110), Perbill::from_percent(10));
+		assert_eq!(deviation(100u64, 90), Perbill::from_percent(10));
+		assert_eq!(deviation(100u64, 100), Perbill::zero());
+		assert_eq!(deviation(100u64, 1000), Perbill::one());
+		assert_eq!(deviation(0u64, 1), Perbill::one());
+	}
+}
+
+mod history {
+	use super::*;
+	use crate::types::Ohlc;
+
+	fn feed(value: OracleValue, timestamp: Timestamp) {
+		MockTime::mock_now(move || timestamp);
+		MockProvider::mock_get(move |(account, _), key| match (*account, *key) {
+			(FEEDER_1, KEY_A) => Ok(Some((value, timestamp))),
+			_ => unreachable!(),
+		});
+
+		assert_ok!(OracleCollection::update_collection(
+			RuntimeOrigin::signed(ANY),
+			COLLECTION_ID
+		));
+	}
+
+	fn setup_collection(keep_history: bool) {
+		util::set_collection_info(CollectionInfo {
+			feeders: crate::util::feeders_from([FEEDER_1]).unwrap(),
+			keep_history,
+			..Default::default()
+		});
+
+		assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+	}
+
+	fn full_history() -> Vec<Ohlc<Runtime>> {
+		OracleCollection::price_history(COLLECTION_ID, KEY_A, 0, Timestamp::MAX)
+	}
+
+	#[test]
+	fn records_ohlc() {
+		new_test_ext().execute_with(|| {
+			setup_collection(true);
+
+			feed(100, NOW);
+			feed(120, NOW + 10);
+			feed(90, NOW + 20);
+			feed(110, NOW + 30);
+			feed(130, NOW + HISTORY_PERIOD + 5);
+
+			assert_eq!(
+				full_history(),
+				vec![
+					Ohlc {
+						period_start: NOW,
+						open: 100,
+						high: 120,
+						low: 90,
+						close: (110, NOW + 30),
+					},
+					Ohlc {
+						period_start: NOW + HISTORY_PERIOD,
+						open: 130,
+						high: 130,
+						low: 130,
+						close: (130, NOW + HISTORY_PERIOD + 5),
+					},
+				]
+			);
+
+			assert_eq!(
+				OracleCollection::price_history(
+					COLLECTION_ID,
+					KEY_A,
+					NOW + 1,
+					NOW + HISTORY_PERIOD
+				)
+				.len(),
+				1
+			);
+		});
+	}
+
+	#[test]
+	fn ignores_not_newer_values() {
+		new_test_ext().execute_with(|| {
+			setup_collection(true);
+
+			feed(100, NOW + 10);
+			feed(120, NOW + 10);
+			feed(90, NOW + 5);
+
+			assert_eq!(
+				full_history(),
+				vec![Ohlc {
+					period_start: NOW,
+					open: 100,
+					high: 100,
+					low: 100,
+					close: (100, NOW + 10),
+				}]
+			);
+		});
+	}
+
+	#[test]
+	fn discards_older_periods() {
+		new_test_ext().execute_with(|| {
+			setup_collection(true);
+
+			for period in 0..MAX_HISTORY_PERIODS + 1 {
+				feed(100, NOW + HISTORY_PERIOD * period as Timestamp);
+			}
+
+			let history = full_history();
+			assert_eq!(history.len(), MAX_HISTORY_PERIODS as usize);
+			assert_eq!(history[0].period_start, NOW + HISTORY_PERIOD);
+			assert_eq!(
+				history.last().unwrap().period_start,
+				NOW + HISTORY_PERIOD * MAX_HISTORY_PERIODS as Timestamp
+			);
+		});
+	}
+
+	#[test]
+	fn without_history() {
+		new_test_ext().execute_with(|| {
+			setup_collection(false);
+
+			feed(100, NOW);
+
+			assert_eq!(full_history(), vec![]);
+		});
+	}
+
+	#[test]
+	fn bounds_first_update_with_history() {
+		new_test_ext().execute_with(|| {
+			util::set_collection_info(CollectionInfo {
+				feeders: crate::util::feeders_from([FEEDER_1]).unwrap(),
+				keep_history: true,
+				max_deviation: Some(Perbill::from_percent(10)),
+				..Default::default()
+			});
+			assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+
+			feed(100, NOW);
+
+			// Without a cached value, the last one of the history is the reference
+			Collection::<Runtime>::remove(COLLECTION_ID);
+			feed(150, NOW + 1);
+
+			assert!(OracleCollection::is_frozen(&KEY_A, &COLLECTION_ID));
+			assert_eq!(
+				Collection::<Runtime>::get(COLLECTION_ID).as_vec(),
+				vec![(KEY_A, (100, NOW))]
+			);
+		});
+	}
+
+	#[test]
+	fn unregister_key_removes_history() {
+		new_test_ext().execute_with(|| {
+			setup_collection(true);
+
+			feed(100, NOW);
+			assert_eq!(full_history().len(), 1);
+
+			assert_ok!(OracleCollection::unregister_id(&KEY_A, &COLLECTION_ID));
+
+			assert_eq!(full_history(), vec![]);
+			assert_eq!(
+				LastHistoryPeriod::<Runtime>::get(COLLECTION_ID, KEY_A),
+				None
+			);
+		});
+	}
+}
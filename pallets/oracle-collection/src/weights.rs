@@ -11,12 +11,35 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use frame_support::weights::Weight;
+use frame_support::weights::{constants::RocksDbWeight, Weight};
 
 pub trait WeightInfo {
 	fn propose_update_collection_info(feeders: u32) -> Weight;
 	fn apply_update_collection_info(feeders: u32) -> Weight;
 	fn update_collection(feeders: u32, keys: u32) -> Weight;
+
+	fn propose_unfreeze_key() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_update_collection_info` with
+		//       one feeder. Besides its accesses, this one reads the frozen key.
+		Weight::from_parts(25_478_335, 3698)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn apply_unfreeze_key(feeders: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `apply_update_collection_info`.
+		//       Besides its accesses, this one removes the frozen key, aggregates
+		//       the values of its feeders and writes it in the collection.
+		Weight::from_parts(35_167_218, 6581)
+			.saturating_add(Weight::from_parts(724_794, 3186).saturating_mul(feeders.into()))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().reads(feeders.into()))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
 }
 
 impl WeightInfo for () {
@@ -31,4 +54,12 @@ impl WeightInfo for () {
 	fn update_collection(_: u32, _: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn propose_unfreeze_key() -> Weight {
+		Weight::zero()
+	}
+
+	fn apply_unfreeze_key(_: u32) -> Weight {
+		Weight::zero()
+	}
 }
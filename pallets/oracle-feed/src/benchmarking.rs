@@ -1,10 +1,22 @@
 use cfg_traits::fees::PayFee;
 use frame_benchmarking::{v2::*, whitelisted_caller};
-use frame_support::traits::OriginTrait;
+use frame_support::{
+	traits::{Currency, Get, OriginTrait, Time},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use parity_scale_codec::Decode;
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::traits::Saturating;
+use sp_std::vec::Vec;
 
-use crate::pallet::{Call, Config, Pallet};
+use crate::{
+	pallet::{Call, Config, Pallet},
+	types::{Attestation, AttestationSignature, AttestationSigner, SignedAttestation},
+	util,
+};
+
+const ATTESTATION_KEY_TYPE: KeyTypeId = KeyTypeId(*b"orcl");
 
 #[cfg(test)]
 fn init_mocks() {
@@ -12,12 +24,75 @@ fn init_mocks() {
 	crate::mock::MockPayFee::mock_pay(|_| Ok(()));
 }
 
+/// Bonds the feeder and funds the rewards account, so feeding is rewarded.
+fn setup_rewarded_feeder<T: Config>(feeder: &T::AccountId) -> Result<(), BenchmarkError> {
+	let bond = T::MinFeederBond::get();
+	let funds = T::Currency::minimum_balance().saturating_add(T::FeedReward::get());
+
+	T::Currency::make_free_balance_be(feeder, funds.saturating_add(bond));
+	T::Currency::make_free_balance_be(&Pallet::<T>::rewards_account(), funds);
+
+	Pallet::<T>::bond(RawOrigin::Signed(feeder.clone()).into(), bond)?;
+
+	Ok(())
+}
+
+/// Generates a signer and its proof of possession for the feeder
+fn signer_with_proof<T: Config>(
+	feeder: &T::AccountId,
+) -> Result<(sp_core::sr25519::Public, AttestationSignature), BenchmarkError> {
+	let public = sp_io::crypto::sr25519_generate(ATTESTATION_KEY_TYPE, None);
+
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(feeder.clone()).into();
+	let payload = util::signer_payload(&Pallet::<T>::genesis_hash(), origin.caller());
+	let proof = sp_io::crypto::sr25519_sign(ATTESTATION_KEY_TYPE, &public, &payload)
+		.ok_or(BenchmarkError::Stop("Unable to sign the signer proof"))?;
+
+	Ok((public, AttestationSignature::Sr25519(proof)))
+}
+
+/// Generates a signer, registers it for the feeder and signs an attestation
+/// with the current time.
+fn signed_attestation<T: Config>(
+	feeder: &T::AccountId,
+) -> Result<crate::pallet::SignedAttestationOf<T>, BenchmarkError>
+where
+	T::OracleKey: Default,
+	T::OracleValue: Default,
+{
+	let (public, proof) = signer_with_proof::<T>(feeder)?;
+	let signer = AttestationSigner::Sr25519(public);
+
+	Pallet::<T>::register_attestation_signer(
+		RawOrigin::Signed(feeder.clone()).into(),
+		signer.clone(),
+		proof,
+	)?;
+
+	let attestation = Attestation {
+		key: T::OracleKey::default(),
+		value: T::OracleValue::default(),
+		timestamp: T::Time::now(),
+	};
+
+	let payload = util::attestation_payload(&Pallet::<T>::genesis_hash(), &attestation);
+	let signature = sp_io::crypto::sr25519_sign(ATTESTATION_KEY_TYPE, &public, &payload)
+		.ok_or(BenchmarkError::Stop("Unable to sign the attestation"))?;
+
+	Ok(SignedAttestation {
+		attestation,
+		signer,
+		signature: AttestationSignature::Sr25519(signature),
+	})
+}
+
 #[benchmarks(
     where
         T::OracleKey: Default,
         T::OracleValue: Default,
         <T::RuntimeOrigin as OriginTrait>::AccountId: Clone + Decode,
         T::RuntimeOrigin: From<RawOrigin<<T::RuntimeOrigin as OriginTrait>::AccountId>>,
+        T::RuntimeOrigin: OriginTrait<AccountId = T::AccountId>,
 )]
 mod benchmarks {
 	use super::*;
@@ -29,6 +104,7 @@ mod benchmarks {
 
 		let feeder: <T::RuntimeOrigin as OriginTrait>::AccountId = whitelisted_caller();
 
+		setup_rewarded_feeder::<T>(&feeder)?;
 		T::FirstValuePayFee::add_pay_requirements(&feeder);
 
 		#[extrinsic_call]
@@ -56,6 +132,9 @@ mod benchmarks {
 			T::OracleValue::default(),
 		)?;
 
+		// Bonded after the first feed, so the next one is rewarded
+		setup_rewarded_feeder::<T>(&feeder)?;
+
 		#[extrinsic_call]
 		feed(
 			RawOrigin::Signed(feeder),
@@ -66,5 +145,101 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn bond() -> Result<(), BenchmarkError> {
+		let feeder: T::AccountId = whitelisted_caller();
+		let amount = T::MinFeederBond::get();
+
+		T::Currency::make_free_balance_be(
+			&feeder,
+			T::Currency::minimum_balance().saturating_add(amount),
+		);
+
+		#[extrinsic_call]
+		bond(RawOrigin::Signed(feeder), amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unbond() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let feeder: T::AccountId = whitelisted_caller();
+		setup_rewarded_feeder::<T>(&feeder)?;
+
+		#[extrinsic_call]
+		unbond(RawOrigin::Signed(feeder), T::MinFeederBond::get());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_attestation_signer() -> Result<(), BenchmarkError> {
+		let feeder: T::AccountId = whitelisted_caller();
+		let (public, proof) = signer_with_proof::<T>(&feeder)?;
+
+		#[extrinsic_call]
+		register_attestation_signer(
+			RawOrigin::Signed(feeder),
+			AttestationSigner::Sr25519(public),
+			proof,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unregister_attestation_signer() -> Result<(), BenchmarkError> {
+		let feeder: T::AccountId = whitelisted_caller();
+		let (public, proof) = signer_with_proof::<T>(&feeder)?;
+		let signer = AttestationSigner::Sr25519(public);
+
+		Pallet::<T>::register_attestation_signer(
+			RawOrigin::Signed(feeder.clone()).into(),
+			signer.clone(),
+			proof,
+		)?;
+
+		#[extrinsic_call]
+		unregister_attestation_signer(RawOrigin::Signed(feeder), signer);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn feed_attestations(n: Linear<1, 100>) -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let relayer: T::AccountId = whitelisted_caller();
+
+		// Each attestation comes from a different rewarded feeder,
+		// and it is the first value for the relayer to pay
+		let attestations = (0..n)
+			.map(|i| {
+				let feeder: T::AccountId = account("feeder", i, 0);
+				setup_rewarded_feeder::<T>(&feeder)?;
+				T::FirstValuePayFee::add_pay_requirements(&relayer);
+
+				signed_attestation::<T>(&feeder)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		T::Currency::make_free_balance_be(
+			&Pallet::<T>::rewards_account(),
+			T::Currency::minimum_balance().saturating_add(T::FeedReward::get() * n.into()),
+		);
+
+		let attestations = BoundedVec::try_from(attestations)
+			.map_err(|_| BenchmarkError::Stop("MaxAttestations lower than the benchmark limit"))?;
+
+		#[extrinsic_call]
+		feed_attestations(RawOrigin::Signed(relayer), attestations);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -18,7 +18,49 @@
 //! Feeding is permissionless given an initial fee for each key.
 //!
 //!
-//! //! ### Assumptions
+//! Each feeder keeps a bounded history of the last values fed for each key,
+//! which can be used to compute time-weighted aggregations.
+//!
+//! Signed feeders can optionally bond an amount. Each value fed for a key
+//! registered in a collection fed by the feeder is rated against the value of
+//! the collection. While the bond reaches the minimum, accurate values are
+//! rewarded from the rewards account, at most once per reward interval. The
+//! rewards account is expected to be funded by pool fees. Bonded feeders whose
+//! values deviate more than a threshold several consecutive times get part of
+//! their bond slashed into the rewards account.
+//!
+//! ### Off-chain attestations
+//!
+//! Instead of calling `feed` per key, a feeder can register a signer key and
+//! sign attestations off-chain. Registering a signer requires a proof of
+//! possession: a signature by the signer key over the SCALE encoding of
+//! `(b"cfg/oracle/signr", genesis_hash, feeder)`, where `feeder` is the
+//! encoded origin of the feeder registering it. Any account can relay a batch
+//! of them with `feed_attestations`, and their values are stored as fed by the
+//! feeder at the attested timestamp.
+//!
+//! An attestation is signed over the SCALE encoding of the tuple:
+//!
+//! ```text
+//! (
+//!     b"cfg/oracle/attst",  // [u8; 16], no length prefix
+//!     genesis_hash,         // Hash of the block 0 of the chain, [u8; 32]
+//!     key,                  // OracleKey
+//!     value,                // OracleValue
+//!     timestamp,            // Moment, milliseconds for the Centrifuge runtimes
+//! )
+//! ```
+//!
+//! Supported signers are:
+//! - `sr25519`: signature of the payload using the `substrate` signing context.
+//! - `ecdsa` (secp256k1): recoverable signature of the `blake2_256` hash of the
+//!   payload, with the 33 bytes compressed public key as signer.
+//!
+//! An attestation is accepted if its timestamp is not in the future, is not
+//! older than `MaxAttestationAge` and is newer than the last value fed by the
+//! feeder for the key.
+//!
+//! ### Assumptions
 //!
 //! This pallet neither aggregates nor validates anything. It just stores values
 //! by account as they come. It's expected that another pallet reads the storage
@@ -43,19 +85,37 @@ pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use cfg_traits::{fees::PayFee, ValueProvider};
+	use cfg_traits::{fees::PayFee, FeederAccuracy, ValueProvider};
 	use frame_support::{
 		pallet_prelude::*,
-		traits::{OriginTrait, Time},
+		traits::{
+			BalanceStatus, Currency, ExistenceRequirement, OriginTrait, ReservableCurrency, Time,
+		},
+		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, EnsureAddAssign, EnsureSub, Saturating, Zero},
+		Perbill,
+	};
+	use sp_std::vec::Vec;
 
-	use crate::weights::WeightInfo;
+	use crate::{
+		types::{
+			Attestation, AttestationSignature, AttestationSigner, FeederStats, SignedAttestation,
+		},
+		util,
+		weights::WeightInfo,
+	};
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
 	pub type Feeder<T> = <<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+	pub type SignedAttestationOf<T> =
+		SignedAttestation<<T as Config>::OracleKey, <T as Config>::OracleValue, MomentOf<T>>;
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -82,6 +142,55 @@ pub mod pallet {
 
 		/// Ensure the feeder origin
 		type FeederOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Max number of historical values stored per feeder and key
+		#[pallet::constant]
+		type MaxHistorySize: Get<u32>;
+
+		/// Currency used to bond and reward feeders
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Rates the values fed against the values of the collections of
+		/// their keys
+		type FeederAccuracy: FeederAccuracy<Feeder<Self>, Self::OracleKey>;
+
+		/// Identifier of the account that pays the feeder rewards and
+		/// receives the slashed bonds
+		#[pallet::constant]
+		type RewardsPalletId: Get<PalletId>;
+
+		/// Minimum bond required to be rewarded
+		#[pallet::constant]
+		type MinFeederBond: Get<BalanceOf<Self>>;
+
+		/// Reward paid to a bonded feeder for feeding a key
+		#[pallet::constant]
+		type FeedReward: Get<BalanceOf<Self>>;
+
+		/// Minimum time between two rewarded feeds of a feeder
+		#[pallet::constant]
+		type RewardInterval: Get<MomentOf<Self>>;
+
+		/// Deviation from the reference value from which a value is
+		/// considered inaccurate
+		#[pallet::constant]
+		type SlashDeviation: Get<Perbill>;
+
+		/// Number of consecutive inaccurate values that slash a bond
+		#[pallet::constant]
+		type SlashRounds: Get<u32>;
+
+		/// Proportion of the bond slashed
+		#[pallet::constant]
+		type SlashFraction: Get<Perbill>;
+
+		/// Max number of attestations fed in one call
+		#[pallet::constant]
+		type MaxAttestations: Get<u32>;
+
+		/// Max age of an attestation when it is fed
+		#[pallet::constant]
+		type MaxAttestationAge: Get<MomentOf<Self>>;
 	}
 
 	/// Store all oracle values indexed by feeder
@@ -95,6 +204,39 @@ pub mod pallet {
 		(T::OracleValue, MomentOf<T>),
 	>;
 
+	/// Store the last values fed by each feeder for each key, sorted from the
+	/// oldest to the newest. Once full, the oldest value is discarded.
+	#[pallet::storage]
+	pub(crate) type FedValuesHistory<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Feeder<T>,
+		Blake2_128Concat,
+		T::OracleKey,
+		BoundedVec<(T::OracleValue, MomentOf<T>), T::MaxHistorySize>,
+		ValueQuery,
+	>;
+
+	/// Store the feeder that registered each attestation signer
+	#[pallet::storage]
+	pub(crate) type AttestationSigners<T: Config> =
+		StorageMap<_, Blake2_128Concat, AttestationSigner, Feeder<T>>;
+
+	/// Store the amount bonded by each feeder account
+	#[pallet::storage]
+	pub(crate) type Bonds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Store the last time each feeder was rewarded
+	#[pallet::storage]
+	pub(crate) type LastRewarded<T: Config> =
+		StorageMap<_, Blake2_128Concat, Feeder<T>, MomentOf<T>>;
+
+	/// Store the performance statistics of each feeder
+	#[pallet::storage]
+	pub(crate) type Stats<T: Config> =
+		StorageMap<_, Blake2_128Concat, Feeder<T>, FeederStats<BalanceOf<T>>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -103,6 +245,63 @@ pub mod pallet {
 			key: T::OracleKey,
 			value: T::OracleValue,
 		},
+		Bonded {
+			account: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		Unbonded {
+			account: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		Rewarded {
+			account: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		Slashed {
+			account: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		AttestationSignerRegistered {
+			feeder: Feeder<T>,
+			signer: AttestationSigner,
+		},
+		AttestationSignerUnregistered {
+			feeder: Feeder<T>,
+			signer: AttestationSigner,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Only signed feeders can bond
+		NotSignedFeeder,
+
+		/// The amount to unbond is greater than the bond
+		NotEnoughBond,
+
+		/// The feeder can not unbond while its last rounds are inaccurate
+		PendingInaccurateRounds,
+
+		/// The signer is already registered
+		SignerAlreadyRegistered,
+
+		/// The signer is not registered by the feeder
+		SignerNotRegistered,
+
+		/// The proof is not a signature of the feeder by the signer
+		InvalidSignerProof,
+
+		/// The signature does not match the attestation and the signer
+		InvalidAttestationSignature,
+
+		/// The timestamp of the attestation is after the current time
+		AttestationFromFuture,
+
+		/// The attestation is older than the max attestation age
+		AttestationOutdated,
+
+		/// The attestation is not newer than the last value fed for the key
+		AttestationNotNewer,
 	}
 
 	#[pallet::call]
@@ -121,8 +320,9 @@ pub mod pallet {
 
 			let feeder = origin.clone().into_caller();
 			let signed_account = origin.into_signer();
+			let now = T::Time::now();
 
-			FedValues::<T>::mutate(&feeder, key, |prev_value| {
+			let new_weight = FedValues::<T>::mutate(&feeder, key, |prev_value| {
 				let new_weight = match (&prev_value, signed_account) {
 					(None, Some(account_id)) => {
 						T::FirstValuePayFee::pay(&account_id)?;
@@ -137,16 +337,355 @@ pub mod pallet {
 					}
 				};
 
-				*prev_value = Some((value, T::Time::now()));
+				let fed_value = (value, now);
+				*prev_value = Some(fed_value);
+				Self::note_fed(&feeder, key, fed_value);
+
+				Ok::<_, DispatchError>(new_weight)
+			})?;
+
+			Self::rate(&feeder, &key, now);
+
+			Ok(new_weight.into())
+		}
+
+		/// Bond an amount from a signed feeder account. Bonded feeders are
+		/// rewarded for feeding and slashed for inaccurate values.
+		#[pallet::weight(T::WeightInfo::bond())]
+		#[pallet::call_index(1)]
+		pub fn bond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let account = Self::ensure_signed_feeder(origin)?;
+
+			T::Currency::reserve(&account, amount)?;
+			Bonds::<T>::try_mutate(&account, |bond| bond.ensure_add_assign(amount))?;
+
+			Self::deposit_event(Event::<T>::Bonded { account, amount });
+
+			Ok(())
+		}
+
+		/// Unbond an amount previously bonded. It is not possible to unbond
+		/// while the last values of the feeder are inaccurate.
+		#[pallet::weight(T::WeightInfo::unbond())]
+		#[pallet::call_index(2)]
+		pub fn unbond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let account = Self::ensure_signed_feeder(origin.clone())?;
+
+			ensure!(
+				Stats::<T>::get(origin.into_caller())
+					.inaccurate_streak
+					.is_zero(),
+				Error::<T>::PendingInaccurateRounds
+			);
+
+			Bonds::<T>::try_mutate_exists(&account, |bond| {
+				let remaining = bond
+					.unwrap_or_default()
+					.ensure_sub(amount)
+					.map_err(|_| Error::<T>::NotEnoughBond)?;
+
+				*bond = (!remaining.is_zero()).then_some(remaining);
+
+				Ok::<_, DispatchError>(())
+			})?;
+
+			T::Currency::unreserve(&account, amount);
+
+			Self::deposit_event(Event::<T>::Unbonded { account, amount });
+
+			Ok(())
+		}
+
+		/// Register a key used by the feeder to sign attestations off-chain.
+		/// The values of the attestations signed with it are stored as fed
+		/// by the feeder. The `proof` is a signature by the key of the
+		/// feeder, proving the feeder owns it.
+		#[pallet::weight(T::WeightInfo::register_attestation_signer())]
+		#[pallet::call_index(3)]
+		pub fn register_attestation_signer(
+			origin: OriginFor<T>,
+			signer: AttestationSigner,
+			proof: AttestationSignature,
+		) -> DispatchResult {
+			T::FeederOrigin::ensure_origin(origin.clone())?;
+			let feeder = origin.into_caller();
+
+			let payload = util::signer_payload(&Self::genesis_hash(), &feeder);
+			ensure!(
+				proof.verify(&payload, &signer),
+				Error::<T>::InvalidSignerProof
+			);
 
-				Self::deposit_event(Event::<T>::Fed {
-					feeder: feeder.clone(),
+			ensure!(
+				!AttestationSigners::<T>::contains_key(&signer),
+				Error::<T>::SignerAlreadyRegistered
+			);
+
+			AttestationSigners::<T>::insert(&signer, feeder.clone());
+
+			Self::deposit_event(Event::<T>::AttestationSignerRegistered { feeder, signer });
+
+			Ok(())
+		}
+
+		/// Unregister a key previously registered by the feeder.
+		#[pallet::weight(T::WeightInfo::unregister_attestation_signer())]
+		#[pallet::call_index(4)]
+		pub fn unregister_attestation_signer(
+			origin: OriginFor<T>,
+			signer: AttestationSigner,
+		) -> DispatchResult {
+			T::FeederOrigin::ensure_origin(origin.clone())?;
+			let feeder = origin.into_caller();
+
+			ensure!(
+				AttestationSigners::<T>::get(&signer).as_ref() == Some(&feeder),
+				Error::<T>::SignerNotRegistered
+			);
+
+			AttestationSigners::<T>::remove(&signer);
+
+			Self::deposit_event(Event::<T>::AttestationSignerUnregistered { feeder, signer });
+
+			Ok(())
+		}
+
+		/// Permissionless call to feed a batch of attestations signed
+		/// off-chain by registered signers. Any account can relay them.
+		/// The whole batch fails if any attestation is not valid.
+		/// The first time a value is set for a key, an extra fee is required
+		/// for the relayer.
+		#[pallet::weight(T::WeightInfo::feed_attestations(attestations.len() as u32))]
+		#[pallet::call_index(5)]
+		pub fn feed_attestations(
+			origin: OriginFor<T>,
+			attestations: BoundedVec<SignedAttestationOf<T>, T::MaxAttestations>,
+		) -> DispatchResult {
+			ensure_signed(origin.clone())?;
+			let relayer = origin.into_signer().ok_or(DispatchError::BadOrigin)?;
+
+			let now = T::Time::now();
+			let genesis_hash = Self::genesis_hash();
+
+			for signed in attestations {
+				let SignedAttestation {
+					attestation,
+					signer,
+					signature,
+				} = signed;
+
+				let feeder =
+					AttestationSigners::<T>::get(&signer).ok_or(Error::<T>::SignerNotRegistered)?;
+
+				let payload = util::attestation_payload(&genesis_hash, &attestation);
+				ensure!(
+					signature.verify(&payload, &signer),
+					Error::<T>::InvalidAttestationSignature
+				);
+
+				let Attestation {
 					key,
 					value,
+					timestamp,
+				} = attestation;
+
+				ensure!(timestamp <= now, Error::<T>::AttestationFromFuture);
+				ensure!(
+					now.saturating_sub(timestamp) <= T::MaxAttestationAge::get(),
+					Error::<T>::AttestationOutdated
+				);
+
+				FedValues::<T>::try_mutate(&feeder, key, |prev_value| {
+					match prev_value {
+						// Attestations of a key can not be replayed or reordered
+						Some((_, last_timestamp)) => {
+							ensure!(timestamp > *last_timestamp, Error::<T>::AttestationNotNewer)
+						}
+						None => T::FirstValuePayFee::pay(&relayer)?,
+					}
+
+					let fed_value = (value, timestamp);
+					*prev_value = Some(fed_value);
+					Self::note_fed(&feeder, key, fed_value);
+
+					Ok::<_, DispatchError>(())
+				})?;
+
+				Self::rate(&feeder, &key, now);
+			}
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Hash of the genesis block, used to bind attestations to this chain
+		pub fn genesis_hash() -> T::Hash {
+			frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero())
+		}
+
+		fn note_fed(
+			feeder: &Feeder<T>,
+			key: T::OracleKey,
+			fed_value: (T::OracleValue, MomentOf<T>),
+		) {
+			Self::push_history(feeder, &key, fed_value);
+
+			Stats::<T>::mutate(feeder, |stats| stats.feeds.saturating_inc());
+
+			Self::deposit_event(Event::<T>::Fed {
+				feeder: feeder.clone(),
+				key,
+				value: fed_value.0,
+			});
+		}
+
+		/// Account that pays the rewards and receives the slashed bonds
+		pub fn rewards_account() -> T::AccountId {
+			T::RewardsPalletId::get().into_account_truncating()
+		}
+
+		/// Returns the performance statistics of a feeder
+		pub fn feeder_stats(feeder: Feeder<T>) -> FeederStats<BalanceOf<T>> {
+			Stats::<T>::get(feeder)
+		}
+
+		/// Returns the amount bonded by a feeder account
+		pub fn bond_of(account: T::AccountId) -> BalanceOf<T> {
+			Bonds::<T>::get(account)
+		}
+
+		fn ensure_signed_feeder(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+			T::FeederOrigin::ensure_origin(origin.clone())?;
+			Ok(ensure_signed(origin).map_err(|_| Error::<T>::NotSignedFeeder)?)
+		}
+
+		/// Rates the value fed by a feeder for a key against the reference
+		/// value of the key. Accurate values of bonded feeders are rewarded
+		/// and several consecutive inaccurate ones slash their bond. Keys not
+		/// registered in a collection fed by the feeder are neither rated nor
+		/// rewarded.
+		fn rate(feeder: &Feeder<T>, key: &T::OracleKey, now: MomentOf<T>) {
+			let Some(deviation) = T::FeederAccuracy::deviation(feeder, key) else {
+				return;
+			};
+
+			let accurate = deviation <= T::SlashDeviation::get();
+
+			let slash = Stats::<T>::mutate(feeder, |stats| {
+				stats.rounds.saturating_inc();
+
+				if accurate {
+					stats.inaccurate_streak = 0;
+					return false;
+				}
+
+				stats.inaccurate_rounds.saturating_inc();
+				stats.inaccurate_streak.saturating_inc();
+
+				if stats.inaccurate_streak < T::SlashRounds::get() {
+					return false;
+				}
+
+				stats.inaccurate_streak = 0;
+				true
+			});
+
+			let origin: T::RuntimeOrigin = feeder.clone().into();
+			let Ok(account) = ensure_signed(origin) else {
+				return;
+			};
+
+			if accurate {
+				Self::reward(feeder, &account, now);
+			}
+
+			if slash {
+				let slashed = Self::slash(&account);
+				Stats::<T>::mutate(feeder, |stats| {
+					stats.slashed = stats.slashed.saturating_add(slashed)
 				});
+			}
+		}
 
-				Ok(new_weight.into())
-			})
+		/// Pays the feed reward to a bonded feeder if it was not rewarded
+		/// during the last reward interval. If the rewards account can not
+		/// afford it, the feeder is not rewarded.
+		fn reward(feeder: &Feeder<T>, account: &T::AccountId, now: MomentOf<T>) {
+			if Bonds::<T>::get(account) < T::MinFeederBond::get() {
+				return;
+			}
+
+			if let Some(last_rewarded) = LastRewarded::<T>::get(feeder) {
+				if now.saturating_sub(last_rewarded) < T::RewardInterval::get() {
+					return;
+				}
+			}
+
+			let amount = T::FeedReward::get();
+			let paid = T::Currency::transfer(
+				&Self::rewards_account(),
+				account,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			);
+
+			if paid.is_ok() {
+				LastRewarded::<T>::insert(feeder, now);
+				Stats::<T>::mutate(feeder, |stats| {
+					stats.rewarded = stats.rewarded.saturating_add(amount)
+				});
+
+				Self::deposit_event(Event::<T>::Rewarded {
+					account: account.clone(),
+					amount,
+				});
+			}
+		}
+
+		/// Slashes a proportion of the bond of a feeder into the rewards
+		/// account.
+		fn slash(account: &T::AccountId) -> BalanceOf<T> {
+			let bond = Bonds::<T>::get(account);
+			let amount = T::SlashFraction::get() * bond;
+
+			// The amount that could not be moved is not slashed
+			let not_slashed = T::Currency::repatriate_reserved(
+				account,
+				&Self::rewards_account(),
+				amount,
+				BalanceStatus::Free,
+			)
+			.unwrap_or(amount);
+
+			let slashed = amount.saturating_sub(not_slashed);
+			if slashed.is_zero() {
+				return slashed;
+			}
+
+			Bonds::<T>::mutate_exists(account, |bond| {
+				let remaining = bond.unwrap_or_default().saturating_sub(slashed);
+				*bond = (!remaining.is_zero()).then_some(remaining);
+			});
+
+			Self::deposit_event(Event::<T>::Slashed {
+				account: account.clone(),
+				amount: slashed,
+			});
+
+			slashed
+		}
+
+		fn push_history(
+			feeder: &Feeder<T>,
+			key: &T::OracleKey,
+			fed_value: (T::OracleValue, MomentOf<T>),
+		) {
+			FedValuesHistory::<T>::mutate(feeder, key, |history| {
+				// If the history is full, the oldest value is discarded.
+				// It only fails if `MaxHistorySize` is 0, where nothing is stored.
+				let _ = history.force_insert_keep_right(history.len(), fed_value);
+			});
 		}
 	}
 
@@ -160,20 +699,142 @@ pub mod pallet {
 			Ok(FedValues::<T>::get(source.caller(), id))
 		}
 
+		fn history(
+			source: &T::RuntimeOrigin,
+			id: &T::OracleKey,
+		) -> Result<Vec<Self::Value>, DispatchError> {
+			Ok(FedValuesHistory::<T>::get(source.caller(), id).into_inner())
+		}
+
 		#[cfg(feature = "runtime-benchmarks")]
 		fn set(source: &T::RuntimeOrigin, key: &T::OracleKey, value: Self::Value) {
-			FedValues::<T>::insert(source.caller(), key, value)
+			FedValues::<T>::insert(source.caller(), key, value);
+			Self::push_history(source.caller(), key, value);
 		}
 	}
 }
 
+pub mod types {
+	use frame_support::pallet_prelude::*;
+	use sp_core::{ecdsa, sr25519};
+	use sp_runtime::traits::Verify;
+
+	/// Public key used by a feeder to sign attestations off-chain
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+	pub enum AttestationSigner {
+		Sr25519(sr25519::Public),
+		Ecdsa(ecdsa::Public),
+	}
+
+	/// Signature of an attestation
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+	pub enum AttestationSignature {
+		Sr25519(sr25519::Signature),
+		Ecdsa(ecdsa::Signature),
+	}
+
+	impl AttestationSignature {
+		/// Verifies the signature of a payload. The signature scheme must
+		/// match the one of the signer.
+		pub fn verify(&self, payload: &[u8], signer: &AttestationSigner) -> bool {
+			match (self, signer) {
+				(Self::Sr25519(signature), AttestationSigner::Sr25519(public)) => {
+					signature.verify(payload, public)
+				}
+				(Self::Ecdsa(signature), AttestationSigner::Ecdsa(public)) => {
+					signature.verify(payload, public)
+				}
+				_ => false,
+			}
+		}
+	}
+
+	/// Value of a key attested off-chain by a feeder
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+	pub struct Attestation<Key, Value, Moment> {
+		/// Key of the value
+		pub key: Key,
+
+		/// Attested value
+		pub value: Value,
+
+		/// Time when the value was attested
+		pub timestamp: Moment,
+	}
+
+	/// An attestation with its signer and signature
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+	pub struct SignedAttestation<Key, Value, Moment> {
+		pub attestation: Attestation<Key, Value, Moment>,
+		pub signer: AttestationSigner,
+		pub signature: AttestationSignature,
+	}
+
+	/// Performance statistics of a feeder
+	#[derive(
+		Encode, Decode, Clone, PartialEq, Eq, Default, TypeInfo, RuntimeDebug, MaxEncodedLen,
+	)]
+	pub struct FeederStats<Balance> {
+		/// Number of values fed
+		pub feeds: u32,
+
+		/// Total amount rewarded for feeding
+		pub rewarded: Balance,
+
+		/// Number of values of the feeder that were rated
+		pub rounds: u32,
+
+		/// Number of values of the feeder that were inaccurate
+		pub inaccurate_rounds: u32,
+
+		/// Number of consecutive inaccurate values since the last accurate
+		/// one or the last slash
+		pub inaccurate_streak: u32,
+
+		/// Total amount slashed from the bond
+		pub slashed: Balance,
+	}
+}
+
 pub mod util {
-	use parity_scale_codec::MaxEncodedLen;
+	use frame_support::BoundedVec;
+	use parity_scale_codec::{Encode, MaxEncodedLen};
+	use sp_std::vec::Vec;
+
+	use crate::{
+		pallet::{Config, MomentOf},
+		types::Attestation,
+	};
+
+	/// Prefix of the payload signed by an attestation
+	pub const ATTESTATION_PREFIX: [u8; 16] = *b"cfg/oracle/attst";
 
-	use crate::pallet::{Config, MomentOf};
+	/// Prefix of the payload signed to register a signer
+	pub const SIGNER_PREFIX: [u8; 16] = *b"cfg/oracle/signr";
+
+	/// Builds the payload signed to register a signer for a feeder: the SCALE
+	/// encoding of `(SIGNER_PREFIX, genesis_hash, feeder)`
+	pub fn signer_payload<Hash: Encode, Feeder: Encode>(
+		genesis_hash: &Hash,
+		feeder: &Feeder,
+	) -> Vec<u8> {
+		(SIGNER_PREFIX, genesis_hash, feeder).encode()
+	}
+
+	/// Builds the payload signed by an attestation: the SCALE encoding of
+	/// `(ATTESTATION_PREFIX, genesis_hash, attestation)`
+	pub fn attestation_payload<Hash: Encode, Key: Encode, Value: Encode, Moment: Encode>(
+		genesis_hash: &Hash,
+		attestation: &Attestation<Key, Value, Moment>,
+	) -> Vec<u8> {
+		(ATTESTATION_PREFIX, genesis_hash, attestation).encode()
+	}
 
 	pub fn size_of_feed<T: Config>() -> u32 {
-		let max_len = <(T::OracleKey, T::OracleValue, MomentOf<T>)>::max_encoded_len();
+		let max_len = <(T::OracleKey, T::OracleValue, MomentOf<T>)>::max_encoded_len()
+			.saturating_add(
+				BoundedVec::<(T::OracleValue, MomentOf<T>), T::MaxHistorySize>::max_encoded_len(),
+			);
 
 		match max_len.try_into() {
 			Ok(size) => size,
@@ -1,6 +1,12 @@
-use frame_support::{derive_impl, traits::EitherOfDiverse};
+use frame_support::{
+	derive_impl,
+	traits::{ConstU128, ConstU32, ConstU64, EitherOfDiverse},
+	PalletId,
+};
 use frame_system::{EnsureRoot, EnsureSigned};
 use sp_io::TestExternalities;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+use sp_runtime::Perbill;
 
 use crate::pallet as pallet_oracle_feed;
 
@@ -8,37 +14,84 @@ pub type AccountId = u64;
 pub type OracleKey = u8;
 pub type OracleValue = u128;
 pub type Timestamp = u64;
+pub type Balance = u128;
+
+pub const MAX_HISTORY_SIZE: u32 = 3;
+pub const MIN_FEEDER_BOND: Balance = 1000;
+pub const FEED_REWARD: Balance = 10;
+pub const REWARD_INTERVAL: Timestamp = 100;
+pub const SLASH_ROUNDS: u32 = 3;
+pub const MAX_ATTESTATIONS: u32 = 100;
+pub const MAX_ATTESTATION_AGE: Timestamp = 1000;
 
 frame_support::construct_runtime!(
 	pub enum Runtime {
 		System: frame_system,
 		MockTime: cfg_mocks::pallet_mock_time,
 		MockPayFee: cfg_mocks::pallet_mock_pay_fee,
+		MockFeederAccuracy: cfg_mocks::feeder_accuracy::pallet,
+		Balances: pallet_balances,
 		OracleFeed: pallet_oracle_feed,
 	}
 );
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type Block = frame_system::mocking::MockBlock<Runtime>;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type RuntimeHoldReason = ();
+}
+
 impl cfg_mocks::pallet_mock_time::Config for Runtime {
 	type Moment = Timestamp;
 }
 
 impl cfg_mocks::pallet_mock_pay_fee::Config for Runtime {}
 
+impl cfg_mocks::feeder_accuracy::pallet::Config for Runtime {
+	type Feeder = OriginCaller;
+	type Key = OracleKey;
+}
+
+frame_support::parameter_types! {
+	pub const RewardsPalletId: PalletId = PalletId(*b"or/rewrd");
+	pub const SlashDeviation: Perbill = Perbill::from_percent(10);
+	pub const SlashFraction: Perbill = Perbill::from_percent(50);
+}
+
 impl pallet_oracle_feed::Config for Runtime {
+	type Currency = Balances;
+	type FeedReward = ConstU128<FEED_REWARD>;
+	type FeederAccuracy = MockFeederAccuracy;
 	type FeederOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSigned<AccountId>>;
 	type FirstValuePayFee = MockPayFee;
+	type MaxAttestationAge = ConstU64<MAX_ATTESTATION_AGE>;
+	type MaxAttestations = ConstU32<MAX_ATTESTATIONS>;
+	type MaxHistorySize = ConstU32<MAX_HISTORY_SIZE>;
+	type MinFeederBond = ConstU128<MIN_FEEDER_BOND>;
 	type OracleKey = OracleKey;
 	type OracleValue = OracleValue;
+	type RewardInterval = ConstU64<REWARD_INTERVAL>;
+	type RewardsPalletId = RewardsPalletId;
 	type RuntimeEvent = RuntimeEvent;
+	type SlashDeviation = SlashDeviation;
+	type SlashFraction = SlashFraction;
+	type SlashRounds = ConstU32<SLASH_ROUNDS>;
 	type Time = MockTime;
 	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> TestExternalities {
-	System::externalities()
+	let mut ext = System::externalities();
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext.execute_with(|| MockFeederAccuracy::mock_deviation(|_, _| None));
+	ext
 }
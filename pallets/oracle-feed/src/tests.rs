@@ -65,3 +65,586 @@ fn get_unfeeded() {
 		assert_ok!(OracleFeed::get(&RuntimeOrigin::signed(FEEDER), &KEY), None);
 	});
 }
+
+#[test]
+fn feed_history() {
+	new_test_ext().execute_with(|| {
+		MockPayFee::mock_pay(|_| Ok(()));
+
+		for i in 0..(MAX_HISTORY_SIZE as u64 + 1) {
+			MockTime::mock_now(move || TIMESTAMP1 + i);
+			assert_ok!(OracleFeed::feed(
+				RuntimeOrigin::signed(FEEDER),
+				KEY,
+				VALUE1 + i as OracleValue
+			));
+		}
+
+		// The oldest value was discarded
+		assert_ok!(
+			OracleFeed::history(&RuntimeOrigin::signed(FEEDER), &KEY),
+			vec![
+				(VALUE1 + 1, TIMESTAMP1 + 1),
+				(VALUE1 + 2, TIMESTAMP1 + 2),
+				(VALUE1 + 3, TIMESTAMP1 + 3),
+			]
+		);
+	});
+}
+
+#[test]
+fn history_unfeeded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(
+			OracleFeed::history(&RuntimeOrigin::signed(FEEDER), &KEY),
+			vec![]
+		);
+	});
+}
+
+mod incentives {
+	use frame_support::{
+		assert_noop,
+		traits::{Currency, ReservableCurrency},
+	};
+	use sp_runtime::Perbill;
+
+	use super::*;
+	use crate::{Error, Pallet};
+
+	const INITIAL_BALANCE: Balance = 10_000;
+	const REWARDS_BALANCE: Balance = 1_000;
+
+	fn feeder() -> crate::Feeder<Runtime> {
+		RuntimeOrigin::signed(FEEDER).into_caller()
+	}
+
+	fn setup() {
+		MockPayFee::mock_pay(|_| Ok(()));
+		MockTime::mock_now(|| TIMESTAMP1);
+		mock_deviation(Perbill::zero());
+
+		Balances::make_free_balance_be(&FEEDER, INITIAL_BALANCE);
+		Balances::make_free_balance_be(&Pallet::<Runtime>::rewards_account(), REWARDS_BALANCE);
+	}
+
+	fn mock_deviation(deviation: Perbill) {
+		MockFeederAccuracy::mock_deviation(move |feeder_, key| {
+			assert_eq!(*feeder_, feeder());
+			assert_eq!(*key, KEY);
+			Some(deviation)
+		});
+	}
+
+	fn rate(rounds: u32, deviation: Perbill) {
+		mock_deviation(deviation);
+		for _ in 0..rounds {
+			assert_ok!(OracleFeed::feed(RuntimeOrigin::signed(FEEDER), KEY, VALUE1));
+		}
+	}
+
+	#[test]
+	fn bond_and_unbond() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_ok!(OracleFeed::bond(
+				RuntimeOrigin::signed(FEEDER),
+				MIN_FEEDER_BOND
+			));
+			assert_eq!(Balances::reserved_balance(FEEDER), MIN_FEEDER_BOND);
+			assert_eq!(OracleFeed::bond_of(FEEDER), MIN_FEEDER_BOND);
+
+			assert_noop!(
+				OracleFeed::unbond(RuntimeOrigin::signed(FEEDER), MIN_FEEDER_BOND + 1),
+				Error::<Runtime>::NotEnoughBond
+			);
+
+			assert_ok!(OracleFeed::unbond(
+				RuntimeOrigin::signed(FEEDER),
+				MIN_FEEDER_BOND
+			));
+			assert_eq!(Balances::reserved_balance(FEEDER), 0);
+			assert_eq!(OracleFeed::bond_of(FEEDER), 0);
+		});
+	}
+
+	#[test]
+	fn bond_from_root() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				OracleFeed::bond(RuntimeOrigin::root(), MIN_FEEDER_BOND),
+				Error::<Runtime>::NotSignedFeeder
+			);
+		});
+	}
+
+	#[test]
+	fn reward_bonded_feeder() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_ok!(OracleFeed::bond(
+				RuntimeOrigin::signed(FEEDER),
+				MIN_FEEDER_BOND
+			));
+
+			assert_ok!(OracleFeed::feed(RuntimeOrigin::signed(FEEDER), KEY, VALUE1));
+			System::assert_has_event(
+				Event::<Runtime>::Rewarded {
+					account: FEEDER,
+					amount: FEED_REWARD,
+				}
+				.into(),
+			);
+
+			// Feeding again before the reward interval is not rewarded
+			MockTime::mock_now(|| TIMESTAMP1 + REWARD_INTERVAL - 1);
+			assert_ok!(OracleFeed::feed(RuntimeOrigin::signed(FEEDER), KEY, VALUE2));
+			assert_eq!(OracleFeed::feeder_stats(feeder()).rewarded, FEED_REWARD);
+
+			MockTime::mock_now(|| TIMESTAMP1 + REWARD_INTERVAL);
+			assert_ok!(OracleFeed::feed(RuntimeOrigin::signed(FEEDER), KEY, VALUE2));
+
+			let stats = OracleFeed::feeder_stats(feeder());
+			assert_eq!(stats.feeds, 3);
+			assert_eq!(stats.rewarded, 2 * FEED_REWARD);
+			assert_eq!(
+				Balances::free_balance(FEEDER),
+				INITIAL_BALANCE - MIN_FEEDER_BOND + 2 * FEED_REWARD
+			);
+		});
+	}
+
+	#[test]
+	fn no_reward_without_bond() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_ok!(OracleFeed::feed(RuntimeOrigin::signed(FEEDER), KEY, VALUE1));
+
+			let stats = OracleFeed::feeder_stats(feeder());
+			assert_eq!(stats.feeds, 1);
+			assert_eq!(stats.rewarded, 0);
+			assert_eq!(Balances::free_balance(FEEDER), INITIAL_BALANCE);
+		});
+	}
+
+	#[test]
+	fn no_reward_for_inaccurate_value() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_ok!(OracleFeed::bond(
+				RuntimeOrigin::signed(FEEDER),
+				MIN_FEEDER_BOND
+			));
+			rate(1, Perbill::from_percent(20));
+
+			let stats = OracleFeed::feeder_stats(feeder());
+			assert_eq!(stats.rounds, 1);
+			assert_eq!(stats.inaccurate_rounds, 1);
+			assert_eq!(stats.rewarded, 0);
+		});
+	}
+
+	#[test]
+	fn no_reward_for_unrated_key() {
+		new_test_ext().execute_with(|| {
+			setup();
+			MockFeederAccuracy::mock_deviation(|_, _| None);
+
+			assert_ok!(OracleFeed::bond(
+				RuntimeOrigin::signed(FEEDER),
+				MIN_FEEDER_BOND
+			));
+			assert_ok!(OracleFeed::feed(RuntimeOrigin::signed(FEEDER), KEY, VALUE1));
+
+			let stats = OracleFeed::feeder_stats(feeder());
+			assert_eq!(stats.feeds, 1);
+			assert_eq!(stats.rounds, 0);
+			assert_eq!(stats.rewarded, 0);
+		});
+	}
+
+	#[test]
+	fn no_reward_with_empty_rewards_account() {
+		new_test_ext().execute_with(|| {
+			setup();
+			Balances::make_free_balance_be(&Pallet::<Runtime>::rewards_account(), 0);
+
+			assert_ok!(OracleFeed::bond(
+				RuntimeOrigin::signed(FEEDER),
+				MIN_FEEDER_BOND
+			));
+			assert_ok!(OracleFeed::feed(RuntimeOrigin::signed(FEEDER), KEY, VALUE1));
+
+			assert_eq!(OracleFeed::feeder_stats(feeder()).rewarded, 0);
+		});
+	}
+
+	#[test]
+	fn slash_after_inaccurate_rounds() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_ok!(OracleFeed::bond(
+				RuntimeOrigin::signed(FEEDER),
+				MIN_FEEDER_BOND
+			));
+
+			rate(SLASH_ROUNDS - 1, Perbill::from_percent(20));
+
+			// An accurate value resets the streak
+			rate(1, Perbill::from_percent(10));
+			rate(SLASH_ROUNDS - 1, Perbill::from_percent(20));
+			assert_eq!(Balances::reserved_balance(FEEDER), MIN_FEEDER_BOND);
+
+			assert_noop!(
+				OracleFeed::unbond(RuntimeOrigin::signed(FEEDER), MIN_FEEDER_BOND),
+				Error::<Runtime>::PendingInaccurateRounds
+			);
+
+			rate(1, Perbill::from_percent(20));
+
+			let slashed = MIN_FEEDER_BOND / 2;
+			System::assert_last_event(
+				Event::<Runtime>::Slashed {
+					account: FEEDER,
+					amount: slashed,
+				}
+				.into(),
+			);
+
+			assert_eq!(
+				Balances::reserved_balance(FEEDER),
+				MIN_FEEDER_BOND - slashed
+			);
+			assert_eq!(OracleFeed::bond_of(FEEDER), MIN_FEEDER_BOND - slashed);
+			assert_eq!(
+				Balances::free_balance(Pallet::<Runtime>::rewards_account()),
+				REWARDS_BALANCE - FEED_REWARD + slashed
+			);
+
+			let stats = OracleFeed::feeder_stats(feeder());
+			assert_eq!(stats.rounds, 2 * SLASH_ROUNDS);
+			assert_eq!(stats.inaccurate_rounds, 2 * SLASH_ROUNDS - 1);
+			assert_eq!(stats.inaccurate_streak, 0);
+			assert_eq!(stats.slashed, slashed);
+		});
+	}
+
+	#[test]
+	fn slash_only_the_bond() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			// Reserved by other means, not part of the bond
+			assert_ok!(Balances::reserve(&FEEDER, MIN_FEEDER_BOND));
+
+			rate(SLASH_ROUNDS, Perbill::from_percent(20));
+
+			assert_eq!(Balances::reserved_balance(FEEDER), MIN_FEEDER_BOND);
+			assert_eq!(OracleFeed::feeder_stats(feeder()).slashed, 0);
+		});
+	}
+}
+
+mod attestations {
+	use frame_support::{assert_noop, BoundedVec};
+	use sp_core::{ecdsa, sr25519, Pair};
+
+	use super::*;
+	use crate::{
+		types::{Attestation, AttestationSignature, AttestationSigner, SignedAttestation},
+		util, Error, Pallet, SignedAttestationOf,
+	};
+
+	const RELAYER: AccountId = 2;
+	const OTHER_FEEDER: AccountId = 3;
+	const NOW: Timestamp = 10_000;
+
+	fn sr25519_pair() -> sr25519::Pair {
+		sr25519::Pair::from_seed(&[1; 32])
+	}
+
+	fn ecdsa_pair() -> ecdsa::Pair {
+		ecdsa::Pair::from_seed(&[2; 32])
+	}
+
+	fn signer_payload(feeder: AccountId) -> Vec<u8> {
+		util::signer_payload(
+			&Pallet::<Runtime>::genesis_hash(),
+			&RuntimeOrigin::signed(feeder).into_caller(),
+		)
+	}
+
+	fn sr25519_proof(feeder: AccountId) -> AttestationSignature {
+		AttestationSignature::Sr25519(sr25519_pair().sign(&signer_payload(feeder)))
+	}
+
+	fn ecdsa_proof(feeder: AccountId) -> AttestationSignature {
+		AttestationSignature::Ecdsa(ecdsa_pair().sign(&signer_payload(feeder)))
+	}
+
+	fn attestation(
+		value: OracleValue,
+		timestamp: Timestamp,
+	) -> Attestation<OracleKey, OracleValue, Timestamp> {
+		Attestation {
+			key: KEY,
+			value,
+			timestamp,
+		}
+	}
+
+	fn payload(attestation: &Attestation<OracleKey, OracleValue, Timestamp>) -> Vec<u8> {
+		util::attestation_payload(&Pallet::<Runtime>::genesis_hash(), attestation)
+	}
+
+	fn sign_sr25519(value: OracleValue, timestamp: Timestamp) -> SignedAttestationOf<Runtime> {
+		let attestation = attestation(value, timestamp);
+		let signature = sr25519_pair().sign(&payload(&attestation));
+
+		SignedAttestation {
+			attestation,
+			signer: AttestationSigner::Sr25519(sr25519_pair().public()),
+			signature: AttestationSignature::Sr25519(signature),
+		}
+	}
+
+	fn sign_ecdsa(value: OracleValue, timestamp: Timestamp) -> SignedAttestationOf<Runtime> {
+		let attestation = attestation(value, timestamp);
+		let signature = ecdsa_pair().sign(&payload(&attestation));
+
+		SignedAttestation {
+			attestation,
+			signer: AttestationSigner::Ecdsa(ecdsa_pair().public()),
+			signature: AttestationSignature::Ecdsa(signature),
+		}
+	}
+
+	fn batch(
+		attestations: impl IntoIterator<Item = SignedAttestationOf<Runtime>>,
+	) -> BoundedVec<SignedAttestationOf<Runtime>, <Runtime as crate::Config>::MaxAttestations> {
+		BoundedVec::try_from(attestations.into_iter().collect::<Vec<_>>()).unwrap()
+	}
+
+	fn setup() {
+		MockTime::mock_now(|| NOW);
+		MockPayFee::mock_pay(|account| {
+			assert_eq!(*account, RELAYER);
+			Ok(())
+		});
+
+		assert_ok!(OracleFeed::register_attestation_signer(
+			RuntimeOrigin::signed(FEEDER),
+			AttestationSigner::Sr25519(sr25519_pair().public()),
+			sr25519_proof(FEEDER),
+		));
+		assert_ok!(OracleFeed::register_attestation_signer(
+			RuntimeOrigin::signed(OTHER_FEEDER),
+			AttestationSigner::Ecdsa(ecdsa_pair().public()),
+			ecdsa_proof(OTHER_FEEDER),
+		));
+	}
+
+	#[test]
+	fn register_and_unregister_signer() {
+		new_test_ext().execute_with(|| {
+			let signer = AttestationSigner::Sr25519(sr25519_pair().public());
+
+			assert_ok!(OracleFeed::register_attestation_signer(
+				RuntimeOrigin::signed(FEEDER),
+				signer.clone(),
+				sr25519_proof(FEEDER),
+			));
+
+			System::assert_last_event(
+				Event::<Runtime>::AttestationSignerRegistered {
+					feeder: RuntimeOrigin::signed(FEEDER).into_caller(),
+					signer: signer.clone(),
+				}
+				.into(),
+			);
+
+			assert_noop!(
+				OracleFeed::register_attestation_signer(
+					RuntimeOrigin::signed(OTHER_FEEDER),
+					signer.clone(),
+					sr25519_proof(OTHER_FEEDER),
+				),
+				Error::<Runtime>::SignerAlreadyRegistered
+			);
+
+			assert_noop!(
+				OracleFeed::unregister_attestation_signer(
+					RuntimeOrigin::signed(OTHER_FEEDER),
+					signer.clone(),
+				),
+				Error::<Runtime>::SignerNotRegistered
+			);
+
+			assert_ok!(OracleFeed::unregister_attestation_signer(
+				RuntimeOrigin::signed(FEEDER),
+				signer.clone(),
+			));
+
+			System::assert_last_event(
+				Event::<Runtime>::AttestationSignerUnregistered {
+					feeder: RuntimeOrigin::signed(FEEDER).into_caller(),
+					signer,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn register_signer_without_proof_of_possession() {
+		new_test_ext().execute_with(|| {
+			let signer = AttestationSigner::Sr25519(sr25519_pair().public());
+
+			// Proof signed for another feeder
+			assert_noop!(
+				OracleFeed::register_attestation_signer(
+					RuntimeOrigin::signed(OTHER_FEEDER),
+					signer.clone(),
+					sr25519_proof(FEEDER),
+				),
+				Error::<Runtime>::InvalidSignerProof
+			);
+
+			// Proof signed by another key
+			assert_noop!(
+				OracleFeed::register_attestation_signer(
+					RuntimeOrigin::signed(FEEDER),
+					signer,
+					ecdsa_proof(FEEDER),
+				),
+				Error::<Runtime>::InvalidSignerProof
+			);
+		});
+	}
+
+	#[test]
+	fn feed_attestations() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_ok!(OracleFeed::feed_attestations(
+				RuntimeOrigin::signed(RELAYER),
+				batch([sign_sr25519(VALUE1, NOW - 10), sign_ecdsa(VALUE2, NOW - 20)]),
+			));
+
+			assert_ok!(
+				OracleFeed::get(&RuntimeOrigin::signed(FEEDER), &KEY),
+				Some((VALUE1, NOW - 10))
+			);
+			assert_ok!(
+				OracleFeed::get(&RuntimeOrigin::signed(OTHER_FEEDER), &KEY),
+				Some((VALUE2, NOW - 20))
+			);
+
+			System::assert_has_event(
+				Event::<Runtime>::Fed {
+					feeder: RuntimeOrigin::signed(FEEDER).into_caller(),
+					key: KEY,
+					value: VALUE1,
+				}
+				.into(),
+			);
+
+			// Feeding newer values again do not require fees
+			MockPayFee::mock_pay(|_| {
+				unreachable!("Feeding the same key again do not require fees")
+			});
+
+			assert_ok!(OracleFeed::feed_attestations(
+				RuntimeOrigin::signed(RELAYER),
+				batch([sign_sr25519(VALUE2, NOW)]),
+			));
+
+			assert_ok!(
+				OracleFeed::history(&RuntimeOrigin::signed(FEEDER), &KEY),
+				vec![(VALUE1, NOW - 10), (VALUE2, NOW)]
+			);
+		});
+	}
+
+	#[test]
+	fn feed_attestations_with_wrong_signature() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			let mut signed = sign_sr25519(VALUE1, NOW);
+			signed.attestation.value = VALUE2;
+
+			assert_noop!(
+				OracleFeed::feed_attestations(RuntimeOrigin::signed(RELAYER), batch([signed])),
+				Error::<Runtime>::InvalidAttestationSignature
+			);
+
+			// The signature scheme must match the signer
+			let mut signed = sign_sr25519(VALUE1, NOW);
+			signed.signature = sign_ecdsa(VALUE1, NOW).signature;
+
+			assert_noop!(
+				OracleFeed::feed_attestations(RuntimeOrigin::signed(RELAYER), batch([signed])),
+				Error::<Runtime>::InvalidAttestationSignature
+			);
+		});
+	}
+
+	#[test]
+	fn feed_attestations_from_unregistered_signer() {
+		new_test_ext().execute_with(|| {
+			MockTime::mock_now(|| NOW);
+
+			assert_noop!(
+				OracleFeed::feed_attestations(
+					RuntimeOrigin::signed(RELAYER),
+					batch([sign_sr25519(VALUE1, NOW)])
+				),
+				Error::<Runtime>::SignerNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn feed_attestations_with_wrong_timestamps() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_noop!(
+				OracleFeed::feed_attestations(
+					RuntimeOrigin::signed(RELAYER),
+					batch([sign_sr25519(VALUE1, NOW + 1)])
+				),
+				Error::<Runtime>::AttestationFromFuture
+			);
+
+			assert_noop!(
+				OracleFeed::feed_attestations(
+					RuntimeOrigin::signed(RELAYER),
+					batch([sign_sr25519(VALUE1, NOW - MAX_ATTESTATION_AGE - 1)])
+				),
+				Error::<Runtime>::AttestationOutdated
+			);
+
+			assert_ok!(OracleFeed::feed_attestations(
+				RuntimeOrigin::signed(RELAYER),
+				batch([sign_sr25519(VALUE1, NOW)])
+			));
+
+			// Replaying the same attestation is not allowed
+			assert_noop!(
+				OracleFeed::feed_attestations(
+					RuntimeOrigin::signed(RELAYER),
+					batch([sign_sr25519(VALUE1, NOW)])
+				),
+				Error::<Runtime>::AttestationNotNewer
+			);
+		});
+	}
+}
@@ -11,11 +11,67 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use frame_support::weights::Weight;
+use frame_support::weights::{constants::RocksDbWeight, Weight};
 
 pub trait WeightInfo {
 	fn feed_with_fee() -> Weight;
 	fn feed_without_fee() -> Weight;
+
+	fn bond() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `feed_with_fee`, which also mutates
+		//       the account of the feeder. This one reserves the amount and
+		//       writes the bond.
+		Weight::from_parts(50_855_000, 4176)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn unbond() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `feed_with_fee`, which also mutates
+		//       the account of the feeder. This one reads the stats of the
+		//       feeder, unreserves the amount and writes the bond.
+		Weight::from_parts(50_855_000, 4176)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn register_attestation_signer() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `feed_with_fee`, covering the
+		//       verification of the proof. This one reads and writes the signer.
+		Weight::from_parts(50_855_000, 4176)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn unregister_attestation_signer() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `feed_without_fee`. This one reads
+		//       and removes the signer.
+		Weight::from_parts(20_097_000, 4176)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn feed_attestations(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken twice from `feed_with_fee` per
+		//       attestation, covering the verification of the signature and
+		//       the rating of the feeder. Per attestation, this one reads the
+		//       signer, writes the value and its history, pays the first value
+		//       fee and rewards or slashes the feeder.
+		Weight::from_parts(101_710_000, 4176)
+			.saturating_mul(n.into())
+			.saturating_add(RocksDbWeight::get().reads(10_u64.saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64.saturating_mul(n.into())))
+	}
 }
 
 impl WeightInfo for () {
@@ -26,4 +82,24 @@ impl WeightInfo for () {
 	fn feed_without_fee() -> Weight {
 		Weight::zero()
 	}
+
+	fn bond() -> Weight {
+		Weight::zero()
+	}
+
+	fn unbond() -> Weight {
+		Weight::zero()
+	}
+
+	fn register_attestation_signer() -> Weight {
+		Weight::zero()
+	}
+
+	fn unregister_attestation_signer() -> Weight {
+		Weight::zero()
+	}
+
+	fn feed_attestations(_: u32) -> Weight {
+		Weight::zero()
+	}
 }
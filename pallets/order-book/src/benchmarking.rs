@@ -11,11 +11,11 @@
 // GNU General Public License for more details.
 
 use cfg_traits::{
-	swaps::{OrderRatio, TokenSwaps},
+	swaps::{OrderOptions, OrderRatio, TokenSwaps},
 	AssetMetadataOf, ValueProvider,
 };
 use frame_benchmarking::{account, v2::*};
-use frame_support::traits::fungibles::Mutate as _;
+use frame_support::traits::fungibles::{Inspect as _, Mutate as _};
 use frame_system::RawOrigin;
 use orml_traits::asset_registry::{Inspect, Mutate};
 use sp_runtime::{
@@ -32,10 +32,14 @@ const FEEDER: u32 = 23;
 
 #[cfg(test)]
 fn init_mocks() {
-	use crate::mock::{MockFulfilledOrderHook, MockRatioProvider, Ratio};
+	use crate::mock::{
+		MockExpiredOrderHook, MockFulfilledOrderHook, MockRatioProvider, MockTime, Ratio,
+	};
 
 	MockFulfilledOrderHook::mock_notify_status_change(|_, _| Ok(()));
+	MockExpiredOrderHook::mock_notify_status_change(|_, _| Ok(()));
 	MockRatioProvider::mock_get(|_, _| Ok(Some(Ratio::saturating_from_integer(RATIO))));
+	MockTime::mock_now(|| 0);
 }
 
 struct Helper<T>(sp_std::marker::PhantomData<T>);
@@ -118,6 +122,23 @@ where
 			CURRENCY_OUT.into(),
 			Self::amount_out(),
 			OrderRatio::Market,
+			OrderOptions::default(),
+		)
+		.unwrap()
+	}
+
+	/// Places an order swapping back all the funds of `account_in` at the
+	/// inverse ratio, crossing the order placed by `place_order()`.
+	pub fn place_counter_order(account_in: &T::AccountId) -> T::OrderIdNonce {
+		let amount = T::Currency::balance(CURRENCY_IN.into(), account_in);
+
+		<Pallet<T> as TokenSwaps<T::AccountId>>::place_order(
+			account_in.clone(),
+			CURRENCY_OUT.into(),
+			CURRENCY_IN.into(),
+			amount.into(),
+			OrderRatio::Custom(T::Ratio::saturating_from_rational(1, RATIO)),
+			OrderOptions::default(),
 		)
 		.unwrap()
 	}
@@ -235,5 +256,53 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn match_orders() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let (account_out, account_in) = Helper::<T>::setup();
+		let order_id = Helper::<T>::place_order(&account_out);
+		let counter_order_id = Helper::<T>::place_counter_order(&account_in);
+
+		Helper::<T>::feed_market();
+
+		#[block]
+		{
+			Pallet::<T>::match_pending_orders(frame_support::weights::Weight::MAX);
+		}
+
+		assert!(Orders::<T>::get(order_id).is_err());
+		assert!(Orders::<T>::get(counter_order_id).is_err());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_order() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let (account_out, _) = Helper::<T>::setup();
+		let order_id = Helper::<T>::place_order(&account_out);
+
+		// Already expired, as if the time had passed since placing it
+		Orders::<T>::mutate(order_id, |order| {
+			if let Ok(order) = order {
+				order.options.expires_at = Some(0);
+			}
+		});
+		ExpiringOrders::<T>::insert(order_id, 0);
+
+		#[block]
+		{
+			Pallet::<T>::expire_orders(frame_support::weights::Weight::MAX);
+		}
+
+		assert!(Orders::<T>::get(order_id).is_err());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -15,6 +15,21 @@
 //! The Orderbook pallet allows orders for currency swaps to be placed and
 //! fulfilled.
 //!
+//! Orders are also matched against each other on idle time. The orders of
+//! each pair of currencies are kept sorted by price-time priority: the order
+//! with the best ratio goes first and, for the same ratio, the oldest one.
+//! Market orders are priced with the ratio of the market feeder. Two orders
+//! cross when the product of their ratios is not above one, and they are
+//! swapped at the ratio of the oldest one, partially filling the biggest.
+//!
+//! Orders can be placed with options, through `place_order_with_options`:
+//! - An expiry time, after which the order is removed on idle time and its
+//!   reserved funds are returned to the placing account.
+//! - Fill-or-kill, where the order must be completely matched against the
+//!   existing orders when placed, or it is not placed at all.
+//! - A minimum fill amount, which replaces the default minimum fulfillment
+//!   amount for each partial fill, including the ones made by matching.
+//!
 //! This pallet was made using the ZeitGeist Orderbook pallet as a reference;
 //! with much of the code being copied or adapted from that pallet.
 //! The ZeitGeist Orderbook pallet can be found here:
@@ -31,6 +46,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
 pub use pallet::*;
@@ -40,11 +56,12 @@ pub use weights::WeightInfo;
 pub mod pallet {
 	use cfg_primitives::conversion::convert_balance_decimals;
 	use cfg_traits::{
-		swaps::{OrderInfo, OrderRatio, Swap, SwapInfo, TokenSwaps},
-		StatusNotificationHook, ValueProvider,
+		swaps::{OrderInfo, OrderOptions, OrderRatio, Swap, SwapInfo, TokenSwaps},
+		Seconds, StatusNotificationHook, TimeAsSecs, ValueProvider,
 	};
 	use frame_support::{
 		pallet_prelude::{DispatchResult, Member, StorageDoubleMap, StorageValue, *},
+		storage::with_storage_layer,
 		traits::{
 			fungibles::{Inspect as AssetInspect, InspectHold, Mutate, MutateHold},
 			tokens::{AssetId, Precision, Preservation},
@@ -63,12 +80,15 @@ pub mod pallet {
 		},
 		FixedPointNumber, FixedPointOperand, TokenError,
 	};
-	use sp_std::cmp::{min, Ordering};
+	use sp_std::{
+		cmp::{min, Ordering},
+		vec::Vec,
+	};
 
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as AssetInspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -177,10 +197,46 @@ pub mod pallet {
 		/// The admin origin of this pallet
 		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Max number of orders of each ratio kind that can be placed to swap
+		/// from one currency to another.
+		///
+		/// NOTE: Orders placed through `TokenSwaps` by other pallets are still
+		/// placed when this limit is reached, but they are not matched on idle
+		/// time and can only be filled.
+		#[pallet::constant]
+		type MaxOrdersPerPair: Get<u32>;
+
+		/// Max number of orders a fill-or-kill order can be matched against
+		/// when placed.
+		#[pallet::constant]
+		type MaxFillOrKillMatches: Get<u32>;
+
+		/// The hook which acts upon an expired order, with its remaining swap
+		type ExpiredOrderHook: StatusNotificationHook<
+			Id = Self::OrderIdNonce,
+			Status = Swap<Self::BalanceOut, Self::CurrencyId>,
+			Error = DispatchError,
+		>;
+
+		/// Time provider to check the order expiries
+		type Time: TimeAsSecs;
+
 		/// Type for pallet weights
 		type Weights: WeightInfo;
 	}
 
+	/// Result of matching two orders of opposite directions
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+	pub(crate) enum MatchOutcome<OrderId> {
+		/// Any of the orders changed
+		Matched,
+		/// The orders do not cross, neither do any worse orders of the pair
+		NotCrossing,
+		/// The order limits the fill below the min fill amounts, so it can not
+		/// be matched with the other one
+		Unfillable(OrderId),
+	}
+
 	/// Order Storage item.
 	/// Contains fields relevant to order information
 	#[derive(
@@ -211,6 +267,9 @@ pub mod pallet {
 
 		/// Price given for this order,
 		pub ratio: OrderRatio<T::Ratio>,
+
+		/// How this order can be filled and for how long
+		pub options: OrderOptions<T::BalanceOut>,
 	}
 
 	/// Map of Orders to look up orders by their order id.
@@ -250,6 +309,48 @@ pub mod pallet {
 	pub type MarketFeederId<T: Config> =
 		StorageValue<_, T::FeederId, ResultQuery<Error<T>::MarketFeederNotFound>>;
 
+	/// Custom ratio orders swapping from `currency_out` to `currency_in`,
+	/// sorted by price-time priority: lower ratio first and, for the same
+	/// ratio, older order first.
+	#[pallet::storage]
+	pub type SortedOrders<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::CurrencyId,
+		Twox64Concat,
+		T::CurrencyId,
+		BoundedVec<(T::Ratio, T::OrderIdNonce), T::MaxOrdersPerPair>,
+		ValueQuery,
+	>;
+
+	/// Market ratio orders swapping from `currency_out` to `currency_in`,
+	/// older order first.
+	#[pallet::storage]
+	pub type MarketOrders<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::CurrencyId,
+		Twox64Concat,
+		T::CurrencyId,
+		BoundedVec<T::OrderIdNonce, T::MaxOrdersPerPair>,
+		ValueQuery,
+	>;
+
+	/// Pairs of currencies, in any order, with orders that could be matched.
+	#[pallet::storage]
+	pub type PendingMatches<T: Config> =
+		StorageMap<_, Twox64Concat, (T::CurrencyId, T::CurrencyId), (), OptionQuery>;
+
+	/// Expiry time of the orders placed with one.
+	#[pallet::storage]
+	pub type ExpiringOrders<T: Config> =
+		StorageMap<_, Twox64Concat, T::OrderIdNonce, Seconds, OptionQuery>;
+
+	/// Last expiring order checked by the expiry sweeper, from where the next
+	/// sweep continues.
+	#[pallet::storage]
+	pub type ExpirySweepCursor<T: Config> = StorageValue<_, T::OrderIdNonce, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -292,6 +393,19 @@ pub mod pallet {
 		},
 		/// Event emitted when a valid trading pair is removed.
 		FeederChanged { feeder_id: T::FeederId },
+		/// Event emitted when an order expired and its remaining amount was
+		/// returned.
+		OrderExpired {
+			order_id: T::OrderIdNonce,
+			account: T::AccountId,
+			amount_out: T::BalanceOut,
+		},
+		/// Event emitted when an expired order could not be removed. It is
+		/// tried again in the next sweep.
+		OrderExpiryFailed {
+			order_id: T::OrderIdNonce,
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::error]
@@ -321,6 +435,22 @@ pub mod pallet {
 		/// The amount of assets to sell exceeds the maximum provided by the
 		/// fulfilling account
 		SlippageExceeded,
+		/// The max number of orders for the pair of currencies was reached
+		TooManyOrders,
+		/// The order expired or is placed with an expiry time already passed
+		OrderExpired,
+		/// The fill-or-kill order could not be completely filled when placed
+		FillOrKillNotFilled,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let weight = Self::expire_orders(remaining_weight);
+			weight.saturating_add(Self::match_pending_orders(
+				remaining_weight.saturating_sub(weight),
+			))
+		}
 	}
 
 	#[pallet::call]
@@ -335,18 +465,14 @@ pub mod pallet {
 			amount_out: T::BalanceOut,
 			ratio: OrderRatio<T::Ratio>,
 		) -> DispatchResult {
-			let account_id = ensure_signed(origin)?;
-
-			Self::inner_place_order(
-				account_id,
+			Self::place_order_with_options(
+				origin,
 				currency_in,
 				currency_out,
 				amount_out,
 				ratio,
-				Self::min_fulfillment_amount(currency_out)?,
-			)?;
-
-			Ok(())
+				OrderOptions::default(),
+			)
 		}
 
 		/// Update an existing order
@@ -371,6 +497,7 @@ pub mod pallet {
 				amount_out,
 				ratio,
 				Self::min_fulfillment_amount(order.currency_out)?,
+				false,
 			)
 		}
 
@@ -419,6 +546,53 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Create an order with the given options.
+		/// The min fill amount of the options can not be below the default min
+		/// fulfillment amount.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::Weights::place_order()
+			.saturating_add(match options.expires_at {
+				Some(_) => T::DbWeight::get().writes(1),
+				None => Weight::zero(),
+			})
+			.saturating_add(match options.fill_or_kill {
+				true => T::Weights::match_orders()
+					.saturating_mul(T::MaxFillOrKillMatches::get().into()),
+				false => Weight::zero(),
+			})
+		)]
+		pub fn place_order_with_options(
+			origin: OriginFor<T>,
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_out: T::BalanceOut,
+			ratio: OrderRatio<T::Ratio>,
+			options: OrderOptions<T::BalanceOut>,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+			let min_fulfillment_amount_out = Self::min_fulfillment_amount(currency_out)?;
+
+			ensure!(
+				options
+					.min_fill
+					.map_or(true, |min_fill| min_fill >= min_fulfillment_amount_out),
+				Error::<T>::BelowMinFulfillmentAmount
+			);
+
+			Self::inner_place_order(
+				account_id,
+				currency_in,
+				currency_out,
+				amount_out,
+				ratio,
+				options,
+				min_fulfillment_amount_out,
+				false,
+			)?;
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -428,7 +602,9 @@ pub mod pallet {
 			currency_out: T::CurrencyId,
 			amount_out: T::BalanceOut,
 			ratio: OrderRatio<T::Ratio>,
+			options: OrderOptions<T::BalanceOut>,
 			min_fulfillment_amount_out: T::BalanceOut,
+			allow_unindexed: bool,
 		) -> Result<T::OrderIdNonce, DispatchError> {
 			let order_id = OrderIdNonceStore::<T>::try_mutate(|n| {
 				n.ensure_add_assign(One::one())?;
@@ -442,6 +618,11 @@ pub mod pallet {
 
 			ensure!(currency_in != currency_out, Error::<T>::SameCurrencyIds);
 
+			if let Some(expires_at) = options.expires_at {
+				ensure!(expires_at > T::Time::now(), Error::<T>::OrderExpired);
+				ExpiringOrders::<T>::insert(order_id, expires_at);
+			}
+
 			T::Currency::hold(currency_out, &(), &account, amount_out.into())?;
 
 			let new_order = Order {
@@ -453,8 +634,11 @@ pub mod pallet {
 				ratio,
 				amount_out_initial: amount_out,
 				amount_in: Zero::zero(),
+				options,
 			};
 
+			Self::index_order(&new_order, allow_unindexed)?;
+
 			Orders::<T>::insert(order_id, new_order.clone());
 			UserOrders::<T>::insert(&account, order_id, ());
 
@@ -468,14 +652,50 @@ pub mod pallet {
 				min_fulfillment_amount_out,
 			});
 
+			if options.fill_or_kill {
+				Self::fill_or_kill(order_id)?;
+			}
+
 			Ok(order_id)
 		}
 
+		/// Matches a just placed order against the best counter orders until
+		/// it is completely filled. Fails otherwise.
+		fn fill_or_kill(order_id: T::OrderIdNonce) -> DispatchResult {
+			for _ in 0..T::MaxFillOrKillMatches::get() {
+				let Ok(order) = Orders::<T>::get(order_id) else {
+					break;
+				};
+
+				let order_ratio = Self::order_ratio(&order)?;
+
+				let Some((counter_ratio, counter)) =
+					Self::best_order(order.currency_in, order.currency_out, &[])?
+				else {
+					break;
+				};
+
+				if Self::match_orders(order_ratio, order, counter_ratio, counter)?
+					!= MatchOutcome::Matched
+				{
+					break;
+				}
+			}
+
+			ensure!(
+				!Orders::<T>::contains_key(order_id),
+				Error::<T>::FillOrKillNotFilled
+			);
+
+			Ok(())
+		}
+
 		fn inner_update_order(
 			mut order: Order<T>,
 			amount_out: T::BalanceOut,
 			ratio: OrderRatio<T::Ratio>,
 			min_fulfillment_amount_out: T::BalanceOut,
+			allow_unindexed: bool,
 		) -> DispatchResult {
 			ensure!(
 				amount_out >= min_fulfillment_amount_out,
@@ -508,8 +728,17 @@ pub mod pallet {
 				Ordering::Equal => (),
 			}
 
+			// Orders are indexed by ratio and id, so only changing the ratio
+			// moves the order, which keeps its time priority for the new ratio
+			if ratio != order.ratio {
+				Self::unindex_order(&order);
+				order.ratio = ratio;
+				Self::index_order(&order, allow_unindexed)?;
+			} else {
+				Self::note_pending_match(order.currency_out, order.currency_in);
+			}
+
 			order.amount_out = amount_out;
-			order.ratio = ratio;
 
 			Orders::<T>::insert(order.order_id, order.clone());
 
@@ -530,51 +759,413 @@ pub mod pallet {
 
 			Orders::<T>::remove(order.order_id);
 			UserOrders::<T>::remove(&order.placing_account, order.order_id);
+			ExpiringOrders::<T>::remove(order.order_id);
+			Self::unindex_order(&order);
+
+			Ok(())
+		}
+
+		fn is_expired(order: &Order<T>) -> bool {
+			order
+				.options
+				.expires_at
+				.map_or(false, |expires_at| expires_at <= T::Time::now())
+		}
+
+		/// Removes an expired order, returning its reserved amount and
+		/// notifying its remaining swap.
+		fn expire_order(order: Order<T>) -> DispatchResult {
+			T::Currency::release(
+				order.currency_out,
+				&(),
+				&order.placing_account,
+				order.amount_out.into(),
+				Precision::Exact,
+			)?;
+
+			Self::remove_order(order.order_id)?;
+
+			T::ExpiredOrderHook::notify_status_change(
+				order.order_id,
+				Swap {
+					amount_out: order.amount_out,
+					currency_in: order.currency_in,
+					currency_out: order.currency_out,
+				},
+			)?;
+
+			Self::deposit_event(Event::OrderExpired {
+				order_id: order.order_id,
+				account: order.placing_account,
+				amount_out: order.amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Expires the orders whose expiry time has passed, continuing from the
+		/// last checked order until all were checked or the weight limit is
+		/// reached.
+		pub fn expire_orders(max_weight: Weight) -> Weight {
+			let expire_weight = T::Weights::expire_order();
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+			if weight.any_gt(max_weight) {
+				return Weight::zero();
+			}
+
+			let iter = match ExpirySweepCursor::<T>::get() {
+				Some(order_id) => {
+					ExpiringOrders::<T>::iter_from(ExpiringOrders::<T>::hashed_key_for(order_id))
+				}
+				None => ExpiringOrders::<T>::iter(),
+			};
+
+			let mut last_checked = None;
+			let mut completed = true;
+			let mut expired = Vec::new();
+
+			for (order_id, expires_at) in iter {
+				if weight.saturating_add(expire_weight).any_gt(max_weight) {
+					completed = false;
+					break;
+				}
+
+				weight.saturating_accrue(expire_weight);
+				last_checked = Some(order_id);
+
+				if expires_at <= T::Time::now() {
+					expired.push(order_id);
+				}
+			}
+
+			match (completed, last_checked) {
+				(true, _) => ExpirySweepCursor::<T>::kill(),
+				(false, Some(order_id)) => ExpirySweepCursor::<T>::put(order_id),
+				(false, None) => (),
+			}
+
+			for order_id in expired {
+				let result = match Orders::<T>::get(order_id) {
+					Ok(order) => with_storage_layer(|| Self::expire_order(order)),
+					Err(_) => {
+						// The order is no longer in the book
+						ExpiringOrders::<T>::remove(order_id);
+						continue;
+					}
+				};
+
+				if let Err(error) = result {
+					// The order is kept and expired again in the next sweep
+					Self::deposit_event(Event::OrderExpiryFailed { order_id, error });
+				}
+			}
+
+			weight
+		}
+
+		/// Adds an order to the orders of its pair, by price-time priority.
+		/// If the pair is full, the order is left unindexed when allowed.
+		fn index_order(order: &Order<T>, allow_unindexed: bool) -> DispatchResult {
+			let indexed = match order.ratio {
+				OrderRatio::Custom(ratio) => {
+					SortedOrders::<T>::try_mutate(order.currency_out, order.currency_in, |orders| {
+						let entry = (ratio, order.order_id);
+						let index = orders.partition_point(|other| *other < entry);
+						orders
+							.try_insert(index, entry)
+							.map_err(|_| Error::<T>::TooManyOrders)
+					})
+				}
+				OrderRatio::Market => {
+					MarketOrders::<T>::try_mutate(order.currency_out, order.currency_in, |orders| {
+						orders
+							.try_push(order.order_id)
+							.map_err(|_| Error::<T>::TooManyOrders)
+					})
+				}
+			};
+
+			match indexed {
+				Ok(()) => Self::note_pending_match(order.currency_out, order.currency_in),
+				Err(_) if allow_unindexed => (),
+				Err(e) => Err(e)?,
+			}
 
 			Ok(())
 		}
 
+		/// Removes an order from the orders of its pair, if it was there
+		fn unindex_order(order: &Order<T>) {
+			match order.ratio {
+				OrderRatio::Custom(_) => {
+					SortedOrders::<T>::mutate(order.currency_out, order.currency_in, |orders| {
+						orders.retain(|(_, order_id)| *order_id != order.order_id)
+					})
+				}
+				OrderRatio::Market => {
+					MarketOrders::<T>::mutate(order.currency_out, order.currency_in, |orders| {
+						orders.retain(|order_id| *order_id != order.order_id)
+					})
+				}
+			}
+		}
+
+		fn pair_key(
+			currency_a: T::CurrencyId,
+			currency_b: T::CurrencyId,
+		) -> (T::CurrencyId, T::CurrencyId) {
+			match currency_a < currency_b {
+				true => (currency_a, currency_b),
+				false => (currency_b, currency_a),
+			}
+		}
+
+		fn note_pending_match(currency_a: T::CurrencyId, currency_b: T::CurrencyId) {
+			PendingMatches::<T>::insert(Self::pair_key(currency_a, currency_b), ());
+		}
+
+		/// Matches the orders of the pending pairs of currencies until there
+		/// are no more crossing orders or the weight limit is reached.
+		/// Orders which can not be matched with the best counter order, or
+		/// fail to be matched, are skipped in favor of the next ones of their
+		/// side. Settled pairs stop being pending, including the ones with
+		/// market orders, until an order of the pair is placed or updated.
+		pub fn match_pending_orders(max_weight: Weight) -> Weight {
+			let match_weight = T::Weights::match_orders();
+			let settle_weight = T::DbWeight::get().writes(1);
+			let mut weight = Weight::zero();
+			let mut settled_pairs = Vec::new();
+
+			'pairs: for (currency_a, currency_b) in PendingMatches::<T>::iter_keys() {
+				let mut skipped = Vec::new();
+
+				loop {
+					if weight
+						.saturating_add(match_weight)
+						.saturating_add(settle_weight)
+						.any_gt(max_weight)
+					{
+						break 'pairs;
+					}
+
+					weight.saturating_accrue(match_weight);
+
+					let (Ok(Some((order_ratio, order))), Ok(Some((counter_ratio, counter)))) = (
+						Self::best_order(currency_a, currency_b, &skipped),
+						Self::best_order(currency_b, currency_a, &skipped),
+					) else {
+						break;
+					};
+
+					let order_ids = [order.order_id, counter.order_id];
+
+					let matched = with_storage_layer(|| {
+						Self::match_orders(order_ratio, order, counter_ratio, counter)
+					});
+
+					match matched {
+						Ok(MatchOutcome::Matched) => (),
+						Ok(MatchOutcome::NotCrossing) => break,
+						Ok(MatchOutcome::Unfillable(order_id)) => skipped.push(order_id),
+						// The failing order is unknown, both are skipped
+						Err(_) => skipped.extend(order_ids),
+					}
+				}
+
+				weight.saturating_accrue(settle_weight);
+				settled_pairs.push((currency_a, currency_b));
+			}
+
+			for pair in settled_pairs {
+				PendingMatches::<T>::remove(pair);
+			}
+
+			weight
+		}
+
+		/// Returns the order with the best price-time priority to swap from
+		/// `currency_out` to `currency_in`, along with its ratio. The
+		/// `skipped` orders are not considered.
+		fn best_order(
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+			skipped: &[T::OrderIdNonce],
+		) -> Result<Option<(T::Ratio, Order<T>)>, DispatchError> {
+			let custom = SortedOrders::<T>::get(currency_out, currency_in)
+				.into_iter()
+				.find(|(_, order_id)| !skipped.contains(order_id));
+
+			let market = match MarketOrders::<T>::get(currency_out, currency_in)
+				.into_iter()
+				.find(|order_id| !skipped.contains(order_id))
+			{
+				Some(order_id) => Self::market_ratio(currency_out, currency_in)
+					.ok()
+					.map(|ratio| (ratio, order_id)),
+				None => None,
+			};
+
+			let best = match (custom, market) {
+				(Some(custom), Some(market)) => Some(min(custom, market)),
+				(custom, market) => custom.or(market),
+			};
+
+			best.map(|(ratio, order_id)| Ok((ratio, Orders::<T>::get(order_id)?)))
+				.transpose()
+		}
+
+		/// Swaps two orders of opposite directions if they cross, respecting
+		/// their min fill amounts. An expired order is expired instead.
+		fn match_orders(
+			order_ratio: T::Ratio,
+			order: Order<T>,
+			counter_ratio: T::Ratio,
+			counter: Order<T>,
+		) -> Result<MatchOutcome<T::OrderIdNonce>, DispatchError> {
+			for expiring in [&order, &counter] {
+				if Self::is_expired(expiring) {
+					Self::expire_order(expiring.clone())?;
+					return Ok(MatchOutcome::Matched);
+				}
+			}
+
+			let (currency_a, currency_b) = (order.currency_out, order.currency_in);
+
+			if order_ratio.ensure_mul(counter_ratio)? > T::Ratio::one() {
+				return Ok(MatchOutcome::NotCrossing);
+			}
+
+			// The oldest order sets the ratio of the swap
+			let (ratio, counter_ratio) = match order.order_id < counter.order_id {
+				true => (order_ratio, T::Ratio::one().ensure_div(order_ratio)?),
+				false => (T::Ratio::one().ensure_div(counter_ratio)?, counter_ratio),
+			};
+
+			let counter_amount = Self::convert_with_ratio(
+				currency_b,
+				currency_a,
+				counter_ratio,
+				counter.amount_out,
+			)?;
+			let amount_out = min(order.amount_out, Self::balance_out(counter_amount));
+			let amount_in = Self::convert_with_ratio(currency_a, currency_b, ratio, amount_out)?;
+
+			let counter_amount_out = Self::balance_out(amount_in);
+
+			if amount_out.is_zero()
+				|| amount_in.is_zero()
+				|| amount_out < Self::min_fill(&order)?
+				|| counter_amount_out < Self::min_fill(&counter)?
+			{
+				// The other order can still be matched with a bigger one
+				let limiting = match amount_out < order.amount_out {
+					true => counter.order_id,
+					false => order.order_id,
+				};
+
+				return Ok(MatchOutcome::Unfillable(limiting));
+			}
+
+			T::Currency::release(
+				currency_b,
+				&(),
+				&counter.placing_account,
+				amount_in.into(),
+				Precision::Exact,
+			)?;
+
+			Self::fulfill_order_with_ratio(
+				order.clone(),
+				amount_out,
+				ratio,
+				counter.placing_account.clone(),
+				amount_in,
+			)?;
+
+			Self::note_fulfillment(
+				counter,
+				counter_amount_out,
+				Self::balance_in(amount_out),
+				counter_ratio,
+				order.placing_account,
+			)?;
+
+			Ok(MatchOutcome::Matched)
+		}
+
+		/// Min amount an order can be partially filled with
+		fn min_fill(order: &Order<T>) -> Result<T::BalanceOut, DispatchError> {
+			Ok(min(
+				order.amount_out,
+				match order.options.min_fill {
+					Some(min_fill) => min_fill,
+					None => Self::min_fulfillment_amount(order.currency_out)?,
+				},
+			))
+		}
+
+		fn order_ratio(order: &Order<T>) -> Result<T::Ratio, DispatchError> {
+			match order.ratio {
+				OrderRatio::Market => Self::market_ratio(order.currency_out, order.currency_in),
+				OrderRatio::Custom(ratio) => Ok(ratio),
+			}
+		}
+
+		fn balance_out(amount: T::BalanceIn) -> T::BalanceOut {
+			let amount: BalanceOf<T> = amount.into();
+			amount.into()
+		}
+
+		fn balance_in(amount: T::BalanceOut) -> T::BalanceIn {
+			let amount: BalanceOf<T> = amount.into();
+			amount.into()
+		}
+
 		fn fulfill_order_with_amount(
 			order: Order<T>,
 			amount_out: T::BalanceOut,
 			fulfilling_account: T::AccountId,
 			max_amount_in: T::BalanceIn,
 		) -> DispatchResult {
-			let min_fulfillment_amount_out = min(
-				order.amount_out,
-				Self::min_fulfillment_amount(order.currency_out)?,
-			);
+			ensure!(!Self::is_expired(&order), Error::<T>::OrderExpired);
+
+			let min_fulfillment_amount_out = Self::min_fill(&order)?;
 
 			ensure!(
 				amount_out >= min_fulfillment_amount_out,
 				Error::<T>::BelowMinFulfillmentAmount,
 			);
 
-			let ratio = match order.ratio {
-				OrderRatio::Market => Self::market_ratio(order.currency_out, order.currency_in)?,
-				OrderRatio::Custom(ratio) => ratio,
-			};
+			let ratio = Self::order_ratio(&order)?;
+
+			Self::fulfill_order_with_ratio(
+				order,
+				amount_out,
+				ratio,
+				fulfilling_account,
+				max_amount_in,
+			)
+		}
 
+		/// Swaps `amount_out` of an order with the fulfilling account at the
+		/// given ratio.
+		fn fulfill_order_with_ratio(
+			order: Order<T>,
+			amount_out: T::BalanceOut,
+			ratio: T::Ratio,
+			fulfilling_account: T::AccountId,
+			max_amount_in: T::BalanceIn,
+		) -> DispatchResult {
 			let amount_in =
 				Self::convert_with_ratio(order.currency_out, order.currency_in, ratio, amount_out)?;
 			// Protect the fulfilling account from extreme market conditions
 			ensure!(amount_in <= max_amount_in, Error::<T>::SlippageExceeded);
 
-			let remaining_amount_out = order
-				.amount_out
-				.checked_sub(&amount_out)
-				.ok_or(Error::<T>::FulfillAmountTooLarge)?;
-
-			let partial_fulfillment = !remaining_amount_out.is_zero();
-			if partial_fulfillment {
-				let mut updated_order = order.clone();
-				updated_order.amount_out = remaining_amount_out;
-				updated_order.amount_in = order.amount_in.ensure_add(amount_in)?;
-
-				Orders::<T>::insert(updated_order.order_id, updated_order.clone());
-			} else {
-				Self::remove_order(order.order_id)?;
-			}
+			ensure!(
+				amount_out <= order.amount_out,
+				Error::<T>::FulfillAmountTooLarge
+			);
 
 			T::Currency::release(
 				order.currency_out,
@@ -608,6 +1199,34 @@ pub mod pallet {
 				Preservation::Expendable,
 			)?;
 
+			Self::note_fulfillment(order, amount_out, amount_in, ratio, fulfilling_account)
+		}
+
+		/// Updates or removes an order after swapping part of it, notifying
+		/// the swap.
+		fn note_fulfillment(
+			order: Order<T>,
+			amount_out: T::BalanceOut,
+			amount_in: T::BalanceIn,
+			ratio: T::Ratio,
+			fulfilling_account: T::AccountId,
+		) -> DispatchResult {
+			let remaining_amount_out = order
+				.amount_out
+				.checked_sub(&amount_out)
+				.ok_or(Error::<T>::FulfillAmountTooLarge)?;
+
+			let partial_fulfillment = !remaining_amount_out.is_zero();
+			if partial_fulfillment {
+				let mut updated_order = order.clone();
+				updated_order.amount_out = remaining_amount_out;
+				updated_order.amount_in = order.amount_in.ensure_add(amount_in)?;
+
+				Orders::<T>::insert(updated_order.order_id, updated_order.clone());
+			} else {
+				Self::remove_order(order.order_id)?;
+			}
+
 			T::FulfilledOrderHook::notify_status_change(
 				order.order_id,
 				SwapInfo {
@@ -695,6 +1314,7 @@ pub mod pallet {
 			currency_out: T::CurrencyId,
 			amount_out: T::BalanceOut,
 			ratio: OrderRatio<T::Ratio>,
+			options: OrderOptions<T::BalanceOut>,
 		) -> Result<Self::OrderId, DispatchError> {
 			Self::inner_place_order(
 				account,
@@ -702,7 +1322,9 @@ pub mod pallet {
 				currency_out,
 				amount_out,
 				ratio,
+				options,
 				T::BalanceOut::zero(),
+				true,
 			)
 		}
 
@@ -734,7 +1356,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let order = Orders::<T>::get(order_id)?;
 
-			Self::inner_update_order(order, amount_out, ratio, T::BalanceOut::zero())
+			Self::inner_update_order(order, amount_out, ratio, T::BalanceOut::zero(), true)
 		}
 
 		fn get_order_details(
@@ -748,6 +1370,7 @@ pub mod pallet {
 						amount_out: order.amount_out,
 					},
 					ratio: order.ratio,
+					options: order.options,
 				})
 				.ok()
 		}
@@ -769,11 +1392,7 @@ pub mod pallet {
 			amount_out: Self::BalanceOut,
 		) -> DispatchResult {
 			let order = <Orders<T>>::get(order_id)?;
-
-			let ratio = match order.ratio {
-				OrderRatio::Market => Self::market_ratio(order.currency_out, order.currency_in)?,
-				OrderRatio::Custom(ratio) => ratio,
-			};
+			let ratio = Self::order_ratio(&order)?;
 
 			let max_amount_in =
 				Self::convert_with_ratio(order.currency_out, order.currency_in, ratio, amount_out)?;
@@ -0,0 +1,104 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+pub mod v2 {
+	use cfg_traits::swaps::{OrderOptions, OrderRatio};
+	use frame_support::{
+		traits::{Get, OnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use parity_scale_codec::Decode;
+	use sp_std::marker::PhantomData;
+
+	use crate::pallet::{Config, MarketOrders, Order, Orders, PendingMatches, SortedOrders};
+
+	const LOG_PREFIX: &str = "OrderBook-v2";
+
+	#[derive(Decode)]
+	struct OldOrder<T: Config> {
+		order_id: T::OrderIdNonce,
+		placing_account: T::AccountId,
+		currency_in: T::CurrencyId,
+		currency_out: T::CurrencyId,
+		amount_in: T::BalanceIn,
+		amount_out: T::BalanceOut,
+		amount_out_initial: T::BalanceOut,
+		ratio: OrderRatio<T::Ratio>,
+	}
+
+	/// Adds the default options to the existing orders and indexes them by
+	/// price-time priority so they can be matched. Orders that do not fit in
+	/// their pair are not indexed but can still be filled, and are logged.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+			let mut unindexed: u64 = 0;
+
+			Orders::<T>::translate_values::<OldOrder<T>, _>(|old| {
+				Some(Order {
+					order_id: old.order_id,
+					placing_account: old.placing_account,
+					currency_in: old.currency_in,
+					currency_out: old.currency_out,
+					amount_in: old.amount_in,
+					amount_out: old.amount_out,
+					amount_out_initial: old.amount_out_initial,
+					ratio: old.ratio,
+					options: OrderOptions::default(),
+				})
+			});
+
+			for (order_id, order) in Orders::<T>::iter() {
+				count += 1;
+
+				let (currency_out, currency_in) = (order.currency_out, order.currency_in);
+
+				let indexed = match order.ratio {
+					OrderRatio::Custom(ratio) => {
+						SortedOrders::<T>::try_mutate(currency_out, currency_in, |orders| {
+							let entry = (ratio, order_id);
+							let index = orders.partition_point(|other| *other < entry);
+							orders.try_insert(index, entry).map_err(|_| ())
+						})
+					}
+					OrderRatio::Market => {
+						MarketOrders::<T>::try_mutate(currency_out, currency_in, |orders| {
+							// Orders are iterated by hash, they are sorted by id here
+							let index = orders.partition_point(|other| *other < order_id);
+							orders.try_insert(index, order_id).map_err(|_| ())
+						})
+					}
+				};
+
+				if indexed.is_err() {
+					unindexed += 1;
+					log::warn!(
+						"{LOG_PREFIX} Order {order_id:?} does not fit in its pair, not indexed"
+					);
+				}
+
+				let pair = match currency_out < currency_in {
+					true => (currency_out, currency_in),
+					false => (currency_in, currency_out),
+				};
+
+				PendingMatches::<T>::insert(pair, ());
+			}
+
+			log::info!("{LOG_PREFIX} Migrated {count} orders, {unindexed} of them not indexed");
+
+			T::DbWeight::get().reads_writes(count.saturating_mul(3), count.saturating_mul(3))
+		}
+	}
+}
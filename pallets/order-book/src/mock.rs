@@ -10,7 +10,10 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::{swaps::SwapInfo, AssetMetadataOf};
+use cfg_traits::{
+	swaps::{Swap, SwapInfo},
+	AssetMetadataOf,
+};
 use cfg_types::tokens::CurrencyId;
 use frame_support::{derive_impl, parameter_types};
 use frame_system::EnsureRoot;
@@ -58,6 +61,8 @@ frame_support::construct_runtime!(
 		  OrderBook: order_book,
 		  MockRatioProvider: cfg_mocks::value_provider::pallet,
 		  MockFulfilledOrderHook: cfg_mocks::status_notification::pallet,
+		  MockExpiredOrderHook: cfg_mocks::status_notification::pallet::<Instance1>,
+		  MockTime: cfg_mocks::pallet_mock_time,
 	  }
 );
 
@@ -86,6 +91,16 @@ impl cfg_mocks::status_notification::pallet::Config for Runtime {
 	type Status = SwapInfo<Balance, Balance, CurrencyId, Ratio>;
 }
 
+type Instance1 = cfg_mocks::status_notification::pallet::Instance1;
+impl cfg_mocks::status_notification::pallet::Config<Instance1> for Runtime {
+	type Id = OrderId;
+	type Status = Swap<Balance, CurrencyId>;
+}
+
+impl cfg_mocks::pallet_mock_time::Config for Runtime {
+	type Moment = u64;
+}
+
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
 impl pallet_balances::Config for Runtime {
 	type AccountStore = System;
@@ -125,14 +140,18 @@ impl order_book::Config for Runtime {
 	type BalanceOut = Balance;
 	type Currency = Tokens;
 	type CurrencyId = CurrencyId;
+	type ExpiredOrderHook = MockExpiredOrderHook;
 	type FeederId = AccountId;
 	type FulfilledOrderHook = MockFulfilledOrderHook;
+	type MaxFillOrKillMatches = ConstU32<3>;
+	type MaxOrdersPerPair = ConstU32<10>;
 	type MinFulfillmentAmountNative = MinFulfillmentAmountNative;
 	type NativeDecimals = ConstU32<NATIVE_DECIMALS>;
 	type OrderIdNonce = OrderId;
 	type Ratio = Ratio;
 	type RatioProvider = MockRatioProvider;
 	type RuntimeEvent = RuntimeEvent;
+	type Time = MockTime;
 	type Weights = ();
 }
 
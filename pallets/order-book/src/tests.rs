@@ -10,12 +10,12 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::swaps::{OrderInfo, OrderRatio, Swap, SwapInfo, TokenSwaps};
+use cfg_traits::swaps::{OrderInfo, OrderOptions, OrderRatio, Swap, SwapInfo, TokenSwaps};
 use frame_support::{
 	assert_err, assert_noop, assert_ok,
 	traits::fungibles::{Inspect, InspectHold},
 };
-use sp_runtime::{DispatchError, FixedPointNumber};
+use sp_runtime::{DispatchError, DispatchResult, FixedPointNumber};
 
 use super::*;
 use crate::mock::*;
@@ -31,7 +31,7 @@ mod util {
 			CURRENCY_B,
 			CURRENCY_A,
 			amount_out,
-			OrderRatio::Custom(DEFAULT_RATIO)
+			OrderRatio::Custom(DEFAULT_RATIO),
 		));
 
 		OrderIdNonceStore::<Runtime>::get()
@@ -43,7 +43,7 @@ mod util {
 			CURRENCY_B,
 			CURRENCY_A,
 			amount_out,
-			OrderRatio::Market
+			OrderRatio::Market,
 		));
 
 		OrderIdNonceStore::<Runtime>::get()
@@ -113,6 +113,7 @@ fn create_order_works() {
 				amount_out_initial: token_a(10),
 				ratio: OrderRatio::Custom(DEFAULT_RATIO),
 				amount_in: token_b(0),
+				options: OrderOptions::default(),
 			}
 		);
 
@@ -129,7 +130,7 @@ fn create_order_without_required_min_fulfillment_amount() {
 				CURRENCY_B,
 				CURRENCY_A,
 				token_a(1),
-				OrderRatio::Custom(DEFAULT_RATIO)
+				OrderRatio::Custom(DEFAULT_RATIO),
 			),
 			Error::<Runtime>::BelowMinFulfillmentAmount,
 		);
@@ -140,7 +141,8 @@ fn create_order_without_required_min_fulfillment_amount() {
 			CURRENCY_B,
 			CURRENCY_A,
 			token_a(1),
-			OrderRatio::Custom(DEFAULT_RATIO)
+			OrderRatio::Custom(DEFAULT_RATIO),
+			OrderOptions::default(),
 		));
 	})
 }
@@ -168,7 +170,8 @@ fn update_order_works() {
 				amount_out: token_a(15),
 				amount_out_initial: token_a(15),
 				ratio: OrderRatio::Custom((1, 2).into()),
-				amount_in: token_b(0)
+				amount_in: token_b(0),
+				options: OrderOptions::default(),
 			}
 		);
 
@@ -196,6 +199,7 @@ fn update_order_works() {
 				amount_out_initial: token_a(5),
 				ratio: OrderRatio::Custom((1, 2).into()),
 				amount_in: token_b(0),
+				options: OrderOptions::default(),
 			}
 		);
 
@@ -328,6 +332,7 @@ fn fill_order_partial_in_two_times() {
 				amount_out_initial: token_a(10),
 				ratio: OrderRatio::Custom(DEFAULT_RATIO),
 				amount_in: first_amount_in,
+				options: OrderOptions::default(),
 			}
 		);
 
@@ -509,7 +514,8 @@ fn correct_order_details() {
 					currency_in: CURRENCY_B,
 					currency_out: CURRENCY_A
 				},
-				ratio: OrderRatio::Custom(DEFAULT_RATIO)
+				ratio: OrderRatio::Custom(DEFAULT_RATIO),
+				options: OrderOptions::default(),
 			})
 		);
 	});
@@ -524,6 +530,7 @@ fn fulfill_zero_amount_order() {
 			CURRENCY_A,
 			0,
 			OrderRatio::Custom(DEFAULT_RATIO),
+			OrderOptions::default(),
 		)
 		.unwrap();
 
@@ -547,6 +554,7 @@ fn close_zero_amount_order() {
 			CURRENCY_A,
 			0,
 			OrderRatio::Custom(DEFAULT_RATIO),
+			OrderOptions::default(),
 		)
 		.unwrap();
 
@@ -610,6 +618,7 @@ mod market {
 					amount_out_initial: token_a(10),
 					ratio: OrderRatio::Market,
 					amount_in: first_amount_in,
+					options: OrderOptions::default(),
 				}
 			);
 
@@ -666,3 +675,681 @@ mod market {
 		});
 	}
 }
+
+mod matching {
+	use frame_support::{
+		traits::{fungibles::Mutate, Get, Hooks},
+		weights::Weight,
+	};
+
+	use super::*;
+
+	const HALF_RATIO: Ratio = Ratio::from_rational(1, 2);
+
+	fn place_order_a(account: AccountId, amount_out: Balance, ratio: Ratio) -> OrderId {
+		assert_ok!(OrderBook::place_order(
+			RuntimeOrigin::signed(account),
+			CURRENCY_B,
+			CURRENCY_A,
+			amount_out,
+			OrderRatio::Custom(ratio),
+		));
+
+		OrderIdNonceStore::<Runtime>::get()
+	}
+
+	fn place_order_b(amount_out: Balance, ratio: Ratio) -> OrderId {
+		assert_ok!(OrderBook::place_order(
+			RuntimeOrigin::signed(TO),
+			CURRENCY_A,
+			CURRENCY_B,
+			amount_out,
+			OrderRatio::Custom(ratio),
+		));
+
+		OrderIdNonceStore::<Runtime>::get()
+	}
+
+	fn match_orders() {
+		MockFulfilledOrderHook::mock_notify_status_change(|_, _| Ok(()));
+		OrderBook::on_idle(0, Weight::MAX);
+	}
+
+	fn is_pending() -> bool {
+		PendingMatches::<Runtime>::contains_key((CURRENCY_A, CURRENCY_B))
+	}
+
+	#[test]
+	fn crossing_orders() {
+		new_test_ext().execute_with(|| {
+			let order_a = util::create_default_order(token_a(10));
+			let order_b = place_order_b(token_b(20), HALF_RATIO);
+
+			match_orders();
+
+			util::assert_no_exists_order(order_a);
+			assert_err!(
+				Orders::<Runtime>::get(order_b),
+				Error::<Runtime>::OrderNotFound
+			);
+			assert!(!is_pending());
+
+			assert_eq!(Tokens::balance_on_hold(CURRENCY_A, &(), &FROM), 0);
+			assert_eq!(Tokens::balance_on_hold(CURRENCY_B, &(), &TO), 0);
+
+			assert_eq!(Tokens::balance(CURRENCY_A, &FROM), INITIAL_A - token_a(10));
+			assert_eq!(Tokens::balance(CURRENCY_B, &FROM), token_b(20));
+			assert_eq!(Tokens::balance(CURRENCY_A, &TO), token_a(10));
+			assert_eq!(Tokens::balance(CURRENCY_B, &TO), INITIAL_B - token_b(20));
+		});
+	}
+
+	#[test]
+	fn crossing_orders_partially() {
+		new_test_ext().execute_with(|| {
+			let order_a = util::create_default_order(token_a(10));
+			let order_b = place_order_b(token_b(10), HALF_RATIO);
+
+			match_orders();
+
+			assert_ok!(
+				Orders::<Runtime>::get(order_a),
+				Order {
+					order_id: order_a,
+					placing_account: FROM,
+					currency_in: CURRENCY_B,
+					currency_out: CURRENCY_A,
+					amount_out: token_a(5),
+					amount_out_initial: token_a(10),
+					ratio: OrderRatio::Custom(DEFAULT_RATIO),
+					amount_in: token_b(10),
+					options: OrderOptions::default(),
+				}
+			);
+			assert_err!(
+				Orders::<Runtime>::get(order_b),
+				Error::<Runtime>::OrderNotFound
+			);
+
+			assert_eq!(Tokens::balance_on_hold(CURRENCY_A, &(), &FROM), token_a(5));
+			assert_eq!(Tokens::balance(CURRENCY_B, &FROM), token_b(10));
+			assert_eq!(Tokens::balance(CURRENCY_A, &TO), token_a(5));
+		});
+	}
+
+	#[test]
+	fn not_crossing_orders() {
+		new_test_ext().execute_with(|| {
+			let order_a = util::create_default_order(token_a(10));
+			let order_b = place_order_b(token_b(20), Ratio::from_rational(3, 5));
+
+			assert!(is_pending());
+
+			match_orders();
+
+			util::assert_exists_order(order_a);
+			assert_ok!(Orders::<Runtime>::get(order_b));
+			assert!(!is_pending());
+		});
+	}
+
+	#[test]
+	fn price_time_priority() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Tokens::mint_into(CURRENCY_A, &OTHER, INITIAL_A));
+
+			let expensive = place_order_a(FROM, token_a(10), Ratio::from_rational(3, 1));
+			let first = place_order_a(FROM, token_a(10), DEFAULT_RATIO);
+			let second = place_order_a(OTHER, token_a(10), DEFAULT_RATIO);
+
+			assert_eq!(
+				SortedOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B).into_inner(),
+				vec![
+					(DEFAULT_RATIO, first),
+					(DEFAULT_RATIO, second),
+					(Ratio::from_rational(3, 1), expensive)
+				]
+			);
+
+			place_order_b(token_b(20), HALF_RATIO);
+
+			match_orders();
+
+			assert_err!(
+				Orders::<Runtime>::get(first),
+				Error::<Runtime>::OrderNotFound
+			);
+			assert_eq!(
+				SortedOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B).into_inner(),
+				vec![
+					(DEFAULT_RATIO, second),
+					(Ratio::from_rational(3, 1), expensive)
+				]
+			);
+			assert!(SortedOrders::<Runtime>::get(CURRENCY_B, CURRENCY_A).is_empty());
+		});
+	}
+
+	#[test]
+	fn oldest_order_sets_the_ratio() {
+		new_test_ext().execute_with(|| {
+			// Willing to give 4 B for each A
+			let order_b = place_order_b(token_b(20), Ratio::from_rational(1, 4));
+			let order_a = util::create_default_order(token_a(10));
+
+			match_orders();
+
+			assert_err!(
+				Orders::<Runtime>::get(order_b),
+				Error::<Runtime>::OrderNotFound
+			);
+			assert_eq!(
+				Orders::<Runtime>::get(order_a).unwrap().amount_out,
+				token_a(5)
+			);
+
+			assert_eq!(Tokens::balance(CURRENCY_B, &FROM), token_b(20));
+			assert_eq!(Tokens::balance(CURRENCY_A, &TO), token_a(5));
+		});
+	}
+
+	#[test]
+	fn market_order_crossing_custom_order() {
+		new_test_ext().execute_with(|| {
+			let order_a = util::create_default_order_market(token_a(10));
+
+			assert_ok!(OrderBook::set_market_feeder(RuntimeOrigin::root(), FEEDER));
+			MockRatioProvider::mock_get(move |feeder, pair| {
+				assert_eq!(*feeder, FEEDER);
+				assert_eq!(*pair, (CURRENCY_A, CURRENCY_B));
+				Ok(Some(DEFAULT_RATIO))
+			});
+
+			let order_b = place_order_b(token_b(20), HALF_RATIO);
+
+			match_orders();
+
+			util::assert_no_exists_order(order_a);
+			assert_err!(
+				Orders::<Runtime>::get(order_b),
+				Error::<Runtime>::OrderNotFound
+			);
+			assert!(MarketOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B).is_empty());
+			assert!(!is_pending());
+
+			assert_eq!(Tokens::balance(CURRENCY_B, &FROM), token_b(20));
+			assert_eq!(Tokens::balance(CURRENCY_A, &TO), token_a(10));
+		});
+	}
+
+	#[test]
+	fn market_order_pair_is_settled_until_updated() {
+		new_test_ext().execute_with(|| {
+			let order_a = util::create_default_order_market(token_a(10));
+
+			// Without market ratio the market order is not matched
+			place_order_b(token_b(20), HALF_RATIO);
+
+			match_orders();
+
+			util::assert_exists_order(order_a);
+			assert!(!is_pending());
+
+			assert_ok!(OrderBook::update_order(
+				RuntimeOrigin::signed(FROM),
+				order_a,
+				token_a(9),
+				OrderRatio::Market
+			));
+
+			assert!(is_pending());
+		});
+	}
+
+	#[test]
+	fn removed_orders_are_not_matched() {
+		new_test_ext().execute_with(|| {
+			let order_a = util::create_default_order(token_a(10));
+
+			assert_ok!(OrderBook::cancel_order(
+				RuntimeOrigin::signed(FROM),
+				order_a
+			));
+
+			assert!(SortedOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B).is_empty());
+		});
+	}
+
+	#[test]
+	fn updating_ratio_loses_priority() {
+		new_test_ext().execute_with(|| {
+			let first = util::create_default_order(token_a(10));
+			let second = util::create_default_order(token_a(10));
+
+			// Changing the amount keeps the priority
+			assert_ok!(OrderBook::update_order(
+				RuntimeOrigin::signed(FROM),
+				first,
+				token_a(11),
+				OrderRatio::Custom(DEFAULT_RATIO)
+			));
+
+			assert_eq!(
+				SortedOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B).into_inner(),
+				vec![(DEFAULT_RATIO, first), (DEFAULT_RATIO, second)]
+			);
+
+			assert_ok!(OrderBook::update_order(
+				RuntimeOrigin::signed(FROM),
+				first,
+				token_a(9),
+				OrderRatio::Custom(DEFAULT_RATIO)
+			));
+
+			assert_eq!(
+				SortedOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B).into_inner(),
+				vec![(DEFAULT_RATIO, first), (DEFAULT_RATIO, second)]
+			);
+
+			assert_ok!(OrderBook::update_order(
+				RuntimeOrigin::signed(FROM),
+				first,
+				token_a(9),
+				OrderRatio::Custom(Ratio::from_rational(3, 1))
+			));
+
+			assert_eq!(
+				SortedOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B).into_inner(),
+				vec![(DEFAULT_RATIO, second), (Ratio::from_rational(3, 1), first)]
+			);
+		});
+	}
+
+	#[test]
+	fn updating_to_market_ratio_moves_the_order() {
+		new_test_ext().execute_with(|| {
+			let order_id = util::create_default_order(token_a(10));
+
+			assert_ok!(OrderBook::update_order(
+				RuntimeOrigin::signed(FROM),
+				order_id,
+				token_a(10),
+				OrderRatio::Market
+			));
+
+			assert!(SortedOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B).is_empty());
+			assert_eq!(
+				MarketOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B).into_inner(),
+				vec![order_id]
+			);
+		});
+	}
+
+	#[test]
+	fn full_pair_only_rejects_orders_placed_by_accounts() {
+		new_test_ext().execute_with(|| {
+			for _ in 0..<Runtime as Config>::MaxOrdersPerPair::get() {
+				util::create_default_order(token_a(10));
+			}
+
+			assert_noop!(
+				OrderBook::place_order(
+					RuntimeOrigin::signed(FROM),
+					CURRENCY_B,
+					CURRENCY_A,
+					token_a(10),
+					OrderRatio::Custom(DEFAULT_RATIO),
+				),
+				Error::<Runtime>::TooManyOrders
+			);
+
+			let order_id = <OrderBook as TokenSwaps<AccountId>>::place_order(
+				FROM,
+				CURRENCY_B,
+				CURRENCY_A,
+				token_a(10),
+				OrderRatio::Custom(DEFAULT_RATIO),
+				OrderOptions::default(),
+			)
+			.unwrap();
+
+			util::assert_exists_order(order_id);
+			assert!(!SortedOrders::<Runtime>::get(CURRENCY_A, CURRENCY_B)
+				.iter()
+				.any(|(_, id)| *id == order_id));
+
+			util::expect_notification(order_id, 0, token_b(20), token_a(10));
+			assert_ok!(OrderBook::fill_order(
+				RuntimeOrigin::signed(TO),
+				order_id,
+				token_a(10),
+				token_b(20)
+			));
+			util::assert_no_exists_order(order_id);
+		});
+	}
+}
+
+mod options {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	use super::*;
+
+	const NOW: u64 = 10;
+	const HALF_RATIO: Ratio = Ratio::from_rational(1, 2);
+
+	fn set_now(secs: u64) {
+		MockTime::mock_now(move || secs * 1000);
+	}
+
+	fn place_order_a(amount_out: Balance, options: OrderOptions<Balance>) -> DispatchResult {
+		OrderBook::place_order_with_options(
+			RuntimeOrigin::signed(FROM),
+			CURRENCY_B,
+			CURRENCY_A,
+			amount_out,
+			OrderRatio::Custom(DEFAULT_RATIO),
+			options,
+		)
+	}
+
+	fn place_order_b(amount_out: Balance) -> OrderId {
+		assert_ok!(OrderBook::place_order(
+			RuntimeOrigin::signed(TO),
+			CURRENCY_A,
+			CURRENCY_B,
+			amount_out,
+			OrderRatio::Custom(HALF_RATIO),
+		));
+
+		OrderIdNonceStore::<Runtime>::get()
+	}
+
+	fn expiring_at(expires_at: u64) -> OrderOptions<Balance> {
+		OrderOptions {
+			expires_at: Some(expires_at),
+			..Default::default()
+		}
+	}
+
+	mod expiry {
+		use super::*;
+
+		#[test]
+		fn expired_order_is_removed_on_idle() {
+			new_test_ext().execute_with(|| {
+				set_now(NOW);
+				assert_ok!(place_order_a(token_a(10), expiring_at(NOW + 10)));
+				let order_id = OrderIdNonceStore::<Runtime>::get();
+
+				assert_eq!(ExpiringOrders::<Runtime>::get(order_id), Some(NOW + 10));
+
+				// Not expired yet
+				OrderBook::on_idle(0, Weight::MAX);
+				util::assert_exists_order(order_id);
+
+				set_now(NOW + 10);
+				MockExpiredOrderHook::mock_notify_status_change(move |id, swap| {
+					assert_eq!(id, order_id);
+					assert_eq!(
+						swap,
+						Swap {
+							amount_out: token_a(10),
+							currency_in: CURRENCY_B,
+							currency_out: CURRENCY_A,
+						}
+					);
+					Ok(())
+				});
+
+				OrderBook::on_idle(0, Weight::MAX);
+
+				util::assert_no_exists_order(order_id);
+				assert_eq!(ExpiringOrders::<Runtime>::get(order_id), None);
+				assert_eq!(ExpirySweepCursor::<Runtime>::get(), None);
+				assert_eq!(Tokens::balance_on_hold(CURRENCY_A, &(), &FROM), 0);
+				assert_eq!(Tokens::balance(CURRENCY_A, &FROM), INITIAL_A);
+			});
+		}
+
+		#[test]
+		fn failing_expiry_is_retried() {
+			new_test_ext().execute_with(|| {
+				System::set_block_number(1);
+				set_now(NOW);
+				assert_ok!(place_order_a(token_a(10), expiring_at(NOW + 10)));
+				let order_id = OrderIdNonceStore::<Runtime>::get();
+
+				set_now(NOW + 10);
+				MockExpiredOrderHook::mock_notify_status_change(|_, _| {
+					Err(DispatchError::Other("err"))
+				});
+
+				OrderBook::on_idle(0, Weight::MAX);
+
+				System::assert_last_event(
+					Event::<Runtime>::OrderExpiryFailed {
+						order_id,
+						error: DispatchError::Other("err"),
+					}
+					.into(),
+				);
+				util::assert_exists_order(order_id);
+				assert_eq!(ExpiringOrders::<Runtime>::get(order_id), Some(NOW + 10));
+				assert_eq!(Tokens::balance_on_hold(CURRENCY_A, &(), &FROM), token_a(10));
+
+				MockExpiredOrderHook::mock_notify_status_change(|_, _| Ok(()));
+
+				OrderBook::on_idle(0, Weight::MAX);
+
+				util::assert_no_exists_order(order_id);
+				assert_eq!(ExpiringOrders::<Runtime>::get(order_id), None);
+			});
+		}
+
+		#[test]
+		fn placing_already_expired_order() {
+			new_test_ext().execute_with(|| {
+				set_now(NOW);
+
+				assert_err!(
+					place_order_a(token_a(10), expiring_at(NOW)),
+					Error::<Runtime>::OrderExpired
+				);
+			});
+		}
+
+		#[test]
+		fn filling_expired_order() {
+			new_test_ext().execute_with(|| {
+				set_now(NOW);
+				assert_ok!(place_order_a(token_a(10), expiring_at(NOW + 10)));
+				let order_id = OrderIdNonceStore::<Runtime>::get();
+
+				set_now(NOW + 10);
+
+				assert_err!(
+					OrderBook::fill_order(
+						RuntimeOrigin::signed(TO),
+						order_id,
+						token_a(10),
+						Balance::MAX
+					),
+					Error::<Runtime>::OrderExpired
+				);
+			});
+		}
+
+		#[test]
+		fn expired_order_is_not_matched() {
+			new_test_ext().execute_with(|| {
+				set_now(NOW);
+				assert_ok!(place_order_a(token_a(10), expiring_at(NOW + 10)));
+				let order_a = OrderIdNonceStore::<Runtime>::get();
+
+				set_now(NOW + 10);
+				let order_b = place_order_b(token_b(20));
+
+				MockExpiredOrderHook::mock_notify_status_change(|_, _| Ok(()));
+				OrderBook::on_idle(0, Weight::MAX);
+
+				util::assert_no_exists_order(order_a);
+				assert_ok!(Orders::<Runtime>::get(order_b));
+				assert_eq!(Tokens::balance(CURRENCY_A, &TO), 0);
+			});
+		}
+	}
+
+	mod fill_or_kill {
+		use super::*;
+
+		fn fill_or_kill() -> OrderOptions<Balance> {
+			OrderOptions {
+				fill_or_kill: true,
+				..Default::default()
+			}
+		}
+
+		#[test]
+		fn filled_when_placed() {
+			new_test_ext().execute_with(|| {
+				let order_b = place_order_b(token_b(20));
+
+				MockFulfilledOrderHook::mock_notify_status_change(|_, _| Ok(()));
+				assert_ok!(place_order_a(token_a(10), fill_or_kill()));
+				let order_a = OrderIdNonceStore::<Runtime>::get();
+
+				util::assert_no_exists_order(order_a);
+				assert_err!(
+					Orders::<Runtime>::get(order_b),
+					Error::<Runtime>::OrderNotFound
+				);
+
+				assert_eq!(Tokens::balance(CURRENCY_B, &FROM), token_b(20));
+				assert_eq!(Tokens::balance(CURRENCY_A, &TO), token_a(10));
+			});
+		}
+
+		#[test]
+		fn not_filled_when_placed() {
+			new_test_ext().execute_with(|| {
+				place_order_b(token_b(10));
+
+				MockFulfilledOrderHook::mock_notify_status_change(|_, _| Ok(()));
+				assert_err!(
+					place_order_a(token_a(10), fill_or_kill()),
+					Error::<Runtime>::FillOrKillNotFilled
+				);
+			});
+		}
+
+		#[test]
+		fn without_counter_orders() {
+			new_test_ext().execute_with(|| {
+				assert_err!(
+					place_order_a(token_a(10), fill_or_kill()),
+					Error::<Runtime>::FillOrKillNotFilled
+				);
+			});
+		}
+	}
+
+	mod min_fill {
+		use super::*;
+
+		fn min_fill(amount: Balance) -> OrderOptions<Balance> {
+			OrderOptions {
+				min_fill: Some(amount),
+				..Default::default()
+			}
+		}
+
+		#[test]
+		fn below_default_min_fulfillment_amount() {
+			new_test_ext().execute_with(|| {
+				let min_amount = OrderBook::min_fulfillment_amount(CURRENCY_A).unwrap();
+
+				assert_err!(
+					place_order_a(token_a(10), min_fill(min_amount - 1)),
+					Error::<Runtime>::BelowMinFulfillmentAmount
+				);
+			});
+		}
+
+		#[test]
+		fn fill_below_min_fill() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(place_order_a(token_a(10), min_fill(token_a(5))));
+				let order_id = OrderIdNonceStore::<Runtime>::get();
+
+				assert_err!(
+					OrderBook::fill_order(
+						RuntimeOrigin::signed(TO),
+						order_id,
+						token_a(4),
+						Balance::MAX
+					),
+					Error::<Runtime>::BelowMinFulfillmentAmount
+				);
+
+				MockFulfilledOrderHook::mock_notify_status_change(|_, _| Ok(()));
+				assert_ok!(OrderBook::fill_order(
+					RuntimeOrigin::signed(TO),
+					order_id,
+					token_a(5),
+					Balance::MAX
+				));
+			});
+		}
+
+		#[test]
+		fn match_below_min_fill() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(place_order_a(token_a(10), min_fill(token_a(8))));
+				let order_a = OrderIdNonceStore::<Runtime>::get();
+
+				// Only enough to fill 5 tokens of A
+				let order_b = place_order_b(token_b(10));
+
+				OrderBook::on_idle(0, Weight::MAX);
+
+				util::assert_exists_order(order_a);
+				assert_ok!(Orders::<Runtime>::get(order_b));
+			});
+		}
+
+		#[test]
+		fn match_skips_counter_order_below_min_fill() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(place_order_a(token_a(10), min_fill(token_a(8))));
+				let order_a = OrderIdNonceStore::<Runtime>::get();
+
+				// Only enough to fill 5 tokens of A, the next one is matched instead
+				let small = place_order_b(token_b(10));
+				let big = place_order_b(token_b(20));
+
+				MockFulfilledOrderHook::mock_notify_status_change(|_, _| Ok(()));
+				OrderBook::on_idle(0, Weight::MAX);
+
+				util::assert_no_exists_order(order_a);
+				assert_ok!(Orders::<Runtime>::get(small));
+				assert_err!(Orders::<Runtime>::get(big), Error::<Runtime>::OrderNotFound);
+			});
+		}
+
+		#[test]
+		fn match_below_default_min_fulfillment_amount() {
+			new_test_ext().execute_with(|| {
+				let order_a = util::create_default_order(token_a(10));
+
+				// Only enough to fill 1 token of A, below the default min fulfillment
+				let order_b = place_order_b(token_b(2));
+
+				OrderBook::on_idle(0, Weight::MAX);
+
+				util::assert_exists_order(order_a);
+				assert_ok!(Orders::<Runtime>::get(order_b));
+			});
+		}
+	}
+}
@@ -10,6 +10,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
+use frame_support::weights::constants::RocksDbWeight;
 pub use frame_support::weights::Weight;
 
 pub trait WeightInfo {
@@ -18,6 +19,26 @@ pub trait WeightInfo {
 	fn cancel_order() -> Weight;
 	fn fill_order() -> Weight;
 	fn set_market_feeder() -> Weight;
+
+	fn match_orders() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken twice from `fill_order`, as matching
+		//       fulfills both orders and notifies both of their swaps.
+		Weight::from_parts(294_290_000, 22812)
+			.saturating_add(RocksDbWeight::get().reads(24))
+			.saturating_add(RocksDbWeight::get().writes(16))
+	}
+
+	fn expire_order() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `fill_order`, which also releases
+		//       the reserved amount, removes the order and notifies its swap.
+		Weight::from_parts(147_145_000, 11406)
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
 }
 
 impl WeightInfo for () {
@@ -40,4 +61,12 @@ impl WeightInfo for () {
 	fn set_market_feeder() -> Weight {
 		Weight::zero()
 	}
+
+	fn match_orders() -> Weight {
+		Weight::zero()
+	}
+
+	fn expire_order() -> Weight {
+		Weight::zero()
+	}
 }
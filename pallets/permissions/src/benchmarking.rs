@@ -131,6 +131,51 @@ benchmarks! {
 	verify {
 		assert!(!<PermissionsPallet::<T> as TPermissions<T::AccountId>>::has(pool_id, acc, role));
 	}
+
+	add_with_expiry {
+		let acc = admin::<T>(0);
+		let with_role = T::Role::editor();
+		let role = T::Role::editor();
+		let pool_id: T::Scope = Default::default();
+		let valid_till = T::Time::now() + 1_000;
+	}:_(RawOrigin::Root, with_role.clone(), acc.clone(), pool_id.clone(), role.clone(), valid_till)
+	verify {
+		assert!(<PermissionsPallet::<T> as TPermissions<T::AccountId>>::has(pool_id.clone(), acc.clone(), role.clone()));
+		assert_eq!(RoleExpiry::<T>::get((acc, pool_id, role)), Some(valid_till));
+	}
+
+	delegate {
+		// setup pool admin
+		let acc = admin::<T>(0);
+		let with_role = T::Role::editor();
+		let role = T::Role::editor();
+		let pool_id: T::Scope = Default::default();
+		let res = PermissionsPallet::<T>::add(RawOrigin::Root.into(), with_role.clone(), acc.clone(), pool_id.clone(), role.clone());
+		assert_ok!(res);
+
+		// delegate the pool admin role
+		let acc2 = admin::<T>(1);
+		let valid_till = T::Time::now() + 1_000;
+	}:_(RawOrigin::Signed(acc.clone()), with_role.clone(), acc2.clone(), pool_id.clone(), sp_std::vec![role.clone()], valid_till)
+	verify {
+		assert!(<PermissionsPallet::<T> as TPermissions<T::AccountId>>::has(pool_id.clone(), acc2.clone(), role.clone()));
+		assert_eq!(RoleExpiry::<T>::get((acc2, pool_id, role)), Some(valid_till));
+	}
+
+	remove_expired {
+		let acc = admin::<T>(0);
+		let with_role = T::Role::editor();
+		let role = T::Role::editor();
+		let pool_id: T::Scope = Default::default();
+		let res = PermissionsPallet::<T>::add_with_expiry(RawOrigin::Root.into(), with_role.clone(), acc.clone(), pool_id.clone(), role.clone(), T::Time::now());
+		assert_ok!(res);
+	}: {
+		PermissionsPallet::<T>::remove_expired(acc.clone(), pool_id.clone(), role.clone())
+	}
+	verify {
+		assert!(Permission::<T>::get(acc.clone(), pool_id.clone()).is_none());
+		assert!(RoleExpiry::<T>::get((acc, pool_id, role)).is_none());
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -25,19 +25,20 @@ mod benchmarking;
 pub mod weights;
 
 /// Who informs about the caller's role
-enum Who {
+enum Who<AccountId> {
 	/// Admins can add/remove permissions,
 	/// and purge other users permissions.
 	Admin,
 	/// Editors can add/remove permissions
-	Editor,
+	Editor(AccountId),
 }
 
-use cfg_traits::{Permissions, Properties};
+use cfg_traits::{Permissions, Properties, Seconds, TimeAsSecs};
 use cfg_types::permissions::TrancheInvestorInfo;
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::Contains};
 use frame_system::pallet_prelude::*;
 use orml_traits::GetByKey;
+use sp_std::vec::Vec;
 pub use weights::WeightInfo;
 
 #[frame_support::pallet]
@@ -55,7 +56,7 @@ pub mod pallet {
 
 		type Scope: Member + Parameter + MaxEncodedLen;
 
-		type Role: Member + Parameter;
+		type Role: Member + Parameter + MaxEncodedLen;
 
 		type Storage: Member
 			+ Parameter
@@ -73,6 +74,9 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxRolesPerScope: Get<u32>;
 
+		/// The source of the current time, used to expire roles
+		type Time: TimeAsSecs;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -91,6 +95,38 @@ pub mod pallet {
 	#[pallet::getter(fn permission_count)]
 	pub type PermissionCount<T: Config> = StorageMap<_, Blake2_128Concat, T::Scope, u32>;
 
+	/// The time until which an account holds a role in a scope. Roles without
+	/// an entry never expire.
+	#[pallet::storage]
+	pub type RoleExpiry<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::Scope>,
+			NMapKey<Blake2_128Concat, T::Role>,
+		),
+		Seconds,
+	>;
+
+	/// Roles held by delegation, together with their delegator. They can not
+	/// be delegated again and are only held while the delegator holds them.
+	#[pallet::storage]
+	pub type DelegatedRoles<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::Scope>,
+			NMapKey<Blake2_128Concat, T::Role>,
+		),
+		T::AccountId,
+	>;
+
+	/// The last expiring role visited while sweeping expired roles. The next
+	/// sweep resumes after it.
+	#[pallet::storage]
+	pub(crate) type SweepCursor<T: Config> =
+		StorageValue<_, (T::AccountId, T::Scope, T::Role), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -108,6 +144,23 @@ pub mod pallet {
 			from: T::AccountId,
 			scope: T::Scope,
 		},
+		ExpirySet {
+			to: T::AccountId,
+			scope: T::Scope,
+			role: T::Role,
+			valid_till: Seconds,
+		},
+		Delegated {
+			from: T::AccountId,
+			to: T::AccountId,
+			scope: T::Scope,
+			valid_till: Seconds,
+		},
+		Expired {
+			from: T::AccountId,
+			scope: T::Scope,
+			role: T::Role,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -119,11 +172,29 @@ pub mod pallet {
 		NoEditor,
 		WrongParameters,
 		TooManyRoles,
+		/// The expiry is in the past or exceeds the expiry of the roles of
+		/// the granter
+		InvalidExpiry,
+		/// The role is held by delegation and can not be delegated again
+		DelegatedRole,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expired(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(T::WeightInfo::add_as_admin().max(T::WeightInfo::add_as_editor()))]
+		/// Add a role. Roles given by an editor whose `with_role` expires,
+		/// expire at the same time.
+		#[pallet::weight(
+			T::WeightInfo::add_as_admin()
+				.max(T::WeightInfo::add_as_editor())
+				.max(T::WeightInfo::add_with_expiry())
+		)]
 		#[pallet::call_index(0)]
 		pub fn add(
 			origin: OriginFor<T>,
@@ -132,13 +203,31 @@ pub mod pallet {
 			scope: T::Scope,
 			role: T::Role,
 		) -> DispatchResultWithPostInfo {
-			let who = Self::ensure_admin_or_editor(origin, with_role, scope.clone(), role.clone())?;
-
-			Pallet::<T>::do_add(scope, to, role)?;
-
-			match who {
-				Who::Editor => Ok(Some(T::WeightInfo::add_as_editor()).into()),
-				Who::Admin => Ok(Some(T::WeightInfo::add_as_admin()).into()),
+			let who = Self::ensure_admin_or_editor(
+				origin,
+				with_role.clone(),
+				scope.clone(),
+				role.clone(),
+			)?;
+
+			match Self::granter_expiry(&who, &with_role, &scope) {
+				Some(valid_till) => {
+					ensure!(
+						!Self::holds(&to, &scope, &role),
+						Error::<T>::RoleAlreadyGiven
+					);
+
+					Pallet::<T>::do_add_with_expiry(scope, to, role, valid_till)?;
+					Ok(Some(T::WeightInfo::add_with_expiry()).into())
+				}
+				None => {
+					Pallet::<T>::do_add(scope, to, role)?;
+
+					match who {
+						Who::Editor(_) => Ok(Some(T::WeightInfo::add_as_editor()).into()),
+						Who::Admin => Ok(Some(T::WeightInfo::add_as_admin()).into()),
+					}
+				}
 			}
 		}
 
@@ -156,7 +245,7 @@ pub mod pallet {
 			Pallet::<T>::do_remove(scope, from, role)?;
 
 			match who {
-				Who::Editor => Ok(Some(T::WeightInfo::remove_as_editor()).into()),
+				Who::Editor(_) => Ok(Some(T::WeightInfo::remove_as_editor()).into()),
 				Who::Admin => Ok(Some(T::WeightInfo::remove_as_admin()).into()),
 			}
 		}
@@ -171,7 +260,7 @@ pub mod pallet {
 				Error::<T>::NoRoles
 			);
 
-			Permission::<T>::remove(from.clone(), scope.clone());
+			Self::remove_all(&from, &scope);
 
 			Self::deposit_event(Event::<T>::Purged { from, scope });
 
@@ -192,12 +281,112 @@ pub mod pallet {
 				Error::<T>::NoRoles
 			);
 
-			Permission::<T>::remove(from.clone(), scope.clone());
+			Self::remove_all(&from, &scope);
 
 			Self::deposit_event(Event::<T>::Purged { from, scope });
 
 			Ok(())
 		}
+
+		/// Add a role which expires at the given time, or renew the expiry of
+		/// a role already held.
+		///
+		/// Roles given by an editor whose `with_role` expires, can not outlive
+		/// it.
+		#[pallet::weight(T::WeightInfo::add_with_expiry())]
+		#[pallet::call_index(4)]
+		pub fn add_with_expiry(
+			origin: OriginFor<T>,
+			with_role: T::Role,
+			to: T::AccountId,
+			scope: T::Scope,
+			role: T::Role,
+			valid_till: Seconds,
+		) -> DispatchResult {
+			let who = Self::ensure_admin_or_editor(
+				origin,
+				with_role.clone(),
+				scope.clone(),
+				role.clone(),
+			)?;
+
+			ensure!(
+				Self::granter_expiry(&who, &with_role, &scope)
+					.map_or(true, |granter_till| valid_till <= granter_till),
+				Error::<T>::InvalidExpiry
+			);
+
+			Pallet::<T>::do_add_with_expiry(scope, to, role, valid_till)
+		}
+
+		/// Delegate roles held by the caller to another account until the
+		/// given time.
+		///
+		/// The caller must be an editor of every delegated role with
+		/// `with_role`. Delegated roles can not outlive the caller's own nor
+		/// its `with_role`, are no longer held once the caller loses them, and
+		/// roles held by delegation can not be delegated again.
+		#[pallet::weight(T::WeightInfo::delegate().saturating_mul(roles.len() as u64))]
+		#[pallet::call_index(5)]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			with_role: T::Role,
+			to: T::AccountId,
+			scope: T::Scope,
+			roles: Vec<T::Role>,
+			valid_till: Seconds,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			ensure!(
+				!DelegatedRoles::<T>::contains_key((&from, &scope, &with_role)),
+				Error::<T>::DelegatedRole
+			);
+
+			for role in roles {
+				ensure!(Self::holds(&from, &scope, &role), Error::<T>::RoleNotGiven);
+				ensure!(
+					Self::is_editor(&from, &with_role, &scope, &role),
+					Error::<T>::NoEditor
+				);
+				ensure!(
+					!DelegatedRoles::<T>::contains_key((&from, &scope, &role)),
+					Error::<T>::DelegatedRole
+				);
+				ensure!(
+					[&role, &with_role].into_iter().all(|delegator_role| {
+						RoleExpiry::<T>::get((&from, &scope, delegator_role))
+							.map_or(true, |delegator_till| valid_till <= delegator_till)
+					}),
+					Error::<T>::InvalidExpiry
+				);
+
+				// A role held by other means than delegation is not replaced by a
+				// delegated one
+				ensure!(
+					!Self::holds(&to, &scope, &role)
+						|| DelegatedRoles::<T>::contains_key((&to, &scope, &role)),
+					Error::<T>::RoleAlreadyGiven
+				);
+
+				Pallet::<T>::do_add_with_expiry(
+					scope.clone(),
+					to.clone(),
+					role.clone(),
+					valid_till,
+				)?;
+				DelegatedRoles::<T>::insert((&to, &scope, &role), &from);
+			}
+
+			Self::deposit_event(Event::<T>::Delegated {
+				from,
+				to,
+				scope,
+				valid_till,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -207,30 +396,88 @@ impl<T: Config> Pallet<T> {
 		with_role: T::Role,
 		scope: T::Scope,
 		role: T::Role,
-	) -> Result<Who, DispatchError> {
+	) -> Result<Who<T::AccountId>, DispatchError> {
 		// check if origin is admin
 		match Self::ensure_admin(origin.clone()) {
 			Ok(()) => Ok(Who::Admin),
 			_ => {
 				// check if origin is editor
 				let editor = ensure_signed(origin)?;
-				let is_editor = Permission::<T>::get(editor.clone(), scope.clone())
-					.map(|roles| {
-						roles.exists(with_role.clone())
-							&& T::Editors::contains(&(editor, Some(with_role), scope, role))
-					})
-					.unwrap_or(false);
-				ensure!(is_editor, Error::<T>::NoEditor);
-				Ok(Who::Editor)
+				ensure!(
+					Self::is_editor(&editor, &with_role, &scope, &role),
+					Error::<T>::NoEditor
+				);
+				Ok(Who::Editor(editor))
 			}
 		}
 	}
 
+	fn is_editor(
+		editor: &T::AccountId,
+		with_role: &T::Role,
+		scope: &T::Scope,
+		role: &T::Role,
+	) -> bool {
+		Self::holds(editor, scope, with_role)
+			&& T::Editors::contains(&(
+				editor.clone(),
+				Some(with_role.clone()),
+				scope.clone(),
+				role.clone(),
+			))
+	}
+
+	/// The time until which the granter can give roles. Editors can not give
+	/// roles which outlive their `with_role`.
+	fn granter_expiry(
+		who: &Who<T::AccountId>,
+		with_role: &T::Role,
+		scope: &T::Scope,
+	) -> Option<Seconds> {
+		match who {
+			Who::Admin => None,
+			Who::Editor(editor) => RoleExpiry::<T>::get((editor, scope, with_role)),
+		}
+	}
+
+	/// Whether the account holds the role in the scope, it has not expired
+	/// yet and, if delegated, the delegator still holds it.
+	///
+	/// NOTE: Besides the roles of the account, this reads the expiry and the
+	/// delegation of the role, and the roles and expiry of the delegator.
+	/// Weights of callers must account for those five reads.
+	fn holds(who: &T::AccountId, scope: &T::Scope, role: &T::Role) -> bool {
+		Permission::<T>::get(who, scope).map_or(false, |roles| roles.exists(role.clone()))
+			&& !Self::is_expired(who, scope, role)
+			&& !Self::is_revoked_delegation(who, scope, role)
+	}
+
+	/// Whether the account holds the role by delegation and the delegator no
+	/// longer holds it.
+	fn is_revoked_delegation(who: &T::AccountId, scope: &T::Scope, role: &T::Role) -> bool {
+		DelegatedRoles::<T>::get((who, scope, role)).map_or(false, |delegator| {
+			!Permission::<T>::get(&delegator, scope)
+				.map_or(false, |roles| roles.exists(role.clone()))
+				|| Self::is_expired(&delegator, scope, role)
+		})
+	}
+
+	fn is_expired(who: &T::AccountId, scope: &T::Scope, role: &T::Role) -> bool {
+		RoleExpiry::<T>::get((who, scope, role))
+			.map_or(false, |valid_till| valid_till < T::Time::now())
+	}
+
 	fn ensure_admin(origin: OriginFor<T>) -> DispatchResult {
 		T::AdminOrigin::ensure_origin(origin).map_or(Err(Error::<T>::NoEditor.into()), |_| Ok(()))
 	}
 
 	fn do_add(scope: T::Scope, to: T::AccountId, role: T::Role) -> DispatchResult {
+		// An expired role which was not yet swept, or a delegated one whose
+		// delegator lost it, can be given again
+		if Self::is_expired(&to, &scope, &role) || Self::is_revoked_delegation(&to, &scope, &role) {
+			Self::do_remove(scope.clone(), to.clone(), role.clone())?;
+		}
+
 		PermissionCount::<T>::try_mutate(scope.clone(), |perm_count| {
 			let num_permissions = perm_count.map_or(1, |count| count + 1);
 			if num_permissions > T::MaxRolesPerScope::get() {
@@ -291,9 +538,135 @@ impl<T: Config> Pallet<T> {
 			)
 		})?;
 
+		RoleExpiry::<T>::remove((&from, &scope, &role));
+		DelegatedRoles::<T>::remove((&from, &scope, &role));
+
 		Self::deposit_event(Event::<T>::Removed { from, scope, role });
 		Ok(())
 	}
+
+	/// Removes all roles of an account in a scope.
+	fn remove_all(from: &T::AccountId, scope: &T::Scope) {
+		Permission::<T>::remove(from, scope);
+		let _ = RoleExpiry::<T>::clear_prefix((from.clone(), scope.clone()), u32::MAX, None);
+		let _ = DelegatedRoles::<T>::clear_prefix((from.clone(), scope.clone()), u32::MAX, None);
+	}
+
+	fn do_add_with_expiry(
+		scope: T::Scope,
+		to: T::AccountId,
+		role: T::Role,
+		valid_till: Seconds,
+	) -> DispatchResult {
+		ensure!(valid_till >= T::Time::now(), Error::<T>::InvalidExpiry);
+
+		// A role already held is renewed
+		if !Self::holds(&to, &scope, &role) {
+			Self::do_add(scope.clone(), to.clone(), role.clone())?;
+		}
+		RoleExpiry::<T>::insert((&to, &scope, &role), valid_till);
+
+		Self::deposit_event(Event::<T>::ExpirySet {
+			to,
+			scope,
+			role,
+			valid_till,
+		});
+		Ok(())
+	}
+
+	/// All roles of an account per scope, together with the time until
+	/// which each role is held if it expires.
+	pub fn account_roles(
+		who: T::AccountId,
+		roles_of: impl Fn(&T::Storage) -> Vec<T::Role>,
+	) -> Vec<(T::Scope, Vec<(T::Role, Option<Seconds>)>)> {
+		Permission::<T>::iter_prefix(&who)
+			.map(|(scope, storage)| {
+				let roles = roles_of(&storage)
+					.into_iter()
+					.filter(|role| {
+						!Self::is_expired(&who, &scope, role)
+							&& !Self::is_revoked_delegation(&who, &scope, role)
+					})
+					.map(|role| {
+						let valid_till = RoleExpiry::<T>::get((&who, &scope, &role));
+						(role, valid_till)
+					})
+					.collect();
+
+				(scope, roles)
+			})
+			.collect()
+	}
+
+	/// Removes an expired role together with its expiry.
+	pub(crate) fn remove_expired(from: T::AccountId, scope: T::Scope, role: T::Role) {
+		// NOTE: The role itself could already be removed by a purge of the
+		// account. Only the expiry is left to be removed then.
+		if Self::do_remove(scope.clone(), from.clone(), role.clone()).is_err() {
+			RoleExpiry::<T>::remove((&from, &scope, &role));
+			DelegatedRoles::<T>::remove((&from, &scope, &role));
+		}
+
+		Self::deposit_event(Event::<T>::Expired { from, scope, role });
+	}
+
+	/// Removes expired roles, bounded by the given weight.
+	///
+	/// The sweep resumes in the next call after the last visited role if the
+	/// weight is exhausted before all expiring roles were visited.
+	pub(crate) fn sweep_expired(max_weight: Weight) -> Weight {
+		let read_weight = T::DbWeight::get().reads(1);
+		let write_weight = T::DbWeight::get().writes(1);
+		let step_weight = read_weight
+			.saturating_add(write_weight)
+			.saturating_add(T::WeightInfo::remove_expired());
+		let mut weight = Weight::zero();
+
+		if read_weight.saturating_add(step_weight).any_gt(max_weight) {
+			return weight;
+		}
+
+		weight.saturating_accrue(read_weight);
+
+		let now = T::Time::now();
+		let cursor = SweepCursor::<T>::get();
+		let mut expiries = match &cursor {
+			Some(key) => RoleExpiry::<T>::iter_from(RoleExpiry::<T>::hashed_key_for(key.clone())),
+			None => RoleExpiry::<T>::iter(),
+		};
+		let mut last_visited = None;
+
+		loop {
+			if weight.saturating_add(step_weight).any_gt(max_weight) {
+				if let Some(key) = last_visited {
+					SweepCursor::<T>::put(key);
+					weight.saturating_accrue(write_weight);
+				}
+
+				return weight;
+			}
+
+			weight.saturating_accrue(read_weight);
+
+			let Some(((who, scope, role), valid_till)) = expiries.next() else {
+				if cursor.is_some() {
+					SweepCursor::<T>::kill();
+					weight.saturating_accrue(write_weight);
+				}
+
+				return weight;
+			};
+
+			if valid_till < now {
+				weight.saturating_accrue(T::WeightInfo::remove_expired());
+				Self::remove_expired(who.clone(), scope.clone(), role.clone());
+			}
+
+			last_visited = Some((who, scope, role));
+		}
+	}
 }
 
 impl<T: Config> Permissions<T::AccountId> for Pallet<T> {
@@ -303,7 +676,7 @@ impl<T: Config> Permissions<T::AccountId> for Pallet<T> {
 	type Scope = T::Scope;
 
 	fn has(scope: T::Scope, who: T::AccountId, role: T::Role) -> bool {
-		Permission::<T>::get(who, scope).map_or(false, |roles| roles.exists(role))
+		Pallet::<T>::holds(&who, &scope, &role)
 	}
 
 	fn add(scope: T::Scope, who: T::AccountId, role: T::Role) -> Result<(), DispatchError> {
@@ -14,7 +14,7 @@ use cfg_types::permissions::TrancheInvestorInfo;
 pub use dummy::pallet as pallet_dummy;
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{Contains, EitherOfDiverse, SortedMembers},
+	traits::{Contains, EitherOfDiverse, SortedMembers, UnixTime},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use pallet_permissions::Properties;
@@ -26,20 +26,20 @@ use sp_runtime::{traits::AccountIdConversion, BuildStorage};
 ///! Mock environment setup for testing the pallet-permissions
 use crate::{self as pallet_permissions};
 
-#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq)]
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq, MaxEncodedLen)]
 pub enum OrganisationRole {
 	SeniorExeutive,
 	HeadOfSaubermaching,
 	Admin,
 }
 
-#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq)]
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq, MaxEncodedLen)]
 pub enum XcmRole {
 	Sender,
 	Receiver,
 }
 
-#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq)]
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq, MaxEncodedLen)]
 pub enum Role {
 	Organisation(OrganisationRole),
 	Xcm(XcmRole),
@@ -251,6 +251,14 @@ parameter_types! {
 	pub const One: u64 = 1;
 	pub const MaxRoles: u32 = 10;
 	pub const MaxTranches: u32 = 5;
+	pub static Now: u64 = 0;
+}
+
+pub struct MockTime;
+impl UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_secs(Now::get())
+	}
 }
 
 type AdminOrigin = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<One, u64>>;
@@ -263,6 +271,7 @@ impl pallet_permissions::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Scope = Scope;
 	type Storage = Storage;
+	type Time = MockTime;
 	type TrancheId = [u8; 16];
 	type WeightInfo = ();
 }
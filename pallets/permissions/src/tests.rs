@@ -10,7 +10,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
 use pallet_permissions::{Permissions, Properties};
 
 ///! Tests for the permissions pallet
@@ -441,3 +441,390 @@ fn permission_counting() {
 			assert!(pallet_permissions::PermissionCount::<Runtime>::get(Scope::PalletA,).is_none(),);
 		})
 }
+
+#[test]
+fn expired_roles_are_not_held() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			Now::set(100);
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::add_with_expiry(
+					RuntimeOrigin::signed(1),
+					Role::Organisation(OrganisationRole::Admin),
+					2,
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::SeniorExeutive),
+					50
+				),
+				PermissionsError::<Runtime>::InvalidExpiry
+			);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add_with_expiry(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::Admin),
+				2,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::SeniorExeutive),
+				200
+			));
+			assert!(<pallet_permissions::Pallet<Runtime> as Permissions<
+				AccountId,
+			>>::has(
+				Scope::PalletA,
+				2,
+				Role::Organisation(OrganisationRole::SeniorExeutive)
+			));
+
+			Now::set(201);
+			assert!(!<pallet_permissions::Pallet<Runtime> as Permissions<
+				AccountId,
+			>>::has(
+				Scope::PalletA,
+				2,
+				Role::Organisation(OrganisationRole::SeniorExeutive)
+			));
+
+			// An expired role can be given again
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::Admin),
+				2,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::SeniorExeutive)
+			));
+			assert!(<pallet_permissions::Pallet<Runtime> as Permissions<
+				AccountId,
+			>>::has(
+				Scope::PalletA,
+				2,
+				Role::Organisation(OrganisationRole::SeniorExeutive)
+			));
+			assert!(pallet_permissions::RoleExpiry::<Runtime>::get((
+				2,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::SeniorExeutive)
+			))
+			.is_none());
+		})
+}
+
+#[test]
+fn delegate_works() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			for role in [
+				Role::Organisation(OrganisationRole::Admin),
+				Role::Organisation(OrganisationRole::SeniorExeutive),
+			] {
+				assert_ok!(pallet_permissions::Pallet::<Runtime>::add_with_expiry(
+					RuntimeOrigin::signed(1),
+					Role::Organisation(OrganisationRole::Admin),
+					2,
+					Scope::PalletA,
+					role,
+					500
+				));
+			}
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(2),
+					Role::Organisation(OrganisationRole::Admin),
+					3,
+					Scope::PalletA,
+					vec![Role::Xcm(XcmRole::Sender)],
+					400
+				),
+				PermissionsError::<Runtime>::RoleNotGiven
+			);
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(2),
+					Role::Organisation(OrganisationRole::SeniorExeutive),
+					3,
+					Scope::PalletA,
+					vec![Role::Organisation(OrganisationRole::SeniorExeutive)],
+					400
+				),
+				PermissionsError::<Runtime>::NoEditor
+			);
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(2),
+					Role::Organisation(OrganisationRole::Admin),
+					3,
+					Scope::PalletA,
+					vec![Role::Organisation(OrganisationRole::SeniorExeutive)],
+					600
+				),
+				PermissionsError::<Runtime>::InvalidExpiry
+			);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				Scope::PalletA,
+				vec![
+					Role::Organisation(OrganisationRole::Admin),
+					Role::Organisation(OrganisationRole::SeniorExeutive)
+				],
+				400
+			));
+
+			let roles = pallet_permissions::Permission::<Runtime>::get(3, Scope::PalletA).unwrap();
+			assert!(roles.exists(Role::Organisation(OrganisationRole::Admin)));
+			assert!(roles.exists(Role::Organisation(OrganisationRole::SeniorExeutive)));
+			assert_eq!(
+				pallet_permissions::RoleExpiry::<Runtime>::get((
+					3,
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::SeniorExeutive)
+				)),
+				Some(400)
+			);
+		})
+}
+
+#[test]
+fn on_idle_removes_expired_roles() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			for (who, valid_till) in [(2, 10), (3, 100)] {
+				assert_ok!(pallet_permissions::Pallet::<Runtime>::add_with_expiry(
+					RuntimeOrigin::signed(1),
+					Role::Organisation(OrganisationRole::Admin),
+					who,
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::SeniorExeutive),
+					valid_till
+				));
+			}
+			assert_eq!(
+				pallet_permissions::PermissionCount::<Runtime>::get(Scope::PalletA),
+				Some(2)
+			);
+
+			Now::set(50);
+			pallet_permissions::Pallet::<Runtime>::on_idle(1, Weight::MAX);
+
+			assert!(pallet_permissions::Permission::<Runtime>::get(2, Scope::PalletA).is_none());
+			assert!(pallet_permissions::RoleExpiry::<Runtime>::get((
+				2,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::SeniorExeutive)
+			))
+			.is_none());
+			assert!(pallet_permissions::Permission::<Runtime>::get(3, Scope::PalletA).is_some());
+			assert_eq!(
+				pallet_permissions::PermissionCount::<Runtime>::get(Scope::PalletA),
+				Some(1)
+			);
+			assert!(pallet_permissions::SweepCursor::<Runtime>::get().is_none());
+		})
+}
+
+#[test]
+fn delegated_roles_can_not_be_delegated_again() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::Admin),
+				2,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				Scope::PalletA,
+				vec![Role::Organisation(OrganisationRole::Admin)],
+				400
+			));
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(3),
+					Role::Organisation(OrganisationRole::Admin),
+					4,
+					Scope::PalletA,
+					vec![Role::Organisation(OrganisationRole::Admin)],
+					300
+				),
+				PermissionsError::<Runtime>::DelegatedRole
+			);
+
+			// Removing the role removes the delegation mark
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::remove(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+			assert!(
+				!pallet_permissions::DelegatedRoles::<Runtime>::contains_key((
+					3,
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::Admin)
+				))
+			);
+		})
+}
+
+#[test]
+fn delegated_roles_are_lost_with_the_delegator_role() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::Admin),
+				2,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				Scope::PalletA,
+				vec![Role::Organisation(OrganisationRole::Admin)],
+				400
+			));
+			assert_eq!(
+				pallet_permissions::DelegatedRoles::<Runtime>::get((
+					3,
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::Admin)
+				)),
+				Some(2)
+			);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::remove(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::Admin),
+				2,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+			assert!(!<pallet_permissions::Pallet<Runtime> as Permissions<
+				AccountId,
+			>>::has(
+				Scope::PalletA,
+				3,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+
+			// The role can be given again by other means
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+			assert!(<pallet_permissions::Pallet<Runtime> as Permissions<
+				AccountId,
+			>>::has(
+				Scope::PalletA,
+				3,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+		})
+}
+
+#[test]
+fn editor_grants_inherit_expiry() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add_with_expiry(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::Admin),
+				2,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin),
+				500
+			));
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+				RuntimeOrigin::signed(2),
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::SeniorExeutive)
+			));
+			assert_eq!(
+				pallet_permissions::RoleExpiry::<Runtime>::get((
+					3,
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::SeniorExeutive)
+				)),
+				Some(500)
+			);
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::add_with_expiry(
+					RuntimeOrigin::signed(2),
+					Role::Organisation(OrganisationRole::Admin),
+					4,
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::SeniorExeutive),
+					600
+				),
+				PermissionsError::<Runtime>::InvalidExpiry
+			);
+		})
+}
+
+#[test]
+fn add_with_expiry_renews_role() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			for valid_till in [100, 200] {
+				assert_ok!(pallet_permissions::Pallet::<Runtime>::add_with_expiry(
+					RuntimeOrigin::signed(1),
+					Role::Organisation(OrganisationRole::Admin),
+					2,
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::SeniorExeutive),
+					valid_till
+				));
+			}
+
+			assert_eq!(
+				pallet_permissions::RoleExpiry::<Runtime>::get((
+					2,
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::SeniorExeutive)
+				)),
+				Some(200)
+			);
+			assert_eq!(
+				pallet_permissions::PermissionCount::<Runtime>::get(Scope::PalletA),
+				Some(1)
+			);
+
+			Now::set(150);
+			assert!(<pallet_permissions::Pallet<Runtime> as Permissions<
+				AccountId,
+			>>::has(
+				Scope::PalletA,
+				2,
+				Role::Organisation(OrganisationRole::SeniorExeutive)
+			));
+		})
+}
@@ -11,7 +11,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use frame_support::weights::Weight;
+use frame_support::weights::{constants::RocksDbWeight, Weight};
 
 pub trait WeightInfo {
 	fn add_as_admin() -> Weight;
@@ -20,6 +20,39 @@ pub trait WeightInfo {
 	fn remove_as_editor() -> Weight;
 	fn purge() -> Weight;
 	fn admin_purge() -> Weight;
+
+	fn add_with_expiry() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `add_as_editor`. Besides its accesses,
+		//       this one reads the roles, expiries and delegations of the
+		//       granter, the grantee and their delegators and writes the expiry.
+		Weight::from_parts(24_916_000, 6406)
+			.saturating_add(RocksDbWeight::get().reads(15))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn delegate() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `add_as_editor`, per delegated role.
+		//       Besides its accesses, this one reads the roles, expiries and
+		//       delegations of the delegator, the delegate and the delegators of
+		//       both, and writes the expiry and the delegation.
+		Weight::from_parts(24_916_000, 6406)
+			.saturating_add(RocksDbWeight::get().reads(30))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn remove_expired() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `remove_as_admin`. Besides its
+		//       accesses, this one removes the expiry and the delegation.
+		Weight::from_parts(20_729_000, 3698)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
 }
 
 impl WeightInfo for () {
@@ -46,4 +79,16 @@ impl WeightInfo for () {
 	fn admin_purge() -> Weight {
 		Weight::zero()
 	}
+
+	fn add_with_expiry() -> Weight {
+		Weight::zero()
+	}
+
+	fn delegate() -> Weight {
+		Weight::zero()
+	}
+
+	fn remove_expired() -> Weight {
+		Weight::zero()
+	}
 }
@@ -365,10 +365,13 @@ impl pallet_investments::Config for Test {
 	type CollectedInvestmentHook = NoopCollectHook;
 	type CollectedRedemptionHook = NoopCollectHook;
 	type InvestmentId = (PoolId, TrancheId);
+	type IsPoolAdmin = MockIsAdmin;
 	type MaxOutstandingCollects = MaxOutstandingCollects;
+	type PoolId = PoolId;
 	type PreConditions = Always;
 	type RuntimeEvent = RuntimeEvent;
 	type Tokens = OrmlTokens;
+	type TrancheId = TrancheId;
 	type WeightInfo = ();
 }
 
@@ -454,6 +454,14 @@ impl<T: Config> ChangeGuard for Pallet<T> {
 
 		Ok(change)
 	}
+
+	fn discard(pool_id: Self::PoolId, change_id: Self::ChangeId) -> DispatchResult {
+		NotedChange::<T>::take(pool_id, change_id).ok_or(Error::<T>::ChangeNotFound)?;
+
+		Self::deposit_event(Event::DiscardedChange { pool_id, change_id });
+
+		Ok(())
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
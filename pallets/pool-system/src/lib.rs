@@ -478,6 +478,11 @@ pub mod pallet {
 			nav_fees: T::Balance,
 			reserve: T::Balance,
 		},
+		/// A change was discarded before being released
+		DiscardedChange {
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		},
 	}
 
 	#[pallet::error]
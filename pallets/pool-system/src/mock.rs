@@ -168,6 +168,7 @@ impl pallet_permissions::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Scope = PermissionScope<u64, CurrencyId>;
 	type Storage = PermissionRoles<TimeProvider<Timestamp>, MinDelay, TrancheId, MaxTranches>;
+	type Time = Timestamp;
 	type TrancheId = TrancheId;
 	type WeightInfo = ();
 }
@@ -304,10 +305,13 @@ impl pallet_investments::Config for Runtime {
 	type CollectedInvestmentHook = NoopCollectHook;
 	type CollectedRedemptionHook = NoopCollectHook;
 	type InvestmentId = (PoolId, TrancheId);
+	type IsPoolAdmin = MockIsAdmin;
 	type MaxOutstandingCollects = MaxOutstandingCollects;
+	type PoolId = PoolId;
 	type PreConditions = Always;
 	type RuntimeEvent = RuntimeEvent;
 	type Tokens = Tokens;
+	type TrancheId = TrancheId;
 	type WeightInfo = ();
 }
 
@@ -2646,6 +2646,26 @@ mod changes {
 		});
 	}
 
+	#[test]
+	fn discard() {
+		new_test_ext().execute_with(|| {
+			util::default_pool::create();
+
+			let change = PoolChangeProposal::new([]);
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+
+			assert_ok!(PoolSystem::discard(DEFAULT_POOL_ID, change_id));
+			assert_noop!(
+				PoolSystem::released(DEFAULT_POOL_ID, change_id),
+				Error::<Runtime>::ChangeNotFound
+			);
+			assert_noop!(
+				PoolSystem::discard(DEFAULT_POOL_ID, change_id),
+				Error::<Runtime>::ChangeNotFound
+			);
+		});
+	}
+
 	#[test]
 	fn no_requirements() {
 		new_test_ext().execute_with(|| {
@@ -87,6 +87,14 @@ pub enum TransferEffects<AccountId, CurrencyId, Balance> {
 	},
 }
 
+/// Weight of checking the transfers of `checks` currencies with the
+/// `PreTransfer` conditions. Each check reads the allowance metadata,
+/// allowances and approval policies of the sender for the specific currency
+/// and for all currencies, and writes the used allowances.
+fn pre_transfer_weight<T: frame_system::Config>(checks: u64) -> Weight {
+	T::DbWeight::get().reads_writes(6, 2).saturating_mul(checks)
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -122,7 +130,7 @@ pub mod pallet {
 		/// by the network, and if the receiving chain would handle
 		/// messages correctly.
 		#[pallet::call_index(0)]
-		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer(currency_id.clone(), * amount, dest) + T::DbWeight::get().reads(4))]
+		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer(currency_id.clone(), * amount, dest) + pre_transfer_weight::<T>(1))]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			currency_id: T::CurrencyId,
@@ -161,7 +169,7 @@ pub mod pallet {
 		/// by the network, and if the receiving chain would handle
 		/// messages correctly.
 		#[pallet::call_index(1)]
-		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer_multiasset(asset, dest) + T::DbWeight::get().reads(4))]
+		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer_multiasset(asset, dest) + pre_transfer_weight::<T>(1))]
 		pub fn transfer_multiasset(
 			origin: OriginFor<T>,
 			asset: Box<VersionedAsset>,
@@ -204,7 +212,7 @@ pub mod pallet {
 		/// by the network, and if the receiving chain would handle
 		/// messages correctly.
 		#[pallet::call_index(2)]
-		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer(currency_id.clone(), * amount, dest) + T::DbWeight::get().reads(4))]
+		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer(currency_id.clone(), * amount, dest) + pre_transfer_weight::<T>(1))]
 		pub fn transfer_with_fee(
 			origin: OriginFor<T>,
 			currency_id: T::CurrencyId,
@@ -255,7 +263,7 @@ pub mod pallet {
 		/// by the network, and if the receiving chain would handle
 		/// messages correctly.
 		#[pallet::call_index(3)]
-		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer_multiasset(asset, dest) + T::DbWeight::get().reads(4))]
+		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer_multiasset(asset, dest) + pre_transfer_weight::<T>(2))]
 		pub fn transfer_multiasset_with_fee(
 			origin: OriginFor<T>,
 			asset: Box<VersionedAsset>,
@@ -303,7 +311,7 @@ pub mod pallet {
 		/// by the network, and if the receiving chain would handle
 		/// messages correctly.
 		#[pallet::call_index(4)]
-		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer_multicurrencies(currencies, fee_item, dest) + T::DbWeight::get().reads(4))]
+		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer_multicurrencies(currencies, fee_item, dest) + pre_transfer_weight::<T>(currencies.len() as u64 + 1))]
 		pub fn transfer_multicurrencies(
 			origin: OriginFor<T>,
 			currencies: Vec<(T::CurrencyId, T::Balance)>,
@@ -348,7 +356,7 @@ pub mod pallet {
 		/// by the network, and if the receiving chain would handle
 		/// messages correctly.
 		#[pallet::call_index(5)]
-		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer_multiassets(assets, fee_item, dest) + T::DbWeight::get().reads(4))]
+		#[pallet::weight(orml_xtokens::XtokensWeight::< T >::weight_of_transfer_multiassets(assets, fee_item, dest) + pre_transfer_weight::<T>(T::MaxAssetsForTransfer::get() as u64 + 1))]
 		pub fn transfer_multiassets(
 			origin: OriginFor<T>,
 			assets: Box<VersionedAssets>,
@@ -0,0 +1,181 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_traits::AssetMetadataOf;
+use frame_benchmarking::{account, v2::*};
+use frame_support::traits::fungibles::Mutate as _;
+use frame_system::RawOrigin;
+use orml_traits::asset_registry::{Inspect, Mutate};
+use sp_runtime::{traits::Zero, Perbill};
+
+use super::*;
+
+const CURRENCY_A: u32 = 2001;
+const CURRENCY_B: u32 = 2002;
+const DECIMALS: u32 = 6;
+const AMOUNT: u128 = 1_000_000_000_000;
+const AMPLIFICATION: u32 = 100;
+
+struct Helper<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> Helper<T>
+where
+	T::CurrencyId: From<u32>,
+	T::AssetRegistry: Mutate,
+	<T::AssetRegistry as Inspect>::Balance: Zero,
+	<T::AssetRegistry as Inspect>::CustomMetadata: Default,
+{
+	pub fn setup_currencies() {
+		for currency in [CURRENCY_A, CURRENCY_B] {
+			T::AssetRegistry::register_asset(
+				Some(currency.into()),
+				AssetMetadataOf::<T::AssetRegistry> {
+					decimals: DECIMALS,
+					name: Default::default(),
+					symbol: Default::default(),
+					existential_deposit: Zero::zero(),
+					location: None,
+					additional: Default::default(),
+				},
+			)
+			.unwrap();
+		}
+	}
+
+	pub fn setup_pool() {
+		Self::setup_currencies();
+
+		Pallet::<T>::set_pool(
+			RawOrigin::Root.into(),
+			CURRENCY_A.into(),
+			CURRENCY_B.into(),
+			AMPLIFICATION,
+			Perbill::from_rational(1u32, 1000u32),
+			Perbill::from_percent(1),
+		)
+		.unwrap();
+	}
+
+	pub fn setup_provider() -> T::AccountId {
+		let provider = account::<T::AccountId>("provider", 0, 0);
+
+		T::Tokens::mint_into(CURRENCY_A.into(), &provider, AMOUNT.into()).unwrap();
+		T::Tokens::mint_into(CURRENCY_B.into(), &provider, AMOUNT.into()).unwrap();
+
+		provider
+	}
+
+	pub fn add_liquidity(provider: &T::AccountId) {
+		Pallet::<T>::add_liquidity(
+			RawOrigin::Signed(provider.clone()).into(),
+			CURRENCY_A.into(),
+			CURRENCY_B.into(),
+			(AMOUNT / 2).into(),
+			(AMOUNT / 2).into(),
+			Zero::zero(),
+		)
+		.unwrap();
+	}
+}
+
+#[benchmarks(
+    where
+        T::CurrencyId: From<u32>,
+        T::AssetRegistry: Mutate,
+        <T::AssetRegistry as Inspect>::Balance: Zero,
+        <T::AssetRegistry as Inspect>::CustomMetadata: Default,
+)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_pool() -> Result<(), BenchmarkError> {
+		Helper::<T>::setup_currencies();
+
+		#[extrinsic_call]
+		set_pool(
+			RawOrigin::Root,
+			CURRENCY_A.into(),
+			CURRENCY_B.into(),
+			AMPLIFICATION,
+			Perbill::from_rational(1u32, 1000u32),
+			Perbill::from_percent(1),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_liquidity() -> Result<(), BenchmarkError> {
+		Helper::<T>::setup_pool();
+		let provider = Helper::<T>::setup_provider();
+
+		// The first deposit is the expensive one, computing the invariant
+		#[extrinsic_call]
+		add_liquidity(
+			RawOrigin::Signed(provider),
+			CURRENCY_A.into(),
+			CURRENCY_B.into(),
+			(AMOUNT / 2).into(),
+			(AMOUNT / 2).into(),
+			Zero::zero(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_liquidity() -> Result<(), BenchmarkError> {
+		Helper::<T>::setup_pool();
+		let provider = Helper::<T>::setup_provider();
+		Helper::<T>::add_liquidity(&provider);
+
+		let shares = Shares::<T>::get(
+			Pallet::<T>::pair(CURRENCY_A.into(), CURRENCY_B.into()),
+			&provider,
+		);
+
+		#[extrinsic_call]
+		remove_liquidity(
+			RawOrigin::Signed(provider),
+			CURRENCY_A.into(),
+			CURRENCY_B.into(),
+			shares / 2u32.into(),
+			Zero::zero(),
+			Zero::zero(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn swap() -> Result<(), BenchmarkError> {
+		Helper::<T>::setup_pool();
+		let provider = Helper::<T>::setup_provider();
+		Helper::<T>::add_liquidity(&provider);
+
+		// Swapping most of the reserves away from the peg is the case where
+		// the invariant takes longer to converge
+		#[extrinsic_call]
+		swap(
+			RawOrigin::Signed(provider),
+			CURRENCY_B.into(),
+			CURRENCY_A.into(),
+			(AMOUNT / 2).into(),
+			Zero::zero(),
+		);
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
+}
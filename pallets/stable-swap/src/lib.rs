@@ -0,0 +1,904 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! # Stable Swap Pallet
+//!
+//! The Stable Swap pallet keeps automated market-maker pools for whitelisted
+//! pairs of stablecoins, priced with a curve-style invariant, and uses them as
+//! a fallback for the swaps of an order book.
+//!
+//! The pallet implements `TokenSwaps` by wrapping an inner order book: every
+//! order is placed in the order book, but market orders of a pair with a pool
+//! are also tracked for a fallback period. On idle time, the tracked orders
+//! still unmatched in the order book after a grace period are swapped
+//! against the pool, only if the pool quote is within the max slippage of the
+//! pool from the market ratio of the order book. Then the order is removed
+//! from the order book and its
+//! fulfillment is notified as if it was filled there. The tracked orders are
+//! tried in turns, each idle time continuing from the last order tried.
+//! Orders not swapped within the fallback period stay in the order book as
+//! usual.
+//!
+//! Pools are set by the admin origin, and anyone can add or remove liquidity
+//! of a pool in exchange for pool shares, or swap against a pool, bringing
+//! its quote back to the market. Balances are normalized to the same decimals
+//! before computing the invariant.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod math;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use cfg_primitives::conversion::convert_balance_decimals;
+	use cfg_traits::{
+		swaps::{OrderInfo, OrderOptions, OrderRatio, Swap, SwapInfo, TokenSwaps},
+		StatusNotificationHook,
+	};
+	use frame_support::{
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		traits::{
+			fungibles::{Inspect, Mutate},
+			tokens::Preservation,
+		},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use orml_traits::asset_registry::{self, Inspect as _};
+	use sp_arithmetic::{helpers_128bit::multiply_by_rational_with_rounding, per_things::Rounding};
+	use sp_runtime::{
+		traits::{
+			AccountIdConversion, AtLeast32BitUnsigned, EnsureAdd, EnsureSub, EnsureSubAssign, Zero,
+		},
+		ArithmeticError, FixedPointNumber, FixedPointOperand, Perbill,
+	};
+	use sp_std::vec::Vec;
+
+	use super::*;
+
+	/// Decimals to which balances are normalized before computing the
+	/// invariant.
+	pub const NORMALIZED_DECIMALS: u32 = 18;
+
+	/// Max amplification of a pool.
+	pub const MAX_AMPLIFICATION: u32 = 1_000_000;
+
+	/// Pair of currencies sorted, identifying a pool.
+	pub type PairOf<T> = (<T as Config>::CurrencyId, <T as Config>::CurrencyId);
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier used to derive the account of each pool.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin allowed to set pools.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Currency identifier of the pool reserves.
+		type CurrencyId: Parameter + Member + Copy + Ord + MaxEncodedLen;
+
+		/// Balance of the pool reserves and shares.
+		type Balance: Parameter
+			+ Member
+			+ AtLeast32BitUnsigned
+			+ FixedPointOperand
+			+ Default
+			+ Copy
+			+ MaxEncodedLen
+			+ Into<u128>
+			+ From<u128>;
+
+		/// Ratio used to notify the swaps done by a pool.
+		type Ratio: Parameter + Member + FixedPointNumber + MaxEncodedLen;
+
+		/// Identifier of the orders.
+		type OrderId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// Tokens used as pool reserves.
+		type Tokens: Mutate<Self::AccountId, AssetId = Self::CurrencyId, Balance = Self::Balance>;
+
+		/// Asset registry used to know the decimals of each currency.
+		type AssetRegistry: asset_registry::Inspect<AssetId = Self::CurrencyId>;
+
+		/// Order book wrapped by this pallet.
+		type OrderBook: TokenSwaps<
+			Self::AccountId,
+			CurrencyId = Self::CurrencyId,
+			BalanceIn = Self::Balance,
+			BalanceOut = Self::Balance,
+			Ratio = Self::Ratio,
+			OrderId = Self::OrderId,
+		>;
+
+		/// Notifies the swaps done by a pool, as the order book does for the
+		/// orders filled there.
+		type FulfilledOrderHook: StatusNotificationHook<
+			Id = Self::OrderId,
+			Status = SwapInfo<Self::Balance, Self::Balance, Self::CurrencyId, Self::Ratio>,
+			Error = DispatchError,
+		>;
+
+		/// Number of blocks an order is tried to be swapped against a pool.
+		#[pallet::constant]
+		type FallbackPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks an order is left to the order book before it is
+		/// tried to be swapped against a pool. Must be lower than
+		/// `FallbackPeriod`.
+		#[pallet::constant]
+		type GracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Information of runtime weights
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Parameters of a pool
+	#[derive(
+		Clone, RuntimeDebugNoBound, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct PoolInfo<T: Config> {
+		/// Amplification of the invariant. The higher, the flatter the curve
+		/// around the balanced point.
+		pub amplification: u32,
+
+		/// Fee taken from the incoming amount of each swap, kept by the pool.
+		pub fee: Perbill,
+
+		/// Max deviation of the pool quote from the market ratio allowed for a
+		/// fallback swap.
+		pub max_slippage: Perbill,
+
+		/// Total shares issued to the liquidity providers.
+		pub total_shares: T::Balance,
+	}
+
+	/// An order of the order book waiting for a fallback swap.
+	#[derive(Clone, RuntimeDebug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+	pub struct FallbackOrder<AccountId, BlockNumber> {
+		/// The account of the order.
+		pub who: AccountId,
+
+		/// The block from which the order is tried.
+		pub tried_from: BlockNumber,
+
+		/// The block from which the order is no longer tried.
+		pub expires_at: BlockNumber,
+	}
+
+	#[pallet::storage]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, PairOf<T>, PoolInfo<T>>;
+
+	#[pallet::storage]
+	pub type Shares<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PairOf<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Orders placed in the order book waiting for a fallback swap.
+	#[pallet::storage]
+	pub type FallbackOrders<T: Config> =
+		StorageMap<_, Twox64Concat, T::OrderId, FallbackOrder<T::AccountId, BlockNumberFor<T>>>;
+
+	/// The last order tried for a fallback swap. The next idle time continues
+	/// from the order after it.
+	#[pallet::storage]
+	pub type FallbackCursor<T: Config> = StorageValue<_, T::OrderId>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool was created or updated.
+		PoolSet {
+			currency_a: T::CurrencyId,
+			currency_b: T::CurrencyId,
+			amplification: u32,
+			fee: Perbill,
+			max_slippage: Perbill,
+		},
+		/// Liquidity was added to a pool.
+		LiquidityAdded {
+			who: T::AccountId,
+			currency_a: T::CurrencyId,
+			currency_b: T::CurrencyId,
+			amount_a: T::Balance,
+			amount_b: T::Balance,
+			shares: T::Balance,
+		},
+		/// Liquidity was removed from a pool.
+		LiquidityRemoved {
+			who: T::AccountId,
+			currency_a: T::CurrencyId,
+			currency_b: T::CurrencyId,
+			amount_a: T::Balance,
+			amount_b: T::Balance,
+			shares: T::Balance,
+		},
+		/// An order of the order book was swapped against a pool.
+		Swapped {
+			order_id: T::OrderId,
+			who: T::AccountId,
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+		},
+		/// An order of the order book could not be swapped against a pool. It
+		/// is tried again until its fallback period ends.
+		FallbackSwapFailed {
+			order_id: T::OrderId,
+			error: DispatchError,
+		},
+		/// An account swapped against a pool.
+		PoolSwapped {
+			who: T::AccountId,
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The currencies of a pool must be different.
+		SameCurrencies,
+		/// The amplification is zero or above the max.
+		InvalidAmplification,
+		/// There is no pool for the pair of currencies.
+		PoolNotFound,
+		/// Some currency is not found in the asset registry.
+		MetadataNotFound,
+		/// The pool has no reserves or the amounts provided are zero.
+		NoLiquidity,
+		/// The resulting amounts are below the minimum expected.
+		SlippageExceeded,
+		/// The account has not enough shares of the pool.
+		InsufficientShares,
+		/// The invariant could not be computed for the reserves.
+		InvariantNotConverged,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::fallback_swaps(now, remaining_weight)
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::GracePeriod::get() < T::FallbackPeriod::get(),
+				"The grace period must be lower than the fallback period"
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create or update the pool of a pair of currencies, whitelisting the
+		/// pair for fallback swaps.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_pool())]
+		pub fn set_pool(
+			origin: OriginFor<T>,
+			currency_a: T::CurrencyId,
+			currency_b: T::CurrencyId,
+			amplification: u32,
+			fee: Perbill,
+			max_slippage: Perbill,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(currency_a != currency_b, Error::<T>::SameCurrencies);
+			ensure!(
+				amplification > 0 && amplification <= MAX_AMPLIFICATION,
+				Error::<T>::InvalidAmplification
+			);
+
+			Self::decimals(currency_a)?;
+			Self::decimals(currency_b)?;
+
+			Pools::<T>::mutate(Self::pair(currency_a, currency_b), |pool| {
+				*pool = Some(PoolInfo {
+					amplification,
+					fee,
+					max_slippage,
+					total_shares: pool
+						.as_ref()
+						.map(|pool| pool.total_shares)
+						.unwrap_or_default(),
+				});
+			});
+
+			Self::deposit_event(Event::PoolSet {
+				currency_a,
+				currency_b,
+				amplification,
+				fee,
+				max_slippage,
+			});
+
+			Ok(())
+		}
+
+		/// Add liquidity to a pool.
+		/// Except for the first deposit, the amounts taken are proportional to
+		/// the pool reserves, and the shares are given by the smallest
+		/// proportion provided.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::add_liquidity())]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			currency_a: T::CurrencyId,
+			currency_b: T::CurrencyId,
+			amount_a: T::Balance,
+			amount_b: T::Balance,
+			min_shares: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pair = Self::pair(currency_a, currency_b);
+			let mut pool = Pools::<T>::get(pair).ok_or(Error::<T>::PoolNotFound)?;
+
+			ensure!(
+				!amount_a.is_zero() && !amount_b.is_zero(),
+				Error::<T>::NoLiquidity
+			);
+
+			let pool_account = Self::pool_account(pair);
+			let reserve_a = T::Tokens::balance(currency_a, &pool_account);
+			let reserve_b = T::Tokens::balance(currency_b, &pool_account);
+
+			let (amount_a, amount_b, shares) = match pool.total_shares.is_zero() {
+				true => {
+					let d = math::compute_d(
+						Self::normalize(currency_a, amount_a)?,
+						Self::normalize(currency_b, amount_b)?,
+						pool.amplification,
+					)
+					.ok_or(Error::<T>::InvariantNotConverged)?;
+
+					(amount_a, amount_b, d.into())
+				}
+				false => {
+					ensure!(
+						!reserve_a.is_zero() && !reserve_b.is_zero(),
+						Error::<T>::NoLiquidity
+					);
+
+					let shares = sp_std::cmp::min(
+						Self::mul_div(amount_a, pool.total_shares, reserve_a, Rounding::Down)?,
+						Self::mul_div(amount_b, pool.total_shares, reserve_b, Rounding::Down)?,
+					);
+
+					(
+						Self::mul_div(shares, reserve_a, pool.total_shares, Rounding::Up)?,
+						Self::mul_div(shares, reserve_b, pool.total_shares, Rounding::Up)?,
+						shares,
+					)
+				}
+			};
+
+			ensure!(!shares.is_zero(), Error::<T>::NoLiquidity);
+			ensure!(shares >= min_shares, Error::<T>::SlippageExceeded);
+
+			T::Tokens::transfer(
+				currency_a,
+				&who,
+				&pool_account,
+				amount_a,
+				Preservation::Expendable,
+			)?;
+			T::Tokens::transfer(
+				currency_b,
+				&who,
+				&pool_account,
+				amount_b,
+				Preservation::Expendable,
+			)?;
+
+			pool.total_shares = pool.total_shares.ensure_add(shares)?;
+			Pools::<T>::insert(pair, pool);
+			Shares::<T>::mutate(pair, &who, |balance| {
+				*balance = balance.saturating_add(shares)
+			});
+
+			Self::deposit_event(Event::LiquidityAdded {
+				who,
+				currency_a,
+				currency_b,
+				amount_a,
+				amount_b,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from a pool, burning the shares in exchange for
+		/// their proportion of the pool reserves.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove_liquidity())]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			currency_a: T::CurrencyId,
+			currency_b: T::CurrencyId,
+			shares: T::Balance,
+			min_amount_a: T::Balance,
+			min_amount_b: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pair = Self::pair(currency_a, currency_b);
+			let mut pool = Pools::<T>::get(pair).ok_or(Error::<T>::PoolNotFound)?;
+
+			ensure!(!shares.is_zero(), Error::<T>::InsufficientShares);
+			ensure!(
+				Shares::<T>::get(pair, &who) >= shares,
+				Error::<T>::InsufficientShares
+			);
+
+			let pool_account = Self::pool_account(pair);
+			let amount_a = Self::mul_div(
+				shares,
+				T::Tokens::balance(currency_a, &pool_account),
+				pool.total_shares,
+				Rounding::Down,
+			)?;
+			let amount_b = Self::mul_div(
+				shares,
+				T::Tokens::balance(currency_b, &pool_account),
+				pool.total_shares,
+				Rounding::Down,
+			)?;
+
+			ensure!(
+				amount_a >= min_amount_a && amount_b >= min_amount_b,
+				Error::<T>::SlippageExceeded
+			);
+
+			T::Tokens::transfer(
+				currency_a,
+				&pool_account,
+				&who,
+				amount_a,
+				Preservation::Expendable,
+			)?;
+			T::Tokens::transfer(
+				currency_b,
+				&pool_account,
+				&who,
+				amount_b,
+				Preservation::Expendable,
+			)?;
+
+			pool.total_shares.ensure_sub_assign(shares)?;
+			Pools::<T>::insert(pair, pool);
+			Shares::<T>::mutate_exists(pair, &who, |balance| {
+				*balance = balance
+					.map(|balance| balance.saturating_sub(shares))
+					.filter(|balance| !balance.is_zero());
+			});
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				who,
+				currency_a,
+				currency_b,
+				amount_a,
+				amount_b,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Swap `amount_out` of `currency_out` against the pool of the pair,
+		/// receiving at least `min_amount_in` of `currency_in`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::swap())]
+		pub fn swap(
+			origin: OriginFor<T>,
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_out: T::Balance,
+			min_amount_in: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amount_in = Self::quote(currency_in, currency_out, amount_out)?;
+
+			ensure!(!amount_in.is_zero(), Error::<T>::NoLiquidity);
+			ensure!(amount_in >= min_amount_in, Error::<T>::SlippageExceeded);
+
+			Self::transfer_swap(&who, currency_in, currency_out, amount_in, amount_out)?;
+
+			Self::deposit_event(Event::PoolSwapped {
+				who,
+				currency_in,
+				currency_out,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub fn pair(currency_a: T::CurrencyId, currency_b: T::CurrencyId) -> PairOf<T> {
+			match currency_a < currency_b {
+				true => (currency_a, currency_b),
+				false => (currency_b, currency_a),
+			}
+		}
+
+		pub fn pool_account(pair: PairOf<T>) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(pair)
+		}
+
+		fn decimals(currency: T::CurrencyId) -> Result<u32, DispatchError> {
+			Ok(T::AssetRegistry::metadata(&currency)
+				.ok_or(Error::<T>::MetadataNotFound)?
+				.decimals)
+		}
+
+		fn normalize(currency: T::CurrencyId, amount: T::Balance) -> Result<u128, DispatchError> {
+			Ok(convert_balance_decimals(
+				Self::decimals(currency)?,
+				NORMALIZED_DECIMALS,
+				amount.into(),
+			)?)
+		}
+
+		fn denormalize(currency: T::CurrencyId, amount: u128) -> Result<T::Balance, DispatchError> {
+			Ok(
+				convert_balance_decimals(NORMALIZED_DECIMALS, Self::decimals(currency)?, amount)?
+					.into(),
+			)
+		}
+
+		fn mul_div(
+			a: T::Balance,
+			b: T::Balance,
+			c: T::Balance,
+			rounding: Rounding,
+		) -> Result<T::Balance, DispatchError> {
+			multiply_by_rational_with_rounding(a.into(), b.into(), c.into(), rounding)
+				.map(Into::into)
+				.ok_or(ArithmeticError::Overflow.into())
+		}
+
+		/// Returns the amount of `currency_in` obtained from the pool by
+		/// swapping `amount_out` of `currency_out`, after the pool fee.
+		pub fn quote(
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_out: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let pair = Self::pair(currency_in, currency_out);
+			let pool = Pools::<T>::get(pair).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_account = Self::pool_account(pair);
+
+			let reserve_in = T::Tokens::balance(currency_in, &pool_account);
+			let reserve_out = T::Tokens::balance(currency_out, &pool_account);
+
+			if reserve_in.is_zero() || reserve_out.is_zero() {
+				return Ok(T::Balance::zero());
+			}
+
+			let amount_out = amount_out.saturating_sub(pool.fee.mul_floor(amount_out));
+
+			let amount_in = math::compute_dy(
+				Self::normalize(currency_out, reserve_out)?,
+				Self::normalize(currency_in, reserve_in)?,
+				Self::normalize(currency_out, amount_out)?,
+				pool.amplification,
+			)
+			.ok_or(Error::<T>::InvariantNotConverged)?;
+
+			Ok(sp_std::cmp::min(
+				Self::denormalize(currency_in, amount_in)?,
+				reserve_in,
+			))
+		}
+
+		/// Swaps the orders waiting for a fallback swap, starting after the
+		/// last order tried, until all were tried or the weight limit is
+		/// reached. Orders within their grace period are skipped. The orders
+		/// not swapped are kept to be tried again until their fallback period
+		/// ends.
+		pub fn fallback_swaps(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+			let swap_weight = T::WeightInfo::fallback_swap();
+			let expire_weight = T::DbWeight::get().reads_writes(1, 1);
+			let skip_weight = T::DbWeight::get().reads(1);
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+			if weight.any_gt(max_weight) {
+				return Weight::zero();
+			}
+
+			let mut cursor = FallbackCursor::<T>::get();
+			let mut orders = match cursor {
+				Some(cursor) => {
+					FallbackOrders::<T>::iter_from(FallbackOrders::<T>::hashed_key_for(cursor))
+				}
+				None => FallbackOrders::<T>::iter(),
+			};
+
+			let mut done = Vec::new();
+			loop {
+				if weight.saturating_add(swap_weight).any_gt(max_weight) {
+					break;
+				}
+
+				let Some((order_id, order)) = orders.next() else {
+					// All orders were tried, the next idle time starts again
+					cursor = None;
+					break;
+				};
+
+				cursor = Some(order_id);
+
+				if order.expires_at <= now {
+					weight.saturating_accrue(expire_weight);
+					done.push(order_id);
+					continue;
+				}
+
+				// The order book has the chance to match the order first
+				if now < order.tried_from {
+					weight.saturating_accrue(skip_weight);
+					continue;
+				}
+
+				weight.saturating_accrue(swap_weight);
+
+				match with_storage_layer(|| Self::fallback_swap(order_id, order.who)) {
+					Ok(true) => done.push(order_id),
+					Ok(false) => (),
+					Err(error) => {
+						Self::deposit_event(Event::FallbackSwapFailed { order_id, error })
+					}
+				}
+			}
+
+			for order_id in done {
+				FallbackOrders::<T>::remove(order_id);
+			}
+
+			FallbackCursor::<T>::set(cursor);
+
+			weight
+		}
+
+		/// Swaps an order against the pool of its pair.
+		/// Returns whether the order does not need to be tried again.
+		pub fn fallback_swap(
+			order_id: T::OrderId,
+			who: T::AccountId,
+		) -> Result<bool, DispatchError> {
+			let swap = match T::OrderBook::get_order_details(order_id) {
+				Some(OrderInfo { swap, .. }) => swap,
+				None => return Ok(true), // Already filled or cancelled
+			};
+
+			let pair = Self::pair(swap.currency_in, swap.currency_out);
+			let pool = match Pools::<T>::get(pair) {
+				Some(pool) => pool,
+				None => return Ok(true),
+			};
+
+			let amount_in = Self::quote(swap.currency_in, swap.currency_out, swap.amount_out)?;
+			let market_amount_in = T::OrderBook::convert_by_market(
+				swap.currency_in,
+				swap.currency_out,
+				swap.amount_out,
+			)?;
+
+			let min_amount_in =
+				market_amount_in.ensure_sub(pool.max_slippage.mul_floor(market_amount_in))?;
+
+			if amount_in.is_zero() || amount_in < min_amount_in {
+				return Ok(false);
+			}
+
+			let ratio = T::Ratio::checked_from_rational(
+				Self::normalize(swap.currency_in, amount_in)?,
+				Self::normalize(swap.currency_out, swap.amount_out)?,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			T::OrderBook::cancel_order(order_id)?;
+
+			Self::transfer_swap(
+				&who,
+				swap.currency_in,
+				swap.currency_out,
+				amount_in,
+				swap.amount_out,
+			)?;
+
+			T::FulfilledOrderHook::notify_status_change(
+				order_id,
+				SwapInfo {
+					remaining: Swap {
+						amount_out: T::Balance::zero(),
+						..swap.clone()
+					},
+					swapped_in: amount_in,
+					swapped_out: swap.amount_out,
+					ratio,
+				},
+			)?;
+
+			Self::deposit_event(Event::Swapped {
+				order_id,
+				who,
+				currency_in: swap.currency_in,
+				currency_out: swap.currency_out,
+				amount_in,
+				amount_out: swap.amount_out,
+			});
+
+			Ok(true)
+		}
+
+		fn transfer_swap(
+			who: &T::AccountId,
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+		) -> DispatchResult {
+			let pool_account = Self::pool_account(Self::pair(currency_in, currency_out));
+
+			T::Tokens::transfer(
+				currency_out,
+				who,
+				&pool_account,
+				amount_out,
+				Preservation::Expendable,
+			)?;
+			T::Tokens::transfer(
+				currency_in,
+				&pool_account,
+				who,
+				amount_in,
+				Preservation::Expendable,
+			)?;
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> TokenSwaps<T::AccountId> for Pallet<T> {
+		type BalanceIn = T::Balance;
+		type BalanceOut = T::Balance;
+		type CurrencyId = T::CurrencyId;
+		type OrderId = T::OrderId;
+		type Ratio = T::Ratio;
+
+		fn place_order(
+			account: T::AccountId,
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_out: T::Balance,
+			ratio: OrderRatio<T::Ratio>,
+			options: OrderOptions<T::Balance>,
+		) -> Result<T::OrderId, DispatchError> {
+			let order_id = T::OrderBook::place_order(
+				account.clone(),
+				currency_in,
+				currency_out,
+				amount_out,
+				ratio,
+				options,
+			)?;
+
+			if ratio == OrderRatio::Market
+				&& !options.fill_or_kill
+				&& Pools::<T>::contains_key(Self::pair(currency_in, currency_out))
+			{
+				let now = frame_system::Pallet::<T>::block_number();
+
+				FallbackOrders::<T>::insert(
+					order_id,
+					FallbackOrder {
+						who: account,
+						tried_from: now.saturating_add(T::GracePeriod::get()),
+						expires_at: now.saturating_add(T::FallbackPeriod::get()),
+					},
+				);
+			}
+
+			Ok(order_id)
+		}
+
+		fn update_order(
+			order_id: T::OrderId,
+			amount_out: T::Balance,
+			ratio: OrderRatio<T::Ratio>,
+		) -> DispatchResult {
+			T::OrderBook::update_order(order_id, amount_out, ratio)
+		}
+
+		fn fill_order(
+			account: T::AccountId,
+			order_id: T::OrderId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+		) -> DispatchResult {
+			T::OrderBook::fill_order(account, order_id, amount_out, max_amount_in)
+		}
+
+		fn fill_order_no_slip_prot(
+			account: T::AccountId,
+			order_id: T::OrderId,
+			amount_out: T::Balance,
+		) -> DispatchResult {
+			T::OrderBook::fill_order_no_slip_prot(account, order_id, amount_out)
+		}
+
+		fn cancel_order(order_id: T::OrderId) -> DispatchResult {
+			T::OrderBook::cancel_order(order_id)?;
+			FallbackOrders::<T>::remove(order_id);
+
+			Ok(())
+		}
+
+		fn get_order_details(
+			order_id: T::OrderId,
+		) -> Option<OrderInfo<T::Balance, T::CurrencyId, T::Ratio>> {
+			T::OrderBook::get_order_details(order_id)
+		}
+
+		fn convert_by_market(
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_out: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			T::OrderBook::convert_by_market(currency_in, currency_out, amount_out)
+		}
+
+		fn market_ratio(
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+		) -> Result<T::Ratio, DispatchError> {
+			T::OrderBook::market_ratio(currency_in, currency_out)
+		}
+	}
+}
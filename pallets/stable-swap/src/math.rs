@@ -0,0 +1,131 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! Stable-swap invariant for pools of two coins:
+//!
+//! ```text
+//! A * n^n * (x + y) + D = A * D * n^n + D^(n + 1) / (n^n * x * y)
+//! ```
+//!
+//! Where `x` and `y` are the reserves normalized to the same decimals, `n`
+//! is the number of coins and `A` is the amplification. Both `D` and the
+//! reserve after a swap are found with the Newton's method.
+
+use sp_core::U256;
+
+const N_COINS: u64 = 2;
+/// Newton's method converges quadratically, in a few iterations even for
+/// the max amplification and very imbalanced reserves. The bound keeps the
+/// worst case, which the swap weights account for, small.
+const MAX_ITERATIONS: u32 = 64;
+
+fn to_u128(value: U256) -> Option<u128> {
+	match value > U256::from(u128::MAX) {
+		true => None,
+		false => Some(value.low_u128()),
+	}
+}
+
+fn converged(a: U256, b: U256) -> bool {
+	match a > b {
+		true => a - b <= U256::one(),
+		false => b - a <= U256::one(),
+	}
+}
+
+/// Computes the invariant `D` of a pool with reserves `x` and `y`.
+/// Returns `None` if some reserve is zero or the invariant does not converge.
+pub fn compute_d(x: u128, y: u128, amplification: u32) -> Option<u128> {
+	if x == 0 || y == 0 {
+		return None;
+	}
+
+	let n = U256::from(N_COINS);
+	let (x, y) = (U256::from(x), U256::from(y));
+	let sum = x.checked_add(y)?;
+	let ann = U256::from(amplification).checked_mul(n.checked_mul(n)?)?;
+
+	let mut d = sum;
+	for _ in 0..MAX_ITERATIONS {
+		// D^(n + 1) / (n^n * x * y)
+		let d_p = d
+			.checked_mul(d)?
+			.checked_div(x.checked_mul(n)?)?
+			.checked_mul(d)?
+			.checked_div(y.checked_mul(n)?)?;
+
+		let prev_d = d;
+
+		let numerator = ann
+			.checked_mul(sum)?
+			.checked_add(d_p.checked_mul(n)?)?
+			.checked_mul(d)?;
+		let denominator = ann
+			.checked_sub(U256::one())?
+			.checked_mul(d)?
+			.checked_add(n.checked_add(U256::one())?.checked_mul(d_p)?)?;
+
+		d = numerator.checked_div(denominator)?;
+
+		if converged(d, prev_d) {
+			return to_u128(d);
+		}
+	}
+
+	None
+}
+
+/// Computes the reserve `y` that keeps the invariant `d` when the other
+/// reserve is `x`.
+/// Returns `None` if `x` is zero or the reserve does not converge.
+pub fn compute_y(x: u128, d: u128, amplification: u32) -> Option<u128> {
+	if x == 0 {
+		return None;
+	}
+
+	let n = U256::from(N_COINS);
+	let (x, d) = (U256::from(x), U256::from(d));
+	let ann = U256::from(amplification).checked_mul(n.checked_mul(n)?)?;
+
+	// D^(n + 1) / (n^n * x * A * n^n)
+	let c = d
+		.checked_mul(d)?
+		.checked_div(x.checked_mul(n)?)?
+		.checked_mul(d)?
+		.checked_div(ann.checked_mul(n)?)?;
+	let b = x.checked_add(d.checked_div(ann)?)?;
+
+	let mut y = d;
+	for _ in 0..MAX_ITERATIONS {
+		let prev_y = y;
+
+		let numerator = y.checked_mul(y)?.checked_add(c)?;
+		let denominator = y.checked_mul(n)?.checked_add(b)?.checked_sub(d)?;
+
+		y = numerator.checked_div(denominator)?;
+
+		if converged(y, prev_y) {
+			return to_u128(y);
+		}
+	}
+
+	None
+}
+
+/// Computes the amount of reserve `y` obtained by adding `dx` to reserve `x`.
+/// The result is rounded down in favor of the pool.
+pub fn compute_dy(x: u128, y: u128, dx: u128, amplification: u32) -> Option<u128> {
+	let d = compute_d(x, y, amplification)?;
+	let new_y = compute_y(x.checked_add(dx)?, d, amplification)?;
+
+	Some(y.checked_sub(new_y)?.saturating_sub(1))
+}
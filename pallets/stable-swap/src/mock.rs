@@ -0,0 +1,167 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_traits::{swaps::SwapInfo, AssetMetadataOf};
+use cfg_types::tokens::CurrencyId;
+use frame_support::{derive_impl, parameter_types, PalletId};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+use sp_core::ConstU64;
+use sp_runtime::{BuildStorage, FixedU128};
+
+use crate::pallet as pallet_stable_swap;
+
+pub const ADMIN: AccountId = 0x1;
+pub const PROVIDER: AccountId = 0x2;
+pub const INVESTOR: AccountId = 0x3;
+
+pub const FALLBACK_PERIOD: u64 = 10;
+pub const GRACE_PERIOD: u64 = 2;
+
+pub const CURRENCY_A: CurrencyId = CurrencyId::ForeignAsset(1001);
+pub const CURRENCY_B: CurrencyId = CurrencyId::ForeignAsset(1002);
+pub const CURRENCY_C: CurrencyId = CurrencyId::ForeignAsset(1003);
+pub const CURRENCY_A_DECIMALS: u32 = 6;
+pub const CURRENCY_B_DECIMALS: u32 = 12;
+
+pub const INITIAL_A: Balance = token_a(1_000_000);
+pub const INITIAL_B: Balance = token_b(1_000_000);
+
+pub const fn token_a(amount: Balance) -> Balance {
+	amount * (10 as Balance).pow(CURRENCY_A_DECIMALS)
+}
+
+pub const fn token_b(amount: Balance) -> Balance {
+	amount * (10 as Balance).pow(CURRENCY_B_DECIMALS)
+}
+
+pub type Balance = u128;
+pub type AccountId = u64;
+pub type OrderId = u64;
+pub type Ratio = FixedU128;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Tokens: orml_tokens,
+		MockTokenSwaps: cfg_mocks::token_swaps::pallet,
+		MockFulfilledOrderHook: cfg_mocks::status_notification::pallet,
+		StableSwap: pallet_stable_swap,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Runtime>;
+}
+
+cfg_test_utils::mocks::orml_asset_registry::impl_mock_registry! {
+	RegistryMock,
+	CurrencyId,
+	Balance,
+	(),
+	()
+}
+
+impl cfg_mocks::token_swaps::pallet::Config for Runtime {
+	type BalanceIn = Balance;
+	type BalanceOut = Balance;
+	type CurrencyId = CurrencyId;
+	type OrderId = OrderId;
+	type Ratio = Ratio;
+}
+
+impl cfg_mocks::status_notification::pallet::Config for Runtime {
+	type Id = OrderId;
+	type Status = SwapInfo<Balance, Balance, CurrencyId, Ratio>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Amount = i64;
+	type Balance = Balance;
+	type CurrencyHooks = ();
+	type CurrencyId = CurrencyId;
+	type DustRemovalWhitelist = frame_support::traits::Nothing;
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const StableSwapPalletId: PalletId = PalletId(*b"cfg/stsw");
+}
+
+impl pallet_stable_swap::Config for Runtime {
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type AssetRegistry = RegistryMock;
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+	type FallbackPeriod = ConstU64<FALLBACK_PERIOD>;
+	type FulfilledOrderHook = MockFulfilledOrderHook;
+	type GracePeriod = ConstU64<GRACE_PERIOD>;
+	type OrderBook = MockTokenSwaps;
+	type OrderId = OrderId;
+	type PalletId = StableSwapPalletId;
+	type Ratio = Ratio;
+	type RuntimeEvent = RuntimeEvent;
+	type Tokens = Tokens;
+	type WeightInfo = ();
+}
+
+fn metadata(decimals: u32) -> AssetMetadataOf<RegistryMock> {
+	AssetMetadataOf::<RegistryMock> {
+		decimals,
+		name: Default::default(),
+		symbol: Default::default(),
+		existential_deposit: 0,
+		location: None,
+		additional: Default::default(),
+	}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Runtime>::default()
+		.build_storage()
+		.unwrap();
+
+	orml_tokens::GenesisConfig::<Runtime> {
+		balances: vec![
+			(PROVIDER, CURRENCY_A, INITIAL_A),
+			(PROVIDER, CURRENCY_B, INITIAL_B),
+			(INVESTOR, CURRENCY_A, INITIAL_A),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	orml_asset_registry_mock::GenesisConfig {
+		metadata: vec![
+			(CURRENCY_A, metadata(CURRENCY_A_DECIMALS)),
+			(CURRENCY_B, metadata(CURRENCY_B_DECIMALS)),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
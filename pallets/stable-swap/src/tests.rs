@@ -0,0 +1,719 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_traits::swaps::{OrderInfo, OrderOptions, OrderRatio, Swap, TokenSwaps};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungibles::Inspect, Hooks},
+	weights::Weight,
+};
+use sp_runtime::{DispatchError, Perbill};
+
+use super::*;
+use crate::mock::*;
+
+const AMPLIFICATION: u32 = 100;
+const ORDER_ID: OrderId = 1;
+
+fn fee() -> Perbill {
+	Perbill::from_rational(1u32, 1000u32)
+}
+
+fn max_slippage() -> Perbill {
+	Perbill::from_percent(1)
+}
+
+fn normalized(amount: u128) -> u128 {
+	amount * 10u128.pow(pallet::NORMALIZED_DECIMALS)
+}
+
+mod util {
+	use super::*;
+
+	pub fn set_pool() {
+		assert_ok!(StableSwap::set_pool(
+			RuntimeOrigin::root(),
+			CURRENCY_A,
+			CURRENCY_B,
+			AMPLIFICATION,
+			fee(),
+			max_slippage(),
+		));
+	}
+
+	pub fn add_liquidity(amount: Balance) {
+		assert_ok!(StableSwap::add_liquidity(
+			RuntimeOrigin::signed(PROVIDER),
+			CURRENCY_A,
+			CURRENCY_B,
+			token_a(amount),
+			token_b(amount),
+			0,
+		));
+	}
+
+	pub fn pool_balance(currency: CurrencyId) -> Balance {
+		Tokens::balance(
+			currency,
+			&StableSwap::pool_account(StableSwap::pair(CURRENCY_A, CURRENCY_B)),
+		)
+	}
+
+	pub fn place_market_order(amount_out: Balance) {
+		MockTokenSwaps::mock_place_order(|_, _, _, _, _, _| Ok(ORDER_ID));
+
+		assert_ok!(<StableSwap as TokenSwaps<AccountId>>::place_order(
+			INVESTOR,
+			CURRENCY_B,
+			CURRENCY_A,
+			amount_out,
+			OrderRatio::Market,
+			OrderOptions::default(),
+		));
+
+		MockTokenSwaps::mock_get_order_details(move |_| {
+			Some(OrderInfo {
+				swap: Swap {
+					currency_in: CURRENCY_B,
+					currency_out: CURRENCY_A,
+					amount_out,
+				},
+				ratio: OrderRatio::Market,
+				options: OrderOptions::default(),
+			})
+		});
+	}
+
+	/// Market ratio of `ratio` units of B per unit of A
+	pub fn mock_market(ratio: Balance) {
+		MockTokenSwaps::mock_convert_by_market(move |currency_in, currency_out, amount_out| {
+			assert_eq!(currency_in, CURRENCY_B);
+			assert_eq!(currency_out, CURRENCY_A);
+			Ok(amount_out * ratio * token_b(1) / token_a(1))
+		});
+	}
+}
+
+mod invariant {
+	use super::*;
+
+	#[test]
+	fn invariant_of_balanced_reserves() {
+		let d = math::compute_d(normalized(100), normalized(100), AMPLIFICATION).unwrap();
+
+		assert!(d.abs_diff(normalized(200)) <= 1);
+	}
+
+	#[test]
+	fn invariant_without_reserves() {
+		assert_eq!(math::compute_d(0, normalized(100), AMPLIFICATION), None);
+		assert_eq!(math::compute_y(0, normalized(100), AMPLIFICATION), None);
+	}
+
+	#[test]
+	fn swap_near_peg() {
+		let dy = math::compute_dy(
+			normalized(1000),
+			normalized(1000),
+			normalized(10),
+			AMPLIFICATION,
+		)
+		.unwrap();
+
+		assert!(dy < normalized(10));
+		assert!(dy > normalized(10) - normalized(10) / 1000);
+	}
+
+	#[test]
+	fn swap_worsens_with_imbalance() {
+		let balanced = math::compute_dy(
+			normalized(1000),
+			normalized(1000),
+			normalized(10),
+			AMPLIFICATION,
+		)
+		.unwrap();
+
+		let imbalanced = math::compute_dy(
+			normalized(1900),
+			normalized(100),
+			normalized(10),
+			AMPLIFICATION,
+		)
+		.unwrap();
+
+		assert!(imbalanced < balanced);
+	}
+
+	#[test]
+	fn converges_with_extreme_imbalance() {
+		for amplification in [1, AMPLIFICATION, pallet::MAX_AMPLIFICATION] {
+			let d = math::compute_d(normalized(1), normalized(1_000_000_000), amplification);
+			assert!(d.is_some());
+
+			assert!(math::compute_dy(
+				normalized(1),
+				normalized(1_000_000_000),
+				normalized(1),
+				amplification
+			)
+			.is_some());
+		}
+	}
+}
+
+mod pools {
+	use super::*;
+
+	#[test]
+	fn set_pool() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+
+			let pool = Pools::<Runtime>::get(StableSwap::pair(CURRENCY_B, CURRENCY_A)).unwrap();
+			assert_eq!(pool.amplification, AMPLIFICATION);
+			assert_eq!(pool.fee, fee());
+			assert_eq!(pool.max_slippage, max_slippage());
+			assert_eq!(pool.total_shares, 0);
+		});
+	}
+
+	#[test]
+	fn set_pool_wrong_params() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				StableSwap::set_pool(
+					RuntimeOrigin::signed(ADMIN),
+					CURRENCY_A,
+					CURRENCY_B,
+					AMPLIFICATION,
+					fee(),
+					max_slippage(),
+				),
+				DispatchError::BadOrigin
+			);
+
+			assert_noop!(
+				StableSwap::set_pool(
+					RuntimeOrigin::root(),
+					CURRENCY_A,
+					CURRENCY_A,
+					AMPLIFICATION,
+					fee(),
+					max_slippage(),
+				),
+				Error::<Runtime>::SameCurrencies
+			);
+
+			assert_noop!(
+				StableSwap::set_pool(
+					RuntimeOrigin::root(),
+					CURRENCY_A,
+					CURRENCY_B,
+					0,
+					fee(),
+					max_slippage(),
+				),
+				Error::<Runtime>::InvalidAmplification
+			);
+
+			assert_noop!(
+				StableSwap::set_pool(
+					RuntimeOrigin::root(),
+					CURRENCY_A,
+					CURRENCY_C,
+					AMPLIFICATION,
+					fee(),
+					max_slippage(),
+				),
+				Error::<Runtime>::MetadataNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn add_liquidity() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+
+			let pair = StableSwap::pair(CURRENCY_A, CURRENCY_B);
+			let shares = Shares::<Runtime>::get(pair, PROVIDER);
+
+			assert!(shares.abs_diff(normalized(2000)) <= 1);
+			assert_eq!(Pools::<Runtime>::get(pair).unwrap().total_shares, shares);
+			assert_eq!(util::pool_balance(CURRENCY_A), token_a(1000));
+			assert_eq!(util::pool_balance(CURRENCY_B), token_b(1000));
+		});
+	}
+
+	#[test]
+	fn add_liquidity_proportionally() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+
+			let pair = StableSwap::pair(CURRENCY_A, CURRENCY_B);
+			let shares = Shares::<Runtime>::get(pair, PROVIDER);
+
+			assert_ok!(StableSwap::add_liquidity(
+				RuntimeOrigin::signed(PROVIDER),
+				CURRENCY_A,
+				CURRENCY_B,
+				token_a(500),
+				token_b(800),
+				0,
+			));
+
+			assert_eq!(Shares::<Runtime>::get(pair, PROVIDER), shares + shares / 2);
+			assert_eq!(util::pool_balance(CURRENCY_A), token_a(1500));
+			assert_eq!(util::pool_balance(CURRENCY_B), token_b(1500));
+		});
+	}
+
+	#[test]
+	fn add_liquidity_below_min_shares() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+
+			assert_noop!(
+				StableSwap::add_liquidity(
+					RuntimeOrigin::signed(PROVIDER),
+					CURRENCY_A,
+					CURRENCY_B,
+					token_a(1000),
+					token_b(1000),
+					normalized(3000),
+				),
+				Error::<Runtime>::SlippageExceeded
+			);
+		});
+	}
+
+	#[test]
+	fn add_liquidity_without_pool() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				StableSwap::add_liquidity(
+					RuntimeOrigin::signed(PROVIDER),
+					CURRENCY_A,
+					CURRENCY_B,
+					token_a(1000),
+					token_b(1000),
+					0,
+				),
+				Error::<Runtime>::PoolNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn remove_liquidity() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+
+			let pair = StableSwap::pair(CURRENCY_A, CURRENCY_B);
+			let shares = Shares::<Runtime>::get(pair, PROVIDER);
+
+			assert_ok!(StableSwap::remove_liquidity(
+				RuntimeOrigin::signed(PROVIDER),
+				CURRENCY_A,
+				CURRENCY_B,
+				shares / 4,
+				token_a(250),
+				token_b(250),
+			));
+
+			assert_eq!(Shares::<Runtime>::get(pair, PROVIDER), shares - shares / 4);
+			assert_eq!(util::pool_balance(CURRENCY_A), token_a(750));
+			assert_eq!(util::pool_balance(CURRENCY_B), token_b(750));
+
+			assert_ok!(StableSwap::remove_liquidity(
+				RuntimeOrigin::signed(PROVIDER),
+				CURRENCY_B,
+				CURRENCY_A,
+				shares - shares / 4,
+				0,
+				0,
+			));
+
+			assert!(!Shares::<Runtime>::contains_key(pair, PROVIDER));
+			assert_eq!(Pools::<Runtime>::get(pair).unwrap().total_shares, 0);
+			assert_eq!(util::pool_balance(CURRENCY_A), 0);
+			assert_eq!(util::pool_balance(CURRENCY_B), 0);
+		});
+	}
+
+	#[test]
+	fn remove_liquidity_wrong_amounts() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+
+			let pair = StableSwap::pair(CURRENCY_A, CURRENCY_B);
+			let shares = Shares::<Runtime>::get(pair, PROVIDER);
+
+			assert_noop!(
+				StableSwap::remove_liquidity(
+					RuntimeOrigin::signed(PROVIDER),
+					CURRENCY_A,
+					CURRENCY_B,
+					shares + 1,
+					0,
+					0,
+				),
+				Error::<Runtime>::InsufficientShares
+			);
+
+			assert_noop!(
+				StableSwap::remove_liquidity(
+					RuntimeOrigin::signed(PROVIDER),
+					CURRENCY_A,
+					CURRENCY_B,
+					shares / 2,
+					token_a(501),
+					0,
+				),
+				Error::<Runtime>::SlippageExceeded
+			);
+		});
+	}
+}
+
+mod fallback {
+	use super::*;
+
+	#[test]
+	fn place_order_tracked() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::place_market_order(token_a(10));
+
+			assert_eq!(
+				FallbackOrders::<Runtime>::get(ORDER_ID),
+				Some(FallbackOrder {
+					who: INVESTOR,
+					tried_from: 1 + GRACE_PERIOD,
+					expires_at: 1 + FALLBACK_PERIOD,
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn cancel_order_not_tracked() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::place_market_order(token_a(10));
+
+			MockTokenSwaps::mock_cancel_order(|_| Ok(()));
+
+			assert_ok!(<StableSwap as TokenSwaps<AccountId>>::cancel_order(
+				ORDER_ID
+			));
+
+			assert!(!FallbackOrders::<Runtime>::contains_key(ORDER_ID));
+		});
+	}
+
+	#[test]
+	fn place_order_not_tracked() {
+		new_test_ext().execute_with(|| {
+			MockTokenSwaps::mock_place_order(|_, _, _, _, _, _| Ok(ORDER_ID));
+
+			// Without pool
+			assert_ok!(<StableSwap as TokenSwaps<AccountId>>::place_order(
+				INVESTOR,
+				CURRENCY_B,
+				CURRENCY_A,
+				token_a(10),
+				OrderRatio::Market,
+				OrderOptions::default(),
+			));
+
+			util::set_pool();
+
+			// With custom ratio
+			assert_ok!(<StableSwap as TokenSwaps<AccountId>>::place_order(
+				INVESTOR,
+				CURRENCY_B,
+				CURRENCY_A,
+				token_a(10),
+				OrderRatio::Custom(Ratio::from_rational(1, 1)),
+				OrderOptions::default(),
+			));
+
+			// With fill-or-kill
+			assert_ok!(<StableSwap as TokenSwaps<AccountId>>::place_order(
+				INVESTOR,
+				CURRENCY_B,
+				CURRENCY_A,
+				token_a(10),
+				OrderRatio::Market,
+				OrderOptions {
+					fill_or_kill: true,
+					..Default::default()
+				},
+			));
+
+			assert_eq!(FallbackOrders::<Runtime>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn swap_within_slippage() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+			util::place_market_order(token_a(10));
+			util::mock_market(1);
+
+			let amount_in = StableSwap::quote(CURRENCY_B, CURRENCY_A, token_a(10)).unwrap();
+			assert!(amount_in < token_b(10));
+			assert!(amount_in > token_b(10) - max_slippage().mul_floor(token_b(10)));
+
+			MockTokenSwaps::mock_cancel_order(|order_id| {
+				assert_eq!(order_id, ORDER_ID);
+				Ok(())
+			});
+			MockFulfilledOrderHook::mock_notify_status_change(move |order_id, swap_info| {
+				assert_eq!(order_id, ORDER_ID);
+				assert_eq!(swap_info.remaining.amount_out, 0);
+				assert_eq!(swap_info.swapped_in, amount_in);
+				assert_eq!(swap_info.swapped_out, token_a(10));
+				Ok(())
+			});
+
+			StableSwap::on_idle(1 + GRACE_PERIOD, Weight::MAX);
+
+			assert!(!FallbackOrders::<Runtime>::contains_key(ORDER_ID));
+			assert_eq!(FallbackCursor::<Runtime>::get(), None);
+			assert_eq!(
+				Tokens::balance(CURRENCY_A, &INVESTOR),
+				INITIAL_A - token_a(10)
+			);
+			assert_eq!(Tokens::balance(CURRENCY_B, &INVESTOR), amount_in);
+			assert_eq!(util::pool_balance(CURRENCY_A), token_a(1010));
+			assert_eq!(util::pool_balance(CURRENCY_B), token_b(1000) - amount_in);
+		});
+	}
+
+	#[test]
+	fn swap_within_grace_period() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+			util::place_market_order(token_a(10));
+			util::mock_market(1);
+
+			StableSwap::on_idle(GRACE_PERIOD, Weight::MAX);
+
+			assert!(FallbackOrders::<Runtime>::contains_key(ORDER_ID));
+			assert_eq!(Tokens::balance(CURRENCY_A, &INVESTOR), INITIAL_A);
+			assert_eq!(util::pool_balance(CURRENCY_A), token_a(1000));
+		});
+	}
+
+	#[test]
+	fn swap_out_of_slippage() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+			util::place_market_order(token_a(10));
+			util::mock_market(2);
+
+			StableSwap::on_idle(1 + GRACE_PERIOD, Weight::MAX);
+
+			assert!(FallbackOrders::<Runtime>::contains_key(ORDER_ID));
+			assert_eq!(Tokens::balance(CURRENCY_A, &INVESTOR), INITIAL_A);
+		});
+	}
+
+	#[test]
+	fn swap_after_fallback_period() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+			util::place_market_order(token_a(10));
+			util::mock_market(2);
+
+			StableSwap::on_idle(FALLBACK_PERIOD, Weight::MAX);
+			assert!(FallbackOrders::<Runtime>::contains_key(ORDER_ID));
+
+			StableSwap::on_idle(1 + FALLBACK_PERIOD, Weight::MAX);
+			assert!(!FallbackOrders::<Runtime>::contains_key(ORDER_ID));
+			assert_eq!(Tokens::balance(CURRENCY_A, &INVESTOR), INITIAL_A);
+		});
+	}
+
+	#[test]
+	fn swap_failing() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+			util::place_market_order(token_a(10));
+			util::mock_market(1);
+
+			MockTokenSwaps::mock_cancel_order(|_| Err(DispatchError::Other("err")));
+
+			StableSwap::on_idle(1 + GRACE_PERIOD, Weight::MAX);
+
+			System::assert_last_event(
+				Event::<Runtime>::FallbackSwapFailed {
+					order_id: ORDER_ID,
+					error: DispatchError::Other("err"),
+				}
+				.into(),
+			);
+			assert!(FallbackOrders::<Runtime>::contains_key(ORDER_ID));
+			assert_eq!(Tokens::balance(CURRENCY_A, &INVESTOR), INITIAL_A);
+			assert_eq!(util::pool_balance(CURRENCY_A), token_a(1000));
+		});
+	}
+
+	#[test]
+	fn swap_from_cursor() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+			util::place_market_order(token_a(10));
+			util::mock_market(1);
+
+			FallbackOrders::<Runtime>::insert(
+				ORDER_ID + 1,
+				FallbackOrder {
+					who: INVESTOR,
+					tried_from: 1 + GRACE_PERIOD,
+					expires_at: 1 + FALLBACK_PERIOD,
+				},
+			);
+
+			let order_ids = FallbackOrders::<Runtime>::iter_keys().collect::<Vec<_>>();
+			FallbackCursor::<Runtime>::put(order_ids[0]);
+
+			MockTokenSwaps::mock_cancel_order(|_| Ok(()));
+			MockFulfilledOrderHook::mock_notify_status_change(|_, _| Ok(()));
+
+			StableSwap::on_idle(1 + GRACE_PERIOD, Weight::MAX);
+
+			assert!(FallbackOrders::<Runtime>::contains_key(order_ids[0]));
+			assert!(!FallbackOrders::<Runtime>::contains_key(order_ids[1]));
+			assert_eq!(FallbackCursor::<Runtime>::get(), None);
+
+			StableSwap::on_idle(1 + GRACE_PERIOD, Weight::MAX);
+
+			assert_eq!(FallbackOrders::<Runtime>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn swap_without_liquidity() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::place_market_order(token_a(10));
+			util::mock_market(1);
+
+			StableSwap::on_idle(1 + GRACE_PERIOD, Weight::MAX);
+
+			assert!(FallbackOrders::<Runtime>::contains_key(ORDER_ID));
+		});
+	}
+
+	#[test]
+	fn swap_order_already_filled() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+			util::place_market_order(token_a(10));
+
+			MockTokenSwaps::mock_get_order_details(|_| None);
+
+			StableSwap::on_idle(1 + GRACE_PERIOD, Weight::MAX);
+
+			assert!(!FallbackOrders::<Runtime>::contains_key(ORDER_ID));
+			assert_eq!(util::pool_balance(CURRENCY_A), token_a(1000));
+		});
+	}
+}
+
+mod swap {
+	use super::*;
+
+	#[test]
+	fn swap() {
+		new_test_ext().execute_with(|| {
+			util::set_pool();
+			util::add_liquidity(1000);
+
+			let amount_in = StableSwap::quote(CURRENCY_B, CURRENCY_A, token_a(10)).unwrap();
+
+			assert_ok!(StableSwap::swap(
+				RuntimeOrigin::signed(INVESTOR),
+				CURRENCY_B,
+				CURRENCY_A,
+				token_a(10),
+				amount_in,
+			));
+
+			assert_eq!(
+				Tokens::balance(CURRENCY_A, &INVESTOR),
+				INITIAL_A - token_a(10)
+			);
+			assert_eq!(Tokens::balance(CURRENCY_B, &INVESTOR), amount_in);
+			assert_eq!(util::pool_balance(CURRENCY_A), token_a(1010));
+			assert_eq!(util::pool_balance(CURRENCY_B), token_b(1000) - amount_in);
+		});
+	}
+
+	#[test]
+	fn swap_wrong_params() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				StableSwap::swap(
+					RuntimeOrigin::signed(INVESTOR),
+					CURRENCY_B,
+					CURRENCY_A,
+					token_a(10),
+					0,
+				),
+				Error::<Runtime>::PoolNotFound
+			);
+
+			util::set_pool();
+
+			assert_noop!(
+				StableSwap::swap(
+					RuntimeOrigin::signed(INVESTOR),
+					CURRENCY_B,
+					CURRENCY_A,
+					token_a(10),
+					0,
+				),
+				Error::<Runtime>::NoLiquidity
+			);
+
+			util::add_liquidity(1000);
+
+			assert_noop!(
+				StableSwap::swap(
+					RuntimeOrigin::signed(INVESTOR),
+					CURRENCY_B,
+					CURRENCY_A,
+					token_a(10),
+					token_b(10),
+				),
+				Error::<Runtime>::SlippageExceeded
+			);
+		});
+	}
+}
@@ -0,0 +1,80 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::weights::constants::RocksDbWeight;
+pub use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+	fn set_pool() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn swap() -> Weight;
+	fn fallback_swap() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn set_pool() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`. This
+		//       one reads the metadata of both currencies and writes the pool.
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn add_liquidity() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `OrderBook::fill_order`, which also
+		//       transfers both currencies between two accounts. This one reads
+		//       the pool, its reserves and the metadata of both currencies and
+		//       writes the pool and the shares.
+		Weight::from_parts(147_145_000, 11406)
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+
+	fn remove_liquidity() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `OrderBook::fill_order`, which also
+		//       transfers both currencies between two accounts. This one reads
+		//       the pool, its reserves and the shares and writes the pool and
+		//       the shares.
+		Weight::from_parts(147_145_000, 11406)
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+
+	fn swap() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `OrderBook::fill_order`, which also
+		//       transfers both currencies between two accounts. This one reads
+		//       the pool, its reserves and the metadata of both currencies.
+		Weight::from_parts(147_145_000, 11406)
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+
+	fn fallback_swap() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `OrderBook::fill_order` and
+		//       `OrderBook::cancel_order`, as the order is cancelled, swapped
+		//       against the pool and its swap notified.
+		Weight::from_parts(198_200_000, 11406)
+			.saturating_add(RocksDbWeight::get().reads(15))
+			.saturating_add(RocksDbWeight::get().writes(11))
+	}
+}
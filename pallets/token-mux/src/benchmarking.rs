@@ -10,21 +10,29 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::{swaps::OrderRatio, AssetMetadataOf};
+use cfg_traits::{
+	swaps::{OrderOptions, OrderRatio},
+	AssetMetadataOf,
+};
 use cfg_types::tokens::{
 	CurrencyId,
 	CurrencyId::{ForeignAsset, LocalAsset},
 	CustomMetadata, LocalAssetId,
 };
 use frame_benchmarking::v2::*;
-use frame_support::traits::fungibles::{Inspect, Mutate};
+use frame_support::traits::{
+	fungibles::{Inspect, Mutate},
+	Get,
+};
 use frame_system::RawOrigin;
 use orml_traits::asset_registry::{Inspect as OrmlInspect, Mutate as OrmlMutate};
 use sp_arithmetic::traits::{One, Zero};
+use sp_std::vec::Vec;
 
 use super::*;
 
 const FOREIGN_CURRENCY: CurrencyId = ForeignAsset(100);
+const FOREIGN_CURRENCY_BASE: u32 = 1000;
 const LOCAL_CURRENCY: CurrencyId = LocalAsset(LOCAL_ASSET_ID);
 const LOCAL_ASSET_ID: LocalAssetId = LocalAssetId(1000);
 const DECIMALS: u32 = 6;
@@ -42,7 +50,7 @@ fn init_mocks() {
 
 	let who = account::<mock::AccountId>("account", 0, 0);
 
-	MockTokenSwaps::mock_place_order(|_, _, _, _, _| Ok(ORDER_ID));
+	MockTokenSwaps::mock_place_order(|_, _, _, _, _, _| Ok(ORDER_ID));
 	MockTokenSwaps::mock_get_order_details(|_| None);
 
 	MockTokenSwaps::mock_get_order_details(|order_id| {
@@ -54,6 +62,7 @@ fn init_mocks() {
 				amount_out: AMOUNT,
 			},
 			ratio: OrderRatio::Custom(One::one()),
+			options: OrderOptions::default(),
 		})
 	});
 
@@ -86,8 +95,9 @@ where
 	T::AssetRegistry: OrmlMutate,
 {
 	pub fn setup_currencies() {
+		Self::register_variant(FOREIGN_CURRENCY);
 		T::AssetRegistry::register_asset(
-			Some(FOREIGN_CURRENCY.into()),
+			Some(LOCAL_CURRENCY.into()),
 			AssetMetadataOf::<T::AssetRegistry> {
 				decimals: DECIMALS,
 				name: Default::default(),
@@ -96,14 +106,16 @@ where
 				location: None,
 				additional: CustomMetadata {
 					pool_currency: true,
-					local_representation: Some(LOCAL_ASSET_ID),
 					..Default::default()
 				},
 			},
 		)
 		.unwrap();
+	}
+
+	pub fn register_variant(variant: CurrencyId) {
 		T::AssetRegistry::register_asset(
-			Some(LOCAL_CURRENCY.into()),
+			Some(variant.into()),
 			AssetMetadataOf::<T::AssetRegistry> {
 				decimals: DECIMALS,
 				name: Default::default(),
@@ -112,6 +124,7 @@ where
 				location: None,
 				additional: CustomMetadata {
 					pool_currency: true,
+					local_representation: Some(LOCAL_ASSET_ID),
 					..Default::default()
 				},
 			},
@@ -130,6 +143,7 @@ where
 			currency_out.into(),
 			AMOUNT.into(),
 			OrderRatio::Custom(T::BalanceRatio::one()),
+			OrderOptions::default(),
 		)
 		.unwrap()
 	}
@@ -217,5 +231,36 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn check_backing(n: Linear<1, { T::MaxVariants::get() }>) -> Result<(), BenchmarkError> {
+		Helper::<T>::setup_currencies();
+
+		let variants = (0..n)
+			.map(|i| {
+				let variant = ForeignAsset(FOREIGN_CURRENCY_BASE + i);
+				Helper::<T>::register_variant(variant);
+				T::Tokens::mint_into(variant.into(), &Pallet::<T>::account(), AMOUNT.into())
+					.unwrap();
+				Pallet::<T>::index_variant(LOCAL_CURRENCY.into(), variant.into()).unwrap();
+				variant.into()
+			})
+			.collect::<Vec<T::CurrencyId>>();
+
+		// Every variant is checked against an unbacked local representation
+		T::Tokens::mint_into(
+			LOCAL_CURRENCY.into(),
+			&account::<T::AccountId>("account", 0, 0),
+			(AMOUNT * (n as u128 + 1)).into(),
+		)
+		.unwrap();
+
+		#[block]
+		{
+			Pallet::<T>::check_asset(variants[0]);
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -18,10 +18,21 @@
 //! process burns the local asset and transfers back the desired variant
 //! directions.
 //!
+//! Variants and their local representation can have different decimals.
+//! Amounts are converted between them following the configured
+//! [`RoundingPolicy`]. Orders between two variants of the same local
+//! representation are matched directly, using the variants locked in the
+//! pallet account as backing, without minting or burning the local asset.
+//!
+//! The outstanding amount of each local representation must be backed by the
+//! variants locked in the pallet account. This is checked on idle, walking
+//! over the known assets a few at a time, alerting and optionally halting
+//! burns of the local representations that drifted.
+//!
 //! ## Assumptions
 //!
 //! - The exchange rate between the local and its variant assets is exactly one.
-//! - Orders can be created for local <> variant asset
+//! - Orders can be created for local <> variant and variant <> variant assets
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -34,21 +45,40 @@ pub(crate) mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
 pub use cfg_traits::swaps::TokenSwaps;
 pub use pallet::*;
+use sp_std::vec::Vec;
 pub use weights::WeightInfo;
 
+/// Walks over the assets known by the chain.
+pub trait AssetsWalk<CurrencyId> {
+	/// Returns the asset following `previous`, or the first asset if
+	/// `previous` is `None`.
+	fn next(previous: Option<&CurrencyId>) -> Option<CurrencyId>;
+
+	/// Returns all the assets.
+	fn all() -> Vec<CurrencyId> {
+		let mut assets = Vec::new();
+		while let Some(asset) = Self::next(assets.last()) {
+			assets.push(asset);
+		}
+		assets
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
-	use cfg_traits::swaps::{OrderRatio, TokenSwaps};
+	use cfg_primitives::conversion::convert_balance_decimals;
+	use cfg_traits::swaps::{OrderOptions, OrderRatio, TokenSwaps};
 	use cfg_types::tokens::CustomMetadata;
 	use frame_support::{
 		pallet_prelude::{DispatchResult, *},
 		traits::{
 			fungibles,
-			fungibles::Mutate,
+			fungibles::{Inspect as _, Mutate},
 			tokens::{Fortitude, Precision},
 			OriginTrait,
 		},
@@ -57,7 +87,8 @@ pub mod pallet {
 	use frame_system::pallet_prelude::{OriginFor, *};
 	use orml_traits::asset_registry::{self, Inspect as _};
 	use sp_arithmetic::{traits::AtLeast32BitUnsigned, FixedPointOperand};
-	use sp_runtime::traits::{AccountIdConversion, One};
+	use sp_runtime::traits::{AccountIdConversion, One, Saturating};
+	use sp_std::vec::Vec;
 
 	use super::*;
 
@@ -65,7 +96,44 @@ pub mod pallet {
 		<T as frame_system::Config>::AccountId,
 	>>::Balance;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	/// Determines how amounts are rounded when converted between a variant
+	/// and its local representation with different decimals.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum RoundingPolicy {
+		/// Amounts must be converted without remainder, otherwise the swap
+		/// fails.
+		Exact,
+		/// Converted amounts are rounded down. The remainder stays in the
+		/// pallet account as extra backing.
+		Down,
+	}
+
+	/// Amount of a variant locked in the pallet account.
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+	pub struct VariantBacking<CurrencyId, Balance> {
+		pub variant: CurrencyId,
+		pub backing: Balance,
+	}
+
+	/// Outstanding amount of a local representation and the variants backing
+	/// it.
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+	pub struct LocalAssetBacking<CurrencyId, Balance> {
+		pub local: CurrencyId,
+		pub outstanding: Balance,
+		/// Sum of the variants backing, expressed in the local decimals.
+		pub backing: Balance,
+		pub variants: Vec<VariantBacking<CurrencyId, Balance>>,
+	}
+
+	impl<CurrencyId, Balance: PartialOrd> LocalAssetBacking<CurrencyId, Balance> {
+		/// Whether the outstanding amount is fully backed by the variants.
+		pub fn is_backed(&self) -> bool {
+			self.backing >= self.outstanding
+		}
+	}
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -136,9 +204,49 @@ pub mod pallet {
 			Ratio = Self::BalanceRatio,
 		>;
 
+		/// Rounding used to convert amounts between assets with different
+		/// decimals.
+		#[pallet::constant]
+		type RoundingPolicy: Get<RoundingPolicy>;
+
+		/// All the assets known by the chain, among which the variants are
+		/// looked up to check the backing of the local representations.
+		type Assets: AssetsWalk<Self::CurrencyId>;
+
+		/// Maximum number of variants of a local representation.
+		#[pallet::constant]
+		type MaxVariants: Get<u32>;
+
+		/// Whether burning a local representation is halted while its
+		/// backing drifted.
+		#[pallet::constant]
+		type HaltBurnOnDrift: Get<bool>;
+
 		type WeightInfo: WeightInfo;
 	}
 
+	/// Local representations whose outstanding amount is not fully backed by
+	/// their variants, with the missing backing.
+	#[pallet::storage]
+	pub type DriftedAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CurrencyId, BalanceOf<T>, OptionQuery>;
+
+	/// Variants of each local representation, whose backing is summed up
+	/// when checking it.
+	#[pallet::storage]
+	pub type LocalVariants<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CurrencyId,
+		BoundedVec<T::CurrencyId, T::MaxVariants>,
+		ValueQuery,
+	>;
+
+	/// Last asset whose backing was checked on idle. The next check resumes
+	/// after it.
+	#[pallet::storage]
+	pub type BackingCursor<T: Config> = StorageValue<_, T::CurrencyId, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -158,6 +266,16 @@ pub mod pallet {
 			id: T::OrderId,
 			amount: T::BalanceOut,
 		},
+		/// The outstanding amount of a local representation is no longer fully
+		/// backed by its variants.
+		BackingDrifted {
+			local: T::CurrencyId,
+			outstanding: BalanceOf<T>,
+			backing: BalanceOf<T>,
+		},
+		/// The outstanding amount of a local representation is fully backed
+		/// again.
+		BackingRestored { local: T::CurrencyId },
 	}
 
 	#[pallet::error]
@@ -174,9 +292,64 @@ pub mod pallet {
 		/// This means the swap is either not a local to variant or not a
 		/// variant to local swap
 		InvalidSwapCurrencies,
-		/// Variant and local representation have mismatching decimals in their
-		/// metadata. A conversion between the two is not possible
+		/// The amount can not be converted between the decimals of the swapped
+		/// assets without rounding, which is not allowed by the rounding
+		/// policy
 		DecimalMismatch,
+		/// Burning the local representation is halted because its backing
+		/// drifted
+		BurnHalted,
+		/// The local representation already has the maximum number of
+		/// variants
+		TooManyVariants,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			let mut weight = db_weight.reads_writes(1, 1);
+
+			// Reading the next asset and checking the backing of its local
+			// representation, if it is a variant
+			let step_weight = db_weight
+				.reads(1)
+				.saturating_add(T::WeightInfo::check_backing(T::MaxVariants::get()));
+
+			let mut cursor = BackingCursor::<T>::get();
+
+			loop {
+				if weight.saturating_add(step_weight).any_gt(remaining_weight) {
+					break;
+				}
+
+				// The walk ends at the last asset and starts over in the next block
+				let Some(asset) = T::Assets::next(cursor.as_ref()) else {
+					cursor = None;
+					break;
+				};
+
+				weight.saturating_accrue(step_weight);
+				Self::check_asset(asset);
+				cursor = Some(asset);
+			}
+
+			BackingCursor::<T>::set(cursor);
+
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			for info in Self::local_assets() {
+				ensure!(
+					info.is_backed(),
+					"token-mux: local representation not backed by its variants"
+				);
+			}
+
+			Ok(())
+		}
 	}
 
 	#[pallet::call]
@@ -199,6 +372,7 @@ pub mod pallet {
 				variant,
 				amount,
 				OrderRatio::Custom(One::one()),
+				OrderOptions::default(),
 			)?;
 
 			Self::match_swap(T::RuntimeOrigin::signed(Self::account()), order_id, amount)?;
@@ -231,6 +405,7 @@ pub mod pallet {
 				local,
 				amount,
 				OrderRatio::Custom(One::one()),
+				OrderOptions::default(),
 			)?;
 
 			// Match the swap order after placement
@@ -272,8 +447,14 @@ pub mod pallet {
 				Self::try_local(&order.swap.currency_out),
 				Self::try_local(&order.swap.currency_in),
 			) {
-				(Ok(_), Ok(_)) | (Err(_), Err(_)) => {
-					return Err(Error::<T>::InvalidSwapCurrencies.into())
+				(Err(_), Err(_)) => return Err(Error::<T>::InvalidSwapCurrencies.into()),
+				// Exchange foreign for foreign of the same local representation
+				(Ok(local_out), Ok(local_in)) => {
+					ensure!(local_out == local_in, Error::<T>::InvalidSwapCurrencies);
+
+					Self::convert(order.swap.currency_out, order.swap.currency_in, amount)?;
+					Self::index_variant(local_out, order.swap.currency_out)?;
+					T::OrderBook::fill_order_no_slip_prot(Self::account(), order_id, amount)?;
 				}
 				// Mint local and exchange for foreign
 				(Ok(local), Err(_)) => {
@@ -282,7 +463,11 @@ pub mod pallet {
 						Error::<T>::InvalidSwapCurrencies
 					);
 
-					T::Tokens::mint_into(local, &Self::account(), amount.into())?;
+					let amount_in =
+						Self::convert(order.swap.currency_out, order.swap.currency_in, amount)?;
+
+					Self::index_variant(local, order.swap.currency_out)?;
+					T::Tokens::mint_into(local, &Self::account(), amount_in.into())?;
 					T::OrderBook::fill_order_no_slip_prot(Self::account(), order_id, amount)?;
 				}
 				// Exchange foreign for local and burn local
@@ -291,7 +476,12 @@ pub mod pallet {
 						order.swap.currency_out == local,
 						Error::<T>::InvalidSwapCurrencies
 					);
+					ensure!(
+						!T::HaltBurnOnDrift::get() || !DriftedAssets::<T>::contains_key(local),
+						Error::<T>::BurnHalted
+					);
 
+					Self::convert(order.swap.currency_out, order.swap.currency_in, amount)?;
 					T::OrderBook::fill_order_no_slip_prot(Self::account(), order_id, amount)?;
 					T::Tokens::burn_from(
 						local,
@@ -334,5 +524,152 @@ pub mod pallet {
 
 			Ok(local)
 		}
+
+		/// Converts an amount between the decimals of two assets following
+		/// the rounding policy.
+		pub(crate) fn convert(
+			currency_from: T::CurrencyId,
+			currency_to: T::CurrencyId,
+			amount: T::BalanceOut,
+		) -> Result<T::BalanceIn, DispatchError> {
+			let from_decimals = Self::decimals(currency_from)?;
+			let to_decimals = Self::decimals(currency_to)?;
+
+			let amount: BalanceOf<T> = amount.into();
+			let converted = convert_balance_decimals(from_decimals, to_decimals, amount)?;
+
+			if T::RoundingPolicy::get() == RoundingPolicy::Exact {
+				ensure!(
+					convert_balance_decimals(to_decimals, from_decimals, converted)? == amount,
+					Error::<T>::DecimalMismatch
+				);
+			}
+
+			Ok(converted.into())
+		}
+
+		/// Returns the outstanding amount of each local representation with
+		/// the backing of each of its variants.
+		pub fn local_assets() -> Vec<LocalAssetBacking<T::CurrencyId, BalanceOf<T>>> {
+			Self::backings(T::Assets::all())
+		}
+
+		/// Adds `variant` to the variants of `local`, whose backing is checked
+		/// on idle.
+		pub(crate) fn index_variant(
+			local: T::CurrencyId,
+			variant: T::CurrencyId,
+		) -> DispatchResult {
+			LocalVariants::<T>::try_mutate(local, |variants| {
+				if !variants.contains(&variant) {
+					variants
+						.try_push(variant)
+						.map_err(|_| Error::<T>::TooManyVariants)?;
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Checks the backing of the local representation of `asset`, if it
+		/// is a variant, together with the other variants of the local
+		/// representation.
+		pub(crate) fn check_asset(asset: T::CurrencyId) {
+			let Ok(local) = Self::try_local(&asset) else {
+				return;
+			};
+
+			let variants = LocalVariants::<T>::mutate(local, |variants| {
+				// Variants moved to another local representation no longer back it
+				variants.retain(|variant| matches!(Self::try_local(variant), Ok(l) if l == local));
+
+				// NOTE: A variant beyond the maximum is not indexed and its backing is not
+				// counted, which is alerted as drift.
+				if !variants.contains(&asset) {
+					let _ = variants.try_push(asset);
+				}
+
+				variants.clone()
+			});
+
+			Self::check_backing(variants);
+		}
+
+		/// Returns the outstanding amount of each local representation found
+		/// among `currencies`, with the backing of each of its variants.
+		pub(crate) fn backings(
+			currencies: impl IntoIterator<Item = T::CurrencyId>,
+		) -> Vec<LocalAssetBacking<T::CurrencyId, BalanceOf<T>>> {
+			let mut locals: Vec<LocalAssetBacking<T::CurrencyId, BalanceOf<T>>> = Vec::new();
+
+			for variant in currencies {
+				let Ok(local) = Self::try_local(&variant) else {
+					continue;
+				};
+
+				let backing = VariantBacking {
+					variant,
+					backing: T::Tokens::balance(variant, &Self::account()),
+				};
+
+				// A variant that can not be converted does not back anything
+				let local_backing = Self::decimals(variant)
+					.and_then(|from| {
+						Ok(convert_balance_decimals(
+							from,
+							Self::decimals(local)?,
+							backing.backing,
+						)?)
+					})
+					.unwrap_or_default();
+
+				match locals.iter_mut().find(|info| info.local == local) {
+					Some(info) => {
+						info.backing = info.backing.saturating_add(local_backing);
+						info.variants.push(backing);
+					}
+					None => locals.push(LocalAssetBacking {
+						local,
+						outstanding: T::Tokens::total_issuance(local),
+						backing: local_backing,
+						variants: sp_std::vec![backing],
+					}),
+				}
+			}
+
+			locals
+		}
+
+		/// Checks the backing of the local representations found among
+		/// `currencies`, alerting about the ones that drifted or were
+		/// restored.
+		pub(crate) fn check_backing(currencies: impl IntoIterator<Item = T::CurrencyId>) {
+			for info in Self::backings(currencies) {
+				match info.is_backed() {
+					true => {
+						if DriftedAssets::<T>::take(info.local).is_some() {
+							Self::deposit_event(Event::<T>::BackingRestored { local: info.local });
+						}
+					}
+					false => {
+						let missing = info.outstanding.saturating_sub(info.backing);
+						if DriftedAssets::<T>::get(info.local) != Some(missing) {
+							DriftedAssets::<T>::insert(info.local, missing);
+							Self::deposit_event(Event::<T>::BackingDrifted {
+								local: info.local,
+								outstanding: info.outstanding,
+								backing: info.backing,
+							});
+						}
+					}
+				}
+			}
+		}
+
+		fn decimals(currency: T::CurrencyId) -> Result<u32, DispatchError> {
+			Ok(T::AssetRegistry::metadata(&currency)
+				.ok_or(Error::<T>::MetadataNotFound)?
+				.decimals)
+		}
 	}
 }
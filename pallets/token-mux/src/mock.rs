@@ -13,12 +13,12 @@
 use cfg_mocks::pallet_mock_token_swaps;
 use cfg_traits::AssetMetadataOf;
 use cfg_types::tokens::{CustomMetadata, LocalAssetId};
-use frame_support::{derive_impl, parameter_types, PalletId};
+use frame_support::{derive_impl, parameter_types, traits::ConstU32, PalletId};
 use orml_traits::parameter_type_with_key;
 use sp_io::TestExternalities;
 use sp_runtime::{BuildStorage, FixedU128};
 
-use crate::pallet as pallet_token_mux;
+use crate::{pallet as pallet_token_mux, AssetsWalk, RoundingPolicy};
 
 pub type AccountId = u64;
 pub type Balance = u128;
@@ -111,18 +111,46 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub const TokenMuxPalletId: PalletId = PalletId(*b"tokenmux");
+	pub static MuxRoundingPolicy: RoundingPolicy = RoundingPolicy::Down;
+	pub static HaltBurnOnDrift: bool = true;
+}
+
+pub const MUX_ASSETS: [CurrencyId; 6] = [
+	USDC_1,
+	USDC_2,
+	USDC_LOCAL,
+	NON_USDC,
+	UNREGISTERED_ASSET,
+	USDC_DIFF_DECIMALS,
+];
+
+pub struct MuxAssets;
+
+impl AssetsWalk<CurrencyId> for MuxAssets {
+	fn next(previous: Option<&CurrencyId>) -> Option<CurrencyId> {
+		let index = match previous {
+			Some(asset) => MUX_ASSETS.iter().position(|a| a == asset)? + 1,
+			None => 0,
+		};
+
+		MUX_ASSETS.get(index).copied()
+	}
 }
 
 impl pallet_token_mux::Config for Runtime {
 	type AssetRegistry = MockRegistry;
+	type Assets = MuxAssets;
 	type BalanceIn = Balance;
 	type BalanceOut = Balance;
 	type BalanceRatio = Ratio;
 	type CurrencyId = CurrencyId;
+	type HaltBurnOnDrift = HaltBurnOnDrift;
 	type LocalAssetId = LocalAssetId;
+	type MaxVariants = ConstU32<3>;
 	type OrderBook = MockTokenSwaps;
 	type OrderId = SwapId;
 	type PalletId = TokenMuxPalletId;
+	type RoundingPolicy = MuxRoundingPolicy;
 	type RuntimeEvent = RuntimeEvent;
 	type Tokens = OrmlTokens;
 	type WeightInfo = ();
@@ -10,7 +10,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
 use orml_traits::MultiCurrency;
 
 use crate::{
@@ -22,6 +22,7 @@ use crate::{
 	},
 	Error,
 	Event::{Burned, Deposited},
+	LocalVariants,
 };
 
 pub const ORDER_ID: SwapId = 1;
@@ -69,6 +70,10 @@ mod deposit {
 				OrmlTokens::free_balance(USDC_1, &TokenMux::account()),
 				AMOUNT
 			);
+			assert_eq!(
+				LocalVariants::<Runtime>::get(USDC_LOCAL).into_inner(),
+				vec![USDC_1]
+			);
 
 			System::assert_last_event(RuntimeEvent::TokenMux(Deposited {
 				who: USER_1,
@@ -79,6 +84,24 @@ mod deposit {
 		})
 	}
 
+	#[test]
+	fn deposit_too_many_variants() {
+		new_test_ext().execute_with(|| {
+			LocalVariants::<Runtime>::insert(
+				USDC_LOCAL,
+				BoundedVec::truncate_from(vec![USDC_2, USDC_DIFF_DECIMALS, NON_USDC]),
+			);
+
+			mock_order_details(USDC_LOCAL, USDC_1);
+			mock_place_order(USER_1, USDC_LOCAL, USDC_1);
+
+			assert_noop!(
+				TokenMux::deposit(RuntimeOrigin::signed(USER_1), USDC_1, AMOUNT),
+				Error::<Runtime>::TooManyVariants
+			);
+		})
+	}
+
 	#[test]
 	fn deposit_usdc_diff_decimals_variant_works() {
 		new_test_ext().execute_with(|| {
@@ -118,6 +141,10 @@ mod deposit {
 				OrmlTokens::free_balance(USDC_DIFF_DECIMALS, &TokenMux::account()),
 				AMOUNT_DIFF_DEC_USDC
 			);
+			assert_eq!(
+				OrmlTokens::free_balance(USDC_LOCAL, &TokenMux::account()),
+				0
+			);
 
 			System::assert_last_event(RuntimeEvent::TokenMux(Deposited {
 				who: USER_3,
@@ -234,7 +261,7 @@ mod deposit {
 }
 
 mod burn {
-	use cfg_traits::swaps::{OrderInfo, OrderRatio, Swap};
+	use cfg_traits::swaps::{OrderInfo, OrderOptions, OrderRatio, Swap};
 	use frame_support::traits::{fungibles::Mutate, tokens::Preservation};
 	use sp_runtime::{traits::One, DispatchError};
 
@@ -266,16 +293,18 @@ mod burn {
 						amount_out: AMOUNT / 2,
 					},
 					ratio: OrderRatio::Custom(Ratio::one()),
+					options: OrderOptions::default(),
 				})
 			});
 
 			MockTokenSwaps::mock_place_order(
-				move |who, currency_in, currency_out, amount, ratio| {
+				move |who, currency_in, currency_out, amount, ratio, options| {
 					assert_eq!(who, USER_1);
 					assert_eq!(currency_in, USDC_1);
 					assert_eq!(currency_out, USDC_LOCAL);
 					assert_eq!(amount, AMOUNT / 2);
 					assert_eq!(ratio, OrderRatio::Custom(Ratio::one()));
+					assert_eq!(options, OrderOptions::default());
 
 					Ok::<SwapId, DispatchError>(ORDER_ID)
 				},
@@ -421,14 +450,17 @@ pub(crate) mod try_local {
 pub(crate) mod swaps {
 	use super::*;
 	use crate::{
+		mock::{MuxRoundingPolicy, USDC_DIFF_DECIMALS, USER_3},
 		tests::swaps::utils::{
-			mock_order_details, mock_pending_swap_from_local, mock_pending_swap_to_local,
+			mock_fill_order, mock_fill_order_with, mock_order_details, mock_order_details_with,
+			mock_pending_swap_from_local, mock_pending_swap_to_local,
 		},
 		Event::SwapMatched,
+		RoundingPolicy,
 	};
 
 	pub(crate) mod utils {
-		use cfg_traits::swaps::{OrderInfo, OrderRatio, Swap};
+		use cfg_traits::swaps::{OrderInfo, OrderOptions, OrderRatio, Swap};
 		use frame_support::traits::tokens::{fungibles::Mutate, Preservation};
 		use sp_arithmetic::traits::One;
 		use sp_runtime::{DispatchError, DispatchResult};
@@ -454,6 +486,7 @@ pub(crate) mod swaps {
 						amount_out: _amount,
 					},
 					ratio: OrderRatio::Custom(Ratio::one()),
+					options: OrderOptions::default(),
 				})
 			});
 		}
@@ -473,12 +506,13 @@ pub(crate) mod swaps {
 			_amount: Balance,
 		) {
 			MockTokenSwaps::mock_place_order(
-				move |who, currency_in, currency_out, amount, ratio| {
+				move |who, currency_in, currency_out, amount, ratio, options| {
 					assert_eq!(who, _who);
 					assert_eq!(currency_in, _currency_in);
 					assert_eq!(currency_out, _currency_out);
 					assert_eq!(amount, _amount);
 					assert_eq!(ratio, OrderRatio::Custom(Ratio::one()));
+					assert_eq!(options, OrderOptions::default());
 
 					Ok::<SwapId, DispatchError>(ORDER_ID)
 				},
@@ -651,13 +685,121 @@ pub(crate) mod swaps {
 	}
 
 	#[test]
-	fn match_swap_from_to_variant_throws() {
+	fn match_swap_variant_to_variant_works() {
 		new_test_ext().execute_with(|| {
+			assert_ok!(OrmlTokens::deposit(USDC_1, &TokenMux::account(), AMOUNT));
+
 			mock_order_details(USDC_1, USDC_2);
+			mock_fill_order(USDC_2, USER_2, USDC_1, TokenMux::account());
+
+			assert_ok!(TokenMux::match_swap(
+				RuntimeOrigin::signed(USER_NON),
+				ORDER_ID,
+				AMOUNT
+			));
+
+			assert_eq!(OrmlTokens::free_balance(USDC_1, &USER_2), AMOUNT);
+			assert_eq!(
+				OrmlTokens::free_balance(USDC_2, &USER_2),
+				INITIAL_AMOUNT - AMOUNT
+			);
+			assert_eq!(OrmlTokens::free_balance(USDC_1, &TokenMux::account()), 0);
+			assert_eq!(
+				OrmlTokens::free_balance(USDC_2, &TokenMux::account()),
+				AMOUNT
+			);
+			assert_eq!(OrmlTokens::total_issuance(USDC_LOCAL), INITIAL_AMOUNT);
+		})
+	}
+
+	#[test]
+	fn match_swap_variant_to_variant_diff_decimals() {
+		new_test_ext().execute_with(|| {
+			let amount_out = AMOUNT_DIFF_DEC_USDC / 2 + 1;
+
+			assert_ok!(OrmlTokens::deposit(USDC_1, &TokenMux::account(), AMOUNT));
+
+			mock_order_details_with(USDC_1, USDC_DIFF_DECIMALS, amount_out);
+			mock_fill_order_with(
+				USDC_DIFF_DECIMALS,
+				USER_3,
+				USDC_1,
+				TokenMux::account(),
+				amount_out,
+				AMOUNT / 2,
+			);
+
+			MuxRoundingPolicy::set(RoundingPolicy::Exact);
+
+			assert_noop!(
+				TokenMux::match_swap(RuntimeOrigin::signed(USER_NON), ORDER_ID, amount_out),
+				Error::<Runtime>::DecimalMismatch
+			);
+
+			MuxRoundingPolicy::set(RoundingPolicy::Down);
+
+			assert_ok!(TokenMux::match_swap(
+				RuntimeOrigin::signed(USER_NON),
+				ORDER_ID,
+				amount_out
+			));
+
+			assert_eq!(OrmlTokens::free_balance(USDC_1, &USER_3), AMOUNT / 2);
+			assert_eq!(
+				OrmlTokens::free_balance(USDC_DIFF_DECIMALS, &TokenMux::account()),
+				amount_out
+			);
+		})
+	}
+
+	#[test]
+	fn match_swap_variant_to_variant_without_backing() {
+		new_test_ext().execute_with(|| {
+			mock_order_details(USDC_1, USDC_2);
+			mock_fill_order(USDC_2, USER_2, USDC_1, TokenMux::account());
 
 			assert_noop!(
 				TokenMux::match_swap(RuntimeOrigin::signed(USER_NON), ORDER_ID, AMOUNT),
-				Error::<Runtime>::InvalidSwapCurrencies
+				orml_tokens::Error::<Runtime>::BalanceTooLow
+			);
+		})
+	}
+
+	#[test]
+	fn match_swap_to_local_diff_decimals() {
+		new_test_ext().execute_with(|| {
+			let amount_out = AMOUNT_DIFF_DEC_USDC / 2 + 1;
+
+			mock_order_details_with(USDC_LOCAL, USDC_DIFF_DECIMALS, amount_out);
+			mock_fill_order_with(
+				USDC_DIFF_DECIMALS,
+				USER_3,
+				USDC_LOCAL,
+				TokenMux::account(),
+				amount_out,
+				AMOUNT / 2,
+			);
+
+			MuxRoundingPolicy::set(RoundingPolicy::Exact);
+
+			assert_noop!(
+				TokenMux::match_swap(RuntimeOrigin::signed(USER_NON), ORDER_ID, amount_out),
+				Error::<Runtime>::DecimalMismatch
+			);
+
+			MuxRoundingPolicy::set(RoundingPolicy::Down);
+
+			assert_ok!(TokenMux::match_swap(
+				RuntimeOrigin::signed(USER_NON),
+				ORDER_ID,
+				amount_out
+			));
+
+			// Only the rounded down amount is minted
+			assert_eq!(OrmlTokens::free_balance(USDC_LOCAL, &USER_3), AMOUNT / 2);
+			assert_eq!(
+				OrmlTokens::free_balance(USDC_LOCAL, &TokenMux::account()),
+				0
 			);
 		})
 	}
@@ -701,6 +843,189 @@ pub(crate) mod swaps {
 	}
 }
 
+mod local_assets {
+	use super::*;
+	use crate::{
+		mock::USDC_DIFF_DECIMALS, tests::swaps::utils::mock_pending_swap_to_local,
+		LocalAssetBacking, VariantBacking,
+	};
+
+	#[test]
+	fn local_assets_with_backing() {
+		new_test_ext().execute_with(|| {
+			mock_pending_swap_to_local();
+
+			assert_ok!(TokenMux::match_swap(
+				RuntimeOrigin::signed(USER_NON),
+				ORDER_ID,
+				AMOUNT
+			));
+
+			assert_eq!(
+				TokenMux::local_assets(),
+				vec![LocalAssetBacking {
+					local: USDC_LOCAL,
+					outstanding: INITIAL_AMOUNT + AMOUNT,
+					backing: AMOUNT,
+					variants: vec![
+						VariantBacking {
+							variant: USDC_1,
+							backing: AMOUNT,
+						},
+						VariantBacking {
+							variant: USDC_2,
+							backing: 0,
+						},
+						VariantBacking {
+							variant: USDC_DIFF_DECIMALS,
+							backing: 0,
+						},
+					],
+				}]
+			);
+		})
+	}
+
+	#[test]
+	fn local_assets_backing_in_local_decimals() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(OrmlTokens::deposit(
+				USDC_DIFF_DECIMALS,
+				&TokenMux::account(),
+				AMOUNT_DIFF_DEC_USDC
+			));
+
+			assert_eq!(TokenMux::local_assets()[0].backing, AMOUNT);
+		})
+	}
+}
+
+mod backing {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	use super::*;
+	use crate::{
+		mock::{HaltBurnOnDrift, USDC_DIFF_DECIMALS},
+		tests::swaps::utils::mock_pending_swap_from_local,
+		BackingCursor, DriftedAssets,
+		Event::{BackingDrifted, BackingRestored},
+	};
+
+	fn drift_events() -> usize {
+		System::events()
+			.into_iter()
+			.filter(|record| {
+				matches!(
+					record.event,
+					RuntimeEvent::TokenMux(BackingDrifted { .. })
+						| RuntimeEvent::TokenMux(BackingRestored { .. })
+				)
+			})
+			.count()
+	}
+
+	#[test]
+	fn on_idle_alerts_drift() {
+		new_test_ext().execute_with(|| {
+			// The local balance of USER_LOCAL is not backed by any variant
+			TokenMux::on_idle(1, Weight::MAX);
+
+			assert_eq!(
+				DriftedAssets::<Runtime>::get(USDC_LOCAL),
+				Some(INITIAL_AMOUNT)
+			);
+			System::assert_last_event(RuntimeEvent::TokenMux(BackingDrifted {
+				local: USDC_LOCAL,
+				outstanding: INITIAL_AMOUNT,
+				backing: 0,
+			}));
+
+			// Same drift is not alerted twice
+			TokenMux::on_idle(2, Weight::MAX);
+			assert_eq!(drift_events(), 1);
+		})
+	}
+
+	#[test]
+	fn on_idle_resumes_from_cursor() {
+		new_test_ext().execute_with(|| {
+			BackingCursor::<Runtime>::put(USDC_2);
+
+			TokenMux::on_idle(1, Weight::MAX);
+
+			// Only the assets after the cursor were visited
+			assert_eq!(
+				LocalVariants::<Runtime>::get(USDC_LOCAL).into_inner(),
+				vec![USDC_DIFF_DECIMALS]
+			);
+			assert_eq!(
+				DriftedAssets::<Runtime>::get(USDC_LOCAL),
+				Some(INITIAL_AMOUNT)
+			);
+
+			// The walk ended and starts over
+			assert_eq!(BackingCursor::<Runtime>::get(), None);
+
+			TokenMux::on_idle(2, Weight::MAX);
+
+			assert_eq!(
+				LocalVariants::<Runtime>::get(USDC_LOCAL).into_inner(),
+				vec![USDC_DIFF_DECIMALS, USDC_1, USDC_2]
+			);
+		})
+	}
+
+	#[test]
+	fn on_idle_alerts_restored() {
+		new_test_ext().execute_with(|| {
+			TokenMux::on_idle(1, Weight::MAX);
+
+			assert_ok!(OrmlTokens::deposit(
+				USDC_2,
+				&TokenMux::account(),
+				INITIAL_AMOUNT
+			));
+
+			TokenMux::on_idle(2, Weight::MAX);
+
+			assert_eq!(DriftedAssets::<Runtime>::get(USDC_LOCAL), None);
+			System::assert_last_event(RuntimeEvent::TokenMux(BackingRestored {
+				local: USDC_LOCAL,
+			}));
+		})
+	}
+
+	#[test]
+	fn burn_halted_on_drift() {
+		new_test_ext().execute_with(|| {
+			mock_pending_swap_from_local();
+
+			TokenMux::on_idle(1, Weight::MAX);
+
+			assert_noop!(
+				TokenMux::match_swap(RuntimeOrigin::signed(USER_NON), ORDER_ID, AMOUNT),
+				Error::<Runtime>::BurnHalted
+			);
+		})
+	}
+
+	#[test]
+	fn burn_not_halted_on_drift_if_disabled() {
+		new_test_ext().execute_with(|| {
+			HaltBurnOnDrift::set(false);
+			mock_pending_swap_from_local();
+
+			TokenMux::on_idle(1, Weight::MAX);
+
+			assert_ok!(TokenMux::match_swap(
+				RuntimeOrigin::signed(USER_NON),
+				ORDER_ID,
+				AMOUNT
+			));
+		})
+	}
+}
+
 mod is_local_representation {
 	use cfg_traits::HasLocalAssetRepresentation;
 	use cfg_types::tokens::CurrencyId;
@@ -10,12 +10,29 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
+use frame_support::weights::constants::RocksDbWeight;
 pub use frame_support::weights::Weight;
 
 pub trait WeightInfo {
 	fn deposit() -> Weight;
 	fn burn() -> Weight;
 	fn match_swap() -> Weight;
+
+	fn check_backing(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `burn` per variant, which also reads
+		//       the metadata and balances of a variant and its local
+		//       representation. Besides, this one reads the asset, the indexed
+		//       variants and the issuance of the local representation and writes
+		//       the variants and the drift.
+		Weight::from_parts(208_840_000, 6021)
+			.saturating_mul(n.into())
+			.saturating_add(Weight::from_parts(0, 11406))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().reads(5_u64.saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
 }
 
 impl WeightInfo for () {
@@ -30,4 +47,8 @@ impl WeightInfo for () {
 	fn match_swap() -> Weight {
 		Weight::zero()
 	}
+
+	fn check_backing(_: u32) -> Weight {
+		Weight::zero()
+	}
 }
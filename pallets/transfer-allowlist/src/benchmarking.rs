@@ -17,26 +17,29 @@ use frame_benchmarking::{account, v2::*};
 use frame_support::{
 	pallet_prelude::Get,
 	traits::{fungible::Unbalanced, tokens::Precision, Currency, ReservableCurrency},
+	BoundedVec,
 };
-use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use frame_system::RawOrigin;
 use sp_core::crypto::AccountId32;
-use sp_runtime::{
-	traits::{CheckedAdd, One},
-	Saturating,
-};
+use sp_runtime::{DispatchError, Saturating};
+use sp_std::{boxed::Box, vec::Vec};
 
 use super::*;
 
 const BENCHMARK_CURRENCY_ID: FilterCurrency = FilterCurrency::Specific(CurrencyId::ForeignAsset(1));
 
+/// A transfer of the benchmark currency is subject to the approval policies
+/// of the currency and of all currencies.
+const BENCHMARK_REQUEST_CURRENCY_IDS: [FilterCurrency; 2] =
+	[BENCHMARK_CURRENCY_ID, FilterCurrency::All];
+
 #[benchmarks(
 where
 	T: Config<CurrencyId = FilterCurrency>,
 	<T as frame_system::Config>::AccountId: Into<AccountId32>,
 	T::Location: From<<T as frame_system::Config>::AccountId>,
 	T::ReserveCurrency: Currency<<T as frame_system::Config>::AccountId> + ReservableCurrency<<T as frame_system::Config>::AccountId>,
-	BlockNumberFor<T>: One,
-	<<T as Config>::ReserveCurrency as frame_support::traits::fungible::Inspect<<T as frame_system::Config>::AccountId,>>::Balance: From<u64>
+	<<T as Config>::ReserveCurrency as frame_support::traits::fungible::Inspect<<T as frame_system::Config>::AccountId,>>::Balance: From<u64>,
 )]
 mod benchmarks {
 	use super::*;
@@ -61,7 +64,7 @@ mod benchmarks {
 		Pallet::<T>::add_allowance_delay(
 			RawOrigin::Signed(sender.clone()).into(),
 			BENCHMARK_CURRENCY_ID,
-			200u32.into(),
+			200,
 		)?;
 
 		#[extrinsic_call]
@@ -78,11 +81,7 @@ mod benchmarks {
 		let (sender, _) = set_up_users::<T>();
 
 		#[extrinsic_call]
-		add_allowance_delay(
-			RawOrigin::Signed(sender),
-			BENCHMARK_CURRENCY_ID,
-			200u32.into(),
-		);
+		add_allowance_delay(RawOrigin::Signed(sender), BENCHMARK_CURRENCY_ID, 200);
 
 		Ok(())
 	}
@@ -96,11 +95,7 @@ mod benchmarks {
 		)?;
 
 		#[extrinsic_call]
-		add_allowance_delay(
-			RawOrigin::Signed(sender),
-			BENCHMARK_CURRENCY_ID,
-			200u32.into(),
-		);
+		add_allowance_delay(RawOrigin::Signed(sender), BENCHMARK_CURRENCY_ID, 200);
 
 		Ok(())
 	}
@@ -111,7 +106,7 @@ mod benchmarks {
 		Pallet::<T>::add_allowance_delay(
 			RawOrigin::Signed(sender.clone()).into(),
 			BENCHMARK_CURRENCY_ID,
-			1u32.into(),
+			1,
 		)?;
 
 		#[extrinsic_call]
@@ -126,26 +121,19 @@ mod benchmarks {
 	#[benchmark]
 	fn update_allowance_delay() -> Result<(), BenchmarkError> {
 		let (sender, _) = set_up_users::<T>();
+		// A zero delay makes the delay modifiable without advancing the time
 		Pallet::<T>::add_allowance_delay(
 			RawOrigin::Signed(sender.clone()).into(),
 			BENCHMARK_CURRENCY_ID,
-			1u32.into(),
+			0,
 		)?;
 		Pallet::<T>::toggle_allowance_delay_once_future_modifiable(
 			RawOrigin::Signed(sender.clone()).into(),
 			BENCHMARK_CURRENCY_ID,
 		)?;
-		let b = frame_system::Pallet::<T>::block_number()
-			.checked_add(&1u32.into())
-			.expect("Mock block advancement failed.");
-		frame_system::Pallet::<T>::set_block_number(b);
 
 		#[extrinsic_call]
-		update_allowance_delay(
-			RawOrigin::Signed(sender),
-			BENCHMARK_CURRENCY_ID,
-			200u32.into(),
-		);
+		update_allowance_delay(RawOrigin::Signed(sender), BENCHMARK_CURRENCY_ID, 200);
 
 		Ok(())
 	}
@@ -156,16 +144,13 @@ mod benchmarks {
 		Pallet::<T>::add_allowance_delay(
 			RawOrigin::Signed(sender.clone()).into(),
 			BENCHMARK_CURRENCY_ID,
-			1u32.into(),
+			0,
 		)?;
 		Pallet::<T>::toggle_allowance_delay_once_future_modifiable(
 			RawOrigin::Signed(sender.clone()).into(),
 			BENCHMARK_CURRENCY_ID,
 		)?;
-		let b = frame_system::Pallet::<T>::block_number()
-			.checked_add(&2u32.into())
-			.expect("Mock block advancement failed.");
-		frame_system::Pallet::<T>::set_block_number(b);
+		T::BenchmarkHelper::advance_time(1);
 
 		#[extrinsic_call]
 		purge_allowance_delay(RawOrigin::Signed(sender), BENCHMARK_CURRENCY_ID);
@@ -179,7 +164,7 @@ mod benchmarks {
 		Pallet::<T>::add_allowance_delay(
 			RawOrigin::Signed(sender.clone()).into(),
 			BENCHMARK_CURRENCY_ID,
-			1u32.into(),
+			0,
 		)?;
 		Pallet::<T>::add_transfer_allowance(
 			RawOrigin::Signed(sender.clone()).into(),
@@ -190,11 +175,7 @@ mod benchmarks {
 			RawOrigin::Signed(sender.clone()).into(),
 			BENCHMARK_CURRENCY_ID,
 		)?;
-
-		let b = frame_system::Pallet::<T>::block_number()
-			.checked_add(&2u32.into())
-			.expect("Mock block advancement failed.");
-		frame_system::Pallet::<T>::set_block_number(b);
+		T::BenchmarkHelper::advance_time(1);
 
 		#[extrinsic_call]
 		purge_allowance_delay(RawOrigin::Signed(sender), BENCHMARK_CURRENCY_ID);
@@ -205,11 +186,11 @@ mod benchmarks {
 	#[benchmark]
 	fn remove_transfer_allowance_delay_present() -> Result<(), BenchmarkError> {
 		let (sender, receiver) = set_up_users::<T>();
-		let delay = BlockNumberFor::<T>::one();
+		let delay = 1;
 		Pallet::<T>::add_allowance_delay(
 			RawOrigin::Signed(sender.clone()).into(),
 			BENCHMARK_CURRENCY_ID,
-			delay.clone(),
+			delay,
 		)?;
 		Pallet::<T>::add_transfer_allowance(
 			RawOrigin::Signed(sender.clone()).into(),
@@ -217,11 +198,6 @@ mod benchmarks {
 			T::Location::from(receiver.clone()),
 		)?;
 
-		let b = frame_system::Pallet::<T>::block_number()
-			.checked_add(&1u32.into())
-			.expect("Mock block advancement failed.");
-		frame_system::Pallet::<T>::set_block_number(b);
-
 		#[extrinsic_call]
 		remove_transfer_allowance(
 			RawOrigin::Signed(sender),
@@ -264,6 +240,7 @@ mod benchmarks {
 			BENCHMARK_CURRENCY_ID,
 			T::Location::from(receiver.clone()),
 		)?;
+		T::BenchmarkHelper::advance_time(1);
 
 		#[extrinsic_call]
 		purge_transfer_allowance(
@@ -294,6 +271,7 @@ mod benchmarks {
 			BENCHMARK_CURRENCY_ID,
 			T::Location::from(receiver.clone()),
 		)?;
+		T::BenchmarkHelper::advance_time(1);
 
 		#[extrinsic_call]
 		purge_transfer_allowance(
@@ -305,6 +283,102 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_approval_policy() -> Result<(), BenchmarkError> {
+		let (sender, _) = set_up_users::<T>();
+		let approvers = set_up_approvers::<T>();
+
+		#[extrinsic_call]
+		set_approval_policy(
+			RawOrigin::Signed(sender),
+			BENCHMARK_CURRENCY_ID,
+			Default::default(),
+			approvers,
+			T::MaxApprovers::get(),
+			3600,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_approval_policy() -> Result<(), BenchmarkError> {
+		let (sender, _) = set_up_users::<T>();
+		Pallet::<T>::add_allowance_delay(
+			RawOrigin::Signed(sender.clone()).into(),
+			BENCHMARK_CURRENCY_ID,
+			200,
+		)?;
+		set_up_approval_policy::<T>(sender.clone())?;
+
+		#[extrinsic_call]
+		remove_approval_policy(RawOrigin::Signed(sender), BENCHMARK_CURRENCY_ID);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn request_transfer_approval() -> Result<(), BenchmarkError> {
+		let (sender, receiver) = set_up_users::<T>();
+		set_up_approval_policy::<T>(sender.clone())?;
+
+		#[extrinsic_call]
+		request_transfer_approval(
+			RawOrigin::Signed(sender),
+			request_currency_ids::<T>(),
+			Box::new(T::BenchmarkHelper::approvable_call(receiver)),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_transfer() -> Result<(), BenchmarkError> {
+		let (sender, receiver) = set_up_users::<T>();
+		let mut approvers = set_up_approval_policy::<T>(sender.clone())?;
+		let request_id = set_up_transfer_request::<T>(sender, receiver)?;
+
+		// The last approval reaches the required approvals and schedules the
+		// execution of the transfer
+		let approver = approvers.pop().expect("approval policy has approvers");
+		for approver in approvers {
+			Pallet::<T>::approve_transfer(RawOrigin::Signed(approver).into(), request_id)?;
+		}
+
+		#[extrinsic_call]
+		approve_transfer(RawOrigin::Signed(approver), request_id);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_transfer_request() -> Result<(), BenchmarkError> {
+		let (sender, receiver) = set_up_users::<T>();
+		set_up_approval_policy::<T>(sender.clone())?;
+		let request_id = set_up_transfer_request::<T>(sender.clone(), receiver)?;
+
+		#[extrinsic_call]
+		cancel_transfer_request(RawOrigin::Signed(sender), request_id);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_approved_transfer() -> Result<(), BenchmarkError> {
+		let (sender, receiver) = set_up_users::<T>();
+		let approvers = set_up_approval_policy::<T>(sender.clone())?;
+		let request_id = set_up_transfer_request::<T>(sender, receiver)?;
+
+		for approver in approvers {
+			Pallet::<T>::approve_transfer(RawOrigin::Signed(approver).into(), request_id)?;
+		}
+
+		#[extrinsic_call]
+		execute_approved_transfer(RawOrigin::Root, request_id);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
 
@@ -330,3 +404,52 @@ where
 fn set_up_second_receiver<T: Config>() -> T::AccountId {
 	account::<T::AccountId>("Receiver_1", 3, 0)
 }
+
+fn set_up_approvers<T: Config>() -> BoundedVec<T::AccountId, T::MaxApprovers> {
+	(0..T::MaxApprovers::get())
+		.map(|i| account::<T::AccountId>("Approver", i, 0))
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("approvers are bounded by MaxApprovers")
+}
+
+fn request_currency_ids<T: Config<CurrencyId = FilterCurrency>>() -> RequestCurrenciesOf<T> {
+	BoundedVec::truncate_from(BENCHMARK_REQUEST_CURRENCY_IDS.to_vec())
+}
+
+/// Sets up the approval policies of the benchmark currency and of all
+/// currencies, sharing the same approvers, so that each approval counts for
+/// both policies.
+fn set_up_approval_policy<T: Config<CurrencyId = FilterCurrency>>(
+	sender: T::AccountId,
+) -> Result<Vec<T::AccountId>, DispatchError> {
+	let approvers = set_up_approvers::<T>();
+
+	for currency_id in BENCHMARK_REQUEST_CURRENCY_IDS {
+		Pallet::<T>::set_approval_policy(
+			RawOrigin::Signed(sender.clone()).into(),
+			currency_id,
+			Default::default(),
+			approvers.clone(),
+			T::MaxApprovers::get(),
+			3600,
+		)?;
+	}
+
+	Ok(approvers.into_inner())
+}
+
+fn set_up_transfer_request<T: Config<CurrencyId = FilterCurrency>>(
+	sender: T::AccountId,
+	receiver: T::AccountId,
+) -> Result<TransferRequestId, DispatchError> {
+	let request_id = NextTransferRequestId::<T>::get();
+
+	Pallet::<T>::request_transfer_approval(
+		RawOrigin::Signed(sender).into(),
+		request_currency_ids::<T>(),
+		Box::new(T::BenchmarkHelper::approvable_call(receiver)),
+	)?;
+
+	Ok(request_id)
+}
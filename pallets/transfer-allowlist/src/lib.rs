@@ -22,7 +22,19 @@
 //! currency is made, /then/ transfers from the sending account are restricted
 //! for that currency to:
 //! - the account(s) for which allowances have been made
-//! - the block range specified in the allowance
+//! - the time window specified in the allowance
+//! - the amount limits specified in the allowance, if any
+//!
+//! Allowance windows and delays are expressed in seconds, so that they do not
+//! depend on the block time of the chain.
+//!
+//! Additionally, an approval policy can be set for a sending account and
+//! currency. Transfers above the threshold of the policy are then only allowed
+//! once requested and approved by the required number of approvers within the
+//! timeout of the policy. A transfer subject to several policies, like the ones
+//! of a specific currency and of all currencies, is requested and approved
+//! under all of them. Approved transfers are executed through a scheduled
+//! call.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(test)]
@@ -34,32 +46,49 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
 pub use cfg_traits::TransferAllowance;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// Helper to benchmark transfer approvals.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, RuntimeCall> {
+	/// Returns a call contained in the approvable calls, transferring funds
+	/// to the receiver when dispatched on behalf of a funded sender.
+	fn approvable_call(receiver: AccountId) -> RuntimeCall;
+
+	/// Advances the time provided to the pallet by the given seconds.
+	fn advance_time(seconds: cfg_traits::Seconds);
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use core::fmt::Debug;
 
+	use cfg_traits::{Seconds, TimeAsSecs};
 	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
 		pallet_prelude::{DispatchResult, Member, OptionQuery, StorageDoubleMap, StorageNMap, *},
 		traits::{
 			fungible,
 			fungible::MutateHold,
+			schedule::{v3::Anon as ScheduleAnon, DispatchTime, LOWEST_PRIORITY},
 			tokens::{AssetId, Precision},
+			Contains, QueryPreimage, StorePreimage,
 		},
 		Twox64Concat,
 	};
 	use frame_system::pallet_prelude::{OriginFor, *};
-	use parity_scale_codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
+	use parity_scale_codec::{Decode, DecodeLimit, Encode, EncodeLike, MaxEncodedLen};
 	use scale_info::TypeInfo;
 	use sp_runtime::{
-		traits::{AtLeast32BitUnsigned, EnsureAdd, EnsureSub},
+		traits::{AtLeast32BitUnsigned, Dispatchable, EnsureAdd, EnsureSub, Zero},
 		Saturating,
 	};
+	use sp_std::{boxed::Box, vec::Vec};
 
 	use super::*;
 
@@ -68,8 +97,41 @@ pub mod pallet {
 		<T as frame_system::Config>::AccountId,
 	>>::Balance;
 
-	/// AllowanceDetails where `BlockNumber` is of type `BlockNumberFor<T>`
-	pub type AllowanceDetailsOf<T> = AllowanceDetails<BlockNumberFor<T>>;
+	/// AllowanceDetails where `Moment` is expressed in `Seconds`
+	pub type AllowanceDetailsOf<T> = AllowanceDetails<Seconds, <T as Config>::Balance>;
+
+	/// AllowanceLimits where `Moment` is expressed in `Seconds`
+	pub type AllowanceLimitsOf<T> = AllowanceLimits<Seconds, <T as Config>::Balance>;
+
+	/// ApprovalPolicy with the accounts and balance type of the pallet
+	pub type ApprovalPolicyOf<T> = ApprovalPolicy<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		<T as Config>::MaxApprovers,
+	>;
+
+	/// TransferRequest with the accounts and currency type of the pallet
+	pub type TransferRequestOf<T> = TransferRequest<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::CurrencyId,
+		<T as Config>::MaxApprovers,
+		<T as Config>::MaxCallSize,
+	>;
+
+	/// Identifier of a transfer request
+	pub type TransferRequestId = u64;
+
+	/// Maximum number of approval policies a transfer can be requested for
+	/// approval under, as a transfer can be subject to the policies of several
+	/// currencies of the sending account, i.e. a specific one and all.
+	pub type MaxRequestPolicies = ConstU32<2>;
+
+	/// Currencies of the approval policies a transfer is requested for
+	/// approval under.
+	pub type RequestCurrenciesOf<T> = BoundedVec<<T as Config>::CurrencyId, MaxRequestPolicies>;
+
+	/// Max nesting depth allowed when decoding the call of a transfer request
+	const CALL_DECODE_DEPTH_LIMIT: u32 = 8;
 
 	/// Resons for holding as defined by the `fungible::hold::Inspect` trait
 	pub type ReasonOf<T> = <<T as Config>::ReserveCurrency as fungible::hold::Inspect<
@@ -77,7 +139,7 @@ pub mod pallet {
 	>>::Reason;
 
 	/// The current storage version.
-	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -87,6 +149,7 @@ pub mod pallet {
 	#[pallet::composite_enum]
 	pub enum HoldReason {
 		TransferAllowance,
+		TransferRequest,
 	}
 
 	#[pallet::config]
@@ -95,6 +158,9 @@ pub mod pallet {
 
 		type CurrencyId: AssetId + Parameter + Member + Copy;
 
+		/// Balance type of the transferred amounts
+		type Balance: Member + Parameter + MaxEncodedLen + AtLeast32BitUnsigned + Default + Copy;
+
 		/// Currency for holding/unholding with allowlist adding/removal,
 		/// given that the allowlist will be in storage
 		type ReserveCurrency: fungible::hold::Mutate<
@@ -111,6 +177,54 @@ pub mod pallet {
 		/// Type containing the locations a transfer can be sent to.
 		type Location: Member + TypeInfo + Encode + EncodeLike + Decode + MaxEncodedLen;
 
+		/// Time provider used for the allowance windows and delays
+		type Time: TimeAsSecs;
+
+		/// The overarching call type, used to dispatch approved transfers.
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo
+			+ From<Call<Self>>;
+
+		/// Calls that can be requested for approval.
+		/// Only calls checking their transfers against this pallet should be
+		/// contained, as the approval policy of the request is bypassed while
+		/// executing them.
+		type ApprovableCalls: Contains<<Self as Config>::RuntimeCall>;
+
+		/// The caller origin, used to schedule approved transfers as root.
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+		/// Scheduler used to execute approved transfers.
+		type Scheduler: ScheduleAnon<
+			BlockNumberFor<Self>,
+			<Self as Config>::RuntimeCall,
+			Self::PalletsOrigin,
+			Hasher = Self::Hashing,
+		>;
+
+		/// Preimage provider used to bound the scheduled calls.
+		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
+		/// Maximum number of approvers of an approval policy.
+		#[pallet::constant]
+		type MaxApprovers: Get<u32>;
+
+		/// Maximum encoded size of a call requested for approval.
+		#[pallet::constant]
+		type MaxCallSize: Get<u32>;
+
+		/// Maximum weight of a call requested for approval.
+		#[pallet::constant]
+		type MaxCallWeight: Get<Weight>;
+
+		/// Helper providing an approvable call to benchmark transfer
+		/// approvals.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, <Self as Config>::RuntimeCall>;
+
 		/// Type for pallet weights
 		type WeightInfo: WeightInfo;
 	}
@@ -120,56 +234,98 @@ pub mod pallet {
 	//
 	/// Struct to define when a transfer should be allowed from
 	/// the sender, receiver, and currency combination.
-	/// Transfer allowed time set by range of block numbers
+	/// Transfer allowed time set by a range of timestamps in seconds
 	/// Defaults to `allowed_at` starting at 0, and `blocked_at` ending at MAX
-	/// block value as per `Default` impl.
-	/// Current block must be between allowed at and blocked at
+	/// value as per `Default` impl.
+	/// Current time must be between allowed at and blocked at
 	/// for transfer to be approved if allowance for sender/currency/receiver
-	/// present.
+	/// present. The transferred amount must also be within the limits of the
+	/// allowance, if any.
 	#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
-	pub struct AllowanceDetails<BlockNumber> {
-		/// Specifies a block number after which transfers will be allowed
+	pub struct AllowanceDetails<Moment, Balance> {
+		/// Specifies a time after which transfers will be allowed
 		/// for the sender & currency and destination location.
 		/// This is by default set to 0 with the add allowance extrinsic,
-		/// unless a delay is set, in which case it is set to the current block
+		/// unless a delay is set, in which case it is set to the current time
 		/// + delay.
-		pub allowed_at: BlockNumber,
-		/// Specifies a block number after-which transfers will be blocked
+		pub allowed_at: Moment,
+		/// Specifies a time after-which transfers will be blocked
 		/// for the sender & currency and destination location.
-		/// This is by default set to `BlockNumber::Max()`, except when an
+		/// This is by default set to `Moment::Max()`, except when an
 		/// allowance has been removed but not purged. In that case it is set to
-		/// the current block + delay. if the allowance is later updated with
+		/// the current time + delay. if the allowance is later updated with
 		/// the add allowance extrinsic, it is set back to max.
-		pub blocked_at: BlockNumber,
+		pub blocked_at: Moment,
+		/// Amount limits for transfers using this allowance.
+		/// No limits are set by default.
+		pub limits: AllowanceLimits<Moment, Balance>,
+		/// Amount transferred within the current fixed velocity window.
+		pub usage: AllowanceUsage<Moment, Balance>,
 	}
 
-	impl<BlockNumber> Default for AllowanceDetails<BlockNumber>
+	impl<Moment, Balance> Default for AllowanceDetails<Moment, Balance>
 	where
-		BlockNumber: AtLeast32BitUnsigned,
+		Moment: AtLeast32BitUnsigned,
+		Balance: Default,
 	{
 		fn default() -> Self {
 			Self {
-				allowed_at: BlockNumber::zero(),
-				blocked_at: BlockNumber::max_value(),
+				allowed_at: Moment::zero(),
+				blocked_at: Moment::max_value(),
+				limits: AllowanceLimits::default(),
+				usage: AllowanceUsage::default(),
 			}
 		}
 	}
 
+	/// Amount limits of an allowance.
+	#[derive(Clone, Debug, Default, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+	pub struct AllowanceLimits<Moment, Balance> {
+		/// Maximum amount of a single transfer, if any.
+		pub per_transfer: Option<Balance>,
+		/// Maximum amount transferred within a window of time, if any.
+		pub velocity: Option<VelocityLimit<Moment, Balance>>,
+	}
+
+	/// Maximum amount that can be transferred within a fixed window of time.
+	///
+	/// NOTE: Windows are fixed, not rolling. As a new window only starts once
+	/// the previous one elapsed, up to twice the amount can be transferred
+	/// within `fixed_window` across the boundary of two windows.
+	#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+	pub struct VelocityLimit<Moment, Balance> {
+		/// Maximum amount transferred within a window.
+		pub amount: Balance,
+		/// Length of a window.
+		pub fixed_window: Moment,
+	}
+
+	/// Tracks the amount transferred within the current fixed velocity window.
+	/// A new window starts with the first transfer once the previous window
+	/// has elapsed.
+	#[derive(Clone, Debug, Default, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+	pub struct AllowanceUsage<Moment, Balance> {
+		/// Start of the current window.
+		pub window_start: Moment,
+		/// Amount transferred since the start of the current window.
+		pub transferred: Balance,
+	}
+
 	/// Metadata values used to track and manage Allowances for a sending
 	/// Account/Currency combination. contains the number of allowances/presence
 	/// of existing allowances for said combination, as well as whether a delay
 	/// is set for the allowance to take effect, and if--and then when--a delay
 	/// is modifiable.
 	#[derive(Clone, Copy, Debug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
-	pub struct AllowanceMetadata<BlockNumber> {
+	pub struct AllowanceMetadata<Moment> {
 		pub(super) allowance_count: u64,
-		pub(super) current_delay: Option<BlockNumber>,
-		pub(super) once_modifiable_after: Option<BlockNumber>,
+		pub(super) current_delay: Option<Moment>,
+		pub(super) once_modifiable_after: Option<Moment>,
 	}
 
-	impl<BlockNumber> Default for AllowanceMetadata<BlockNumber>
+	impl<Moment> Default for AllowanceMetadata<Moment>
 	where
-		BlockNumber: AtLeast32BitUnsigned,
+		Moment: AtLeast32BitUnsigned,
 	{
 		fn default() -> Self {
 			Self {
@@ -179,11 +335,62 @@ pub mod pallet {
 			}
 		}
 	}
+
+	/// Policy requiring approvals for transfers of a sending account and
+	/// currency above a threshold.
+	#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+	#[scale_info(skip_type_params(MaxApprovers))]
+	pub struct ApprovalPolicy<AccountId, Balance, MaxApprovers: Get<u32>> {
+		/// Transfers above this amount require approval.
+		pub threshold: Balance,
+		/// Accounts allowed to approve transfers.
+		pub approvers: BoundedVec<AccountId, MaxApprovers>,
+		/// Number of approvals required for a transfer.
+		pub required: u32,
+		/// Time within which a requested transfer must be approved.
+		pub timeout: Seconds,
+		/// Time at which the policy stops applying, once it has been removed.
+		pub active_until: Option<Seconds>,
+	}
+
+	impl<AccountId, Balance, MaxApprovers> ApprovalPolicy<AccountId, Balance, MaxApprovers>
+	where
+		MaxApprovers: Get<u32>,
+	{
+		/// Whether the policy applies at the given time.
+		pub fn is_active(&self, now: Seconds) -> bool {
+			self.active_until.map_or(true, |until| now < until)
+		}
+	}
+
+	/// Transfer held until approved under the approval policy of the sending
+	/// account and currency.
+	#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+	#[scale_info(skip_type_params(MaxApprovers, MaxCallSize))]
+	pub struct TransferRequest<AccountId, CurrencyId, MaxApprovers, MaxCallSize>
+	where
+		MaxApprovers: Get<u32>,
+		MaxCallSize: Get<u32>,
+	{
+		/// Account requesting the transfer, which dispatches the call.
+		pub sender: AccountId,
+		/// Currencies of the approval policies the transfer is approved under.
+		pub currency_ids: BoundedVec<CurrencyId, MaxRequestPolicies>,
+		/// Encoded call executed once approved.
+		pub call: BoundedVec<u8, MaxCallSize>,
+		/// Approvers that approved the transfer, per approval policy.
+		pub approvals: BoundedVec<BoundedVec<AccountId, MaxApprovers>, MaxRequestPolicies>,
+		/// Time after which the transfer can no longer be approved.
+		pub expires_at: Seconds,
+		/// Whether the transfer is approved and scheduled for execution.
+		pub approved: bool,
+	}
+
 	/// Storage item containing number of allowances set, delay for sending
-	/// account/currency, and block number delay is modifiable at. Contains an
+	/// account/currency, and time the delay is modifiable at. Contains an
 	/// instance of AllowanceMetadata with allowance count as `u64`,
-	/// current_delay as `Option<BlockNumberFor<T>>`, and modifiable_at as
-	/// `Option<BlockNumberFor<T>>`. If a delay is set, but no allowances have
+	/// current_delay as `Option<Seconds>`, and modifiable_at as
+	/// `Option<Seconds>`. If a delay is set, but no allowances have
 	/// been created, `allowance_count` will be set to `0`. A double map is used
 	/// here as we need to know whether there is a restriction set for the
 	/// account and currency in the case where there is no allowance for
@@ -209,7 +416,7 @@ pub mod pallet {
 		T::AccountId,
 		Twox64Concat,
 		T::CurrencyId,
-		AllowanceMetadata<BlockNumberFor<T>>,
+		AllowanceMetadata<Seconds>,
 		OptionQuery,
 	>;
 
@@ -224,10 +431,38 @@ pub mod pallet {
 			NMapKey<Twox64Concat, T::CurrencyId>,
 			NMapKey<Blake2_128Concat, T::Location>,
 		),
-		AllowanceDetails<BlockNumberFor<T>>,
+		AllowanceDetailsOf<T>,
 		OptionQuery,
 	>;
 
+	/// Storage item for approval policies specified for a sending account and
+	/// currency type
+	#[pallet::storage]
+	pub type ApprovalPolicies<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::CurrencyId,
+		ApprovalPolicyOf<T>,
+		OptionQuery,
+	>;
+
+	/// Identifier of the next transfer request
+	#[pallet::storage]
+	pub type NextTransferRequestId<T: Config> = StorageValue<_, TransferRequestId, ValueQuery>;
+
+	/// Storage item for transfers pending approval or execution
+	#[pallet::storage]
+	pub type TransferRequests<T: Config> =
+		StorageMap<_, Twox64Concat, TransferRequestId, TransferRequestOf<T>, OptionQuery>;
+
+	/// Sending account and currencies of the approved transfer being executed.
+	/// Their approval policies are bypassed during the execution.
+	#[pallet::storage]
+	pub type ExecutingApprovedTransfer<T: Config> =
+		StorageValue<_, (T::AccountId, RequestCurrenciesOf<T>), OptionQuery>;
+
 	//
 	// Pallet Errors and Events
 	//
@@ -254,6 +489,48 @@ pub mod pallet {
 		/// Transfer from sending account and currency not allowed to
 		/// destination
 		NoAllowanceForDestination,
+		/// The fixed window of the velocity limit must not be zero
+		InvalidVelocityWindow,
+		/// Transfer amount exceeds the per transfer limit of the allowance
+		TransferAmountAboveLimit,
+		/// Transfer amount exceeds the amount left within the current fixed
+		/// velocity window of the allowance
+		VelocityLimitExceeded,
+		/// The required approvals must be between one and the number of
+		/// approvers, approvers must be unique and the timeout must not be zero
+		InvalidApprovalPolicy,
+		/// An approval policy is active for the sending account and currency
+		ApprovalPolicyActive,
+		/// No active approval policy for the sending account and currency
+		NoApprovalPolicy,
+		/// Transfer amount is above the threshold of the approval policy and
+		/// must be requested for approval
+		TransferApprovalRequired,
+		/// The call can not be requested for approval
+		CallNotApprovable,
+		/// The encoded call exceeds the maximum call size
+		CallTooLarge,
+		/// The call exceeds the maximum call weight
+		CallOverweight,
+		/// No transfer request with the given identifier
+		TransferRequestNotFound,
+		/// The transfer request expired and can no longer be approved
+		TransferRequestExpired,
+		/// Only the sender can cancel a transfer request before it expires
+		TransferRequestNotExpired,
+		/// The transfer request is already approved and scheduled
+		TransferRequestAlreadyApproved,
+		/// The transfer request has not been approved yet
+		TransferRequestNotApproved,
+		/// The account is not an approver of the approval policy
+		NotApprover,
+		/// The approver already approved the transfer request
+		DuplicateApproval,
+		/// The call of the transfer request could not be decoded
+		InvalidCall,
+		/// A transfer must be requested for approval under at least one
+		/// approval policy, and at most once under each
+		InvalidRequestCurrencies,
 	}
 
 	#[pallet::event]
@@ -264,16 +541,17 @@ pub mod pallet {
 			sender_account_id: T::AccountId,
 			currency_id: T::CurrencyId,
 			receiver: T::Location,
-			allowed_at: BlockNumberFor<T>,
-			blocked_at: BlockNumberFor<T>,
+			allowed_at: Seconds,
+			blocked_at: Seconds,
+			limits: AllowanceLimitsOf<T>,
 		},
 		/// Event for successful removal of transfer allowance perms
 		TransferAllowanceRemoved {
 			sender_account_id: T::AccountId,
 			currency_id: T::CurrencyId,
 			receiver: T::Location,
-			allowed_at: BlockNumberFor<T>,
-			blocked_at: BlockNumberFor<T>,
+			allowed_at: Seconds,
+			blocked_at: Seconds,
 		},
 		/// Event for successful removal of transfer allowance perms
 		TransferAllowancePurged {
@@ -285,40 +563,76 @@ pub mod pallet {
 		TransferAllowanceDelayAdd {
 			sender_account_id: T::AccountId,
 			currency_id: T::CurrencyId,
-			delay: BlockNumberFor<T>,
+			delay: Seconds,
 		},
 		/// Event for Allowance delay update
 		TransferAllowanceDelayUpdate {
 			sender_account_id: T::AccountId,
 			currency_id: T::CurrencyId,
-			delay: BlockNumberFor<T>,
+			delay: Seconds,
 		},
 		/// Event for Allowance delay future modification allowed
 		ToggleTransferAllowanceDelayFutureModifiable {
 			sender_account_id: T::AccountId,
 			currency_id: T::CurrencyId,
-			modifiable_once_after: Option<BlockNumberFor<T>>,
+			modifiable_once_after: Option<Seconds>,
 		},
 		/// Event for Allowance delay removal
 		TransferAllowanceDelayPurge {
 			sender_account_id: T::AccountId,
 			currency_id: T::CurrencyId,
 		},
+		/// Event for approval policy creation
+		ApprovalPolicySet {
+			sender_account_id: T::AccountId,
+			currency_id: T::CurrencyId,
+			threshold: T::Balance,
+			required: u32,
+			timeout: Seconds,
+		},
+		/// Event for approval policy removal, taking effect at `active_until`
+		ApprovalPolicyRemoved {
+			sender_account_id: T::AccountId,
+			currency_id: T::CurrencyId,
+			active_until: Seconds,
+		},
+		/// Event for a transfer requested for approval
+		TransferApprovalRequested {
+			request_id: TransferRequestId,
+			sender_account_id: T::AccountId,
+			currency_ids: RequestCurrenciesOf<T>,
+			expires_at: Seconds,
+		},
+		/// Event for an approval of a transfer request
+		TransferRequestApproved {
+			request_id: TransferRequestId,
+			approver: T::AccountId,
+		},
+		/// Event for a transfer request reaching the required approvals,
+		/// scheduling its execution
+		ApprovedTransferScheduled { request_id: TransferRequestId },
+		/// Event for transfer request cancellation
+		TransferRequestCancelled { request_id: TransferRequestId },
+		/// Event for the execution of an approved transfer
+		ApprovedTransferExecuted {
+			request_id: TransferRequestId,
+			result: DispatchResult,
+		},
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Adds a transfer allowance for a sending Account/Currency.
-		/// Allowance either starts at the current block + the delay set for the
+		/// Allowance either starts at the current time + the delay set for the
 		/// account, if a delay is present.
-		/// or block 0 if no delay is present.
+		/// or time 0 if no delay is present.
 		/// Important! Account/Currency sets with an allowance set are
 		/// restricted to just the allowances added for the account -
 		/// to have unrestricted transfers allowed for the sending Account and
 		/// Currency, no allowances should be present.
 		///
 		/// Running this for an existing allowance generates a new allowance
-		/// based on the current delay, or lack thereof
+		/// based on the current delay, or lack thereof, keeping its limits.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::add_transfer_allowance_no_existing_metadata().max(T::WeightInfo::add_transfer_allowance_existing_metadata()))]
 		pub fn add_transfer_allowance(
@@ -328,51 +642,13 @@ pub mod pallet {
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
 
-			let allowance_details = match Self::get_account_currency_restriction_count_delay(
-				&account_id,
-				currency_id,
-			) {
-				Some(AllowanceMetadata {
-					current_delay: Some(delay),
-					..
-				}) => AllowanceDetails {
-					allowed_at: <frame_system::Pallet<T>>::block_number().saturating_add(delay),
-					..AllowanceDetails::default()
-				},
-				_ => AllowanceDetails::default(),
-			};
-
-			if !<AccountCurrencyTransferAllowance<T>>::contains_key((
-				&account_id,
-				&currency_id,
-				&receiver,
-			)) {
-				Self::increment_or_create_allowance_count(&account_id, &currency_id)?;
-				T::ReserveCurrency::hold(
-					&HoldReason::TransferAllowance.into(),
-					&account_id,
-					T::Deposit::get(),
-				)?;
-			};
-			<AccountCurrencyTransferAllowance<T>>::insert(
-				(&account_id, &currency_id, &receiver),
-				&allowance_details,
-			);
-
-			Self::deposit_event(Event::TransferAllowanceCreated {
-				sender_account_id: account_id,
-				currency_id,
-				receiver,
-				allowed_at: allowance_details.allowed_at,
-				blocked_at: allowance_details.blocked_at,
-			});
-			Ok(())
+			Self::do_add_transfer_allowance(account_id, currency_id, receiver, None)
 		}
 
 		/// Restricts a transfer allowance for a sending
 		/// account/currency/receiver location to:
-		/// - either the current block + delay if a delay is set
-		/// - or the current block if no delay is set
+		/// - either the current time + delay if a delay is set
+		/// - or the current time if no delay is set
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::remove_transfer_allowance_delay_present().max(T::WeightInfo::remove_transfer_allowance_no_delay()))]
 		pub fn remove_transfer_allowance(
@@ -389,8 +665,8 @@ pub mod pallet {
 				Some(AllowanceMetadata {
 					current_delay: Some(delay),
 					..
-				}) => <frame_system::Pallet<T>>::block_number().saturating_add(delay),
-				_ => <frame_system::Pallet<T>>::block_number(),
+				}) => T::Time::now().saturating_add(delay),
+				_ => T::Time::now(),
 			};
 			match <AccountCurrencyTransferAllowance<T>>::get((&account_id, &currency_id, &receiver))
 			{
@@ -427,10 +703,10 @@ pub mod pallet {
 			receiver: T::Location,
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
-			let current_block = <frame_system::Pallet<T>>::block_number();
+			let now = T::Time::now();
 			match <AccountCurrencyTransferAllowance<T>>::get((&account_id, &currency_id, &receiver))
 			{
-				Some(AllowanceDetails { blocked_at, .. }) if blocked_at < current_block => {
+				Some(AllowanceDetails { blocked_at, .. }) if blocked_at < now => {
 					T::ReserveCurrency::release(
 						&HoldReason::TransferAllowance.into(),
 						&account_id,
@@ -464,7 +740,7 @@ pub mod pallet {
 		pub fn add_allowance_delay(
 			origin: OriginFor<T>,
 			currency_id: T::CurrencyId,
-			delay: BlockNumberFor<T>,
+			delay: Seconds,
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
 			let count_delay = match Self::get_account_currency_restriction_count_delay(
@@ -503,15 +779,15 @@ pub mod pallet {
 		#[pallet::call_index(4)]
 		#[pallet::weight(T::WeightInfo::update_allowance_delay())]
 		/// Updates an allowance delay, only callable if the delay has been set
-		/// to allow future modifications and the delay modifiable_at block has
+		/// to allow future modifications and the delay modifiable_at time has
 		/// been passed.
 		pub fn update_allowance_delay(
 			origin: OriginFor<T>,
 			currency_id: T::CurrencyId,
-			delay: BlockNumberFor<T>,
+			delay: Seconds,
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
-			let current_block = <frame_system::Pallet<T>>::block_number();
+			let now = T::Time::now();
 			match Self::get_account_currency_restriction_count_delay(&account_id, currency_id) {
 				None => Err(DispatchError::from(Error::<T>::NoMatchingDelay)),
 				Some(AllowanceMetadata {
@@ -525,7 +801,7 @@ pub mod pallet {
 				Some(AllowanceMetadata {
 					once_modifiable_after: Some(modifiable_at),
 					..
-				}) if current_block < modifiable_at => Err(DispatchError::from(Error::<T>::DelayUnmodifiable)),
+				}) if now < modifiable_at => Err(DispatchError::from(Error::<T>::DelayUnmodifiable)),
 				Some(metadata) => {
 					<AccountCurrencyTransferCountDelay<T>>::insert(
 						&account_id,
@@ -551,14 +827,14 @@ pub mod pallet {
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::toggle_allowance_delay_once_future_modifiable())]
 		/// This allows the delay value to be modified after the current delay
-		/// has passed since the current block Or sets the delay value to be not
+		/// has passed since the current time Or sets the delay value to be not
 		/// modifiable iff modifiable at has already passed
 		pub fn toggle_allowance_delay_once_future_modifiable(
 			origin: OriginFor<T>,
 			currency_id: T::CurrencyId,
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
-			let current_block = <frame_system::Pallet<T>>::block_number();
+			let now = T::Time::now();
 			let metadata = match Self::get_account_currency_restriction_count_delay(
 				&account_id,
 				currency_id,
@@ -571,7 +847,7 @@ pub mod pallet {
 				Some(AllowanceMetadata {
 					once_modifiable_after: Some(modifiable_at),
 					..
-				}) if modifiable_at > current_block => Err(DispatchError::from(Error::<T>::DelayUnmodifiable)),
+				}) if modifiable_at > now => Err(DispatchError::from(Error::<T>::DelayUnmodifiable)),
 				Some(
 					metadata @ AllowanceMetadata {
 						once_modifiable_after: Some(_),
@@ -587,7 +863,7 @@ pub mod pallet {
 						..
 					},
 				) => Ok(AllowanceMetadata {
-					once_modifiable_after: Some(current_block.ensure_add(current_delay)?),
+					once_modifiable_after: Some(now.ensure_add(current_delay)?),
 					..metadata
 				}),
 			}?;
@@ -609,13 +885,13 @@ pub mod pallet {
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
 
-			let current_block = <frame_system::Pallet<T>>::block_number();
+			let now = T::Time::now();
 			match Self::get_account_currency_restriction_count_delay(&account_id, currency_id) {
 				Some(AllowanceMetadata {
 					allowance_count: 0,
 					once_modifiable_after: Some(modifiable_at),
 					..
-				}) if modifiable_at < current_block => {
+				}) if modifiable_at < now => {
 					<AccountCurrencyTransferCountDelay<T>>::remove(&account_id, currency_id);
 					Self::deposit_event(Event::TransferAllowanceDelayPurge {
 						sender_account_id: account_id,
@@ -628,7 +904,7 @@ pub mod pallet {
 						once_modifiable_after: Some(modifiable_at),
 						..
 					},
-				) if modifiable_at <= current_block => {
+				) if modifiable_at <= now => {
 					<AccountCurrencyTransferCountDelay<T>>::insert(
 						&account_id,
 						currency_id,
@@ -648,9 +924,468 @@ pub mod pallet {
 				_ => Err(DispatchError::from(Error::<T>::DelayUnmodifiable)),
 			}
 		}
+
+		/// Adds a transfer allowance for a sending Account/Currency with
+		/// amount limits. Behaves as `add_transfer_allowance`, so the
+		/// allowance starts after the delay set for the account, if a delay
+		/// is present. Running this for an existing allowance replaces its
+		/// limits, keeping the amount transferred within the current window.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::add_transfer_allowance_no_existing_metadata().max(T::WeightInfo::add_transfer_allowance_existing_metadata()))]
+		pub fn add_transfer_allowance_with_limits(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			receiver: T::Location,
+			limits: AllowanceLimitsOf<T>,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+
+			Self::do_add_transfer_allowance(account_id, currency_id, receiver, Some(limits))
+		}
+
+		/// Sets an approval policy for a sending account/currency.
+		/// Transfers above the threshold then require `required` approvals of
+		/// the given approvers, see [`Pallet::request_transfer_approval`].
+		/// The policy applies immediately. Calling on an account/currency with
+		/// an active policy will fail, the policy has to be removed first.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_approval_policy())]
+		pub fn set_approval_policy(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			threshold: T::Balance,
+			approvers: BoundedVec<T::AccountId, T::MaxApprovers>,
+			required: u32,
+			timeout: Seconds,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+			let now = T::Time::now();
+
+			ensure!(
+				!ApprovalPolicies::<T>::get(&account_id, currency_id)
+					.is_some_and(|policy| policy.is_active(now)),
+				Error::<T>::ApprovalPolicyActive
+			);
+
+			let mut unique_approvers = approvers.to_vec();
+			unique_approvers.sort();
+			unique_approvers.dedup();
+			ensure!(
+				required > 0
+					&& required as usize <= approvers.len()
+					&& unique_approvers.len() == approvers.len()
+					&& timeout > 0,
+				Error::<T>::InvalidApprovalPolicy
+			);
+
+			ApprovalPolicies::<T>::insert(
+				&account_id,
+				currency_id,
+				ApprovalPolicy {
+					threshold,
+					approvers,
+					required,
+					timeout,
+					active_until: None,
+				},
+			);
+			Self::deposit_event(Event::ApprovalPolicySet {
+				sender_account_id: account_id,
+				currency_id,
+				threshold,
+				required,
+				timeout,
+			});
+			Ok(())
+		}
+
+		/// Removes the approval policy of a sending account/currency.
+		/// The policy stops applying:
+		/// - either at the current time + delay if a delay is set
+		/// - or immediately if no delay is set
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::remove_approval_policy())]
+		pub fn remove_approval_policy(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+			let now = T::Time::now();
+
+			let active_until = match Self::get_account_currency_restriction_count_delay(
+				&account_id,
+				currency_id,
+			) {
+				Some(AllowanceMetadata {
+					current_delay: Some(delay),
+					..
+				}) => now.saturating_add(delay),
+				_ => now,
+			};
+
+			let active_until = ApprovalPolicies::<T>::try_mutate_exists(
+				&account_id,
+				currency_id,
+				|maybe_policy| -> Result<Seconds, DispatchError> {
+					let policy = maybe_policy
+						.as_mut()
+						.filter(|policy| policy.is_active(now))
+						.ok_or(Error::<T>::NoApprovalPolicy)?;
+
+					// A pending removal can only be brought forward
+					let active_until = policy
+						.active_until
+						.map_or(active_until, |until| until.min(active_until));
+
+					if active_until <= now {
+						*maybe_policy = None;
+					} else {
+						policy.active_until = Some(active_until);
+					}
+
+					Ok(active_until)
+				},
+			)?;
+
+			Self::deposit_event(Event::ApprovalPolicyRemoved {
+				sender_account_id: account_id,
+				currency_id,
+				active_until,
+			});
+			Ok(())
+		}
+
+		/// Requests approval for a call transferring funds of the sending
+		/// account above the threshold of its approval policies for the given
+		/// currencies. A transfer subject to several policies, like the ones
+		/// of a specific currency and of all currencies, must be requested
+		/// under all of them. The call is dispatched on behalf of the sender
+		/// once approved by the required approvers of each policy within the
+		/// shortest timeout of the policies. A deposit is held until the
+		/// request is executed or cancelled.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::request_transfer_approval())]
+		pub fn request_transfer_approval(
+			origin: OriginFor<T>,
+			currency_ids: RequestCurrenciesOf<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+			let now = T::Time::now();
+
+			ensure!(
+				!currency_ids.is_empty()
+					&& currency_ids
+						.iter()
+						.enumerate()
+						.all(|(i, currency_id)| !currency_ids[..i].contains(currency_id)),
+				Error::<T>::InvalidRequestCurrencies
+			);
+
+			let mut timeout = Seconds::MAX;
+			for currency_id in currency_ids.iter() {
+				let policy = ApprovalPolicies::<T>::get(&account_id, currency_id)
+					.filter(|policy| policy.is_active(now))
+					.ok_or(Error::<T>::NoApprovalPolicy)?;
+				timeout = timeout.min(policy.timeout);
+			}
+
+			ensure!(
+				T::ApprovableCalls::contains(&call),
+				Error::<T>::CallNotApprovable
+			);
+			ensure!(
+				call.get_dispatch_info()
+					.weight
+					.all_lte(T::MaxCallWeight::get()),
+				Error::<T>::CallOverweight
+			);
+			let call = BoundedVec::try_from(call.encode()).map_err(|_| Error::<T>::CallTooLarge)?;
+
+			T::ReserveCurrency::hold(
+				&HoldReason::TransferRequest.into(),
+				&account_id,
+				T::Deposit::get(),
+			)?;
+
+			let request_id = NextTransferRequestId::<T>::try_mutate(
+				|next_id| -> Result<TransferRequestId, DispatchError> {
+					let request_id = *next_id;
+					*next_id = next_id.ensure_add(1)?;
+					Ok(request_id)
+				},
+			)?;
+			let expires_at = now.ensure_add(timeout)?;
+			let approvals = BoundedVec::try_from(
+				currency_ids
+					.iter()
+					.map(|_| BoundedVec::default())
+					.collect::<Vec<_>>(),
+			)
+			.map_err(|_| DispatchError::Other("Approvals are bounded by currencies"))?;
+
+			TransferRequests::<T>::insert(
+				request_id,
+				TransferRequest {
+					sender: account_id.clone(),
+					currency_ids: currency_ids.clone(),
+					call,
+					approvals,
+					expires_at,
+					approved: false,
+				},
+			);
+			Self::deposit_event(Event::TransferApprovalRequested {
+				request_id,
+				sender_account_id: account_id,
+				currency_ids,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Approves a transfer request as an approver of any of the approval
+		/// policies the transfer is requested under. The approval counts for
+		/// each of those policies the account is an approver of. Once the
+		/// required approvals of every policy are reached, the execution of
+		/// the transfer is scheduled for the next block.
+		/// Only approvals of current approvers of the policies are counted.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			request_id: TransferRequestId,
+		) -> DispatchResult {
+			let approver = ensure_signed(origin)?;
+			let now = T::Time::now();
+
+			let approved = TransferRequests::<T>::try_mutate(
+				request_id,
+				|maybe_request| -> Result<bool, DispatchError> {
+					let request = maybe_request
+						.as_mut()
+						.ok_or(Error::<T>::TransferRequestNotFound)?;
+
+					ensure!(
+						!request.approved,
+						Error::<T>::TransferRequestAlreadyApproved
+					);
+					ensure!(now < request.expires_at, Error::<T>::TransferRequestExpired);
+
+					let mut is_approver = false;
+					let mut approved = true;
+					for (currency_id, approvals) in request
+						.currency_ids
+						.iter()
+						.zip(request.approvals.iter_mut())
+					{
+						let policy = ApprovalPolicies::<T>::get(&request.sender, currency_id)
+							.filter(|policy| policy.is_active(now))
+							.ok_or(Error::<T>::NoApprovalPolicy)?;
+
+						approvals.retain(|account| policy.approvers.contains(account));
+
+						if policy.approvers.contains(&approver) {
+							ensure!(
+								!approvals.contains(&approver),
+								Error::<T>::DuplicateApproval
+							);
+							approvals.try_push(approver.clone()).map_err(|_| {
+								DispatchError::Other("Approvals are bounded by approvers")
+							})?;
+							is_approver = true;
+						}
+
+						approved &= approvals.len() >= policy.required as usize;
+					}
+
+					ensure!(is_approver, Error::<T>::NotApprover);
+					request.approved = approved;
+
+					Ok(request.approved)
+				},
+			)?;
+
+			Self::deposit_event(Event::TransferRequestApproved {
+				request_id,
+				approver,
+			});
+
+			if approved {
+				let call: <T as Config>::RuntimeCall =
+					Call::<T>::execute_approved_transfer { request_id }.into();
+				T::Scheduler::schedule(
+					DispatchTime::After(Zero::zero()),
+					None,
+					LOWEST_PRIORITY,
+					frame_system::RawOrigin::Root.into(),
+					T::Preimages::bound(call)?,
+				)?;
+
+				Self::deposit_event(Event::ApprovedTransferScheduled { request_id });
+			}
+
+			Ok(())
+		}
+
+		/// Cancels a transfer request which has not been approved and
+		/// releases its deposit. Callable by the sender at any time, or by
+		/// anyone once the request expired.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::cancel_transfer_request())]
+		pub fn cancel_transfer_request(
+			origin: OriginFor<T>,
+			request_id: TransferRequestId,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+
+			let request = TransferRequests::<T>::get(request_id)
+				.ok_or(Error::<T>::TransferRequestNotFound)?;
+			ensure!(
+				!request.approved,
+				Error::<T>::TransferRequestAlreadyApproved
+			);
+			ensure!(
+				account_id == request.sender || request.expires_at <= T::Time::now(),
+				Error::<T>::TransferRequestNotExpired
+			);
+
+			TransferRequests::<T>::remove(request_id);
+			T::ReserveCurrency::release(
+				&HoldReason::TransferRequest.into(),
+				&request.sender,
+				T::Deposit::get(),
+				Precision::BestEffort,
+			)?;
+
+			Self::deposit_event(Event::TransferRequestCancelled { request_id });
+			Ok(())
+		}
+
+		/// Executes an approved transfer request, dispatching its call on
+		/// behalf of the sender while bypassing the approval policies it was
+		/// approved under. Scheduled once the request is approved.
+		/// The request is removed and its deposit released regardless of the
+		/// result of the call. Only the actual weight of the call is charged.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::execute_approved_transfer().saturating_add(T::MaxCallWeight::get()))]
+		pub fn execute_approved_transfer(
+			origin: OriginFor<T>,
+			request_id: TransferRequestId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let request = TransferRequests::<T>::get(request_id)
+				.ok_or(Error::<T>::TransferRequestNotFound)?;
+			ensure!(request.approved, Error::<T>::TransferRequestNotApproved);
+
+			TransferRequests::<T>::remove(request_id);
+			T::ReserveCurrency::release(
+				&HoldReason::TransferRequest.into(),
+				&request.sender,
+				T::Deposit::get(),
+				Precision::BestEffort,
+			)?;
+
+			let (result, call_weight) =
+				match <T as Config>::RuntimeCall::decode_all_with_depth_limit(
+					CALL_DECODE_DEPTH_LIMIT,
+					&mut request.call.as_slice(),
+				) {
+					Ok(call) => {
+						let info = call.get_dispatch_info();
+
+						ExecutingApprovedTransfer::<T>::put((
+							request.sender.clone(),
+							request.currency_ids,
+						));
+						let result =
+							call.dispatch(frame_system::RawOrigin::Signed(request.sender).into());
+						ExecutingApprovedTransfer::<T>::kill();
+
+						let call_weight = extract_actual_weight(&result, &info);
+						(result.map(|_| ()).map_err(|e| e.error), call_weight)
+					}
+					Err(_) => (Err(Error::<T>::InvalidCall.into()), Weight::zero()),
+				};
+
+			Self::deposit_event(Event::ApprovedTransferExecuted { request_id, result });
+			Ok(Some(T::WeightInfo::execute_approved_transfer().saturating_add(call_weight)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Adds a transfer allowance with the given limits, see
+		/// [`Pallet::add_transfer_allowance`]. The limits of an existing
+		/// allowance are kept if no limits are given. Its usage is always kept,
+		/// so that re-adding an allowance does not restart its velocity window.
+		pub(crate) fn do_add_transfer_allowance(
+			account_id: T::AccountId,
+			currency_id: T::CurrencyId,
+			receiver: T::Location,
+			limits: Option<AllowanceLimitsOf<T>>,
+		) -> DispatchResult {
+			if let Some(velocity) = limits.as_ref().and_then(|limits| limits.velocity.as_ref()) {
+				ensure!(velocity.fixed_window > 0, Error::<T>::InvalidVelocityWindow);
+			}
+
+			let existing_allowance =
+				<AccountCurrencyTransferAllowance<T>>::get((&account_id, &currency_id, &receiver));
+
+			let is_new_allowance = existing_allowance.is_none();
+			let (limits, usage) = match existing_allowance {
+				Some(AllowanceDetails {
+					limits: existing_limits,
+					usage,
+					..
+				}) => (limits.unwrap_or(existing_limits), usage),
+				None => (limits.unwrap_or_default(), AllowanceUsage::default()),
+			};
+
+			let allowance_details = match Self::get_account_currency_restriction_count_delay(
+				&account_id,
+				currency_id,
+			) {
+				Some(AllowanceMetadata {
+					current_delay: Some(delay),
+					..
+				}) => AllowanceDetails {
+					allowed_at: T::Time::now().saturating_add(delay),
+					limits,
+					usage,
+					..AllowanceDetails::default()
+				},
+				_ => AllowanceDetails {
+					limits,
+					usage,
+					..AllowanceDetails::default()
+				},
+			};
+
+			if is_new_allowance {
+				Self::increment_or_create_allowance_count(&account_id, &currency_id)?;
+				T::ReserveCurrency::hold(
+					&HoldReason::TransferAllowance.into(),
+					&account_id,
+					T::Deposit::get(),
+				)?;
+			};
+			<AccountCurrencyTransferAllowance<T>>::insert(
+				(&account_id, &currency_id, &receiver),
+				&allowance_details,
+			);
+
+			Self::deposit_event(Event::TransferAllowanceCreated {
+				sender_account_id: account_id,
+				currency_id,
+				receiver,
+				allowed_at: allowance_details.allowed_at,
+				blocked_at: allowance_details.blocked_at,
+				limits: allowance_details.limits,
+			});
+			Ok(())
+		}
+
 		/// Increments number of allowances present for a sending
 		/// account/currency set. If no allowances set, an entry with 1 added,
 		/// if entry already present, it is then incremented.
@@ -742,9 +1477,46 @@ pub mod pallet {
 				_ => Err(DispatchError::from(Error::<T>::NoAllowancesSet)),
 			}
 		}
+
+		/// Checks the amount against the limits of the allowance and accounts
+		/// it in the current fixed velocity window, starting a new window if
+		/// the current one elapsed.
+		fn use_allowance_in_fixed_window(
+			details: &mut AllowanceDetailsOf<T>,
+			amount: T::Balance,
+			now: Seconds,
+		) -> DispatchResult {
+			if let Some(per_transfer) = details.limits.per_transfer {
+				ensure!(amount <= per_transfer, Error::<T>::TransferAmountAboveLimit);
+			}
+
+			if let Some(velocity) = &details.limits.velocity {
+				if now
+					>= details
+						.usage
+						.window_start
+						.saturating_add(velocity.fixed_window)
+				{
+					details.usage = AllowanceUsage {
+						window_start: now,
+						transferred: T::Balance::zero(),
+					};
+				}
+
+				let transferred = details.usage.transferred.ensure_add(amount)?;
+				ensure!(
+					transferred <= velocity.amount,
+					Error::<T>::VelocityLimitExceeded
+				);
+				details.usage.transferred = transferred;
+			}
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> TransferAllowance<T::AccountId> for Pallet<T> {
+		type Balance = T::Balance;
 		type CurrencyId = T::CurrencyId;
 		type Location = T::Location;
 
@@ -755,35 +1527,68 @@ pub mod pallet {
 		/// but the destination does not have an allowance added then the
 		/// transfer is not allowed. If there is an allowance for the sending
 		/// account and currency, and there's an allowance present:
-		/// then we check whether the current block is between the `allowed_at`
-		/// and `blocked_at` blocks in the allowance.
+		/// then we check whether the current time is between the `allowed_at`
+		/// and `blocked_at` times in the allowance, and whether the amount is
+		/// within the limits of the allowance. The amount is then accounted in
+		/// the fixed velocity window of the allowance.
 		fn allowance(
 			send: T::AccountId,
 			receive: Self::Location,
 			currency: T::CurrencyId,
+			amount: T::Balance,
 		) -> Result<Option<Self::Location>, DispatchError> {
 			match Self::get_account_currency_restriction_count_delay(&send, currency) {
 				Some(AllowanceMetadata {
 					allowance_count: count,
 					..
 				}) if count > 0 => {
-					let current_block = <frame_system::Pallet<T>>::block_number();
-					match <AccountCurrencyTransferAllowance<T>>::get((
-						&send,
-						&currency,
-						receive.clone(),
-					)) {
-						Some(AllowanceDetails {
-							allowed_at,
-							blocked_at,
-						}) if current_block >= allowed_at && current_block < blocked_at => Ok(Some(receive)),
-						_ => Err(DispatchError::from(Error::<T>::NoAllowanceForDestination)),
-					}
+					let now = T::Time::now();
+					<AccountCurrencyTransferAllowance<T>>::try_mutate(
+						(&send, &currency, receive.clone()),
+						|details| match details {
+							Some(details)
+								if now >= details.allowed_at && now < details.blocked_at =>
+							{
+								Self::use_allowance_in_fixed_window(details, amount, now)?;
+								Ok(Some(receive))
+							}
+							_ => Err(DispatchError::from(Error::<T>::NoAllowanceForDestination)),
+						},
+					)
 				}
 				// In this case no allowances are set for the sending account & currency,
 				// therefore no restrictions should be in place.
 				_ => Ok(None),
 			}
 		}
+
+		fn is_missing_allowance(error: &DispatchError) -> bool {
+			*error == Error::<T>::NoAllowanceForDestination.into()
+		}
+
+		/// This checks whether a transfer from an account and currency
+		/// requires approval. If there is an active approval policy for the
+		/// sending account and currency, transfers above its threshold are
+		/// only allowed while executing a transfer request approved under the
+		/// policy.
+		fn ensure_approved(
+			send: T::AccountId,
+			currency: T::CurrencyId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			match ApprovalPolicies::<T>::get(&send, currency) {
+				Some(policy) if policy.is_active(T::Time::now()) && amount > policy.threshold => {
+					ensure!(
+						ExecutingApprovedTransfer::<T>::get()
+							.is_some_and(|(sender, currency_ids)| sender == send
+								&& currency_ids.contains(&currency)),
+						Error::<T>::TransferApprovalRequired
+					);
+					Ok(())
+				}
+				// In this case no approval policy applies to the transfer
+				_ => Ok(()),
+			}
+		}
 	}
 }
@@ -0,0 +1,130 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+pub mod v2 {
+	use cfg_traits::{Seconds, TimeAsSecs};
+	use frame_support::{
+		pallet_prelude::{Decode, Encode},
+		traits::{Get, OnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_runtime::{
+		traits::{Bounded, SaturatedConversion},
+		Saturating,
+	};
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	use crate::pallet::{
+		AccountCurrencyTransferAllowance, AccountCurrencyTransferCountDelay, AllowanceDetails,
+		AllowanceMetadata, Config,
+	};
+
+	#[derive(Encode, Decode)]
+	struct AllowanceDetailsV1<BlockNumber> {
+		allowed_at: BlockNumber,
+		blocked_at: BlockNumber,
+	}
+
+	/// Expresses the allowance windows and delays in seconds instead of
+	/// blocks and adds the amount limits to the allowances.
+	///
+	/// Block numbers are converted relative to the current block and time
+	/// using the given block time in seconds, delays are multiplied by it.
+	/// Allowances open or blocked until the maximum block stay so. Existing
+	/// allowances have no amount limits.
+	pub struct Migration<T, BlockTime>(PhantomData<(T, BlockTime)>);
+
+	impl<T: Config, BlockTime: Get<Seconds>> OnRuntimeUpgrade for Migration<T, BlockTime> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let now = T::Time::now();
+			let block_time = BlockTime::get();
+
+			let to_seconds = |block: BlockNumberFor<T>| -> Seconds {
+				if block == BlockNumberFor::<T>::max_value() {
+					Seconds::MAX
+				} else if block >= current_block {
+					let blocks: Seconds = (block - current_block).saturated_into();
+					now.saturating_add(blocks.saturating_mul(block_time))
+				} else {
+					let blocks: Seconds = (current_block - block).saturated_into();
+					now.saturating_sub(blocks.saturating_mul(block_time))
+				}
+			};
+
+			let to_delay = |blocks: BlockNumberFor<T>| -> Seconds {
+				blocks
+					.saturated_into::<Seconds>()
+					.saturating_mul(block_time)
+			};
+
+			AccountCurrencyTransferAllowance::<T>::translate_values::<
+				AllowanceDetailsV1<BlockNumberFor<T>>,
+				_,
+			>(|details| {
+				count += 1;
+
+				Some(AllowanceDetails {
+					allowed_at: to_seconds(details.allowed_at),
+					blocked_at: to_seconds(details.blocked_at),
+					..AllowanceDetails::default()
+				})
+			});
+
+			AccountCurrencyTransferCountDelay::<T>::translate_values::<
+				AllowanceMetadata<BlockNumberFor<T>>,
+				_,
+			>(|metadata| {
+				count += 1;
+
+				Some(AllowanceMetadata {
+					allowance_count: metadata.allowance_count,
+					current_delay: metadata.current_delay.map(to_delay),
+					once_modifiable_after: metadata.once_modifiable_after.map(to_seconds),
+				})
+			});
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let allowances = AccountCurrencyTransferAllowance::<T>::iter_keys().count() as u64;
+			let delays = AccountCurrencyTransferCountDelay::<T>::iter_keys().count() as u64;
+
+			Ok((allowances, delays).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (allowances, delays) = <(u64, u64)>::decode(&mut state.as_slice())
+				.map_err(|_| sp_runtime::TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+
+			// Every entry decodes in seconds
+			frame_support::ensure!(
+				AccountCurrencyTransferAllowance::<T>::iter().count() as u64 == allowances,
+				"TransferAllowlistV2: Allowances lost"
+			);
+			frame_support::ensure!(
+				AccountCurrencyTransferCountDelay::<T>::iter().count() as u64 == delays,
+				"TransferAllowlistV2: Delays lost"
+			);
+
+			Ok(())
+		}
+	}
+}
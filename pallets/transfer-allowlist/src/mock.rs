@@ -10,24 +10,31 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
+use cfg_mocks::pallet_mock_time;
+use cfg_traits::{Seconds, TimeAsSecs};
 use cfg_types::tokens::FilterCurrency;
-use frame_support::{derive_impl, traits::ConstU64, Deserialize, Serialize};
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64, EqualPrivilegeOnly, Everything, OnInitialize},
+	weights::Weight,
+	Deserialize, Serialize,
+};
+use frame_system::EnsureRoot;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::crypto::AccountId32;
-use sp_runtime::{
-	traits::{CheckedAdd, IdentityLookup},
-	BuildStorage,
-};
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
 
 use crate as transfer_allowlist;
 
-pub(crate) const STARTING_BLOCK: u64 = 50;
+pub(crate) const STARTING_TIME: Seconds = 50;
 pub(crate) const SENDER: AccountId32 = AccountId32::new([1u8; 32]);
 pub(crate) const ACCOUNT_RECEIVER: AccountId32 = AccountId32::new([2u8; 32]);
 pub(crate) const FEE_DEFICIENT_SENDER: AccountId32 = AccountId32::new([3u8; 32]);
 pub(crate) const OTHER_RECEIVER: AccountId32 = AccountId32::new([100u8; 32]);
+pub(crate) const APPROVER_1: AccountId32 = AccountId32::new([4u8; 32]);
+pub(crate) const APPROVER_2: AccountId32 = AccountId32::new([5u8; 32]);
+pub(crate) const APPROVER_3: AccountId32 = AccountId32::new([6u8; 32]);
 
 type Balance = u64;
 
@@ -35,6 +42,8 @@ frame_support::construct_runtime!(
 	  pub enum Runtime {
 		  Balances: pallet_balances,
 		  System: frame_system,
+		  MockTime: pallet_mock_time,
+		  Scheduler: pallet_scheduler,
 		  TransferAllowList: transfer_allowlist,
 	  }
 );
@@ -52,16 +61,67 @@ impl pallet_balances::Config for Runtime {
 	type AccountStore = System;
 }
 
+impl pallet_mock_time::Config for Runtime {
+	type Moment = u64;
+}
+
+parameter_types! {
+	pub const MaximumSchedulerWeight: Weight = Weight::from_parts(u64::MAX, u64::MAX);
+	pub const MaxCallWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type MaxScheduledPerBlock = ConstU32<10>;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type PalletsOrigin = OriginCaller;
+	type Preimages = ();
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type ScheduleOrigin = EnsureRoot<AccountId32>;
+	type WeightInfo = ();
+}
+
 impl transfer_allowlist::Config for Runtime {
+	type ApprovableCalls = Everything;
+	type Balance = Balance;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
 	type CurrencyId = FilterCurrency;
 	type Deposit = ConstU64<10>;
 	type Location = Location;
+	type MaxApprovers = ConstU32<3>;
+	type MaxCallSize = ConstU32<256>;
+	type MaxCallWeight = MaxCallWeight;
+	type PalletsOrigin = OriginCaller;
+	type Preimages = ();
 	type ReserveCurrency = Balances;
+	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type Scheduler = Scheduler;
+	type Time = MockTime;
 	type WeightInfo = ();
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl transfer_allowlist::BenchmarkHelper<AccountId32, RuntimeCall> for MockBenchmarkHelper {
+	fn approvable_call(receiver: AccountId32) -> RuntimeCall {
+		RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: receiver,
+			value: 1,
+		})
+	}
+
+	fn advance_time(seconds: Seconds) {
+		advance_secs(seconds)
+	}
+}
+
 #[derive(
 	Clone,
 	Debug,
@@ -104,15 +164,24 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut e = sp_io::TestExternalities::new(t);
 
 	e.execute_with(|| {
-		System::set_block_number(STARTING_BLOCK);
+		System::set_block_number(1);
+		set_now(STARTING_TIME);
 	});
 
 	e
 }
 
-pub fn advance_n_blocks<T: frame_system::Config>(n: BlockNumberFor<T>) {
-	let b = frame_system::Pallet::<T>::block_number()
-		.checked_add(&n)
-		.expect("Mock block advancement failed.");
-	frame_system::Pallet::<T>::set_block_number(b)
+pub fn set_now(now: Seconds) {
+	MockTime::mock_now(move || now * 1000);
+}
+
+pub fn advance_secs(n: Seconds) {
+	set_now(<MockTime as TimeAsSecs>::now() + n)
+}
+
+/// Runs the scheduled calls of the next block
+pub fn run_next_block() {
+	let next_block = System::block_number() + 1;
+	System::set_block_number(next_block);
+	Scheduler::on_initialize(next_block);
 }
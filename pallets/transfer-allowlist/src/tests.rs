@@ -1,5 +1,7 @@
+use cfg_traits::Seconds;
 use cfg_types::tokens::{CurrencyId, FilterCurrency};
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, dispatch::GetDispatchInfo};
+use sp_runtime::DispatchError;
 
 use super::*;
 use crate::mock::*;
@@ -24,6 +26,7 @@ fn add_transfer_allowance_works() {
 			AllowanceDetails {
 				allowed_at: 0u64,
 				blocked_at: u64::MAX,
+				..Default::default()
 			}
 		);
 		assert_eq!(
@@ -71,9 +74,10 @@ fn add_transfer_allowance_updates_with_delay_set() {
 			))
 			.unwrap(),
 			AllowanceDetails {
-				// current block is set to 50, delay is 200
+				// current time is set to 50, delay is 200
 				allowed_at: 250u64,
 				blocked_at: u64::MAX,
+				..Default::default()
 			}
 		);
 		// verify correctly incremented -- should still just have one val
@@ -134,7 +138,8 @@ fn transfer_allowance_allows_correctly_with_allowance_set() {
 			TransferAllowList::allowance(
 				SENDER.into(),
 				local_location(ACCOUNT_RECEIVER),
-				TEST_CURRENCY_ID
+				TEST_CURRENCY_ID,
+				10
 			),
 			Ok(Some(local_location(ACCOUNT_RECEIVER)))
 		)
@@ -153,7 +158,8 @@ fn transfer_allowance_blocks_when_account_not_allowed() {
 			TransferAllowList::allowance(
 				SENDER.into(),
 				local_location(OTHER_RECEIVER),
-				TEST_CURRENCY_ID
+				TEST_CURRENCY_ID,
+				10
 			),
 			Error::<Runtime>::NoAllowanceForDestination,
 		)
@@ -178,7 +184,8 @@ fn transfer_allowance_blocks_correctly_when_before_start_block() {
 			TransferAllowList::allowance(
 				SENDER.into(),
 				local_location(ACCOUNT_RECEIVER),
-				TEST_CURRENCY_ID
+				TEST_CURRENCY_ID,
+				10
 			),
 			Error::<Runtime>::NoAllowanceForDestination,
 		)
@@ -197,7 +204,8 @@ fn transfer_allowance_blocks_correctly_when_after_blocked_at_block() {
 			TransferAllowList::allowance(
 				SENDER.into(),
 				local_location(ACCOUNT_RECEIVER),
-				TEST_CURRENCY_ID
+				TEST_CURRENCY_ID,
+				10
 			),
 			Ok(Some(local_location(ACCOUNT_RECEIVER)))
 		)
@@ -226,9 +234,10 @@ fn remove_transfer_allowance_works() {
 			))
 			.unwrap(),
 			AllowanceDetails {
-				// current block is 50, no delay set
+				// current time is 50, no delay set
 				allowed_at: 0u64,
 				blocked_at: 50u64,
+				..Default::default()
 			}
 		);
 
@@ -277,9 +286,10 @@ fn remove_transfer_allowance_with_delay_works() {
 			))
 			.unwrap(),
 			AllowanceDetails {
-				// current block is 50, no delay set
+				// current time is 50, no delay set
 				allowed_at: 0u64,
 				blocked_at: 250u64,
+				..Default::default()
 			}
 		);
 
@@ -322,7 +332,7 @@ fn purge_transfer_allowance_works() {
 			local_location(ACCOUNT_RECEIVER)
 		));
 		assert_eq!(Balances::reserved_balance(&SENDER), 10);
-		advance_n_blocks::<Runtime>(6u64);
+		advance_secs(6u64);
 
 		// test removal
 		assert_ok!(TransferAllowList::purge_transfer_allowance(
@@ -401,7 +411,7 @@ fn purge_transfer_allowance_when_multiple_present_for_sender_currency_properly_d
 			local_location(ACCOUNT_RECEIVER)
 		));
 
-		advance_n_blocks::<Runtime>(6u64);
+		advance_secs(6u64);
 
 		// test removal
 		assert_ok!(TransferAllowList::purge_transfer_allowance(
@@ -433,7 +443,8 @@ fn purge_transfer_allowance_when_multiple_present_for_sender_currency_properly_d
 			.unwrap(),
 			AllowanceDetails {
 				allowed_at: 55u64,
-				blocked_at: u64::MAX
+				blocked_at: u64::MAX,
+				..Default::default()
 			}
 		);
 
@@ -581,7 +592,7 @@ fn set_allowance_delay_future_modifiable_fails_if_modifiable_set_and_not_reached
 				TEST_CURRENCY_ID
 			)
 		);
-		advance_n_blocks::<Runtime>(20);
+		advance_secs(20);
 
 		assert_noop!(
 			TransferAllowList::toggle_allowance_delay_once_future_modifiable(
@@ -607,7 +618,7 @@ fn set_allowance_delay_future_modifiable_works_if_modifiable_set_and_reached() {
 				TEST_CURRENCY_ID
 			)
 		);
-		advance_n_blocks::<Runtime>(200);
+		advance_secs(200);
 
 		assert_ok!(
 			TransferAllowList::toggle_allowance_delay_once_future_modifiable(
@@ -657,7 +668,7 @@ fn purge_allowance_delay_works() {
 				TEST_CURRENCY_ID
 			)
 		);
-		advance_n_blocks::<Runtime>(201);
+		advance_secs(201);
 		assert_ok!(TransferAllowList::purge_allowance_delay(
 			RuntimeOrigin::signed(SENDER),
 			TEST_CURRENCY_ID
@@ -732,7 +743,7 @@ fn purge_allowance_delay_fails_if_modifiable_at_not_reached() {
 			),
 			Error::<Runtime>::DelayUnmodifiable
 		);
-		advance_n_blocks::<Runtime>(20u64);
+		advance_secs(20u64);
 		assert_noop!(
 			TransferAllowList::purge_allowance_delay(
 				RuntimeOrigin::signed(SENDER),
@@ -765,7 +776,7 @@ fn update_allowance_delay_fails_if_modifiable_after_not_set() {
 			TEST_CURRENCY_ID,
 			10u64
 		));
-		advance_n_blocks::<Runtime>(15);
+		advance_secs(15);
 		assert_noop!(
 			TransferAllowList::update_allowance_delay(
 				RuntimeOrigin::signed(SENDER),
@@ -791,7 +802,7 @@ fn update_allowance_delay_fails_if_modifiable_after_not_reached() {
 				TEST_CURRENCY_ID
 			)
 		);
-		advance_n_blocks::<Runtime>(15);
+		advance_secs(15);
 		assert_noop!(
 			TransferAllowList::update_allowance_delay(
 				RuntimeOrigin::signed(SENDER),
@@ -818,7 +829,7 @@ fn update_allowance_delay_works() {
 				TEST_CURRENCY_ID
 			)
 		);
-		advance_n_blocks::<Runtime>(12);
+		advance_secs(12);
 		assert_ok!(TransferAllowList::update_allowance_delay(
 			RuntimeOrigin::signed(SENDER),
 			TEST_CURRENCY_ID,
@@ -852,3 +863,685 @@ fn update_allowance_delay_works() {
 		)
 	})
 }
+
+#[test]
+fn transfer_allowance_window_follows_time_not_blocks() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransferAllowList::add_allowance_delay(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			3600u64
+		));
+		assert_ok!(TransferAllowList::add_transfer_allowance(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			local_location(ACCOUNT_RECEIVER)
+		));
+
+		// producing blocks does not open the allowance
+		System::set_block_number(1_000);
+		assert_err!(
+			TransferAllowList::allowance(
+				SENDER.into(),
+				local_location(ACCOUNT_RECEIVER),
+				TEST_CURRENCY_ID,
+				10
+			),
+			Error::<Runtime>::NoAllowanceForDestination,
+		);
+
+		advance_secs(3600);
+		assert_eq!(
+			TransferAllowList::allowance(
+				SENDER.into(),
+				local_location(ACCOUNT_RECEIVER),
+				TEST_CURRENCY_ID,
+				10
+			),
+			Ok(Some(local_location(ACCOUNT_RECEIVER)))
+		);
+	})
+}
+
+fn add_allowance_with_limits(per_transfer: Option<u64>, velocity: Option<(u64, Seconds)>) {
+	assert_ok!(TransferAllowList::add_transfer_allowance_with_limits(
+		RuntimeOrigin::signed(SENDER),
+		TEST_CURRENCY_ID,
+		local_location(ACCOUNT_RECEIVER),
+		AllowanceLimits {
+			per_transfer,
+			velocity: velocity.map(|(amount, window)| VelocityLimit {
+				amount,
+				fixed_window: window,
+			}),
+		}
+	));
+}
+
+fn transfer(amount: u64) -> Result<Option<Location>, DispatchError> {
+	TransferAllowList::allowance(
+		SENDER.into(),
+		local_location(ACCOUNT_RECEIVER),
+		TEST_CURRENCY_ID,
+		amount,
+	)
+}
+
+#[test]
+fn add_transfer_allowance_with_limits_works() {
+	new_test_ext().execute_with(|| {
+		add_allowance_with_limits(Some(100), Some((500, 3600)));
+
+		assert_eq!(
+			TransferAllowList::get_account_currency_transfer_allowance((
+				SENDER,
+				TEST_CURRENCY_ID,
+				local_location(ACCOUNT_RECEIVER)
+			))
+			.unwrap(),
+			AllowanceDetails {
+				allowed_at: 0u64,
+				blocked_at: u64::MAX,
+				limits: AllowanceLimits {
+					per_transfer: Some(100),
+					velocity: Some(VelocityLimit {
+						amount: 500,
+						fixed_window: 3600
+					}),
+				},
+				usage: AllowanceUsage::default(),
+			}
+		);
+		assert_eq!(Balances::reserved_balance(&SENDER), 10);
+	})
+}
+
+#[test]
+fn add_transfer_allowance_with_limits_fails_with_zero_window() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransferAllowList::add_transfer_allowance_with_limits(
+				RuntimeOrigin::signed(SENDER),
+				TEST_CURRENCY_ID,
+				local_location(ACCOUNT_RECEIVER),
+				AllowanceLimits {
+					per_transfer: None,
+					velocity: Some(VelocityLimit {
+						amount: 500,
+						fixed_window: 0
+					}),
+				}
+			),
+			Error::<Runtime>::InvalidVelocityWindow
+		);
+	})
+}
+
+#[test]
+fn transfer_allowance_rejects_amount_above_per_transfer_limit() {
+	new_test_ext().execute_with(|| {
+		add_allowance_with_limits(Some(100), None);
+
+		assert_ok!(transfer(100));
+		assert_err!(transfer(101), Error::<Runtime>::TransferAmountAboveLimit);
+	})
+}
+
+#[test]
+fn transfer_allowance_rejects_amount_above_velocity_limit() {
+	new_test_ext().execute_with(|| {
+		add_allowance_with_limits(None, Some((500, 3600)));
+
+		assert_ok!(transfer(300));
+		advance_secs(1800);
+		assert_ok!(transfer(200));
+		assert_err!(transfer(1), Error::<Runtime>::VelocityLimitExceeded);
+
+		assert_eq!(
+			TransferAllowList::get_account_currency_transfer_allowance((
+				SENDER,
+				TEST_CURRENCY_ID,
+				local_location(ACCOUNT_RECEIVER)
+			))
+			.unwrap()
+			.usage,
+			AllowanceUsage {
+				window_start: STARTING_TIME,
+				transferred: 500,
+			}
+		);
+	})
+}
+
+#[test]
+fn transfer_allowance_fixed_velocity_window_restarts_once_elapsed() {
+	new_test_ext().execute_with(|| {
+		add_allowance_with_limits(None, Some((500, 3600)));
+
+		assert_ok!(transfer(500));
+		advance_secs(3599);
+		assert_err!(transfer(1), Error::<Runtime>::VelocityLimitExceeded);
+
+		advance_secs(1);
+		assert_ok!(transfer(500));
+		assert_eq!(
+			TransferAllowList::get_account_currency_transfer_allowance((
+				SENDER,
+				TEST_CURRENCY_ID,
+				local_location(ACCOUNT_RECEIVER)
+			))
+			.unwrap()
+			.usage,
+			AllowanceUsage {
+				window_start: STARTING_TIME + 3600,
+				transferred: 500,
+			}
+		);
+	})
+}
+
+#[test]
+fn add_transfer_allowance_keeps_existing_limits() {
+	new_test_ext().execute_with(|| {
+		add_allowance_with_limits(Some(100), Some((500, 3600)));
+		assert_ok!(transfer(100));
+
+		assert_ok!(TransferAllowList::add_transfer_allowance(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			local_location(ACCOUNT_RECEIVER),
+		));
+
+		let details = TransferAllowList::get_account_currency_transfer_allowance((
+			SENDER,
+			TEST_CURRENCY_ID,
+			local_location(ACCOUNT_RECEIVER),
+		))
+		.unwrap();
+		assert_eq!(details.limits.per_transfer, Some(100));
+		assert_eq!(
+			details.usage,
+			AllowanceUsage {
+				window_start: STARTING_TIME,
+				transferred: 100,
+			}
+		);
+		assert_err!(transfer(101), Error::<Runtime>::TransferAmountAboveLimit);
+		assert_eq!(Balances::reserved_balance(&SENDER), 10);
+	})
+}
+
+#[test]
+fn add_transfer_allowance_with_limits_keeps_usage() {
+	new_test_ext().execute_with(|| {
+		add_allowance_with_limits(None, Some((500, 3600)));
+		assert_ok!(transfer(500));
+
+		add_allowance_with_limits(None, Some((600, 3600)));
+
+		assert_ok!(transfer(100));
+		assert_err!(transfer(1), Error::<Runtime>::VelocityLimitExceeded);
+	})
+}
+
+fn set_approval_policy() {
+	assert_ok!(TransferAllowList::set_approval_policy(
+		RuntimeOrigin::signed(SENDER),
+		TEST_CURRENCY_ID,
+		5,
+		vec![APPROVER_1, APPROVER_2, APPROVER_3].try_into().unwrap(),
+		2,
+		3600
+	));
+}
+
+fn request_currencies(currency_ids: Vec<FilterCurrency>) -> RequestCurrenciesOf<Runtime> {
+	currency_ids.try_into().unwrap()
+}
+
+fn request_transfer_approval(value: u64) -> TransferRequestId {
+	request_transfer_approval_under(vec![TEST_CURRENCY_ID], value)
+}
+
+fn request_transfer_approval_under(
+	currency_ids: Vec<FilterCurrency>,
+	value: u64,
+) -> TransferRequestId {
+	let request_id = NextTransferRequestId::<Runtime>::get();
+	assert_ok!(TransferAllowList::request_transfer_approval(
+		RuntimeOrigin::signed(SENDER),
+		request_currencies(currency_ids),
+		Box::new(RuntimeCall::Balances(
+			pallet_balances::Call::transfer_keep_alive {
+				dest: ACCOUNT_RECEIVER,
+				value,
+			}
+		))
+	));
+	request_id
+}
+
+#[test]
+fn approval_policy_requires_approval_above_threshold() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransferAllowList::ensure_approved(
+			SENDER,
+			TEST_CURRENCY_ID,
+			6
+		));
+
+		set_approval_policy();
+
+		assert_ok!(TransferAllowList::ensure_approved(
+			SENDER,
+			TEST_CURRENCY_ID,
+			5
+		));
+		assert_err!(
+			TransferAllowList::ensure_approved(SENDER, TEST_CURRENCY_ID, 6),
+			Error::<Runtime>::TransferApprovalRequired
+		);
+		assert_ok!(TransferAllowList::ensure_approved(
+			SENDER,
+			FilterCurrency::All,
+			6
+		));
+	})
+}
+
+#[test]
+fn set_approval_policy_fails_if_invalid_or_active() {
+	new_test_ext().execute_with(|| {
+		for (approvers, required, timeout) in [
+			(vec![APPROVER_1, APPROVER_2], 0, 3600),
+			(vec![APPROVER_1, APPROVER_2], 3, 3600),
+			(vec![APPROVER_1, APPROVER_1], 2, 3600),
+			(vec![APPROVER_1, APPROVER_2], 2, 0),
+		] {
+			assert_noop!(
+				TransferAllowList::set_approval_policy(
+					RuntimeOrigin::signed(SENDER),
+					TEST_CURRENCY_ID,
+					5,
+					approvers.try_into().unwrap(),
+					required,
+					timeout
+				),
+				Error::<Runtime>::InvalidApprovalPolicy
+			);
+		}
+
+		set_approval_policy();
+
+		assert_noop!(
+			TransferAllowList::set_approval_policy(
+				RuntimeOrigin::signed(SENDER),
+				TEST_CURRENCY_ID,
+				100,
+				vec![APPROVER_1].try_into().unwrap(),
+				1,
+				3600
+			),
+			Error::<Runtime>::ApprovalPolicyActive
+		);
+	})
+}
+
+#[test]
+fn remove_approval_policy_applies_after_delay() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransferAllowList::add_allowance_delay(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			200
+		));
+		set_approval_policy();
+
+		assert_ok!(TransferAllowList::remove_approval_policy(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID
+		));
+		assert_eq!(
+			ApprovalPolicies::<Runtime>::get(SENDER, TEST_CURRENCY_ID)
+				.unwrap()
+				.active_until,
+			Some(STARTING_TIME + 200)
+		);
+
+		advance_secs(199);
+		assert_err!(
+			TransferAllowList::ensure_approved(SENDER, TEST_CURRENCY_ID, 6),
+			Error::<Runtime>::TransferApprovalRequired
+		);
+
+		advance_secs(1);
+		assert_ok!(TransferAllowList::ensure_approved(
+			SENDER,
+			TEST_CURRENCY_ID,
+			6
+		));
+		assert_noop!(
+			TransferAllowList::remove_approval_policy(
+				RuntimeOrigin::signed(SENDER),
+				TEST_CURRENCY_ID
+			),
+			Error::<Runtime>::NoApprovalPolicy
+		);
+		set_approval_policy();
+	})
+}
+
+#[test]
+fn remove_approval_policy_without_delay_removes_policy() {
+	new_test_ext().execute_with(|| {
+		set_approval_policy();
+
+		assert_ok!(TransferAllowList::remove_approval_policy(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID
+		));
+		assert_eq!(
+			ApprovalPolicies::<Runtime>::get(SENDER, TEST_CURRENCY_ID),
+			None
+		);
+	})
+}
+
+#[test]
+fn request_transfer_approval_fails_without_policy() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransferAllowList::request_transfer_approval(
+				RuntimeOrigin::signed(SENDER),
+				request_currencies(vec![TEST_CURRENCY_ID]),
+				Box::new(RuntimeCall::Balances(
+					pallet_balances::Call::transfer_keep_alive {
+						dest: ACCOUNT_RECEIVER,
+						value: 15,
+					}
+				))
+			),
+			Error::<Runtime>::NoApprovalPolicy
+		);
+	})
+}
+
+#[test]
+fn approved_transfer_is_executed_by_scheduler() {
+	new_test_ext().execute_with(|| {
+		set_approval_policy();
+		let request_id = request_transfer_approval(15);
+		assert_eq!(Balances::reserved_balance(&SENDER), 10);
+
+		assert_ok!(TransferAllowList::approve_transfer(
+			RuntimeOrigin::signed(APPROVER_1),
+			request_id
+		));
+		assert_noop!(
+			TransferAllowList::approve_transfer(RuntimeOrigin::signed(APPROVER_1), request_id),
+			Error::<Runtime>::DuplicateApproval
+		);
+		assert_noop!(
+			TransferAllowList::approve_transfer(RuntimeOrigin::signed(OTHER_RECEIVER), request_id),
+			Error::<Runtime>::NotApprover
+		);
+		assert!(
+			!TransferRequests::<Runtime>::get(request_id)
+				.unwrap()
+				.approved
+		);
+
+		assert_ok!(TransferAllowList::approve_transfer(
+			RuntimeOrigin::signed(APPROVER_2),
+			request_id
+		));
+		assert!(
+			TransferRequests::<Runtime>::get(request_id)
+				.unwrap()
+				.approved
+		);
+		assert_noop!(
+			TransferAllowList::approve_transfer(RuntimeOrigin::signed(APPROVER_3), request_id),
+			Error::<Runtime>::TransferRequestAlreadyApproved
+		);
+		assert_noop!(
+			TransferAllowList::cancel_transfer_request(RuntimeOrigin::signed(SENDER), request_id),
+			Error::<Runtime>::TransferRequestAlreadyApproved
+		);
+
+		run_next_block();
+
+		assert_eq!(TransferRequests::<Runtime>::get(request_id), None);
+		assert_eq!(Balances::reserved_balance(&SENDER), 0);
+		assert_eq!(Balances::free_balance(&ACCOUNT_RECEIVER), 15);
+		System::assert_has_event(
+			Event::ApprovedTransferExecuted {
+				request_id,
+				result: Ok(()),
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn expired_transfer_request_can_be_cancelled_by_anyone() {
+	new_test_ext().execute_with(|| {
+		set_approval_policy();
+		let request_id = request_transfer_approval(15);
+
+		assert_noop!(
+			TransferAllowList::cancel_transfer_request(
+				RuntimeOrigin::signed(OTHER_RECEIVER),
+				request_id
+			),
+			Error::<Runtime>::TransferRequestNotExpired
+		);
+
+		advance_secs(3600);
+		assert_noop!(
+			TransferAllowList::approve_transfer(RuntimeOrigin::signed(APPROVER_1), request_id),
+			Error::<Runtime>::TransferRequestExpired
+		);
+
+		assert_ok!(TransferAllowList::cancel_transfer_request(
+			RuntimeOrigin::signed(OTHER_RECEIVER),
+			request_id
+		));
+		assert_eq!(TransferRequests::<Runtime>::get(request_id), None);
+		assert_eq!(Balances::reserved_balance(&SENDER), 0);
+	})
+}
+
+#[test]
+fn approval_policy_is_bypassed_only_while_executing_its_transfer() {
+	new_test_ext().execute_with(|| {
+		set_approval_policy();
+
+		ExecutingApprovedTransfer::<Runtime>::put((
+			SENDER,
+			request_currencies(vec![FilterCurrency::All]),
+		));
+		assert_err!(
+			TransferAllowList::ensure_approved(SENDER, TEST_CURRENCY_ID, 6),
+			Error::<Runtime>::TransferApprovalRequired
+		);
+
+		ExecutingApprovedTransfer::<Runtime>::put((
+			SENDER,
+			request_currencies(vec![TEST_CURRENCY_ID]),
+		));
+		assert_ok!(TransferAllowList::ensure_approved(
+			SENDER,
+			TEST_CURRENCY_ID,
+			6
+		));
+	})
+}
+
+#[test]
+fn request_transfer_approval_fails_with_invalid_currencies() {
+	new_test_ext().execute_with(|| {
+		set_approval_policy();
+
+		for currency_ids in [vec![], vec![TEST_CURRENCY_ID, TEST_CURRENCY_ID]] {
+			assert_noop!(
+				TransferAllowList::request_transfer_approval(
+					RuntimeOrigin::signed(SENDER),
+					request_currencies(currency_ids),
+					Box::new(RuntimeCall::Balances(
+						pallet_balances::Call::transfer_keep_alive {
+							dest: ACCOUNT_RECEIVER,
+							value: 15,
+						}
+					))
+				),
+				Error::<Runtime>::InvalidRequestCurrencies
+			);
+		}
+
+		// Requesting under a currency without an active policy fails
+		assert_noop!(
+			TransferAllowList::request_transfer_approval(
+				RuntimeOrigin::signed(SENDER),
+				request_currencies(vec![TEST_CURRENCY_ID, FilterCurrency::All]),
+				Box::new(RuntimeCall::Balances(
+					pallet_balances::Call::transfer_keep_alive {
+						dest: ACCOUNT_RECEIVER,
+						value: 15,
+					}
+				))
+			),
+			Error::<Runtime>::NoApprovalPolicy
+		);
+	})
+}
+
+#[test]
+fn transfer_requested_under_several_policies_requires_approval_of_each() {
+	new_test_ext().execute_with(|| {
+		set_approval_policy();
+		assert_ok!(TransferAllowList::set_approval_policy(
+			RuntimeOrigin::signed(SENDER),
+			FilterCurrency::All,
+			5,
+			vec![APPROVER_3].try_into().unwrap(),
+			1,
+			1800
+		));
+
+		let request_id =
+			request_transfer_approval_under(vec![TEST_CURRENCY_ID, FilterCurrency::All], 15);
+		assert_eq!(
+			TransferRequests::<Runtime>::get(request_id)
+				.unwrap()
+				.expires_at,
+			STARTING_TIME + 1800
+		);
+
+		assert_ok!(TransferAllowList::approve_transfer(
+			RuntimeOrigin::signed(APPROVER_1),
+			request_id
+		));
+		assert_ok!(TransferAllowList::approve_transfer(
+			RuntimeOrigin::signed(APPROVER_2),
+			request_id
+		));
+		// The policy of all currencies is not approved yet
+		assert!(
+			!TransferRequests::<Runtime>::get(request_id)
+				.unwrap()
+				.approved
+		);
+
+		assert_ok!(TransferAllowList::approve_transfer(
+			RuntimeOrigin::signed(APPROVER_3),
+			request_id
+		));
+		assert!(
+			TransferRequests::<Runtime>::get(request_id)
+				.unwrap()
+				.approved
+		);
+
+		run_next_block();
+
+		assert_eq!(Balances::free_balance(&ACCOUNT_RECEIVER), 15);
+		System::assert_has_event(
+			Event::ApprovedTransferExecuted {
+				request_id,
+				result: Ok(()),
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn approval_policies_are_bypassed_for_all_currencies_of_the_request() {
+	new_test_ext().execute_with(|| {
+		set_approval_policy();
+		assert_ok!(TransferAllowList::set_approval_policy(
+			RuntimeOrigin::signed(SENDER),
+			FilterCurrency::All,
+			5,
+			vec![APPROVER_3].try_into().unwrap(),
+			1,
+			3600
+		));
+
+		ExecutingApprovedTransfer::<Runtime>::put((
+			SENDER,
+			request_currencies(vec![TEST_CURRENCY_ID, FilterCurrency::All]),
+		));
+		assert_ok!(TransferAllowList::ensure_approved(
+			SENDER,
+			TEST_CURRENCY_ID,
+			6
+		));
+		assert_ok!(TransferAllowList::ensure_approved(
+			SENDER,
+			FilterCurrency::All,
+			6
+		));
+
+		ExecutingApprovedTransfer::<Runtime>::put((
+			OTHER_RECEIVER,
+			request_currencies(vec![TEST_CURRENCY_ID, FilterCurrency::All]),
+		));
+		assert_err!(
+			TransferAllowList::ensure_approved(SENDER, FilterCurrency::All, 6),
+			Error::<Runtime>::TransferApprovalRequired
+		);
+	})
+}
+
+#[test]
+fn execute_approved_transfer_charges_actual_call_weight() {
+	new_test_ext().execute_with(|| {
+		set_approval_policy();
+		let request_id = request_transfer_approval(15);
+		for approver in [APPROVER_1, APPROVER_2] {
+			assert_ok!(TransferAllowList::approve_transfer(
+				RuntimeOrigin::signed(approver),
+				request_id
+			));
+		}
+
+		let call = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: ACCOUNT_RECEIVER,
+			value: 15,
+		});
+		let post_info =
+			TransferAllowList::execute_approved_transfer(RuntimeOrigin::root(), request_id)
+				.unwrap();
+
+		assert_eq!(
+			post_info.actual_weight,
+			Some(
+				<() as WeightInfo>::execute_approved_transfer()
+					.saturating_add(call.get_dispatch_info().weight)
+			)
+		);
+	})
+}
@@ -32,6 +32,77 @@ pub trait WeightInfo {
 	fn remove_transfer_allowance_no_delay() -> Weight;
 	fn purge_transfer_allowance_no_remaining_metadata() -> Weight;
 	fn purge_transfer_allowance_remaining_metadata() -> Weight;
+
+	fn set_approval_policy() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from
+		//       `add_allowance_delay_existing_metadata`. This one reads the time
+		//       and reads and writes the policy.
+		Weight::from_parts(23_655_000, 3556)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn remove_approval_policy() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from
+		//       `remove_transfer_allowance_delay_present`. This one reads the time
+		//       and the delay and reads and writes the policy.
+		Weight::from_parts(35_746_000, 3596)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn request_transfer_approval() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from
+		//       `add_transfer_allowance_no_existing_metadata`, which also holds a
+		//       deposit. This one reads the time and the policies and writes the
+		//       next request id and the request.
+		Weight::from_parts(89_187_000, 3674)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn approve_transfer() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from
+		//       `add_transfer_allowance_no_existing_metadata`. This one reads the
+		//       time and the policies, reads and writes the request and schedules
+		//       its execution.
+		Weight::from_parts(89_187_000, 42428)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn cancel_transfer_request() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from
+		//       `purge_transfer_allowance_no_remaining_metadata`, which also
+		//       releases a deposit. This one reads the time and reads and removes
+		//       the request.
+		Weight::from_parts(84_177_000, 3674)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn execute_approved_transfer() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from
+		//       `purge_transfer_allowance_no_remaining_metadata`, which also
+		//       releases a deposit. This one reads and removes the request and
+		//       sets and clears the executing transfer. The weight of the
+		//       dispatched call is added separately.
+		Weight::from_parts(84_177_000, 3674)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
 }
 
 /// Weights for pallet_transfer_allowlist using the Substrate node and
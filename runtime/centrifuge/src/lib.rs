@@ -34,10 +34,10 @@ use cfg_traits::{
 	Seconds,
 };
 use cfg_types::{
-	domain_address::DomainAddress,
+	domain_address::{Domain, DomainAddress},
 	fee_keys::{Fee, FeeKey},
 	fixed_point::{Quantity, Rate, Ratio},
-	investments::InvestmentPortfolio,
+	investments::{InvestmentPortfolio, InvestmentPosition},
 	locations::RestrictedTransferLocation,
 	oracles::OracleKey,
 	permissions::{PermissionRoles, PermissionScope, PermissionedCurrencyRole, PoolRole, Role},
@@ -108,7 +108,10 @@ use runtime_common::{
 	gateway, instances,
 	instances::CouncilCollective,
 	message_queue::{NarrowOriginToSibling, ParaIdToSibling},
-	oracle::{DigestedOracleRatioProvider, Feeder, OracleConverterBridge, OracleRatioProvider},
+	oracle::{
+		DigestedOracleRatioProvider, Feeder, FeederAccuracyBridge, OracleConverterBridge,
+		OracleRatioProvider,
+	},
 	origin::EnsureAccountOrRootOr,
 	origins::gov::types::{
 		AllOfCouncil, EnsureRootOr, HalfOfCouncil, ThreeFourthOfCouncil, TwoThirdOfCouncil,
@@ -116,7 +119,7 @@ use runtime_common::{
 	permissions::{IsUnfrozenTrancheInvestor, PoolAdminCheck},
 	rewards::SingleCurrencyMovement,
 	routing::{LPGatewayRouterProvider, MessageSerializer, RouterDispatcher, RouterId},
-	transfer_filter::{PreLpTransfer, PreNativeTransfer},
+	transfer_filter::{ApprovableTransferCalls, PreLpTransfer, PreNativeTransfer},
 	xcm::AccountIdToLocation,
 	xcm_transactor, AllowanceDeposit, CurrencyED,
 };
@@ -1567,8 +1570,10 @@ impl
 		if let Some(with_role) = maybe_role {
 			match *with_role {
 				Role::PoolRole(PoolRole::PoolAdmin) => match *role {
-					// PoolAdmins can manage all other admins, but not tranche investors
-					Role::PoolRole(PoolRole::TrancheInvestor(_, _)) => false,
+					// PoolAdmins can manage all other admins, but not tranche investors.
+					// Recovery admins can only be given by root or the council.
+					Role::PoolRole(PoolRole::TrancheInvestor(_, _))
+					| Role::PoolRole(PoolRole::RecoveryAdmin) => false,
 					Role::PoolRole(..) => true,
 					_ => false,
 				},
@@ -1622,6 +1627,7 @@ impl pallet_permissions::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Scope = PermissionScope<PoolId, CurrencyId>;
 	type Storage = PermissionRoles<TimeProvider<Timestamp>, MinDelay, TrancheId, MaxTranches>;
+	type Time = Timestamp;
 	type TrancheId = TrancheId;
 	type WeightInfo = weights::pallet_permissions::WeightInfo<Runtime>;
 }
@@ -1637,10 +1643,13 @@ impl pallet_investments::Config for Runtime {
 	type CollectedInvestmentHook = pallet_foreign_investments::CollectedInvestmentHook<Runtime>;
 	type CollectedRedemptionHook = pallet_foreign_investments::CollectedRedemptionHook<Runtime>;
 	type InvestmentId = InvestmentId;
+	type IsPoolAdmin = PoolAdminCheck<Permissions>;
 	type MaxOutstandingCollects = MaxOutstandingCollects;
+	type PoolId = PoolId;
 	type PreConditions = IsUnfrozenTrancheInvestor<Permissions>;
 	type RuntimeEvent = RuntimeEvent;
 	type Tokens = Tokens;
+	type TrancheId = TrancheId;
 	type WeightInfo = weights::pallet_investments::WeightInfo<Runtime>;
 }
 
@@ -1655,26 +1664,58 @@ parameter_types! {
 
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxFeedersPerKey: u32 = 5;
+
+	pub const MaxCollectionsPerOracleKey: u32 = 10;
+
+	pub const PriceHistoryPeriod: Millis = MILLISECS_PER_DAY;
+	pub const MaxPriceHistoryPeriods: u32 = 365;
+
+	pub const MaxFeedHistorySize: u32 = 10;
+
+	pub const OracleFeedRewardsPalletId: PalletId = cfg_types::ids::ORACLE_FEED_REWARDS_PALLET_ID;
+	pub const MinFeederBond: Balance = 1000 * CFG;
+	pub const FeedReward: Balance = CFG / 10;
+	pub const FeedRewardInterval: Millis = SECONDS_PER_HOUR * 1000;
+	pub const FeederSlashDeviation: Perbill = Perbill::from_percent(5);
+	pub const FeederSlashRounds: u32 = 10;
+	pub const FeederSlashFraction: Perbill = Perbill::from_percent(10);
+
+	pub const MaxFeedAttestations: u32 = 100;
+	pub const MaxFeedAttestationAge: Millis = SECONDS_PER_HOUR * 1000;
 }
 
 impl pallet_oracle_feed::Config for Runtime {
+	type Currency = Balances;
+	type FeedReward = FeedReward;
+	type FeederAccuracy = FeederAccuracyBridge<RuntimeOrigin, OraclePriceCollection>;
 	type FeederOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSigned<AccountId>>;
 	type FirstValuePayFee = FeeToTreasury<Fees, FirstValueFee>;
+	type MaxAttestationAge = MaxFeedAttestationAge;
+	type MaxAttestations = MaxFeedAttestations;
+	type MaxHistorySize = MaxFeedHistorySize;
+	type MinFeederBond = MinFeederBond;
 	type OracleKey = OracleKey;
 	type OracleValue = Ratio;
+	type RewardInterval = FeedRewardInterval;
+	type RewardsPalletId = OracleFeedRewardsPalletId;
 	type RuntimeEvent = RuntimeEvent;
+	type SlashDeviation = FeederSlashDeviation;
+	type SlashFraction = FeederSlashFraction;
+	type SlashRounds = FeederSlashRounds;
 	type Time = Timestamp;
 	type WeightInfo = weights::pallet_oracle_feed::WeightInfo<Self>;
 }
 
 impl pallet_oracle_collection::Config for Runtime {
-	type AggregationProvider = pallet_oracle_collection::util::MedianAggregation;
 	type ChangeGuard = PoolSystem;
 	type CollectionId = PoolId;
 	type FeederId = Feeder<RuntimeOrigin>;
+	type HistoryPeriod = PriceHistoryPeriod;
 	type IsAdmin = PoolAdminCheck<Permissions>;
 	type MaxCollectionSize = MaxRegisteredPricesPerPool;
+	type MaxCollectionsPerKey = MaxCollectionsPerOracleKey;
 	type MaxFeedersPerKey = MaxFeedersPerKey;
+	type MaxHistoryPeriods = MaxPriceHistoryPeriods;
 	type OracleKey = OracleKey;
 	type OracleProvider =
 		OracleConverterBridge<RuntimeOrigin, OraclePriceFeed, PoolSystem, OrmlAssetRegistry>;
@@ -1775,6 +1816,8 @@ impl pallet_uniques::Config for Runtime {
 
 parameter_types! {
 	pub MinFulfillmentAmountNative: Balance = 10 * CFG;
+	pub const MaxOrdersPerPair: u32 = 1000;
+	pub const MaxFillOrKillMatches: u32 = 20;
 	pub NativeDecimals: u32 = cfg_primitives::currency_decimals::NATIVE;
 }
 
@@ -1785,8 +1828,11 @@ impl pallet_order_book::Config for Runtime {
 	type BalanceOut = Balance;
 	type Currency = Tokens;
 	type CurrencyId = CurrencyId;
+	type ExpiredOrderHook = pallet_foreign_investments::ExpiredSwapHook<Runtime>;
 	type FeederId = Feeder<RuntimeOrigin>;
 	type FulfilledOrderHook = ForeignInvestments;
+	type MaxFillOrKillMatches = MaxFillOrKillMatches;
+	type MaxOrdersPerPair = MaxOrdersPerPair;
 	type MinFulfillmentAmountNative = MinFulfillmentAmountNative;
 	type NativeDecimals = NativeDecimals;
 	type OrderIdNonce = u64;
@@ -1797,43 +1843,128 @@ impl pallet_order_book::Config for Runtime {
 		OrmlAssetRegistry,
 	>;
 	type RuntimeEvent = RuntimeEvent;
+	type Time = Timestamp;
 	type Weights = weights::pallet_order_book::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	// Swaps are market orders kept until filled, expiring and placing them
+	// again would only churn the order book
+	pub const SwapLifetime: Option<Seconds> = None;
+}
+
 impl pallet_foreign_investments::Config for Runtime {
 	type CurrencyId = CurrencyId;
 	type ForeignBalance = Balance;
 	type Hooks = LiquidityPools;
 	type Investment = Investments;
 	type InvestmentId = InvestmentId;
-	type OrderBook = OrderBook;
+	type OrderBook = StableSwap;
 	type OrderId = OrderId;
 	type PoolBalance = Balance;
 	type PoolInspect = PoolSystem;
 	type RuntimeEvent = RuntimeEvent;
 	type SwapBalance = Balance;
+	type SwapLifetime = SwapLifetime;
 	type SwapRatio = Ratio;
+	type Time = Timestamp;
 	type TrancheBalance = Balance;
 }
 
+parameter_types! {
+	pub const StableSwapPalletId: PalletId = cfg_types::ids::STABLE_SWAP_PALLET_ID;
+	pub const StableSwapFallbackPeriod: BlockNumber = 7 * DAYS;
+	pub const StableSwapGracePeriod: BlockNumber = 1 * DAYS;
+}
+
+impl pallet_stable_swap::Config for Runtime {
+	type AdminOrigin = EnsureAccountOrRootOr<LpAdminAccount, TwoThirdOfCouncil>;
+	type AssetRegistry = OrmlAssetRegistry;
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+	type FallbackPeriod = StableSwapFallbackPeriod;
+	type FulfilledOrderHook = ForeignInvestments;
+	type GracePeriod = StableSwapGracePeriod;
+	type OrderBook = OrderBook;
+	type OrderId = OrderId;
+	type PalletId = StableSwapPalletId;
+	type Ratio = Ratio;
+	type RuntimeEvent = RuntimeEvent;
+	type Tokens = Tokens;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	// To be used if we want to register a particular asset in the chain spec, when running the chain locally.
 	pub LiquidityPoolsPalletIndex: PalletIndex = <LiquidityPools as PalletInfoAccess>::index() as u8;
+	pub const OutboundFeeMargin: Perbill = Perbill::from_percent(10);
+	pub const MaxRemoteCallWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(10);
+}
+
+/// Calls remote pool admins can execute through liquidity pools. Only
+/// calls acting on the pool of the message are allowed. Calls wrapping other
+/// calls are always rejected, so nested calls can never bypass the filter.
+pub struct LpRemoteCallFilter;
+impl Contains<(PoolId, RuntimeCall)> for LpRemoteCallFilter {
+	fn contains((pool_id, call): &(PoolId, RuntimeCall)) -> bool {
+		let call_pool_id = match call {
+			RuntimeCall::Utility(..)
+			| RuntimeCall::Proxy(..)
+			| RuntimeCall::Multisig(..)
+			| RuntimeCall::Scheduler(..)
+			| RuntimeCall::Council(..)
+			| RuntimeCall::TransferAllowList(..) => return false,
+			RuntimeCall::PoolSystem(
+				pallet_pool_system::Call::set_max_reserve { pool_id, .. }
+				| pallet_pool_system::Call::close_epoch { pool_id },
+			)
+			| RuntimeCall::PoolRegistry(
+				pallet_pool_registry::Call::update { pool_id, .. }
+				| pallet_pool_registry::Call::execute_update { pool_id }
+				| pallet_pool_registry::Call::set_metadata { pool_id, .. },
+			)
+			| RuntimeCall::Loans(
+				pallet_loans::Call::propose_write_off_policy { pool_id, .. }
+				| pallet_loans::Call::apply_write_off_policy { pool_id, .. }
+				| pallet_loans::Call::update_portfolio_valuation { pool_id },
+			)
+			| RuntimeCall::Permissions(
+				pallet_permissions::Call::add {
+					scope: PermissionScope::Pool(pool_id),
+					..
+				}
+				| pallet_permissions::Call::remove {
+					scope: PermissionScope::Pool(pool_id),
+					..
+				},
+			) => pool_id,
+			_ => return false,
+		};
+
+		call_pool_id == pool_id
+	}
 }
 
 impl pallet_liquidity_pools::Config for Runtime {
 	type AssetRegistry = OrmlAssetRegistry;
 	type Balance = Balance;
 	type BalanceRatio = Ratio;
+	type ChangeGuard = PoolSystem;
 	type CurrencyId = CurrencyId;
 	type ForeignInvestment = ForeignInvestments;
 	type GeneralCurrencyPrefix = GeneralCurrencyPrefix;
 	type MarketRatio = OrderBook;
+	type MaxRemoteCallWeight = MaxRemoteCallWeight;
+	type NativeCurrency = NativeToken;
+	type OutboundFeeMargin = OutboundFeeMargin;
 	type OutboundMessageHandler = LiquidityPoolsGateway;
 	type Permission = Permissions;
 	type PoolId = PoolId;
 	type PoolInspect = PoolSystem;
 	type PreTransferFilter = PreLpTransfer<TransferAllowList>;
+	type RemoteCallFilter = LpRemoteCallFilter;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeChange = runtime_common::changes::RuntimeChange<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
 	type Time = Timestamp;
 	type Tokens = Tokens;
@@ -1846,6 +1977,8 @@ impl pallet_liquidity_pools::Config for Runtime {
 parameter_types! {
 	pub Sender: DomainAddress = gateway::get_gateway_domain_address::<Runtime>();
 	pub const MaxIncomingMessageSize: u32 = 1024;
+	pub const MaxOutboundBatchSize: u32 = 1024;
+	pub const MaxOutboundBatchFlushes: u32 = 10;
 	pub const MaxRouterCount: u32 = 8;
 }
 
@@ -1869,6 +2002,8 @@ impl pallet_liquidity_pools_gateway::Config for Runtime {
 	type AdminOrigin = EnsureAccountOrRootOr<LpAdminAccount, TwoThirdOfCouncil>;
 	type InboundMessageHandler = LiquidityPools;
 	type MaxIncomingMessageSize = MaxIncomingMessageSize;
+	type MaxOutboundBatchFlushes = MaxOutboundBatchFlushes;
+	type MaxOutboundBatchSize = MaxOutboundBatchSize;
 	type MaxRouterCount = MaxRouterCount;
 	type Message = pallet_liquidity_pools::Message;
 	type MessageQueue = LiquidityPoolsGatewayQueue;
@@ -1881,39 +2016,73 @@ impl pallet_liquidity_pools_gateway::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxFailedMessageRetries: u32 = 5;
+	pub const FailedMessageRetryBaseDelay: BlockNumber = 5 * MINUTES;
+}
+
 impl pallet_liquidity_pools_gateway_queue::Config for Runtime {
+	type AdminOrigin = EnsureAccountOrRootOr<LpAdminAccount, TwoThirdOfCouncil>;
+	type MaxRetryAttempts = MaxFailedMessageRetries;
 	type Message = GatewayMessage<pallet_liquidity_pools::Message, RouterId>;
 	type MessageNonce = LPGatewayQueueMessageNonce;
 	type MessageProcessor = LiquidityPoolsGateway;
+	type RetryBaseDelay = FailedMessageRetryBaseDelay;
 	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const TokenMuxPalletId: PalletId = cfg_types::ids::TOKEN_MUX_PALLET_ID;
+	pub const TokenMuxRoundingPolicy: pallet_token_mux::RoundingPolicy = pallet_token_mux::RoundingPolicy::Down;
+	pub const TokenMuxHaltBurnOnDrift: bool = true;
+	pub const TokenMuxMaxVariants: u32 = 10;
 }
 
 impl pallet_token_mux::Config for Runtime {
 	type AssetRegistry = OrmlAssetRegistry;
+	type Assets = runtime_common::asset_registry::RegisteredAssets<Runtime>;
 	type BalanceIn = Balance;
 	type BalanceOut = Balance;
 	type BalanceRatio = Ratio;
 	type CurrencyId = CurrencyId;
+	type HaltBurnOnDrift = TokenMuxHaltBurnOnDrift;
 	type LocalAssetId = LocalAssetId;
+	type MaxVariants = TokenMuxMaxVariants;
 	type OrderBook = OrderBook;
 	type OrderId = OrderId;
 	type PalletId = TokenMuxPalletId;
+	type RoundingPolicy = TokenMuxRoundingPolicy;
 	type RuntimeEvent = RuntimeEvent;
 	type Tokens = Tokens;
 	type WeightInfo = weights::pallet_token_mux::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxTransferApprovers: u32 = 10;
+	pub const MaxApprovableCallSize: u32 = 1024;
+	pub const MaxApprovableCallWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(10);
+}
+
 impl pallet_transfer_allowlist::Config for Runtime {
+	type ApprovableCalls = ApprovableTransferCalls<Runtime>;
+	type Balance = Balance;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ApprovableTransferCalls<Runtime>;
 	type CurrencyId = FilterCurrency;
 	type Deposit = AllowanceDeposit<Fees>;
 	type Location = RestrictedTransferLocation;
+	type MaxApprovers = MaxTransferApprovers;
+	type MaxCallSize = MaxApprovableCallSize;
+	type MaxCallWeight = MaxApprovableCallWeight;
+	type PalletsOrigin = OriginCaller;
+	type Preimages = Preimage;
 	type ReserveCurrency = Balances;
+	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type Scheduler = Scheduler;
+	type Time = Timestamp;
 	type WeightInfo = weights::pallet_transfer_allowlist::WeightInfo<Runtime>;
 }
 
@@ -2155,6 +2324,7 @@ construct_runtime!(
 		Loans: pallet_loans::{Pallet, Call, Storage, Event<T>} = 187,
 		// Removed: Swaps = 188
 		TokenMux: pallet_token_mux::{Pallet, Call, Storage, Event<T>} = 189,
+		StableSwap: pallet_stable_swap::{Pallet, Call, Storage, Event<T>} = 190,
 	}
 );
 
@@ -2399,6 +2569,10 @@ impl_runtime_apis! {
 		fn expected_cashflows(pool_id: PoolId, loan_id: LoanId) -> Result<Vec<CashflowPayment<Balance>>, DispatchError> {
 			Loans::expected_cashflows(pool_id, loan_id)
 		}
+
+		fn has_frozen_price(pool_id: PoolId, loan_id: LoanId) -> Result<bool, DispatchError> {
+			Loans::has_frozen_price(pool_id, loan_id)
+		}
 	}
 
 	// Investment Runtime APIs
@@ -2408,6 +2582,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	// InvestmentPositionsApi
+	impl runtime_common::apis::InvestmentPositionsApi<Block, AccountId, InvestmentId, InvestmentPosition<Balance, Quantity>> for Runtime {
+		fn investment_positions(account_id: AccountId) -> Vec<(InvestmentId, InvestmentPosition<Balance, Quantity>)> {
+			Investments::investment_positions(&account_id, |(pool_id, tranche_id)| {
+				<Runtime as runtime_common::apis::PoolsApi<Block, PoolId, TrancheId, Balance, CurrencyId, Quantity, MaxTranches>>::tranche_token_price(
+					pool_id,
+					TrancheLoc::Id(tranche_id),
+				)
+			})
+		}
+	}
+
 	// AccountConversionApi
 	impl runtime_common::apis::AccountConversionApi<Block, AccountId> for Runtime {
 		fn conversion_of(location: Location) -> Option<AccountId> {
@@ -2415,6 +2601,62 @@ impl_runtime_apis! {
 		}
 	}
 
+	// GatewayQueueApi
+	impl runtime_common::apis::GatewayQueueApi<
+		Block,
+		LPGatewayQueueMessageNonce,
+		GatewayMessage<pallet_liquidity_pools::Message, RouterId>,
+		BlockNumber,
+	> for Runtime {
+		fn failed_messages(
+			start_after: Option<LPGatewayQueueMessageNonce>,
+			limit: u32,
+		) -> Vec<pallet_liquidity_pools_gateway_queue::FailedMessage<
+			LPGatewayQueueMessageNonce,
+			GatewayMessage<pallet_liquidity_pools::Message, RouterId>,
+			BlockNumber,
+		>> {
+			LiquidityPoolsGatewayQueue::failed_messages(start_after, limit)
+		}
+
+		fn discarded_message_hash(nonce: LPGatewayQueueMessageNonce) -> Option<H256> {
+			LiquidityPoolsGatewayQueue::discarded_messages(nonce)
+		}
+	}
+
+	// LiquidityPoolsApi
+	impl runtime_common::apis::LiquidityPoolsApi<Block, Balance> for Runtime {
+		fn quote_outbound_message(
+			domain: Domain,
+			message: pallet_liquidity_pools::Message,
+		) -> Result<Balance, DispatchError> {
+			LiquidityPools::quote_outbound_fee(domain, &message)
+		}
+	}
+
+	// OracleCollectionApi
+	impl runtime_common::apis::OracleCollectionApi<Block, PoolId, OracleKey, Millis, pallet_oracle_collection::types::Ohlc<Runtime>> for Runtime {
+		fn price_history(
+			collection_id: PoolId,
+			key: OracleKey,
+			from: Millis,
+			to: Millis,
+		) -> Vec<pallet_oracle_collection::types::Ohlc<Runtime>> {
+			OraclePriceCollection::price_history(collection_id, key, from, to)
+		}
+	}
+
+	// OracleFeedApi
+	impl runtime_common::apis::OracleFeedApi<Block, AccountId, Balance> for Runtime {
+		fn feeder_stats(account_id: AccountId) -> pallet_oracle_feed::types::FeederStats<Balance> {
+			OraclePriceFeed::feeder_stats(OriginCaller::system(frame_system::RawOrigin::Signed(account_id)))
+		}
+
+		fn feeder_bond(account_id: AccountId) -> Balance {
+			OraclePriceFeed::bond_of(account_id)
+		}
+	}
+
 	// OrderBookApi
 	impl runtime_common::apis::OrderBookApi<Block, CurrencyId, Balance> for Runtime {
 		fn min_fulfillment_amount(currency_id: CurrencyId) -> Option<Balance> {
@@ -2422,6 +2664,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	// TokenMuxApi
+	impl runtime_common::apis::TokenMuxApi<Block, CurrencyId, Balance> for Runtime {
+		fn local_assets() -> Vec<pallet_token_mux::LocalAssetBacking<CurrencyId, Balance>> {
+			TokenMux::local_assets()
+		}
+	}
+
+	// PermissionsApi
+	impl runtime_common::apis::PermissionsApi<Block, AccountId, PermissionScope<PoolId, CurrencyId>, Role<TrancheId>> for Runtime {
+		fn roles(account_id: AccountId) -> Vec<(PermissionScope<PoolId, CurrencyId>, Vec<(Role<TrancheId>, Option<Seconds>)>)> {
+			Permissions::account_roles(account_id, |roles| roles.roles())
+		}
+	}
+
 	// PoolFeesApi
 	impl runtime_common::apis::PoolFeesApi<Block, PoolId, PoolFeeId, AccountId, Balance, Rate> for Runtime {
 		fn list_fees(pool_id: PoolId) -> Option<cfg_types::pools::PoolFeesList<PoolFeeId, AccountId, Balance, Rate>> {
@@ -2787,6 +3043,9 @@ mod benches {
 		[pallet_remarks, Remarks]
 		[pallet_pool_fees, PoolFees]
 		[pallet_token_mux, TokenMux]
+		[pallet_stable_swap, StableSwap]
+		[pallet_liquidity_pools_gateway, LiquidityPoolsGateway]
+		[pallet_liquidity_pools_gateway_queue, LiquidityPoolsGatewayQueue]
 	);
 }
 
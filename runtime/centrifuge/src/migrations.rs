@@ -17,6 +17,7 @@ use crate::Runtime;
 
 parameter_types! {
 	pub PalletLiquidityPoolsAxelarGateway: &'static str = "LiquidityPoolsAxelarGateway";
+	pub const BlockTimeSecs: cfg_traits::Seconds = cfg_primitives::MILLISECS_PER_BLOCK / 1000;
 }
 
 /// The migration set for Centrifuge @ Polkadot.
@@ -73,6 +74,73 @@ pub type UpgradeCentrifuge1505 = (
 	reset_council::Migration<Runtime>,
 	// Clear voting locks from elections
 	remove_phragmen_votes::Migration<Runtime>,
+	// Add the aggregation strategy to the oracle collection info and the noted changes of it
+	VersionedMigration<
+		1,
+		2,
+		(
+			pallet_oracle_collection::migrations::v2::Migration<Runtime>,
+			runtime_common::migrations::oracle_collection_v2_noted_changes::Migration<Runtime, ()>,
+		),
+		pallet_oracle_collection::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Index the existing orders of the order book to match them
+	VersionedMigration<
+		1,
+		2,
+		pallet_order_book::migrations::v2::Migration<Runtime>,
+		pallet_order_book::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Key the foreign investments by foreign currency
+	VersionedMigration<
+		2,
+		3,
+		pallet_foreign_investments::migrations::v3::Migration<Runtime>,
+		pallet_foreign_investments::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Express the transfer allowlist windows and delays in seconds
+	VersionedMigration<
+		1,
+		2,
+		pallet_transfer_allowlist::migrations::v2::Migration<Runtime, BlockTimeSecs>,
+		pallet_transfer_allowlist::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Index the invest and redeem orders by investment to collect them
+	VersionedMigration<
+		1,
+		2,
+		pallet_investments::migrations::v2::Migration<Runtime>,
+		pallet_investments::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Index the variants of the local representations to check their backing
+	VersionedMigration<
+		1,
+		2,
+		pallet_token_mux::migrations::v2::Migration<Runtime>,
+		pallet_token_mux::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Widen the domain hook addresses to support non-EVM domains
+	VersionedMigration<
+		3,
+		4,
+		runtime_common::migrations::liquidity_pools_gateway_v4::Migration<Runtime>,
+		pallet_liquidity_pools_gateway::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Schedule the automatic retries of the already failed messages
+	VersionedMigration<
+		0,
+		1,
+		pallet_liquidity_pools_gateway_queue::migrations::v1::Migration<Runtime>,
+		pallet_liquidity_pools_gateway_queue::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
 );
 
 mod remove_phragmen_votes {
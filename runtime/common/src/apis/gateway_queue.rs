@@ -0,0 +1,38 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use pallet_liquidity_pools_gateway_queue::FailedMessage;
+use parity_scale_codec::Codec;
+use sp_api::decl_runtime_apis;
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+decl_runtime_apis! {
+	/// Runtime API for the liquidity pools gateway queue pallet.
+	pub trait GatewayQueueApi<MessageNonce, Message, BlockNumber>
+	where
+		MessageNonce: Codec,
+		Message: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns up to `limit` failed messages together with their errors,
+		/// starting after the message with nonce `start_after` if given.
+		fn failed_messages(
+			start_after: Option<MessageNonce>,
+			limit: u32,
+		) -> Vec<FailedMessage<MessageNonce, Message, BlockNumber>>;
+
+		/// Returns the hash of a discarded failed message.
+		fn discarded_message_hash(nonce: MessageNonce) -> Option<H256>;
+	}
+}
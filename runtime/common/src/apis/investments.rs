@@ -24,4 +24,14 @@ decl_runtime_apis! {
 		{
 				fn investment_portfolio(account_id: AccountId) -> Vec<(InvestmentId, InvestmentPortfolio)>;
 		}
+
+		/// Runtime API for the investment positions of an account
+		pub trait InvestmentPositionsApi<AccountId, InvestmentId, InvestmentPosition>
+				where
+				AccountId: Codec,
+				InvestmentId: Codec,
+				InvestmentPosition: Codec,
+		{
+				fn investment_positions(account_id: AccountId) -> Vec<(InvestmentId, InvestmentPosition)>;
+		}
 }
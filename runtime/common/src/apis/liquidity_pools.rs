@@ -0,0 +1,30 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_types::domain_address::Domain;
+use pallet_liquidity_pools::Message;
+use parity_scale_codec::Codec;
+use sp_api::decl_runtime_apis;
+use sp_runtime::DispatchError;
+
+decl_runtime_apis! {
+	/// Runtime API for the liquidity pools pallet.
+	pub trait LiquidityPoolsApi<Balance>
+	where
+		Balance: Codec,
+	{
+		/// Returns the fee in native currency, including the margin, charged
+		/// for sending the message to the domain.
+		fn quote_outbound_message(domain: Domain, message: Message) -> Result<Balance, DispatchError>;
+	}
+}
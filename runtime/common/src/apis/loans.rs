@@ -19,7 +19,7 @@ use sp_std::vec::Vec;
 
 decl_runtime_apis! {
 	/// Runtime API for the rewards pallet.
-	#[api_version(3)]
+	#[api_version(4)]
 	pub trait LoansApi<PoolId, LoanId, Loan, Balance, PriceCollectionInput>
 	where
 		PoolId: Codec,
@@ -32,5 +32,7 @@ decl_runtime_apis! {
 		fn portfolio_loan(pool_id: PoolId, loan_id: LoanId) -> Option<Loan>;
 		fn portfolio_valuation(pool_id: PoolId, input_prices: PriceCollectionInput) -> Result<Balance, DispatchError>;
 		fn expected_cashflows(pool_id: PoolId, loan_id: LoanId) -> Result<Vec<CashflowPayment<Balance>>, DispatchError>;
+		#[api_version(4)]
+		fn has_frozen_price(pool_id: PoolId, loan_id: LoanId) -> Result<bool, DispatchError>;
 	}
 }
@@ -13,18 +13,30 @@
 //! Runtime apis useful in the Centrifuge ecosystem
 pub use account_conversion::*;
 pub use anchors::*;
+pub use gateway_queue::*;
 pub use investments::*;
+pub use liquidity_pools::*;
 pub use loans::*;
+pub use oracle_collection::*;
+pub use oracle_feed::*;
 pub use order_book::*;
+pub use permissions::*;
 pub use pool_fees::*;
 pub use pools::*;
 pub use rewards::*;
+pub use token_mux::*;
 
 mod account_conversion;
 mod anchors;
+mod gateway_queue;
 mod investments;
+mod liquidity_pools;
 mod loans;
+mod oracle_collection;
+mod oracle_feed;
 mod order_book;
+mod permissions;
 mod pool_fees;
 mod pools;
 mod rewards;
+mod token_mux;
@@ -0,0 +1,30 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use parity_scale_codec::Codec;
+use sp_api::decl_runtime_apis;
+use sp_std::vec::Vec;
+
+decl_runtime_apis! {
+	/// Runtime API for the oracle collection pallet.
+	pub trait OracleCollectionApi<CollectionId, OracleKey, Timestamp, Ohlc>
+	where
+		CollectionId: Codec,
+		OracleKey: Codec,
+		Timestamp: Codec,
+		Ohlc: Codec,
+	{
+		/// Returns the price history periods of a key in a collection that
+		/// start between `from` and `to`, both included.
+		fn price_history(collection_id: CollectionId, key: OracleKey, from: Timestamp, to: Timestamp) -> Vec<Ohlc>;
+	}
+}
@@ -0,0 +1,30 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use pallet_oracle_feed::types::FeederStats;
+use parity_scale_codec::Codec;
+use sp_api::decl_runtime_apis;
+
+decl_runtime_apis! {
+	/// Runtime API for the oracle feed pallet.
+	pub trait OracleFeedApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Returns the performance statistics of a signed feeder.
+		fn feeder_stats(account_id: AccountId) -> FeederStats<Balance>;
+
+		/// Returns the amount bonded by a feeder.
+		fn feeder_bond(account_id: AccountId) -> Balance;
+	}
+}
@@ -0,0 +1,30 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use pallet_token_mux::LocalAssetBacking;
+use parity_scale_codec::Codec;
+use sp_api::decl_runtime_apis;
+use sp_std::vec::Vec;
+
+decl_runtime_apis! {
+	/// Runtime API for the token mux pallet.
+	pub trait TokenMuxApi<CurrencyId, Balance>
+	where
+		CurrencyId: Codec,
+		Balance: Codec,
+	{
+		/// Returns each local asset with its outstanding amount and the
+		/// backing held for each of its variants.
+		fn local_assets() -> Vec<LocalAssetBacking<CurrencyId, Balance>>;
+	}
+}
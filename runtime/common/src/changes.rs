@@ -1,4 +1,5 @@
 use frame_support::pallet_prelude::RuntimeDebug;
+use pallet_liquidity_pools::Change as LiquidityPoolsChange;
 use pallet_loans::entities::changes::Change as LoansChange;
 use pallet_oracle_collection::types::Change as OracleCollectionChange;
 use pallet_pool_fees::types::Change as PoolFeesChange;
@@ -10,11 +11,18 @@ use sp_std::{marker::PhantomData, vec::Vec};
 
 /// Auxiliar type to carry all pallets bounds used by RuntimeChange
 pub trait Changeable:
-	pallet_loans::Config + pallet_oracle_collection::Config + pallet_pool_fees::Config
+	pallet_loans::Config
+	+ pallet_oracle_collection::Config
+	+ pallet_pool_fees::Config
+	+ pallet_liquidity_pools::Config
 {
 }
-impl<T: pallet_loans::Config + pallet_oracle_collection::Config + pallet_pool_fees::Config>
-	Changeable for T
+impl<
+		T: pallet_loans::Config
+			+ pallet_oracle_collection::Config
+			+ pallet_pool_fees::Config
+			+ pallet_liquidity_pools::Config,
+	> Changeable for T
 {
 }
 
@@ -24,6 +32,7 @@ pub enum RuntimeChange<T: Changeable, Options: Clone = ()> {
 	Loans(LoansChange<T>),
 	OracleCollection(OracleCollectionChange<T>),
 	PoolFee(PoolFeesChange<T>),
+	LiquidityPools(LiquidityPoolsChange<T>),
 	_Unreachable(PhantomData<Options>),
 }
 
@@ -65,10 +74,14 @@ impl<T: Changeable, Options: Clone> RuntimeChange<T, Options> {
 			},
 			RuntimeChange::OracleCollection(change) => match change {
 				OracleCollectionChange::CollectionInfo(_) => vec![],
+				OracleCollectionChange::UnfreezeKey(_) => vec![],
 			},
 			RuntimeChange::PoolFee(pool_fees_change) => match pool_fees_change {
 				PoolFeesChange::AppendFee(_, _, _) => vec![week],
 			},
+			RuntimeChange::LiquidityPools(change) => match change {
+				LiquidityPoolsChange::Recovery(_) => vec![week],
+			},
 			RuntimeChange::_Unreachable(_) => vec![],
 		}
 	}
@@ -125,3 +138,4 @@ macro_rules! runtime_change_support {
 runtime_change_support!(LoansChange, Loans);
 runtime_change_support!(OracleCollectionChange, OracleCollection);
 runtime_change_support!(PoolFeesChange, PoolFee);
+runtime_change_support!(LiquidityPoolsChange, LiquidityPools);
@@ -228,7 +228,7 @@ pub mod asset_registry {
 	use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 	use scale_info::TypeInfo;
 	use sp_runtime::DispatchError;
-	use sp_std::marker::PhantomData;
+	use sp_std::{marker::PhantomData, vec::Vec};
 
 	#[derive(
 		Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, MaxEncodedLen,
@@ -289,6 +289,28 @@ pub mod asset_registry {
 			Err(())
 		}
 	}
+
+	/// All the assets registered in the asset registry
+	pub struct RegisteredAssets<T>(PhantomData<T>);
+
+	impl<T: orml_asset_registry::module::Config<AssetId = CurrencyId>>
+		pallet_token_mux::AssetsWalk<CurrencyId> for RegisteredAssets<T>
+	{
+		fn next(previous: Option<&CurrencyId>) -> Option<CurrencyId> {
+			use orml_asset_registry::module::Metadata;
+
+			match previous {
+				Some(asset) => {
+					Metadata::<T>::iter_keys_from(Metadata::<T>::hashed_key_for(asset)).next()
+				}
+				None => Metadata::<T>::iter_keys().next(),
+			}
+		}
+
+		fn all() -> Vec<CurrencyId> {
+			orml_asset_registry::module::Metadata::<T>::iter_keys().collect()
+		}
+	}
 }
 
 /// Module for investment portfolio common to all runtimes
@@ -0,0 +1,75 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use pallet_liquidity_pools_gateway::DomainHookAddress;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+const LOG_PREFIX: &str = "LpGatewayV4";
+
+/// Widens the stored domain hook addresses to 32 bytes, keeping the EVM
+/// hooks in the first 20 bytes.
+pub struct Migration<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for Migration<T>
+where
+	T: pallet_liquidity_pools_gateway::Config,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut count: u64 = 0;
+
+		DomainHookAddress::<T>::translate::<[u8; 20], _>(|_, hook| {
+			count += 1;
+
+			let mut bytes = [0; 32];
+			bytes[..20].copy_from_slice(&hook);
+			Some(bytes)
+		});
+
+		log::info!("{LOG_PREFIX}: Migrated {count} domain hook addresses");
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		use parity_scale_codec::Encode;
+
+		let count = DomainHookAddress::<T>::iter_keys().count() as u64;
+
+		log::info!("{LOG_PREFIX}: Pre checks done!");
+
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use parity_scale_codec::Decode;
+
+		let count = u64::decode(&mut state.as_slice())
+			.map_err(|_| sp_runtime::TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+
+		frame_support::ensure!(
+			DomainHookAddress::<T>::iter().count() as u64 == count,
+			"LpGatewayV4: Domain hook addresses lost"
+		);
+
+		log::info!("{LOG_PREFIX}: Post checks done!");
+
+		Ok(())
+	}
+}
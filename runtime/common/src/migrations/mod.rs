@@ -15,12 +15,15 @@
 pub mod asset_registry_local_usdc_dai_usds;
 pub mod foreign_investments_v2;
 pub mod increase_storage_version;
+pub mod liquidity_pools_gateway_v4;
 pub mod liquidity_pools_v2;
 pub mod nuke;
+pub mod oracle_collection_v2_noted_changes;
 pub mod permissions_v1;
 pub mod precompile_account_codes;
 pub mod restricted_location;
 pub mod technical_comittee;
+pub mod tranche_holders;
 
 pub mod utils {
 	use frame_support::storage::unhashed;
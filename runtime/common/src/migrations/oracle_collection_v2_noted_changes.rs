@@ -0,0 +1,110 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_traits::Seconds;
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use pallet_liquidity_pools::Change as LiquidityPoolsChange;
+use pallet_loans::entities::changes::Change as LoansChange;
+use pallet_oracle_collection::migrations::v2::ChangeV1 as OracleCollectionChangeV1;
+use pallet_pool_fees::types::Change as PoolFeesChange;
+use pallet_pool_system::{pool_types::changes::NotedPoolChange, NotedChange};
+use parity_scale_codec::Decode;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::changes::{Changeable, RuntimeChange};
+
+const LOG_PREFIX: &str = "OracleCollectionV2NotedChanges";
+
+#[derive(Decode)]
+enum RuntimeChangeV1<T: Changeable, Options> {
+	Loans(LoansChange<T>),
+	OracleCollection(OracleCollectionChangeV1<T>),
+	PoolFee(PoolFeesChange<T>),
+	LiquidityPools(LiquidityPoolsChange<T>),
+	_Unreachable(PhantomData<Options>),
+}
+
+#[derive(Decode)]
+struct NotedPoolChangeV1<T: Changeable, Options> {
+	submitted_time: Seconds,
+	change: RuntimeChangeV1<T, Options>,
+}
+
+/// Migrates the oracle collection changes noted but not yet applied to the
+/// collection info with an aggregation strategy. It must run along with
+/// `pallet_oracle_collection::migrations::v2::Migration`.
+pub struct Migration<T, Options>(PhantomData<(T, Options)>);
+
+impl<T, Options> OnRuntimeUpgrade for Migration<T, Options>
+where
+	T: Changeable + pallet_pool_system::Config<RuntimeChange = RuntimeChange<T, Options>>,
+	Options: Clone,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut count: u64 = 0;
+
+		NotedChange::<T>::translate::<NotedPoolChangeV1<T, Options>, _>(|_, _, noted| {
+			count += 1;
+
+			let change = match noted.change {
+				RuntimeChangeV1::Loans(change) => RuntimeChange::Loans(change),
+				RuntimeChangeV1::OracleCollection(change) => {
+					RuntimeChange::OracleCollection(change.migrate())
+				}
+				RuntimeChangeV1::PoolFee(change) => RuntimeChange::PoolFee(change),
+				RuntimeChangeV1::LiquidityPools(change) => RuntimeChange::LiquidityPools(change),
+				RuntimeChangeV1::_Unreachable(options) => RuntimeChange::_Unreachable(options),
+			};
+
+			Some(NotedPoolChange {
+				submitted_time: noted.submitted_time,
+				change,
+			})
+		});
+
+		log::info!("{LOG_PREFIX}: Migrated {count} noted changes");
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		use parity_scale_codec::Encode;
+
+		let count = NotedChange::<T>::iter_keys().count() as u64;
+
+		log::info!("{LOG_PREFIX}: Pre checks done!");
+
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut state.as_slice())
+			.map_err(|_| sp_runtime::TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+
+		// Every noted change decodes with the new collection info
+		frame_support::ensure!(
+			NotedChange::<T>::iter().count() as u64 == count,
+			"OracleCollectionV2NotedChanges: Noted changes lost"
+		);
+
+		log::info!("{LOG_PREFIX}: Post checks done!");
+
+		Ok(())
+	}
+}
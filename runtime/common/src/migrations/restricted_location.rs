@@ -16,8 +16,7 @@ mod old {
 	use cfg_primitives::AccountId;
 	use cfg_types::{domain_address::DomainAddress, tokens::FilterCurrency};
 	use frame_support::{pallet_prelude::*, storage_alias};
-	use frame_system::pallet_prelude::*;
-	use pallet_transfer_allowlist::AllowanceDetails;
+	use pallet_transfer_allowlist::AllowanceDetailsOf;
 	use sp_core::H256;
 	use staging_xcm::v3;
 
@@ -45,7 +44,7 @@ mod old {
 			NMapKey<Twox64Concat, FilterCurrency>,
 			NMapKey<Blake2_128Concat, RestrictedTransferLocation>,
 		),
-		AllowanceDetails<BlockNumberFor<T>>,
+		AllowanceDetailsOf<T>,
 		OptionQuery,
 	>;
 
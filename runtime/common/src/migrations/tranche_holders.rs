@@ -0,0 +1,67 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_primitives::{AccountId, Balance, PoolId, TrancheId};
+use cfg_types::tokens::CurrencyId;
+use frame_support::{
+	traits::{Contains, Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+const LOG_PREFIX: &str = "SeedTrancheHolders:";
+
+/// Seeds the number of holders of each tranche in the KYC pallet from the
+/// existing tranche token accounts, excluding the system accounts.
+///
+/// NOTE: The counts are recomputed from scratch, so running it again is
+/// harmless. Acquisition times are not seeded, existing holders have no
+/// holding period.
+pub struct Migration<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for Migration<T>
+where
+	T: pallet_kyc::Config<PoolId = PoolId, TrancheId = TrancheId, AccountId = AccountId>
+		+ orml_tokens::Config<CurrencyId = CurrencyId, Balance = Balance>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads: u64 = 0;
+		let mut holders = BTreeMap::<(PoolId, TrancheId), u32>::new();
+
+		for (who, currency_id, account) in orml_tokens::Accounts::<T>::iter() {
+			reads += 1;
+
+			let CurrencyId::Tranche(pool_id, tranche_id) = currency_id else {
+				continue;
+			};
+
+			if account.free.saturating_add(account.reserved).is_zero()
+				|| <T as pallet_kyc::Config>::SystemAccounts::contains(&who)
+			{
+				continue;
+			}
+
+			let count = holders.entry((pool_id, tranche_id)).or_default();
+			*count = count.saturating_add(1);
+		}
+
+		let writes = holders.len() as u64;
+
+		for ((pool_id, tranche_id), count) in holders {
+			log::info!("{LOG_PREFIX} Tranche {tranche_id:?} of pool {pool_id} has {count} holders");
+			pallet_kyc::Pallet::<T>::seed_holder_count(pool_id, tranche_id, count);
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -2,7 +2,7 @@ use cfg_primitives::{
 	conversion::fixed_point_to_balance,
 	types::{AccountId, Balance, PoolId},
 };
-use cfg_traits::{HasLocalAssetRepresentation, Millis, PoolInspect, ValueProvider};
+use cfg_traits::{FeederAccuracy, HasLocalAssetRepresentation, Millis, PoolInspect, ValueProvider};
 use cfg_types::{
 	fixed_point::{Quantity, Ratio},
 	oracles::OracleKey,
@@ -13,8 +13,8 @@ use orml_traits::asset_registry;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::One;
-use sp_runtime::{traits::EnsureInto, DispatchError};
-use sp_std::marker::PhantomData;
+use sp_runtime::{traits::EnsureInto, DispatchError, Perbill};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 #[derive(Clone, RuntimeDebugNoBound, TypeInfo, Encode, Decode, MaxEncodedLen)]
 #[scale_info(skip_type_params(O))]
@@ -115,6 +115,25 @@ where
 		}
 	}
 
+	fn history(
+		(feeder, pool_id): &(Feeder<Origin>, PoolId),
+		key: &OracleKey,
+	) -> Result<Vec<Self::Value>, DispatchError> {
+		let history = Provider::history(&feeder.0.clone().into(), key)?;
+		if history.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let decimals = decimals_for_pool::<Pools, AssetRegistry>(*pool_id)?.ensure_into()?;
+
+		history
+			.into_iter()
+			.map(|(quantity, timestamp)| {
+				Ok((fixed_point_to_balance(quantity, decimals)?, timestamp))
+			})
+			.collect()
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn set(
 		(feeder, pool_id): &(Feeder<Origin>, PoolId),
@@ -134,6 +153,21 @@ where
 	}
 }
 
+/// A bridge that rates the values of a feeder against the collections it
+/// feeds.
+pub struct FeederAccuracyBridge<Origin, Collections>(PhantomData<(Origin, Collections)>);
+
+impl<Origin, Collections, Key> FeederAccuracy<Origin::PalletsOrigin, Key>
+	for FeederAccuracyBridge<Origin, Collections>
+where
+	Origin: OriginTrait,
+	Collections: FeederAccuracy<Feeder<Origin>, Key>,
+{
+	fn deviation(feeder: &Origin::PalletsOrigin, key: &Key) -> Option<Perbill> {
+		Collections::deviation(&Feeder(feeder.clone()), key)
+	}
+}
+
 /// A provider to get ratio values from currency pairs
 pub struct OracleRatioProvider<Origin, Provider>(PhantomData<(Origin, Provider)>);
 
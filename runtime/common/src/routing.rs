@@ -1,5 +1,6 @@
 use cfg_traits::liquidity_pools::{
-	LpMessageSerializer, MessageReceiver, MessageSender, RouterProvider,
+	GasPriceUpdater, LpMessageSerializer, MessageFeeQuoter, MessageReceiver, MessageSender,
+	RouterProvider,
 };
 use cfg_types::domain_address::{Domain, DomainAddress};
 use frame_support::{
@@ -8,6 +9,8 @@ use frame_support::{
 };
 pub use pallet_axelar_router::AxelarId;
 use pallet_liquidity_pools::Message;
+use sp_core::U256;
+use sp_runtime::DispatchError;
 use sp_std::{marker::PhantomData, vec, vec::Vec};
 
 /// Identification of the router where the messages are sent and received.
@@ -17,7 +20,8 @@ use sp_std::{marker::PhantomData, vec, vec::Vec};
 /// to reach them.
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum RouterId {
-	/// The message must be sent/received by EVM using Axelar
+	/// The message must be sent/received by EVM using Axelar, whether the
+	/// other end is an EVM chain or not
 	Axelar(AxelarId),
 }
 
@@ -31,6 +35,7 @@ impl From<RouterId> for Domain {
 	fn from(router_id: RouterId) -> Self {
 		match router_id {
 			RouterId::Axelar(AxelarId::Evm(chain_id)) => Domain::Evm(chain_id),
+			RouterId::Axelar(AxelarId::Other(chain_id)) => Domain::Other(chain_id),
 		}
 	}
 }
@@ -44,6 +49,7 @@ impl RouterProvider<Domain> for LPGatewayRouterProvider {
 	fn routers_for_domain(domain: Domain) -> Vec<Self::RouterId> {
 		match domain {
 			Domain::Evm(chain_id) => vec![RouterId::Axelar(AxelarId::Evm(chain_id))],
+			Domain::Other(chain_id) => vec![RouterId::Axelar(AxelarId::Other(chain_id))],
 			Domain::Centrifuge => vec![],
 		}
 	}
@@ -68,6 +74,39 @@ where
 	}
 }
 
+impl<Routers> MessageFeeQuoter for RouterDispatcher<Routers>
+where
+	Routers: pallet_axelar_router::Config,
+{
+	type Balance = U256;
+	type Destination = RouterId;
+	type Message = Vec<u8>;
+
+	fn quote(router_id: &RouterId, message: &Self::Message) -> Result<U256, DispatchError> {
+		match router_id {
+			RouterId::Axelar(axelar_id) => {
+				pallet_axelar_router::Pallet::<Routers>::quote(axelar_id, message)
+			}
+		}
+	}
+}
+
+impl<Routers> GasPriceUpdater for RouterDispatcher<Routers>
+where
+	Routers: pallet_axelar_router::Config,
+{
+	type Destination = RouterId;
+	type Price = U256;
+
+	fn update_gas_price(router_id: &RouterId, price: U256) -> DispatchResult {
+		match router_id {
+			RouterId::Axelar(axelar_id) => {
+				pallet_axelar_router::Pallet::<Routers>::update_gas_price(axelar_id, price)
+			}
+		}
+	}
+}
+
 /// Entity in charge of serializing and deserializing messages
 pub struct MessageSerializer<Sender, Receiver>(PhantomData<(Sender, Receiver)>);
 
@@ -88,6 +127,31 @@ where
 	}
 }
 
+impl<Sender, Receiver> MessageFeeQuoter for MessageSerializer<Sender, Receiver>
+where
+	Sender: MessageFeeQuoter<Message = Vec<u8>, Destination = RouterId, Balance = U256>,
+{
+	type Balance = U256;
+	type Destination = RouterId;
+	type Message = Message;
+
+	fn quote(router_id: &RouterId, message: &Self::Message) -> Result<U256, DispatchError> {
+		Sender::quote(router_id, &message.serialize())
+	}
+}
+
+impl<Sender, Receiver> GasPriceUpdater for MessageSerializer<Sender, Receiver>
+where
+	Sender: GasPriceUpdater<Destination = RouterId, Price = U256>,
+{
+	type Destination = RouterId;
+	type Price = U256;
+
+	fn update_gas_price(router_id: &RouterId, price: U256) -> DispatchResult {
+		Sender::update_gas_price(router_id, price)
+	}
+}
+
 impl<Sender, Receiver> MessageReceiver for MessageSerializer<Sender, Receiver>
 where
 	Receiver: MessageReceiver<Middleware = RouterId, Origin = Domain, Message = Message>,
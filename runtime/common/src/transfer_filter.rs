@@ -10,26 +10,40 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_primitives::{AccountId, Balance};
+use cfg_primitives::{AccountId, Balance, PoolId, TrancheId};
 use cfg_traits::{PreConditions, TransferAllowance};
 use cfg_types::{
 	domain_address::DomainAddress,
+	ids::DOMAIN_ID,
 	locations::RestrictedTransferLocation,
 	tokens::{CurrencyId, FilterCurrency},
 };
-use frame_support::{traits::IsSubType, RuntimeDebugNoBound};
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{
+		fungible,
+		fungibles::Inspect,
+		tokens::{Fortitude, Preservation},
+		Contains, IsSubType,
+	},
+	RuntimeDebugNoBound,
+};
+use orml_traits::{
+	currency::{OnDeposit, OnTransfer},
+	Happened,
+};
 use pallet_restricted_tokens::TransferDetails;
 use pallet_restricted_xtokens::TransferEffects;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{Convert, DispatchInfoOf, SignedExtension, StaticLookup},
+	traits::{Convert, DispatchInfoOf, PostDispatchInfoOf, SignedExtension, StaticLookup, Zero},
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
 	DispatchError, DispatchResult, TokenError,
 };
 use sp_std::{boxed::Box, vec::Vec};
 use staging_xcm::{
-	v4::{Asset, Location},
+	v4::{Asset, Fungibility, Location},
 	VersionedLocation,
 };
 
@@ -38,6 +52,7 @@ pub struct PreXcmTransfer<T, C>(sp_std::marker::PhantomData<(T, C)>);
 impl<
 		T: TransferAllowance<
 			AccountId,
+			Balance = Balance,
 			CurrencyId = FilterCurrency,
 			Location = RestrictedTransferLocation,
 		>,
@@ -47,26 +62,35 @@ impl<
 	type Result = DispatchResult;
 
 	fn check(t: TransferEffects<AccountId, CurrencyId, Balance>) -> Self::Result {
-		let currency_based_check = |sender: AccountId, destination: VersionedLocation, currency| {
-			amalgamate_allowance(
-				T::allowance(
-					sender.clone(),
-					RestrictedTransferLocation::Xcm(Box::new(destination.clone())),
-					FilterCurrency::Specific(currency),
-				),
-				T::allowance(
-					sender,
-					RestrictedTransferLocation::Xcm(Box::new(destination)),
-					FilterCurrency::All,
-				),
-			)
-		};
+		let currency_based_check =
+			|sender: AccountId, destination: VersionedLocation, currency, amount| {
+				ensure_approved::<T>(sender.clone(), currency, amount)?;
+
+				amalgamate_allowance::<T>(
+					T::allowance(
+						sender.clone(),
+						RestrictedTransferLocation::Xcm(Box::new(destination.clone())),
+						FilterCurrency::Specific(currency),
+						amount,
+					),
+					T::allowance(
+						sender,
+						RestrictedTransferLocation::Xcm(Box::new(destination)),
+						FilterCurrency::All,
+						amount,
+					),
+				)
+			};
 
 		let asset_based_check = |sender, destination, asset: Asset| {
+			let amount = match asset.fun {
+				Fungibility::Fungible(amount) => amount,
+				Fungibility::NonFungible(_) => Balance::zero(),
+			};
 			let currency =
 				C::convert(asset.id.0).ok_or(DispatchError::Token(TokenError::UnknownAsset))?;
 
-			currency_based_check(sender, destination, currency)
+			currency_based_check(sender, destination, currency, amount)
 		};
 
 		match t {
@@ -74,8 +98,8 @@ impl<
 				sender,
 				destination,
 				currency_id,
-				..
-			} => currency_based_check(sender, destination, currency_id),
+				amount,
+			} => currency_based_check(sender, destination, currency_id, amount),
 			TransferEffects::TransferMultiAsset {
 				sender,
 				destination,
@@ -85,8 +109,9 @@ impl<
 				sender,
 				destination,
 				currency_id,
-				..
-			} => currency_based_check(sender, destination, currency_id),
+				amount,
+				fee,
+			} => currency_based_check(sender, destination, currency_id, amount.saturating_add(fee)),
 			TransferEffects::TransferMultiAssetWithFee {
 				sender,
 				destination,
@@ -107,15 +132,15 @@ impl<
 				currencies,
 				fee,
 			} => {
-				for (currency, ..) in currencies {
-					currency_based_check(sender.clone(), destination.clone(), currency)?;
+				for (currency, amount) in currencies {
+					currency_based_check(sender.clone(), destination.clone(), currency, amount)?;
 				}
 
 				// NOTE: We do check the fee asset and assume that the destination
 				//       is the same as for the actual assets. This is a pure subjective
 				//       security assumption to not allow randomly burning fees of
 				//       protected assets.
-				currency_based_check(sender, destination, fee.0)
+				currency_based_check(sender, destination, fee.0, fee.1)
 			}
 			TransferEffects::TransferMultiAssets {
 				sender,
@@ -145,6 +170,7 @@ pub struct PreNativeTransfer<T>(sp_std::marker::PhantomData<T>);
 impl<
 		T: TransferAllowance<
 			AccountId,
+			Balance = Balance,
 			CurrencyId = FilterCurrency,
 			Location = RestrictedTransferLocation,
 		>,
@@ -153,19 +179,24 @@ impl<
 	type Result = bool;
 
 	fn check(t: TransferDetails<AccountId, CurrencyId, Balance>) -> Self::Result {
-		amalgamate_allowance(
-			T::allowance(
-				t.send.clone(),
-				RestrictedTransferLocation::Local(t.recv.clone()),
-				FilterCurrency::Specific(t.id),
-			),
-			T::allowance(
-				t.send.clone(),
-				RestrictedTransferLocation::Local(t.recv.clone()),
-				FilterCurrency::All,
-			),
-		)
-		.is_ok()
+		ensure_approved::<T>(t.send.clone(), t.id, t.amount)
+			.and_then(|_| {
+				amalgamate_allowance::<T>(
+					T::allowance(
+						t.send.clone(),
+						RestrictedTransferLocation::Local(t.recv.clone()),
+						FilterCurrency::Specific(t.id),
+						t.amount,
+					),
+					T::allowance(
+						t.send.clone(),
+						RestrictedTransferLocation::Local(t.recv.clone()),
+						FilterCurrency::All,
+						t.amount,
+					),
+				)
+			})
+			.is_ok()
 	}
 }
 pub struct PreLpTransfer<T>(sp_std::marker::PhantomData<T>);
@@ -173,31 +204,141 @@ pub struct PreLpTransfer<T>(sp_std::marker::PhantomData<T>);
 impl<
 		T: TransferAllowance<
 			AccountId,
+			Balance = Balance,
 			CurrencyId = FilterCurrency,
 			Location = RestrictedTransferLocation,
 		>,
-	> PreConditions<(AccountId, DomainAddress, CurrencyId)> for PreLpTransfer<T>
+	> PreConditions<(AccountId, DomainAddress, CurrencyId, Balance)> for PreLpTransfer<T>
 {
 	type Result = DispatchResult;
 
-	fn check(t: (AccountId, DomainAddress, CurrencyId)) -> Self::Result {
-		let (sender, receiver, currency) = t;
+	fn check(t: (AccountId, DomainAddress, CurrencyId, Balance)) -> Self::Result {
+		let (sender, receiver, currency, amount) = t;
+		ensure_approved::<T>(sender.clone(), currency, amount)?;
+
 		// NOTE: The order of the allowance check here is
-		amalgamate_allowance(
+		amalgamate_allowance::<T>(
 			T::allowance(
 				sender.clone(),
 				RestrictedTransferLocation::Address(receiver.clone()),
 				FilterCurrency::Specific(currency),
+				amount,
 			),
 			T::allowance(
 				sender,
 				RestrictedTransferLocation::Address(receiver),
 				FilterCurrency::All,
+				amount,
 			),
 		)
 	}
 }
 
+/// The accounts derived for pallets, pools, investments, domains and sibling
+/// or parent chains, which hold tranche tokens on behalf of investors.
+pub struct TrancheSystemAccounts;
+
+impl Contains<AccountId> for TrancheSystemAccounts {
+	fn contains(who: &AccountId) -> bool {
+		const PREFIXES: [&[u8; 4]; 6] = [b"modl", b"pool", b"invs", &DOMAIN_ID, b"sibl", b"para"];
+
+		let who: &[u8] = who.as_ref();
+		PREFIXES.iter().any(|prefix| who.starts_with(*prefix))
+	}
+}
+
+/// Checks every transfer of tranche tokens against the compliance rules of
+/// the tranche, set through the KYC pallet. Used as the pre transfer hook of
+/// the tokens, which covers transfers through extrinsics, liquidity pools,
+/// xcm and investment collects.
+pub struct TrancheTransferRules<T>(sp_std::marker::PhantomData<T>);
+
+impl<T> OnTransfer<AccountId, CurrencyId, Balance> for TrancheTransferRules<T>
+where
+	T: pallet_kyc::Config<PoolId = PoolId, TrancheId = TrancheId, Balance = Balance>
+		+ orml_tokens::Config<CurrencyId = CurrencyId, Balance = Balance>,
+{
+	fn on_transfer(
+		currency_id: CurrencyId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		let CurrencyId::Tranche(pool_id, tranche_id) = currency_id else {
+			return Ok(());
+		};
+
+		pallet_kyc::Pallet::<T>::check_transfer(
+			pool_id,
+			tranche_id,
+			from,
+			to,
+			amount,
+			<orml_tokens::Pallet<T> as Inspect<AccountId>>::balance(currency_id, from),
+			<orml_tokens::Pallet<T> as Inspect<AccountId>>::balance(currency_id, to),
+		)
+	}
+}
+
+/// Notes new holders of tranche tokens in the KYC pallet.
+pub struct TrancheHolderAdded<T>(sp_std::marker::PhantomData<T>);
+
+impl<T> Happened<(AccountId, CurrencyId)> for TrancheHolderAdded<T>
+where
+	T: pallet_kyc::Config<PoolId = PoolId, TrancheId = TrancheId>,
+{
+	fn happened((who, currency_id): &(AccountId, CurrencyId)) {
+		if let CurrencyId::Tranche(pool_id, tranche_id) = *currency_id {
+			pallet_kyc::Pallet::<T>::note_holder_added(pool_id, tranche_id, who);
+		}
+	}
+}
+
+/// Notes holders of tranche tokens leaving in the KYC pallet.
+pub struct TrancheHolderRemoved<T>(sp_std::marker::PhantomData<T>);
+
+impl<T> Happened<(AccountId, CurrencyId)> for TrancheHolderRemoved<T>
+where
+	T: pallet_kyc::Config<PoolId = PoolId, TrancheId = TrancheId>,
+{
+	fn happened((who, currency_id): &(AccountId, CurrencyId)) {
+		if let CurrencyId::Tranche(pool_id, tranche_id) = *currency_id {
+			pallet_kyc::Pallet::<T>::note_holder_removed(pool_id, tranche_id, who);
+		}
+	}
+}
+
+/// Notes acquisitions of tranche tokens in the KYC pallet, starting the
+/// holding period of a receiver not holding tokens yet.
+pub struct TrancheAcquired<T>(sp_std::marker::PhantomData<T>);
+
+impl<T> OnDeposit<AccountId, CurrencyId, Balance> for TrancheAcquired<T>
+where
+	T: pallet_kyc::Config<PoolId = PoolId, TrancheId = TrancheId>,
+{
+	fn on_deposit(currency_id: CurrencyId, who: &AccountId, _: Balance) -> DispatchResult {
+		if let CurrencyId::Tranche(pool_id, tranche_id) = currency_id {
+			pallet_kyc::Pallet::<T>::note_acquired(pool_id, tranche_id, who);
+		}
+
+		Ok(())
+	}
+}
+
+impl<T> OnTransfer<AccountId, CurrencyId, Balance> for TrancheAcquired<T>
+where
+	T: pallet_kyc::Config<PoolId = PoolId, TrancheId = TrancheId>,
+{
+	fn on_transfer(
+		currency_id: CurrencyId,
+		_: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		<Self as OnDeposit<AccountId, CurrencyId, Balance>>::on_deposit(currency_id, to, amount)
+	}
+}
+
 // NOTE: This code here is really critical. The test are resided in the
 // integration tests section for this reason. The importance is, that
 // nobody is able to create a call that can possibly bypass this filtering.
@@ -211,7 +352,7 @@ pub struct PreBalanceTransferExtension<T: frame_system::Config>(sp_std::marker::
 impl<T> PreBalanceTransferExtension<T>
 where
 	T: frame_system::Config<AccountId = AccountId>
-		+ pallet_balances::Config
+		+ pallet_balances::Config<Balance = Balance>
 		+ pallet_utility::Config<RuntimeCall = <T as frame_system::Config>::RuntimeCall>
 		+ pallet_proxy::Config<RuntimeCall = <T as frame_system::Config>::RuntimeCall>
 		+ pallet_remarks::Config<RuntimeCall = <T as frame_system::Config>::RuntimeCall>
@@ -230,25 +371,34 @@ where
 	fn retrieve(
 		caller: &T::AccountId,
 		call: &<T as frame_system::Config>::RuntimeCall,
-	) -> Result<Vec<(T::AccountId, T::AccountId)>, TransactionValidityError> {
+	) -> Result<Vec<(T::AccountId, T::AccountId, Balance)>, TransactionValidityError> {
 		Self::recursive_search(caller.clone(), call, |who, balance_call, checks| {
-			match balance_call {
-				pallet_balances::Call::transfer_all { dest, .. }
-				| pallet_balances::Call::transfer_allow_death { dest, .. }
-				| pallet_balances::Call::transfer_keep_alive { dest, .. } => {
-					let recv: T::AccountId = <T as frame_system::Config>::Lookup::lookup(
-						dest.clone(),
-					)
-					.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Call))?;
-
-					checks.push((who, recv));
-					Ok(())
+			let (dest, amount) = match balance_call {
+				pallet_balances::Call::transfer_all { dest, keep_alive } => {
+					let preservation = if keep_alive {
+						Preservation::Preserve
+					} else {
+						Preservation::Expendable
+					};
+					let amount = <pallet_balances::Pallet<T> as fungible::Inspect<
+						T::AccountId,
+					>>::reducible_balance(&who, preservation, Fortitude::Polite);
+
+					(dest, amount)
 				}
+				pallet_balances::Call::transfer_allow_death { dest, value }
+				| pallet_balances::Call::transfer_keep_alive { dest, value } => (dest, value),
 
 				// If the call is not a transfer we are fine with it to go through without
 				// further checks
-				_ => Ok(()),
-			}
+				_ => return Ok(()),
+			};
+
+			let recv: T::AccountId = <T as frame_system::Config>::Lookup::lookup(dest)
+				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Call))?;
+
+			checks.push((who, recv, amount));
+			Ok(())
 		})
 	}
 
@@ -259,12 +409,12 @@ where
 		caller: T::AccountId,
 		call: &<T as frame_system::Config>::RuntimeCall,
 		check: F,
-	) -> Result<Vec<(T::AccountId, T::AccountId)>, TransactionValidityError>
+	) -> Result<Vec<(T::AccountId, T::AccountId, Balance)>, TransactionValidityError>
 	where
 		F: Fn(
 				T::AccountId,
 				pallet_balances::Call<T>,
-				&mut Vec<(T::AccountId, T::AccountId)>,
+				&mut Vec<(T::AccountId, T::AccountId, Balance)>,
 			) -> Result<(), TransactionValidityError>
 			+ Clone,
 	{
@@ -313,11 +463,12 @@ where
 impl<T> SignedExtension for PreBalanceTransferExtension<T>
 where
 	T: frame_system::Config<AccountId = AccountId>
-		+ pallet_balances::Config
+		+ pallet_balances::Config<Balance = Balance>
 		+ pallet_utility::Config<RuntimeCall = <T as frame_system::Config>::RuntimeCall>
 		+ pallet_proxy::Config<RuntimeCall = <T as frame_system::Config>::RuntimeCall>
 		+ pallet_remarks::Config<RuntimeCall = <T as frame_system::Config>::RuntimeCall>
 		+ pallet_transfer_allowlist::Config<
+			Balance = Balance,
 			CurrencyId = FilterCurrency,
 			Location = RestrictedTransferLocation,
 		> + Sync
@@ -330,7 +481,7 @@ where
 	type AccountId = T::AccountId;
 	type AdditionalSigned = ();
 	type Call = <T as frame_system::Config>::RuntimeCall;
-	type Pre = ();
+	type Pre = Vec<(T::AccountId, T::AccountId, Balance)>;
 
 	const IDENTIFIER: &'static str = "PreBalanceTransferExtension";
 
@@ -345,31 +496,161 @@ where
 		_: &DispatchInfoOf<Self::Call>,
 		_: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		Self::retrieve(who, call)?
-			.iter()
-			.try_for_each(|(who, recv)| {
-				amalgamate_allowance(
-					pallet_transfer_allowlist::pallet::Pallet::<T>::allowance(
-						who.clone(),
-						RestrictedTransferLocation::Local(recv.clone()),
-						FilterCurrency::All,
-					),
-					pallet_transfer_allowlist::pallet::Pallet::<T>::allowance(
-						who.clone(),
-						RestrictedTransferLocation::Local(recv.clone()),
-						FilterCurrency::Specific(CurrencyId::Native),
-					),
+		let checks = Self::retrieve(who, call)?;
+
+		// NOTE: The amounts are only checked against the allowance limits here.
+		//       They are accounted in `post_dispatch` once the call succeeded.
+		with_transaction(|| {
+			let result = checks.iter().try_for_each(|(who, recv, amount)| {
+				ensure_approved::<pallet_transfer_allowlist::pallet::Pallet<T>>(
+					who.clone(),
+					CurrencyId::Native,
+					*amount,
 				)
-				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Custom(255)))
-			})
+				.and_then(|_| use_native_allowance::<T>(who, recv, *amount))
+			});
+
+			TransactionOutcome::Rollback(result)
+		})
+		.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Custom(255)))?;
+
+		Ok(checks)
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_: &DispatchInfoOf<Self::Call>,
+		_: &PostDispatchInfoOf<Self::Call>,
+		_: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if let (Some(checks), Ok(())) = (pre, result) {
+			for (who, recv, amount) in checks {
+				// NOTE: Already checked in `pre_dispatch`, this only fails if the
+				//       dispatched call modified the allowances, in which case
+				//       the transfer is not accounted.
+				let _ = use_native_allowance::<T>(&who, &recv, amount);
+			}
+		}
+
+		Ok(())
 	}
 }
 
-fn amalgamate_allowance(
+/// Checks a native currency transfer against the allowances of the sender and
+/// accounts it against their limits.
+fn use_native_allowance<T>(who: &AccountId, recv: &AccountId, amount: Balance) -> DispatchResult
+where
+	T: frame_system::Config<AccountId = AccountId>
+		+ pallet_transfer_allowlist::Config<
+			Balance = Balance,
+			CurrencyId = FilterCurrency,
+			Location = RestrictedTransferLocation,
+		>,
+{
+	amalgamate_allowance::<pallet_transfer_allowlist::pallet::Pallet<T>>(
+		pallet_transfer_allowlist::pallet::Pallet::<T>::allowance(
+			who.clone(),
+			RestrictedTransferLocation::Local(recv.clone()),
+			FilterCurrency::All,
+			amount,
+		),
+		pallet_transfer_allowlist::pallet::Pallet::<T>::allowance(
+			who.clone(),
+			RestrictedTransferLocation::Local(recv.clone()),
+			FilterCurrency::Specific(CurrencyId::Native),
+			amount,
+		),
+	)
+}
+
+/// Calls that can be requested for approval under an approval policy of the
+/// transfer allowlist. All of them check their transfers against the
+/// transfer allowlist when dispatched.
+pub struct ApprovableTransferCalls<T>(sp_std::marker::PhantomData<T>);
+
+impl<T> Contains<<T as frame_system::Config>::RuntimeCall> for ApprovableTransferCalls<T>
+where
+	T: pallet_restricted_tokens::Config
+		+ pallet_restricted_xtokens::Config
+		+ pallet_liquidity_pools::Config,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<pallet_restricted_tokens::Call<T>>
+		+ IsSubType<pallet_restricted_xtokens::Call<T>>
+		+ IsSubType<pallet_liquidity_pools::Call<T>>,
+{
+	fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		matches!(
+			IsSubType::<pallet_restricted_tokens::Call<T>>::is_sub_type(call),
+			Some(
+				pallet_restricted_tokens::Call::transfer { .. }
+					| pallet_restricted_tokens::Call::transfer_all { .. }
+					| pallet_restricted_tokens::Call::transfer_keep_alive { .. }
+			)
+		) || IsSubType::<pallet_restricted_xtokens::Call<T>>::is_sub_type(call).is_some()
+			|| matches!(
+				IsSubType::<pallet_liquidity_pools::Call<T>>::is_sub_type(call),
+				Some(
+					pallet_liquidity_pools::Call::transfer { .. }
+						| pallet_liquidity_pools::Call::transfer_tranche_tokens { .. }
+				)
+			)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T>
+	pallet_transfer_allowlist::BenchmarkHelper<AccountId, <T as frame_system::Config>::RuntimeCall>
+	for ApprovableTransferCalls<T>
+where
+	T: frame_system::Config<AccountId = AccountId>
+		+ pallet_restricted_tokens::Config<CurrencyId = CurrencyId, Balance = Balance>
+		+ pallet_balances::Config<Balance = Balance>
+		+ pallet_timestamp::Config<Moment = cfg_traits::Millis>,
+	<T as frame_system::Config>::RuntimeCall: From<pallet_restricted_tokens::Call<T>>,
+{
+	fn approvable_call(receiver: AccountId) -> <T as frame_system::Config>::RuntimeCall {
+		use frame_support::traits::Get;
+
+		pallet_restricted_tokens::Call::<T>::transfer {
+			dest: T::Lookup::unlookup(receiver),
+			currency_id: CurrencyId::Native,
+			amount: <T as pallet_balances::Config>::ExistentialDeposit::get(),
+		}
+		.into()
+	}
+
+	fn advance_time(seconds: cfg_traits::Seconds) {
+		let now = pallet_timestamp::Pallet::<T>::get();
+		pallet_timestamp::Pallet::<T>::set_timestamp(
+			now.saturating_add(seconds.saturating_mul(1000)),
+		);
+	}
+}
+
+/// Ensures that neither the approval policy for the specific currency nor the
+/// one for all currencies requires approval for the transfer.
+fn ensure_approved<T>(sender: AccountId, currency: CurrencyId, amount: Balance) -> DispatchResult
+where
+	T: TransferAllowance<AccountId, Balance = Balance, CurrencyId = FilterCurrency>,
+{
+	T::ensure_approved(sender.clone(), FilterCurrency::Specific(currency), amount)?;
+	T::ensure_approved(sender, FilterCurrency::All, amount)
+}
+
+/// Combines the allowances for the specific currency and for all currencies.
+/// A transfer allowed by either of both is allowed, unless it exceeds the
+/// limits of an allowance for the receiver under the other one.
+fn amalgamate_allowance<T>(
 	first: Result<Option<RestrictedTransferLocation>, DispatchError>,
 	second: Result<Option<RestrictedTransferLocation>, DispatchError>,
-) -> DispatchResult {
+) -> DispatchResult
+where
+	T: TransferAllowance<AccountId>,
+{
 	match (first, second) {
+		// There is an allowance set for `Specific(id)` or `All` for the given recv,
+		// but the transfer exceeds its limits
+		(Err(e), _) | (_, Err(e)) if !T::is_missing_allowance(&e) => Err(e),
 		// There is an allowance set for `Specific(id)`, but NOT for the given recv
 		// There is an allowance set for `All`, but NOT for the given recv
 		(Err(e), Err(_)) => Err(e),
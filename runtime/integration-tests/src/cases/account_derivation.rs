@@ -13,7 +13,10 @@
 //! Testing essential account derivations that are done in the runtime
 
 use cfg_primitives::AccountId;
+use cfg_types::domain_address::DomainAddress;
+use parity_scale_codec::Encode;
 use runtime_common::apis::runtime_decl_for_account_conversion_api::AccountConversionApi;
+use sp_core::blake2_256;
 use sp_runtime::traits::{Block, Get, Header, Zero};
 use staging_xcm::v4::{
 	Junction::{AccountId32, AccountKey20, Parachain},
@@ -33,6 +36,8 @@ const KEY_32: [u8; 32] = [
 
 const RANDOM_EVM_ID: u64 = 7868687u64;
 
+const RANDOM_OTHER_ID: u64 = 3421u64;
+
 const RANDOM_PARA_ID: u32 = 1230412u32;
 
 fn network_id(chain_id: u64) -> Option<NetworkId> {
@@ -48,6 +53,11 @@ fn evm_derivation_copy(chain_id: u64) -> AccountId {
 	AccountId::new(bytes)
 }
 
+fn raw32_derivation_copy(chain_id: u64) -> AccountId {
+	let tag = b"RAW32";
+	AccountId::new((tag, chain_id, KEY_32).using_encoded(blake2_256))
+}
+
 #[test_runtimes(all)]
 fn local_evm_account<T: Runtime>() {
 	let env = RuntimeEnv::<T>::default();
@@ -87,6 +97,19 @@ fn lp_evm_account<T: Runtime>() {
 	assert_eq!(evm_derivation_copy(RANDOM_EVM_ID), derived);
 }
 
+#[test_runtimes(all)]
+fn lp_raw32_account<T: Runtime>() {
+	let env = RuntimeEnv::<T>::default();
+
+	let derived = env.parachain_state(|| DomainAddress::Raw32(RANDOM_OTHER_ID, KEY_32).account());
+
+	assert_eq!(raw32_derivation_copy(RANDOM_OTHER_ID), derived);
+	assert_ne!(
+		derived,
+		DomainAddress::Raw32(RANDOM_OTHER_ID + 1, KEY_32).account()
+	);
+}
+
 #[test_runtimes(all)]
 fn relay_chain_account<T: Runtime>() {
 	let env = RuntimeEnv::<T>::default();
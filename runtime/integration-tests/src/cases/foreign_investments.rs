@@ -294,10 +294,14 @@ pub mod utils {
 		(POOL_ID, default_tranche_id::<T>(POOL_ID))
 	}
 
-	pub fn default_order_id<T: Runtime>(investor: &AccountId) -> OrderId {
+	pub fn default_order_id<T: Runtime>(
+		investor: &AccountId,
+		foreign_currency: CurrencyId,
+	) -> OrderId {
 		pallet_foreign_investments::Pallet::<T>::order_id(
 			&investor,
 			default_investment_id::<T>(),
+			foreign_currency,
 			pallet_foreign_investments::Action::Investment,
 		)
 		.expect("Swap order exists; qed")
@@ -1152,8 +1156,11 @@ mod same_currencies {
 
 			// Collecting through investments should not mutate any state
 			let events_before = frame_system::Pallet::<T>::events();
-			let info_before =
-				ForeignInvestmentInfo::<T>::get(&investor, default_investment_id::<T>());
+			let info_before = ForeignInvestmentInfo::<T>::get((
+				&investor,
+				default_investment_id::<T>(),
+				currency_id,
+			));
 			assert_ok!(pallet_investments::Pallet::<T>::collect_investments_for(
 				RawOrigin::Signed(Keyring::Alice.into()).into(),
 				investor.clone(),
@@ -1182,7 +1189,11 @@ mod same_currencies {
 					}
 				}));
 			assert_eq!(
-				ForeignInvestmentInfo::<T>::get(investor, default_investment_id::<T>()),
+				ForeignInvestmentInfo::<T>::get((
+					investor,
+					default_investment_id::<T>(),
+					currency_id
+				)),
 				info_before
 			);
 		});
@@ -1697,9 +1708,8 @@ mod same_currencies {
 						amount,
 					));
 
-					// Should fail to decrease or collect for another
-					// foreign currency as long as `RedemptionState`
-					// exists
+					// Should fail to decrease for another foreign currency
+					// without redemption
 					let decrease_msg = LiquidityPoolMessage::CancelRedeemRequest {
 						pool_id,
 						tranche_id: default_tranche_id::<T>(pool_id),
@@ -1708,7 +1718,7 @@ mod same_currencies {
 					};
 					assert_noop!(
 						pallet_liquidity_pools::Pallet::<T>::handle(EVM_DOMAIN, decrease_msg),
-						pallet_foreign_investments::Error::<T>::MismatchedForeignCurrency
+						pallet_foreign_investments::Error::<T>::InfoNotFound
 					);
 				});
 			}
@@ -1761,7 +1771,7 @@ mod mismatching_currencies {
 			);
 			fulfill_swap_into_pool::<T>(
 				pool_id,
-				default_order_id::<T>(&investor),
+				default_order_id::<T>(&investor, foreign_currency),
 				invest_amount_pool_denominated,
 				invest_amount_foreign_denominated,
 				trader,
@@ -1871,7 +1881,7 @@ mod mismatching_currencies {
 			);
 
 			// Fulfilling order should propagate it from swapping to investing
-			let swap_order_id = default_order_id::<T>(&investor);
+			let swap_order_id = default_order_id::<T>(&investor, foreign_currency);
 			fulfill_swap_into_pool::<T>(
 				pool_id,
 				swap_order_id,
@@ -1914,14 +1924,14 @@ mod mismatching_currencies {
 			assert!(!outbound_message_dispatched::<T>(|| {
 				assert_ok!(pallet_order_book::Pallet::<T>::fill_order(
 					RawOrigin::Signed(trader.clone()).into(),
-					default_order_id::<T>(&investor),
+					default_order_id::<T>(&investor, foreign_currency),
 					invest_amount_pool_denominated / 4,
 					invest_amount_foreign_denominated / 4,
 				));
 				assert!(frame_system::Pallet::<T>::events().iter().any(|e| {
 					e.event
 						== pallet_order_book::Event::<T>::OrderFulfillment {
-							order_id: default_order_id::<T>(&investor),
+							order_id: default_order_id::<T>(&investor, foreign_currency),
 							placing_account: investor.clone(),
 							fulfilling_account: trader.clone(),
 							partial_fulfillment: true,
@@ -1934,7 +1944,7 @@ mod mismatching_currencies {
 				}));
 			}));
 
-			let swap_order_id = default_order_id::<T>(&investor);
+			let swap_order_id = default_order_id::<T>(&investor, foreign_currency);
 			assert_ok!(pallet_order_book::Pallet::<T>::fill_order(
 				RawOrigin::Signed(trader.clone()).into(),
 				swap_order_id,
@@ -2019,7 +2029,7 @@ mod mismatching_currencies {
 			);
 			fulfill_swap_into_pool::<T>(
 				pool_id,
-				default_order_id::<T>(&investor),
+				default_order_id::<T>(&investor, foreign_currency),
 				invest_amount_pool_denominated,
 				invest_amount_foreign_denominated,
 				trader.clone(),
@@ -2059,7 +2069,7 @@ mod mismatching_currencies {
 
 			assert_ok!(pallet_order_book::Pallet::<T>::fill_order(
 				RawOrigin::Signed(trader.clone()).into(),
-				default_order_id::<T>(&investor),
+				default_order_id::<T>(&investor, foreign_currency),
 				invest_amount_pool_denominated / 2,
 				invest_amount_foreign_denominated / 2
 			));
@@ -27,7 +27,7 @@ use pallet_loans::{
 	},
 };
 use runtime_common::{
-	apis::{runtime_decl_for_loans_api::LoansApiV3, runtime_decl_for_pools_api::PoolsApiV1},
+	apis::{runtime_decl_for_loans_api::LoansApiV4, runtime_decl_for_pools_api::PoolsApiV1},
 	oracle::Feeder,
 };
 use sp_runtime::FixedPointNumber;
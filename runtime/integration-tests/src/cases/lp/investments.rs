@@ -118,15 +118,16 @@ mod utils {
 		action: Action,
 		amount: Option<<T as pallet_order_book::Config>::BalanceOut>,
 	) {
-		let order = pallet_order_book::Orders::<T>::get(
-			pallet_foreign_investments::Pallet::<T>::order_id(
-				&investor,
-				investment_id::<T>(pool, tranche),
-				action,
-			)
-			.expect("Nothing to match"),
-		)
-		.unwrap();
+		let order_id = pallet_foreign_investments::OrderIdToSwapId::<T>::iter()
+			.find(|(_, (who, (swap_investment_id, _, swap_action)))| {
+				*who == investor
+					&& *swap_investment_id == investment_id::<T>(pool, tranche)
+					&& *swap_action == action
+			})
+			.map(|(order_id, _)| order_id)
+			.expect("Nothing to match");
+
+		let order = pallet_order_book::Orders::<T>::get(order_id).unwrap();
 
 		let from = &order.currency_out;
 		let to = &order.currency_in;
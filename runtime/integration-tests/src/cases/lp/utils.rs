@@ -92,7 +92,7 @@ pub fn verify_outbound_failure_on_lp<T: Runtime>(to: H160) {
 		.clone();
 
 	// The sender is the sender account on the gateway
-	assert_eq!(T::Sender::get().h160(), status.from);
+	assert_eq!(T::Sender::get().h160(), Some(status.from));
 	assert_eq!(status.to.unwrap().0, to.0);
 	assert!(matches!(
 		last_event::<T, pallet_liquidity_pools_gateway_queue::Event::<T>>(),
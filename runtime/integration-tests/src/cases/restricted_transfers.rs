@@ -44,6 +44,9 @@ use crate::{
 };
 
 mod local {
+	use cfg_primitives::SECONDS_PER_DAY;
+	use pallet_transfer_allowlist::{AllowanceLimits, VelocityLimit};
+
 	use super::*;
 
 	const TRANSFER_AMOUNT: u32 = 100;
@@ -291,6 +294,52 @@ mod local {
 			},
 		);
 	}
+
+	#[test_runtimes(all)]
+	fn failed_transfer_does_not_use_velocity_limit<T: Runtime>() {
+		let mut env = RuntimeEnv::<T>::from_parachain_storage(
+			Genesis::default()
+				.add(genesis::balances::<T>(cfg(TRANSFER_AMOUNT + 10)))
+				.storage(),
+		);
+
+		env.parachain_state_mut(|| {
+			assert_ok!(
+				pallet_transfer_allowlist::Pallet::<T>::add_transfer_allowance_with_limits(
+					RawOrigin::Signed(Keyring::Alice.into()).into(),
+					FilterCurrency::All,
+					RestrictedTransferLocation::Local(Keyring::Bob.id()),
+					AllowanceLimits {
+						per_transfer: None,
+						velocity: Some(VelocityLimit {
+							amount: cfg(TRANSFER_AMOUNT),
+							fixed_window: SECONDS_PER_DAY,
+						}),
+					},
+				)
+			);
+		});
+
+		// The batch fails after the transfer, which is reverted
+		env.submit_now(
+			Keyring::Alice,
+			pallet_utility::Call::<T>::batch_all {
+				calls: vec![
+					transfer_to(Keyring::Bob).into(),
+					pallet_balances::Call::<T>::force_transfer {
+						source: Keyring::Alice.into(),
+						dest: Keyring::Bob.into(),
+						value: cfg(1),
+					}
+					.into(),
+				],
+			},
+		)
+		.unwrap_err();
+
+		// The whole velocity limit is still available
+		process_ok(&mut env, Keyring::Alice, transfer_to(Keyring::Bob));
+	}
 }
 
 mod xcm {
@@ -358,6 +407,9 @@ mod xcm {
 }
 
 mod eth_address {
+	use cfg_primitives::SECONDS_PER_DAY;
+	use pallet_transfer_allowlist::{AllowanceLimits, AllowanceLimitsOf, VelocityLimit};
+
 	use super::*;
 
 	const TRANSFER: u32 = 10;
@@ -365,8 +417,7 @@ mod eth_address {
 	const CONTRACT_ACCOUNT: H160 = H160::repeat_byte(1);
 	const ROUTER_ID: RouterId = RouterId::Axelar(AxelarId::Evm(CHAIN_ID));
 
-	#[test_runtimes(all)]
-	fn restrict_lp_eth_transfer<T: Runtime>() {
+	fn setup<T: Runtime>() -> (RuntimeEnv<T>, CustomCurrency) {
 		let pallet_index = T::PalletInfo::index::<pallet_liquidity_pools::Pallet<T>>();
 		let curr = CustomCurrency(
 			CurrencyId::ForeignAsset(1),
@@ -400,12 +451,21 @@ mod eth_address {
 		);
 
 		env.parachain_state_mut(|| {
-			let curr_contract = DomainAddress::Evm(CHAIN_ID, CONTRACT_ACCOUNT);
-
 			assert_ok!(pallet_liquidity_pools_gateway::Pallet::<T>::set_routers(
 				RawOrigin::Root.into(),
 				BoundedVec::try_from(vec![ROUTER_ID]).unwrap(),
 			));
+		});
+
+		(env, curr)
+	}
+
+	#[test_runtimes(all)]
+	fn restrict_lp_eth_transfer<T: Runtime>() {
+		let (mut env, curr) = setup::<T>();
+
+		env.parachain_state_mut(|| {
+			let curr_contract = DomainAddress::Evm(CHAIN_ID, CONTRACT_ACCOUNT);
 
 			assert_ok!(
 				pallet_transfer_allowlist::Pallet::<T>::add_transfer_allowance(
@@ -434,4 +494,91 @@ mod eth_address {
 			));
 		});
 	}
+
+	fn add_allowance_with_limits<T: Runtime>(filter: FilterCurrency, limits: AllowanceLimitsOf<T>) {
+		assert_ok!(
+			pallet_transfer_allowlist::Pallet::<T>::add_transfer_allowance_with_limits(
+				RawOrigin::Signed(Keyring::Alice.into()).into(),
+				filter,
+				RestrictedTransferLocation::Address(DomainAddress::Evm(CHAIN_ID, CONTRACT_ACCOUNT)),
+				limits,
+			)
+		);
+	}
+
+	#[test_runtimes(all)]
+	fn restrict_lp_eth_transfer_above_per_transfer_limit<T: Runtime>() {
+		let (mut env, curr) = setup::<T>();
+
+		env.parachain_state_mut(|| {
+			add_allowance_with_limits::<T>(
+				FilterCurrency::Specific(curr.id()),
+				AllowanceLimits {
+					per_transfer: Some(curr.val(TRANSFER / 2)),
+					velocity: None,
+				},
+			);
+			// An allowance for all currencies does not lift the limits of the allowance
+			// for the specific currency
+			add_allowance_with_limits::<T>(FilterCurrency::All, AllowanceLimits::default());
+
+			assert_noop!(
+				pallet_liquidity_pools::Pallet::<T>::transfer(
+					RawOrigin::Signed(Keyring::Alice.into()).into(),
+					curr.id(),
+					DomainAddress::Evm(CHAIN_ID, CONTRACT_ACCOUNT),
+					curr.val(TRANSFER),
+				),
+				pallet_transfer_allowlist::Error::<T>::TransferAmountAboveLimit
+			);
+
+			assert_ok!(pallet_liquidity_pools::Pallet::<T>::transfer(
+				RawOrigin::Signed(Keyring::Alice.into()).into(),
+				curr.id(),
+				DomainAddress::Evm(CHAIN_ID, CONTRACT_ACCOUNT),
+				curr.val(TRANSFER / 2),
+			));
+		});
+	}
+
+	#[test_runtimes(all)]
+	fn restrict_lp_eth_transfer_above_velocity_limit<T: Runtime>() {
+		let (mut env, curr) = setup::<T>();
+
+		env.parachain_state_mut(|| {
+			add_allowance_with_limits::<T>(
+				FilterCurrency::Specific(curr.id()),
+				AllowanceLimits::default(),
+			);
+			// The limits of an allowance for all currencies also apply when the
+			// allowance for the specific currency has none
+			add_allowance_with_limits::<T>(
+				FilterCurrency::All,
+				AllowanceLimits {
+					per_transfer: None,
+					velocity: Some(VelocityLimit {
+						amount: curr.val(TRANSFER / 2 + 1),
+						fixed_window: SECONDS_PER_DAY,
+					}),
+				},
+			);
+
+			assert_ok!(pallet_liquidity_pools::Pallet::<T>::transfer(
+				RawOrigin::Signed(Keyring::Alice.into()).into(),
+				curr.id(),
+				DomainAddress::Evm(CHAIN_ID, CONTRACT_ACCOUNT),
+				curr.val(TRANSFER / 2),
+			));
+
+			assert_noop!(
+				pallet_liquidity_pools::Pallet::<T>::transfer(
+					RawOrigin::Signed(Keyring::Alice.into()).into(),
+					curr.id(),
+					DomainAddress::Evm(CHAIN_ID, CONTRACT_ACCOUNT),
+					curr.val(TRANSFER / 2),
+				),
+				pallet_transfer_allowlist::Error::<T>::VelocityLimitExceeded
+			);
+		});
+	}
 }
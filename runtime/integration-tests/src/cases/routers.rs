@@ -118,7 +118,7 @@ mod axelar_evm {
 
 			utils::evm::mint_balance_into_derived_account::<T>(OUTBOUND_CONTRACT, cfg(1));
 			utils::evm::mint_balance_into_derived_account::<T>(
-				get_gateway_domain_address::<T>().h160(),
+				get_gateway_domain_address::<T>().h160().unwrap(),
 				cfg(1),
 			);
 
@@ -131,6 +131,7 @@ pub trait Runtime:
 	+ pallet_restricted_tokens::Config<Balance = Balance, CurrencyId = CurrencyId>
 	+ pallet_restricted_xtokens::Config
 	+ pallet_transfer_allowlist::Config<
+		Balance = Balance,
 		CurrencyId = FilterCurrency,
 		Location = RestrictedTransferLocation,
 	> + pallet_liquidity_pools::Config<
@@ -312,7 +313,7 @@ pub trait Runtime:
 	/// You can extend this bounds to give extra API support
 	type Api: sp_api::runtime_decl_for_core::CoreV4<Self::BlockExt>
 		+ sp_block_builder::runtime_decl_for_block_builder::BlockBuilderV6<Self::BlockExt>
-		+ apis::runtime_decl_for_loans_api::LoansApiV3<
+		+ apis::runtime_decl_for_loans_api::LoansApiV4<
 			Self::BlockExt,
 			PoolId,
 			LoanId,